@@ -0,0 +1,131 @@
+//! Timer-driven background refresh of all story feeds.
+//!
+//! Background refresh is normally reactive: it only happens when a stale feed
+//! is read. When enabled, [`AutoRefresh`] runs a tokio interval task that
+//! refreshes every [`StoryFeed`](crate::types::StoryFeed) on a fixed schedule
+//! and emits a `feed-updated` event for each feed whose story IDs changed, so
+//! feeds stay warm while the user sits on one tab. One-off background
+//! refreshes go through [`refresh_and_notify`], which emits `feed-refreshed`
+//! instead. Either way each change is announced once.
+//!
+//! The task is off by default. The frontend's Auto Refresh setting starts
+//! and stops it through the `set_auto_refresh` command, and the main window
+//! re-applies the setting at launch.
+//!
+//! A change to the Top feed also rebuilds the tray's "Recent" submenu.
+//!
+//! Each tick first checks connectivity: ticks are skipped while offline, and
+//! an `online-changed` event is emitted when connectivity flips.
+
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter};
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, warn};
+
+use crate::client::{feed_refreshed, HnClient, SharedHnClient};
+use crate::tray;
+use crate::types::StoryFeed;
+
+/// Shortest allowed refresh interval, so the timer can't hammer the API.
+pub const MIN_INTERVAL_SECS: u64 = 30;
+
+/// Refresh `feed` in the background, emitting `feed-refreshed` if its
+/// story IDs changed.
+///
+/// Returns the new IDs, like
+/// [`HnClient::background_refresh_feed`](crate::client::HnClient::background_refresh_feed).
+pub async fn refresh_and_notify(
+    app: &AppHandle,
+    client: &HnClient,
+    feed: StoryFeed,
+) -> Option<Vec<u32>> {
+    let old_ids = client.cached_story_ids(feed).await;
+    let ids = client.background_refresh_feed(feed).await?;
+
+    if let Some(refreshed) = feed_refreshed(feed, old_ids.as_deref(), &ids) {
+        if let Err(e) = app.emit("feed-refreshed", &refreshed) {
+            warn!("Failed to emit feed-refreshed event: {}", e);
+        }
+    }
+    Some(ids)
+}
+
+/// Owner of the running auto-refresh task, if any.
+///
+/// Managed as Tauri state so commands can start and stop the timer.
+#[derive(Default)]
+pub struct AutoRefresh {
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl AutoRefresh {
+    /// Create a controller with no task running.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start the refresh timer, replacing any timer already running.
+    ///
+    /// Intervals shorter than [`MIN_INTERVAL_SECS`] are raised to the minimum.
+    /// Each tick refreshes feeds one at a time via
+    /// [`HnClient::refresh_all_feeds`](crate::client::HnClient::refresh_all_feeds),
+    /// which skips feeds that are already being refreshed.
+    pub fn start(&self, app: AppHandle, client: SharedHnClient, interval: Duration) {
+        let interval = interval.max(Duration::from_secs(MIN_INTERVAL_SECS));
+
+        let task = tauri::async_runtime::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            // The first tick completes immediately; feeds were just loaded
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+
+                let connectivity = client.check_connectivity().await;
+                if connectivity.changed {
+                    if let Err(e) = app.emit("online-changed", connectivity.online) {
+                        warn!("Failed to emit online-changed event: {}", e);
+                    }
+                }
+                if !connectivity.online {
+                    debug!("Offline, skipping auto-refresh");
+                    continue;
+                }
+
+                debug!("Auto-refreshing all feeds");
+
+                for update in client.refresh_all_feeds().await {
+                    if let Err(e) = app.emit("feed-updated", &update) {
+                        warn!("Failed to emit feed-updated event: {}", e);
+                    }
+                    if update.feed == StoryFeed::Top {
+                        tray::refresh_recent_stories(&app, &client).await;
+                    }
+                }
+            }
+        });
+
+        if let Some(previous) = self.lock().replace(task) {
+            previous.abort();
+        }
+
+        info!(interval_secs = interval.as_secs(), "Auto-refresh started");
+    }
+
+    /// Stop the refresh timer if it is running.
+    pub fn stop(&self) {
+        if let Some(task) = self.lock().take() {
+            task.abort();
+            info!("Auto-refresh stopped");
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<JoinHandle<()>>> {
+        self.task.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,243 @@
+//! Backup and restore of reading data (bookmarks and read history).
+//!
+//! The stores themselves live in the frontend's localStorage. The frontend
+//! passes their entries to the export commands and persists whatever the
+//! import commands return; this module owns the file format, atomic writes,
+//! and validation of imported files.
+//!
+//! # File Format
+//!
+//! ```json
+//! { "version": 1, "exportedAt": 1706119200000, "bookmarks": [{ "story": {...}, "bookmarkedAt": ... }] }
+//! { "version": 1, "exportedAt": 1706119200000, "readStories": [{ "id": 123, "timestamp": ... }] }
+//! ```
+//!
+//! Bookmark files match the frontend's existing `exportBookmarksAsJson` output.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::persist;
+use crate::types::{ApiError, HNItem};
+
+/// Current backup file format version.
+pub const BACKUP_VERSION: u32 = 1;
+
+/// A bookmarked story, as stored by the frontend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkEntry {
+    /// Full story data, for offline viewing
+    pub story: HNItem,
+    /// When the story was bookmarked (Unix milliseconds)
+    pub bookmarked_at: u64,
+}
+
+/// A read story, as stored by the frontend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadEntry {
+    /// Story ID
+    pub id: u32,
+    /// When the story was last read (Unix milliseconds)
+    pub timestamp: u64,
+}
+
+/// Bookmark backup file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkBackup {
+    pub version: u32,
+    /// When the backup was written (Unix milliseconds)
+    pub exported_at: u64,
+    pub bookmarks: Vec<BookmarkEntry>,
+}
+
+/// Read history backup file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingHistoryBackup {
+    pub version: u32,
+    /// When the backup was written (Unix milliseconds)
+    pub exported_at: u64,
+    pub read_stories: Vec<ReadEntry>,
+}
+
+/// Write bookmarks to `path` as a backup file.
+///
+/// Returns the number of bookmarks written.
+pub fn export_bookmarks(path: &Path, bookmarks: Vec<BookmarkEntry>) -> Result<usize, ApiError> {
+    let count = bookmarks.len();
+    let backup = BookmarkBackup {
+        version: BACKUP_VERSION,
+        exported_at: persist::now_millis(),
+        bookmarks,
+    };
+
+    persist::write_atomic(path, &serde_json::to_vec_pretty(&backup)?)?;
+    Ok(count)
+}
+
+/// Write read history to `path` as a backup file.
+///
+/// Returns the number of entries written.
+pub fn export_reading_history(path: &Path, entries: Vec<ReadEntry>) -> Result<usize, ApiError> {
+    let count = entries.len();
+    let backup = ReadingHistoryBackup {
+        version: BACKUP_VERSION,
+        exported_at: persist::now_millis(),
+        read_stories: entries,
+    };
+
+    persist::write_atomic(path, &serde_json::to_vec_pretty(&backup)?)?;
+    Ok(count)
+}
+
+/// Parse and validate a bookmark backup.
+fn parse_bookmark_backup(json: &str) -> Result<BookmarkBackup, ApiError> {
+    let backup: BookmarkBackup =
+        serde_json::from_str(json).map_err(|e| ApiError::InvalidBackup(e.to_string()))?;
+
+    if backup.version == 0 || backup.version > BACKUP_VERSION {
+        return Err(ApiError::InvalidBackup(format!(
+            "unsupported version {} (expected {})",
+            backup.version, BACKUP_VERSION
+        )));
+    }
+
+    Ok(backup)
+}
+
+/// Merge imported bookmarks into existing ones, deduplicated by story ID.
+///
+/// Existing entries win on conflict. The result is sorted newest first, the
+/// order the frontend stores bookmarks in.
+fn merge_bookmarks(
+    existing: Vec<BookmarkEntry>,
+    imported: Vec<BookmarkEntry>,
+) -> Vec<BookmarkEntry> {
+    let mut seen = HashSet::new();
+    let mut merged: Vec<BookmarkEntry> = existing
+        .into_iter()
+        .chain(imported)
+        .filter(|entry| seen.insert(entry.story.id))
+        .collect();
+
+    merged.sort_by(|a, b| b.bookmarked_at.cmp(&a.bookmarked_at));
+    merged
+}
+
+/// Read a bookmark backup from `path` and merge it into `existing`.
+///
+/// # Errors
+///
+/// Returns `ApiError::InvalidBackup` if the file isn't a valid bookmark
+/// backup; `existing` is untouched in that case.
+pub fn import_bookmarks(
+    path: &Path,
+    existing: Vec<BookmarkEntry>,
+) -> Result<Vec<BookmarkEntry>, ApiError> {
+    let json = std::fs::read_to_string(path)?;
+    let backup = parse_bookmark_backup(&json)?;
+    Ok(merge_bookmarks(existing, backup.bookmarks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RawHNItem;
+    use tempfile::TempDir;
+
+    fn bookmark(id: u32, bookmarked_at: u64) -> BookmarkEntry {
+        let raw: RawHNItem = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "type": "story",
+            "title": format!("Story {}", id)
+        }))
+        .unwrap();
+        BookmarkEntry {
+            story: raw.into(),
+            bookmarked_at,
+        }
+    }
+
+    fn ids(entries: &[BookmarkEntry]) -> Vec<u32> {
+        entries.iter().map(|e| e.story.id).collect()
+    }
+
+    #[test]
+    fn bookmarks_export_import_roundtrip_preserves_ids() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("bookmarks.json");
+
+        let written = export_bookmarks(&path, vec![bookmark(3, 300), bookmark(1, 100)]).unwrap();
+        assert_eq!(written, 2);
+
+        let imported = import_bookmarks(&path, vec![]).unwrap();
+        assert_eq!(ids(&imported), vec![3, 1]);
+    }
+
+    #[test]
+    fn import_bookmarks_merges_and_dedupes_by_id() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("bookmarks.json");
+        export_bookmarks(&path, vec![bookmark(1, 100), bookmark(2, 500)]).unwrap();
+
+        let merged = import_bookmarks(&path, vec![bookmark(1, 900), bookmark(4, 400)]).unwrap();
+
+        assert_eq!(ids(&merged), vec![1, 2, 4]);
+        // The existing entry for story 1 wins over the imported one
+        assert_eq!(merged[0].bookmarked_at, 900);
+    }
+
+    #[test]
+    fn import_bookmarks_accepts_frontend_export_format() {
+        let json = r#"{
+            "version": 1,
+            "exportedAt": 1706119200000,
+            "bookmarks": [{
+                "story": {
+                    "id": 42, "type": 0, "by": "pg", "time": 1706119200,
+                    "text": null, "url": "https://example.com", "score": 10,
+                    "title": "Example", "descendants": 3, "kids": null,
+                    "parent": null, "dead": false, "deleted": false
+                },
+                "bookmarkedAt": 1706119200000
+            }]
+        }"#;
+
+        let backup = parse_bookmark_backup(json).unwrap();
+        assert_eq!(ids(&backup.bookmarks), vec![42]);
+    }
+
+    #[test]
+    fn import_bookmarks_rejects_malformed_files() {
+        let not_json = parse_bookmark_backup("not json");
+        assert!(matches!(not_json, Err(ApiError::InvalidBackup(_))));
+
+        let wrong_shape = parse_bookmark_backup(r#"{"version": 1, "exportedAt": 0}"#);
+        assert!(matches!(wrong_shape, Err(ApiError::InvalidBackup(_))));
+
+        let future = parse_bookmark_backup(r#"{"version": 99, "exportedAt": 0, "bookmarks": []}"#);
+        assert!(matches!(future, Err(ApiError::InvalidBackup(_))));
+    }
+
+    #[test]
+    fn reading_history_export_writes_camel_case_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("history.json");
+
+        let entries = vec![ReadEntry {
+            id: 7,
+            timestamp: 123,
+        }];
+        assert_eq!(export_reading_history(&path, entries).unwrap(), 1);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["version"], BACKUP_VERSION);
+        assert_eq!(json["readStories"][0]["id"], 7);
+        assert!(json["exportedAt"].as_u64().unwrap() > 0);
+    }
+}
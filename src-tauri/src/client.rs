@@ -17,6 +17,8 @@
 //! | Items | 5 min | 10,000 | Stories, comments, etc. |
 //! | Story IDs | 2 min | 10 | Feed listings (per feed type) |
 //! | Users | 10 min | 100 | User profiles |
+//! | Articles | 30 min | 200 | Extracted reader-mode content (by URL) |
+//! | Favicons (disk) | 7 days | - | Site icons by domain; misses kept 1 day |
 //!
 //! # Background Refresh (Stale-While-Revalidate)
 //!
@@ -35,7 +37,7 @@
 //! let client = create_client();
 //!
 //! // Fetch top stories (cached if available)
-//! let response = client.fetch_stories_paginated(StoryFeed::Top, 0, 30).await?;
+//! let response = client.fetch_stories_paginated(StoryFeed::Top, 0, 30, false).await?;
 //!
 //! // Fetch a single item
 //! let story = client.fetch_item(12345).await?;
@@ -54,14 +56,30 @@
 //! - Invalid responses (parse errors)
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use futures::{StreamExt, TryStreamExt};
 use moka::future::Cache;
+use moka::notification::RemovalCause;
+use moka::Expiry;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use reqwest::Client;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock, Semaphore};
 use tracing::{debug, info, instrument, warn};
 
+use crate::code;
+use crate::hiring::{self, JobPosting};
+use crate::html;
+use crate::links;
+use crate::persist::{self, unix_millis};
+use crate::seen_comments;
+use crate::settings;
+use crate::text_block;
+use crate::toc;
 use crate::types::*;
 
 /// Base URL for the official HN Firebase API.
@@ -79,11 +97,273 @@ const STORY_IDS_CACHE_TTL: Duration = Duration::from_secs(2 * 60);
 /// TTL for user profiles - 10 minutes (user data changes less frequently).
 const USER_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
 
-/// Staleness threshold as percentage of TTL.
+/// TTL for extracted article content - 30 minutes (articles rarely change).
+const ARTICLE_CACHE_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// How long a downloaded favicon stays valid on disk - 7 days.
+const FAVICON_CACHE_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// How long a "no favicon" result is remembered - 1 day.
+const FAVICON_MISS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Maximum concurrent favicon downloads.
+const FAVICON_CONCURRENCY: usize = 4;
+
+/// Largest favicon accepted, to keep the disk cache small.
+const MAX_FAVICON_BYTES: usize = 256 * 1024;
+
+/// Largest home page scanned for a `<link rel="icon">` - 1 MiB.
+const MAX_FAVICON_PAGE_BYTES: usize = 1024 * 1024;
+
+/// Default cap on downloaded article size - 5 MiB.
+const DEFAULT_MAX_ARTICLE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Default for [`ClientConfig::article_timeout`] - 45 seconds.
+///
+/// Longer than the 30-second API timeout, since third-party article hosts
+/// are often slower than the HN APIs.
+const DEFAULT_ARTICLE_TIMEOUT: Duration = Duration::from_secs(45);
+
+/// Default for [`ClientConfig::min_article_words`].
+///
+/// Well below a real article, but more than the teaser or cookie notice
+/// readability occasionally settles on.
+const DEFAULT_MIN_ARTICLE_WORDS: usize = 25;
+
+/// Default for [`ClientConfig::max_retries`].
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default for [`ClientConfig::max_retry_wait`] - 10 seconds.
+///
+/// A longer Retry-After gives up at once: the user would rather see the
+/// error than wait that long for a feed.
+const DEFAULT_MAX_RETRY_WAIT: Duration = Duration::from_secs(10);
+
+/// User agent sent with every request unless a fallback is being tried.
+const USER_AGENT: &str = "pastel-hn/0.3";
+
+/// Default for [`ClientConfig::fallback_user_agents`]: a current desktop
+/// browser, for sites that block or strip pages for unknown clients.
+const FALLBACK_USER_AGENTS: &[&str] = &[
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/124.0.0.0 Safari/537.36",
+];
+
+/// Default maximum redirects followed for a single request.
+const DEFAULT_MAX_REDIRECTS: usize = 10;
+
+/// How long a resolved short URL is remembered - 1 day.
+const SHORT_URL_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Maximum redirect hops followed when resolving a short URL.
+const MAX_SHORTENER_REDIRECTS: usize = 5;
+
+/// Link-shortener hosts resolved before article extraction.
+const SHORTENER_HOSTS: &[&str] = &[
+    "t.co",
+    "bit.ly",
+    "buff.ly",
+    "tinyurl.com",
+    "ow.ly",
+    "goo.gl",
+    "is.gd",
+    "lnkd.in",
+    "dlvr.it",
+    "trib.al",
+];
+
+/// How long a connectivity check result is reused - 5 seconds.
+///
+/// Also the minimum spacing between checks, so polling `is_online` can't
+/// hammer the network. Only debounces checks: the offline fallback to
+/// cached data lasts for [`OFFLINE_FALLBACK_TTL`].
+const CONNECTIVITY_CHECK_TTL: Duration = Duration::from_secs(5);
+
+/// How long a failed connectivity check keeps lookups on cached data - 30
+/// seconds.
+///
+/// Checks only run on the refresh timer or an `is_online` call, so the
+/// fallback expires on its own and lookups then try the network again.
+const OFFLINE_FALLBACK_TTL: Duration = Duration::from_secs(30);
+
+/// Timeout for the connectivity check request.
+const CONNECTIVITY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Maximum number of articles downloaded by a single prefetch run.
+const MAX_ARTICLE_PREFETCH: usize = 10;
+
+/// Maximum concurrent article downloads during prefetch.
+///
+/// Article hosts are third-party sites, so we stay polite and keep this low.
+const ARTICLE_PREFETCH_CONCURRENCY: usize = 3;
+
+/// Maximum number of stories in a front-page digest.
+pub const MAX_DIGEST_STORIES: usize = 30;
+
+/// Length of digest excerpts, in characters.
+const DIGEST_EXCERPT_CHARS: usize = 280;
+
+/// Maximum number of stories whose comments a single prefetch run loads.
+const MAX_COMMENT_PREFETCH_STORIES: usize = 30;
+
+/// Largest per-story comment budget accepted by
+/// [`HnClient::prefetch_comments`] and
+/// [`HnClient::fetch_stories_with_comments`].
+const MAX_COMMENT_PREFETCH_NODES: usize = 200;
+
+/// Maximum stories whose comment trees are prefetched or batch-fetched at
+/// once, across all calls.
+const COMMENT_PREFETCH_CONCURRENCY: usize = 4;
+
+/// Maximum concurrent item fetches while warming a feed.
+const WARM_CONCURRENCY: usize = 8;
+
+/// Minimum number of submission IDs scanned per batch when filtering.
+///
+/// Users' submissions are often dominated by one type, so filtered pages
+/// scan ahead in larger batches rather than one round trip per match.
+const SUBMISSIONS_BATCH_SIZE: usize = 30;
+
+/// Maximum number of parents walked by [`HnClient::fetch_comment_context`].
+///
+/// Real threads rarely nest this deep; the cap bounds the number of round
+/// trips for pathological chains.
+pub const MAX_COMMENT_CONTEXT_DEPTH: usize = 30;
+
+/// Default staleness threshold as percentage of TTL.
 ///
 /// When cached data is older than this percentage of its TTL, a background
 /// refresh is triggered while returning the cached data immediately.
-const STALE_THRESHOLD_PERCENT: u64 = 75;
+const DEFAULT_STALE_THRESHOLD_PERCENT: u8 = 75;
+
+/// Valid range for [`ClientConfig::stale_threshold_percent`].
+const STALE_THRESHOLD_RANGE: std::ops::RangeInclusive<u8> = 1..=99;
+
+/// Settings for [`HnClient`].
+///
+/// The defaults point at the production HN and Algolia APIs. Tests override
+/// the base URLs to talk to a local mock server.
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Base URL for the HN Firebase API (no trailing slash).
+    pub hn_base_url: String,
+    /// Base URL for the Algolia HN Search API (no trailing slash).
+    pub algolia_base_url: String,
+    /// Base URL for the HN website (no trailing slash). Scraped for data the
+    /// APIs don't expose, and the target of HN links in exports, reader
+    /// content, and the link commands.
+    pub hn_web_base_url: String,
+    /// Origin that favicon requests go to, with `{domain}` replaced by the
+    /// requested domain. Tests point this at a mock server.
+    pub favicon_origin: String,
+    /// Directory for on-disk caches (favicons).
+    pub cache_dir: PathBuf,
+    /// Percentage of the story IDs TTL after which a feed counts as stale (1-99).
+    ///
+    /// Can be changed at runtime via [`HnClient::set_stale_threshold_percent`].
+    pub stale_threshold_percent: u8,
+    /// Strip tracking parameters from links opened in the browser.
+    ///
+    /// Can be changed at runtime via [`HnClient::set_strip_tracking_params`].
+    pub strip_tracking_params: bool,
+    /// Hosts treated as link shorteners by [`HnClient::resolve_short_url`].
+    pub shortener_hosts: Vec<String>,
+    /// Largest article download accepted by [`HnClient::fetch_article_content`].
+    ///
+    /// Can be changed at runtime via [`HnClient::set_max_article_bytes`].
+    pub max_article_bytes: usize,
+    /// Timeout for the article download in [`HnClient::fetch_article_content`],
+    /// overriding the client-wide API timeout.
+    pub article_timeout: Duration,
+    /// Fewest words readability must extract from a page before
+    /// [`HnClient::fetch_article_content`] falls back to the page's largest
+    /// block of text. 0 disables the fallback.
+    pub min_article_words: usize,
+    /// User agents [`HnClient::fetch_article_content`] retries with, in
+    /// order, when a site answers 403 or the page yields no text. Empty
+    /// disables the retry.
+    pub fallback_user_agents: Vec<String>,
+    /// Maximum redirects followed for a single request.
+    pub max_redirects: usize,
+    /// Follow redirects from `https` to plain `http` URLs.
+    pub allow_insecure_redirects: bool,
+    /// Leave links back to the article's own site out of
+    /// [`ArticleContent::links`].
+    ///
+    /// Can be changed at runtime via [`HnClient::set_exclude_same_host_links`].
+    pub exclude_same_host_links: bool,
+    /// Offline mode: serve items, feeds, and users from cache only and
+    /// return `ApiError::Offline` on a miss instead of using the network.
+    pub cache_only: bool,
+    /// Deepest comment tree loaded by [`HnClient::fetch_comments`] and
+    /// [`HnClient::fetch_comment_children`]; deeper requests are clamped.
+    pub max_comment_depth: u8,
+    /// Cap on the rate of HN and Algolia requests, shared by every fetch
+    /// (including prefetches and background refreshes). Off by default.
+    ///
+    /// Can be changed at runtime via [`HnClient::set_rate_limit`].
+    pub rate_limit: Option<RateLimit>,
+    /// Page size used by [`HnClient::search`] when none (0) is requested.
+    pub default_hits_per_page: u32,
+    /// Largest page size [`HnClient::search`] asks for; larger requests are
+    /// clamped. Never more than [`ALGOLIA_MAX_HITS_PER_PAGE`].
+    pub max_hits_per_page: u32,
+    /// Report item cache evictions as `cache-evicted` events. Off by
+    /// default, since expiry makes them noisy; meant for debugging.
+    ///
+    /// Can be changed at runtime via [`HnClient::set_eviction_events`].
+    pub eviction_events: bool,
+    /// Times an HN or Algolia request is retried after a 429 or 5xx
+    /// response before giving up. 0 disables retries.
+    pub max_retries: u32,
+    /// Longest wait before a retry. Waits follow the response's Retry-After
+    /// (or back off exponentially from 1 second without one); a longer
+    /// Retry-After stops retrying.
+    pub max_retry_wait: Duration,
+}
+
+/// Request rate allowed by [`ClientConfig::rate_limit`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Sustained requests per second
+    pub requests_per_second: f64,
+    /// Requests that may be sent at once after a quiet period
+    pub burst: u32,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            hn_base_url: HN_BASE_URL.to_string(),
+            algolia_base_url: ALGOLIA_BASE_URL.to_string(),
+            hn_web_base_url: links::HN_WEB_URL.to_string(),
+            favicon_origin: "https://{domain}".to_string(),
+            cache_dir: dirs::cache_dir()
+                .unwrap_or_else(std::env::temp_dir)
+                .join("pastel-hn"),
+            stale_threshold_percent: DEFAULT_STALE_THRESHOLD_PERCENT,
+            strip_tracking_params: true,
+            shortener_hosts: SHORTENER_HOSTS.iter().map(|h| h.to_string()).collect(),
+            max_article_bytes: DEFAULT_MAX_ARTICLE_BYTES,
+            article_timeout: DEFAULT_ARTICLE_TIMEOUT,
+            min_article_words: DEFAULT_MIN_ARTICLE_WORDS,
+            fallback_user_agents: FALLBACK_USER_AGENTS
+                .iter()
+                .map(|ua| ua.to_string())
+                .collect(),
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+            allow_insecure_redirects: false,
+            exclude_same_host_links: false,
+            cache_only: false,
+            max_comment_depth: settings::MAX_COMMENT_DEPTH,
+            rate_limit: None,
+            default_hits_per_page: DEFAULT_HITS_PER_PAGE,
+            max_hits_per_page: DEFAULT_MAX_HITS_PER_PAGE,
+            eviction_events: false,
+            max_retries: DEFAULT_MAX_RETRIES,
+            max_retry_wait: DEFAULT_MAX_RETRY_WAIT,
+        }
+    }
+}
 
 /// Check HTTP response for rate limiting and other errors.
 ///
@@ -93,13 +373,8 @@ fn check_response_status(response: &reqwest::Response) -> Result<(), ApiError> {
     let status = response.status();
 
     if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-        // Try to get retry-after header, default to 60 seconds
-        let retry_after = response
-            .headers()
-            .get("retry-after")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.parse::<u32>().ok())
-            .unwrap_or(60);
+        // Default to 60 seconds without a retry-after header
+        let retry_after = retry_after_secs(response).unwrap_or(60);
 
         warn!(retry_after = retry_after, "Rate limited by API");
         return Err(ApiError::RateLimited(retry_after));
@@ -108,6 +383,667 @@ fn check_response_status(response: &reqwest::Response) -> Result<(), ApiError> {
     Ok(())
 }
 
+/// The response's `retry-after` header, in seconds.
+///
+/// Only the delay-seconds form is understood.
+fn retry_after_secs(response: &reqwest::Response) -> Option<u32> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u32>().ok())
+}
+
+/// Whether a response is worth retrying: rate limited or a server error.
+fn is_retryable(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Wait before retry number `retry` (from 1) without a Retry-After: 1s,
+/// 2s, 4s, ...
+fn retry_backoff(retry: u32) -> Duration {
+    Duration::from_secs(1u64 << (retry - 1).min(16))
+}
+
+/// Longest query Algolia accepts, in bytes.
+///
+/// Longer queries are rejected with a 400, so [`HnClient::search`] trims them
+/// first.
+pub const MAX_SEARCH_QUERY_LEN: usize = 512;
+
+/// Most hits Algolia returns per page; larger requests are silently capped.
+pub const ALGOLIA_MAX_HITS_PER_PAGE: u32 = 1000;
+
+/// Default for [`ClientConfig::default_hits_per_page`].
+const DEFAULT_HITS_PER_PAGE: u32 = 20;
+
+/// Default for [`ClientConfig::max_hits_per_page`].
+const DEFAULT_MAX_HITS_PER_PAGE: u32 = 100;
+
+/// The page size to ask Algolia for: `default` for 0, otherwise `requested`
+/// capped at `max` (itself capped at [`ALGOLIA_MAX_HITS_PER_PAGE`]).
+fn clamp_hits_per_page(requested: u32, default: u32, max: u32) -> u32 {
+    let max = max.clamp(1, ALGOLIA_MAX_HITS_PER_PAGE);
+    match requested {
+        0 => default.clamp(1, max),
+        n if n > max => {
+            debug!(requested = n, max = max, "Search page size clamped");
+            max
+        }
+        n => n,
+    }
+}
+
+/// `query` cut to at most `max_len` bytes, preferring a word boundary.
+fn truncate_query(query: &str, max_len: usize) -> &str {
+    if query.len() <= max_len {
+        return query;
+    }
+
+    let mut end = max_len;
+    while !query.is_char_boundary(end) {
+        end -= 1;
+    }
+    let cut = &query[..end];
+    match cut.rfind(char::is_whitespace) {
+        // Don't lose most of the query to find a space
+        Some(space) if space > max_len / 2 => cut[..space].trim_end(),
+        _ => cut,
+    }
+}
+
+/// Comments per page returned by [`HnClient::search_user_comments`].
+pub const USER_COMMENTS_PER_PAGE: u32 = 30;
+
+/// Algolia `tags` for a result type and, optionally, an author.
+///
+/// Comma-separated tags must all match, so `comment,author_pg` finds pg's
+/// comments.
+fn search_tags(filter: SearchFilter, author: Option<&str>) -> Option<String> {
+    let kind = match filter {
+        SearchFilter::All => None,
+        SearchFilter::Story => Some("story".to_string()),
+        SearchFilter::Comment => Some("comment".to_string()),
+    };
+    let author = author.map(|a| format!("author_{}", a));
+    let tags: Vec<String> = kind.into_iter().chain(author).collect();
+    (!tags.is_empty()).then(|| tags.join(","))
+}
+
+/// Error body returned by Algolia, e.g. `{"message": "...", "status": 400}`.
+#[derive(Debug, serde::Deserialize)]
+struct AlgoliaError {
+    message: String,
+}
+
+/// Turn an unsuccessful Algolia response into a descriptive error.
+///
+/// Algolia explains the failure in the body; a query it considers too long
+/// (or otherwise invalid) gets a message the user can act on.
+async fn search_error(response: reqwest::Response) -> ApiError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let message = serde_json::from_str::<AlgoliaError>(&body)
+        .map(|e| e.message)
+        .unwrap_or(body);
+    warn!(status = %status, message = %message, "Search failed");
+
+    if status == reqwest::StatusCode::BAD_REQUEST {
+        if message.to_lowercase().contains("too long") {
+            return ApiError::Api("search query too long; try fewer or shorter words".to_string());
+        }
+        return ApiError::Api(format!("invalid search query: {}", message));
+    }
+    ApiError::Api(format!("search failed ({}): {}", status, message))
+}
+
+/// Extract item IDs from an HN web listing page (e.g. favorites), in page order.
+///
+/// Each listed item is a `<tr class="athing ..." id="...">` row. HN has used
+/// both single and double quotes for these attributes over the years.
+fn parse_favorite_ids(html: &str) -> Vec<u32> {
+    static ITEM_ROW: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"<tr\b[^>]*\bclass=["']athing\b[^>]*>"#).unwrap());
+    static ROW_ID: Lazy<Regex> = Lazy::new(|| Regex::new(r#"\sid=["'](\d+)["']"#).unwrap());
+
+    ITEM_ROW
+        .find_iter(html)
+        .filter_map(|row| ROW_ID.captures(row.as_str()))
+        .filter_map(|caps| caps[1].parse().ok())
+        .collect()
+}
+
+/// Find the icon URL declared by a page's `<link rel="icon">` (or
+/// `shortcut icon` / `apple-touch-icon`), as written in the `href`.
+fn parse_icon_href(html: &str) -> Option<String> {
+    static LINK_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<link\b[^>]*>").unwrap());
+    static REL: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)\srel=["']([^"']*)["']"#).unwrap());
+    static HREF: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?i)\shref=["']([^"']+)["']"#).unwrap());
+
+    LINK_TAG.find_iter(html).find_map(|tag| {
+        let tag = tag.as_str();
+        let rel = REL.captures(tag)?[1].to_ascii_lowercase();
+        if !rel
+            .split_whitespace()
+            .any(|r| r == "icon" || r == "apple-touch-icon")
+        {
+            return None;
+        }
+        Some(HREF.captures(tag)?[1].replace("&amp;", "&"))
+    })
+}
+
+/// Normalize a domain for use as a favicon cache file name.
+///
+/// Returns `None` for anything that isn't a plain public host name, which
+/// also keeps path separators and `..` out of cache paths. IP literals and
+/// single-label hosts such as `localhost` are rejected so a crafted story
+/// URL can't point favicon requests at the local network.
+fn favicon_cache_key(domain: &str) -> Option<String> {
+    let domain = domain.trim().trim_end_matches('.').to_ascii_lowercase();
+    let labels: Vec<&str> = domain.split('.').collect();
+    let valid = domain.len() <= 253
+        && labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        // No TLD is all digits, so this rules out IPv4 literals in any form
+        && !labels[labels.len() - 1].chars().all(|c| c.is_ascii_digit());
+    valid.then_some(domain)
+}
+
+/// Encode favicon bytes as a `data:` URL, sniffing the image type.
+pub fn favicon_data_url(bytes: &[u8]) -> String {
+    use base64::Engine;
+
+    let mime = if bytes.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        "image/jpeg"
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP".as_slice()) {
+        "image/webp"
+    } else if bytes.trim_ascii_start().starts_with(b"<") {
+        "image/svg+xml"
+    } else {
+        "image/x-icon"
+    };
+
+    format!(
+        "data:{};base64,{}",
+        mime,
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    )
+}
+
+/// Read a response body, failing once it grows past `max_bytes`.
+///
+/// See [`read_body_within`].
+async fn read_body_capped(
+    response: reqwest::Response,
+    max_bytes: usize,
+    url: &str,
+) -> Result<Vec<u8>, ApiError> {
+    read_body_within(response, max_bytes, url)
+        .await?
+        .ok_or_else(|| {
+            ApiError::ArticleExtraction(format!("content exceeds the {} byte limit", max_bytes))
+        })
+}
+
+/// Read a response body of at most `max_bytes`, or `None` if it's larger.
+///
+/// A `Content-Length` over the limit is rejected before any of the body is
+/// downloaded; otherwise the body is streamed and the download aborted as
+/// soon as the limit is crossed.
+async fn read_body_within(
+    mut response: reqwest::Response,
+    max_bytes: usize,
+    url: &str,
+) -> Result<Option<Vec<u8>>, ApiError> {
+    if response
+        .content_length()
+        .is_some_and(|len| len > max_bytes as u64)
+    {
+        return Ok(None);
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| request_error(e, url))? {
+        if body.len() + chunk.len() > max_bytes {
+            return Ok(None);
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(Some(body))
+}
+
+/// Decode an HTML page using its declared character encoding.
+///
+/// The charset comes from the `Content-Type` header, else a `<meta charset>`
+/// (or `http-equiv`) tag near the top of the page. A byte order mark takes
+/// precedence over both. Unknown or missing charsets decode as UTF-8, with
+/// invalid bytes replaced.
+fn decode_html(body: &[u8], content_type: Option<&str>) -> String {
+    static META_CHARSET: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"(?i)<meta\b[^>]*\bcharset\s*=\s*["']?\s*([A-Za-z0-9_:.-]+)"#).unwrap()
+    });
+
+    let header_charset = content_type.and_then(|ct| {
+        ct.split(';').skip(1).find_map(|param| {
+            let (name, value) = param.split_once('=')?;
+            name.trim()
+                .eq_ignore_ascii_case("charset")
+                .then(|| value.trim().trim_matches('"').to_string())
+        })
+    });
+    let charset = header_charset.or_else(|| {
+        let head = String::from_utf8_lossy(&body[..body.len().min(1024)]);
+        META_CHARSET.captures(&head).map(|caps| caps[1].to_string())
+    });
+
+    let encoding = charset
+        .and_then(|label| encoding_rs::Encoding::for_label(label.as_bytes()))
+        .unwrap_or(encoding_rs::UTF_8);
+    let (text, _, _) = encoding.decode(body);
+    text.into_owned()
+}
+
+/// Extract reader-mode content from a downloaded HTML page.
+///
+/// `base_url` is the page's final URL, used to resolve relative links. If
+/// readability finds fewer than `min_words` words, the page's largest block
+/// of text is used instead when it has more, at reduced
+/// [`ArticleContent::confidence`].
+fn extract_html_article(
+    body: &[u8],
+    content_type: Option<&str>,
+    base_url: &str,
+    exclude_same_host_links: bool,
+    min_words: usize,
+) -> Result<ArticleContent, ApiError> {
+    let html = decode_html(body, content_type);
+
+    // Parse the URL for readability
+    let parsed_url = url::Url::parse(base_url)
+        .map_err(|e| ApiError::ArticleExtraction(format!("Invalid URL: {}", e)))?;
+
+    // Use readability to extract the main content
+    let mut cursor = std::io::Cursor::new(html.as_bytes());
+    let extracted = readability::extractor::extract(&mut cursor, &parsed_url)
+        .map_err(|e| ApiError::ArticleExtraction(e.to_string()))?;
+
+    // Count words in the text content
+    let mut word_count = extracted.text.split_whitespace().count();
+    let mut html_content = extracted.content;
+    let mut text_content = extracted.text;
+    let mut confidence = 1.0;
+
+    if word_count < min_words {
+        let thoroughness = |words: usize| (words as f32 / min_words as f32).min(1.0);
+        match text_block::largest_text_block(&html).filter(|b| b.word_count > word_count) {
+            Some(block) => {
+                debug!(
+                    url = %base_url,
+                    readability_words = word_count,
+                    fallback_words = block.word_count,
+                    "Readability content too short, using largest text block"
+                );
+                html_content = block.html();
+                text_content = block.text();
+                word_count = block.word_count;
+                // A heuristic guess, so never as trusted as readability
+                confidence = 0.5 * thoroughness(word_count);
+            }
+            None => confidence = thoroughness(word_count),
+        }
+    }
+
+    let (content, headings) = toc::add_heading_anchors(&html_content);
+    let code_blocks = code::extract_code_blocks(&content);
+    let links = links::article_links(&content, &parsed_url, exclude_same_host_links);
+
+    Ok(ArticleContent {
+        title: if extracted.title.is_empty() {
+            None
+        } else {
+            Some(extracted.title)
+        },
+        content,
+        text_content,
+        byline: None, // readability-rs doesn't expose byline directly
+        excerpt: None,
+        site_name: None,
+        lang: None,
+        word_count,
+        headings,
+        code_blocks,
+        links,
+        confidence,
+        user_agent: None,
+    })
+}
+
+/// The opening of `text`, whitespace collapsed, cut at a word boundary to
+/// at most `max_chars` characters (plus an ellipsis when cut).
+///
+/// `None` for text with no words.
+fn article_excerpt(text: &str, max_chars: usize) -> Option<String> {
+    let mut excerpt = String::new();
+    let mut chars = 0;
+    for word in text.split_whitespace() {
+        let len = word.chars().count() + usize::from(!excerpt.is_empty());
+        if chars + len > max_chars {
+            if excerpt.is_empty() {
+                excerpt.extend(word.chars().take(max_chars));
+            }
+            excerpt.push('…');
+            return Some(excerpt);
+        }
+        if !excerpt.is_empty() {
+            excerpt.push(' ');
+        }
+        excerpt.push_str(word);
+        chars += len;
+    }
+    (!excerpt.is_empty()).then_some(excerpt)
+}
+
+/// `stories` sorted by `formula` at Unix time `now`, highest rank first.
+fn rerank_at(mut stories: Vec<HNItem>, formula: RankFormula, now: u64) -> Vec<HNItem> {
+    stories.sort_by(|a, b| formula.rank_at(b, now).total_cmp(&formula.rank_at(a, now)));
+    stories
+}
+
+/// Why the HTTP client stopped following a redirect chain.
+#[derive(Debug, thiserror::Error)]
+enum RedirectError {
+    #[error("more than {0} redirects")]
+    TooMany(usize),
+    #[error("redirect from HTTPS to HTTP")]
+    Downgrade,
+}
+
+/// Decide whether to follow a redirect to `next`.
+///
+/// `previous` holds the URLs already visited, starting with the original
+/// request URL.
+fn check_redirect(
+    previous: &[url::Url],
+    next: &url::Url,
+    max_redirects: usize,
+    allow_insecure: bool,
+) -> Result<(), RedirectError> {
+    if previous.len() > max_redirects {
+        return Err(RedirectError::TooMany(max_redirects));
+    }
+    let downgrade =
+        previous.last().is_some_and(|prev| prev.scheme() == "https") && next.scheme() == "http";
+    if downgrade && !allow_insecure {
+        return Err(RedirectError::Downgrade);
+    }
+    Ok(())
+}
+
+/// Redirect policy for [`HnClient`] requests, enforcing [`check_redirect`].
+fn redirect_policy(config: &ClientConfig) -> reqwest::redirect::Policy {
+    let max_redirects = config.max_redirects;
+    let allow_insecure = config.allow_insecure_redirects;
+
+    reqwest::redirect::Policy::custom(move |attempt| {
+        match check_redirect(
+            attempt.previous(),
+            attempt.url(),
+            max_redirects,
+            allow_insecure,
+        ) {
+            Ok(()) => attempt.follow(),
+            Err(e) => attempt.error(e),
+        }
+    })
+}
+
+/// Map a failed request to a specific error when it timed out or the
+/// redirect policy stopped it.
+fn request_error(error: reqwest::Error, url: &str) -> ApiError {
+    if error.is_timeout() {
+        return ApiError::Timeout(url.to_string());
+    }
+
+    let mut source = std::error::Error::source(&error);
+    while let Some(e) = source {
+        match e.downcast_ref::<RedirectError>() {
+            Some(RedirectError::TooMany(_)) => return ApiError::TooManyRedirects(url.to_string()),
+            Some(RedirectError::Downgrade) => {
+                return ApiError::ArticleExtraction(format!(
+                    "refusing insecure redirect from HTTPS to HTTP fetching {}",
+                    url
+                ))
+            }
+            None => source = e.source(),
+        }
+    }
+    ApiError::Request(error)
+}
+
+/// Extract reader-mode content from a downloaded PDF.
+#[cfg(feature = "pdf")]
+fn extract_pdf_article(body: &[u8]) -> Result<ArticleContent, ApiError> {
+    crate::pdf::extract_article(body)
+}
+
+/// PDF support is compiled out: treat PDFs like any other unsupported type.
+#[cfg(not(feature = "pdf"))]
+fn extract_pdf_article(_body: &[u8]) -> Result<ArticleContent, ApiError> {
+    Err(ApiError::ArticleExtraction(
+        "unsupported content type: application/pdf".to_string(),
+    ))
+}
+
+/// Check whether a cache file exists and was written within `ttl`.
+async fn is_fresh(path: &Path, ttl: Duration) -> bool {
+    match tokio::fs::metadata(path).await.and_then(|m| m.modified()) {
+        Ok(modified) => modified.elapsed().map(|age| age < ttl).unwrap_or(true),
+        Err(_) => false,
+    }
+}
+
+/// A cached value with its own lifetime.
+///
+/// Entries normally live for their cache's TTL; entries restored from a
+/// snapshot live only for what was left of it.
+#[derive(Debug, Clone)]
+struct Cached<T> {
+    value: T,
+    /// When the entry expires
+    expires_at: SystemTime,
+}
+
+impl<T> Cached<T> {
+    fn new(value: T, ttl: Duration) -> Self {
+        Self {
+            value,
+            expires_at: SystemTime::now() + ttl,
+        }
+    }
+
+    /// Time left before the entry expires, as of now.
+    fn remaining(&self) -> Duration {
+        self.expires_at
+            .duration_since(SystemTime::now())
+            .unwrap_or_default()
+    }
+}
+
+/// Expires each [`Cached`] entry at its own `expires_at`.
+struct CachedExpiry;
+
+impl<K, T> Expiry<K, Cached<T>> for CachedExpiry {
+    fn expire_after_create(&self, _: &K, value: &Cached<T>, _: Instant) -> Option<Duration> {
+        Some(value.remaining())
+    }
+
+    fn expire_after_update(
+        &self,
+        _: &K,
+        value: &Cached<T>,
+        _: Instant,
+        _: Option<Duration>,
+    ) -> Option<Duration> {
+        Some(value.remaining())
+    }
+}
+
+/// Snapshot entries for every live entry in `cache`.
+fn snapshot_entries<K, V>(cache: &Cache<K, Cached<V>>) -> Vec<SnapshotEntry<K, V>>
+where
+    K: std::hash::Hash + Eq + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    cache
+        .iter()
+        .map(|(key, cached)| SnapshotEntry {
+            key: (*key).clone(),
+            value: cached.value,
+            expires_at: unix_millis(cached.expires_at),
+        })
+        .collect()
+}
+
+/// Insert unexpired snapshot `entries` into `cache`, returning how many.
+///
+/// No entry outlives `ttl` from `now`, so a snapshot from a skewed clock or
+/// an edited file can't pin stale data in the cache. Entries whose expiry
+/// doesn't fit in a `SystemTime` are skipped.
+async fn restore_entries<K, V>(
+    cache: &Cache<K, Cached<V>>,
+    entries: Vec<SnapshotEntry<K, V>>,
+    now: SystemTime,
+    ttl: Duration,
+) -> usize
+where
+    K: std::hash::Hash + Eq + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    let mut restored = 0;
+    for entry in entries {
+        // Times past what `SystemTime` can hold (narrower on some
+        // platforms) can only come from an edited file
+        let Some(expires_at) = UNIX_EPOCH.checked_add(Duration::from_millis(entry.expires_at))
+        else {
+            continue;
+        };
+        if expires_at <= now {
+            continue;
+        }
+        let cached = Cached {
+            value: entry.value,
+            expires_at: expires_at.min(now + ttl),
+        };
+        cache.insert(entry.key, cached).await;
+        restored += 1;
+    }
+    restored
+}
+
+/// Parse and validate a cache snapshot.
+fn parse_cache_snapshot(json: &[u8]) -> Result<CacheSnapshot, ApiError> {
+    let snapshot: CacheSnapshot =
+        serde_json::from_slice(json).map_err(|e| ApiError::InvalidBackup(e.to_string()))?;
+
+    if snapshot.version == 0 || snapshot.version > CACHE_SNAPSHOT_VERSION {
+        return Err(ApiError::InvalidBackup(format!(
+            "unsupported cache snapshot version {} (expected {})",
+            snapshot.version, CACHE_SNAPSHOT_VERSION
+        )));
+    }
+
+    Ok(snapshot)
+}
+
+/// Most evictions buffered between `cache-evicted` batches; further ones
+/// are dropped until the buffer is drained.
+const MAX_PENDING_EVICTIONS: usize = 1000;
+
+/// How often buffered evictions are sent as one `cache-evicted` event.
+pub const EVICTION_EVENT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Item cache evictions waiting to be reported.
+///
+/// Fed by the item cache's eviction listener while
+/// [`ClientConfig::eviction_events`] is on, and drained by
+/// [`HnClient::take_evictions`].
+#[derive(Debug, Default)]
+struct EvictionLog {
+    enabled: AtomicBool,
+    pending: std::sync::Mutex<Vec<CacheEviction>>,
+}
+
+impl EvictionLog {
+    fn record(&self, id: u32, cause: RemovalCause) {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return;
+        }
+        let cause = match cause {
+            RemovalCause::Expired => EvictionCause::Expired,
+            RemovalCause::Explicit => EvictionCause::Explicit,
+            RemovalCause::Size => EvictionCause::Size,
+            // The item is still cached, just refreshed
+            RemovalCause::Replaced => return,
+        };
+
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        if pending.len() < MAX_PENDING_EVICTIONS {
+            pending.push(CacheEviction { id, cause });
+        }
+    }
+
+    fn take(&self) -> Vec<CacheEviction> {
+        std::mem::take(&mut *self.pending.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+/// Token bucket enforcing a [`RateLimit`].
+///
+/// Callers reserve a token and are told how long to wait before sending, so
+/// concurrent requests queue up in order rather than racing for tokens.
+/// Times are passed in rather than read from the clock, so the rate can be
+/// tested deterministically.
+#[derive(Debug)]
+struct TokenBucket {
+    limit: RateLimit,
+    /// Tokens available as of `updated`; negative while requests are queued
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    /// A full bucket.
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: f64::from(limit.burst.max(1)),
+            updated: now,
+        }
+    }
+
+    /// Take a token at `now`, returning how long to wait before using it.
+    fn acquire(&mut self, now: Instant) -> Duration {
+        let rate = self.limit.requests_per_second;
+        let burst = f64::from(self.limit.burst.max(1));
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(burst) - 1.0;
+        self.updated = self.updated.max(now);
+
+        if self.tokens >= 0.0 || rate <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / rate)
+        }
+    }
+}
+
 /// Tracks staleness and refresh state for background refresh logic.
 ///
 /// This struct maintains per-feed timestamps and prevents duplicate
@@ -116,8 +1052,13 @@ fn check_response_status(response: &reqwest::Response) -> Result<(), ApiError> {
 struct RefreshTracker {
     /// Timestamp of last successful fetch for each feed.
     last_fetch: HashMap<StoryFeed, Instant>,
+    /// Wall-clock time of the same fetches, for display (an `Instant`
+    /// can't be turned into one).
+    refreshed_at: HashMap<StoryFeed, SystemTime>,
     /// Feeds currently being refreshed (prevents duplicate requests).
     refreshing: std::collections::HashSet<StoryFeed>,
+    /// Each feed's ID ordering before its last change, for rank deltas.
+    previous_ids: HashMap<StoryFeed, Vec<u32>>,
 }
 
 impl RefreshTracker {
@@ -128,14 +1069,20 @@ impl RefreshTracker {
     /// Record that a feed was just fetched
     fn mark_fetched(&mut self, feed: StoryFeed) {
         self.last_fetch.insert(feed, Instant::now());
+        self.refreshed_at.insert(feed, SystemTime::now());
         self.refreshing.remove(&feed);
     }
 
-    /// Check if a feed's data is stale (past the threshold but not yet expired)
-    fn is_stale(&self, feed: &StoryFeed, ttl: Duration) -> bool {
+    /// Wall-clock time of a feed's last successful fetch
+    fn last_refreshed(&self, feed: &StoryFeed) -> Option<SystemTime> {
+        self.refreshed_at.get(feed).copied()
+    }
+
+    /// Check if a feed's data is stale (past `threshold_percent` of `ttl` but not yet expired)
+    fn is_stale(&self, feed: &StoryFeed, ttl: Duration, threshold_percent: u8) -> bool {
         if let Some(last) = self.last_fetch.get(feed) {
             let age = last.elapsed();
-            let stale_threshold = ttl * STALE_THRESHOLD_PERCENT as u32 / 100;
+            let stale_threshold = ttl * u32::from(threshold_percent) / 100;
             age >= stale_threshold && age < ttl
         } else {
             false
@@ -153,77 +1100,599 @@ impl RefreshTracker {
     }
 }
 
-/// HN API client with built-in caching, background refresh, and connection pooling.
-///
-/// This is the main interface for fetching HN data. It handles:
+/// Rank changes between two orderings of a feed's story IDs.
 ///
-/// - Transparent caching with configurable TTL
-/// - Stale-while-revalidate pattern for background refresh
-/// - Concurrent item fetching with connection pooling
-/// - Rate limit detection and reporting
+/// Stories in `new` come first, in their new order, followed by stories
+/// that dropped out of the feed. Stories that kept their rank are omitted.
+pub fn position_deltas(old: &[u32], new: &[u32]) -> Vec<PositionDelta> {
+    let old_ranks: HashMap<u32, usize> =
+        old.iter().enumerate().map(|(i, &id)| (id, i + 1)).collect();
+    let in_new: std::collections::HashSet<u32> = new.iter().copied().collect();
+
+    let moved = new.iter().enumerate().filter_map(|(i, &id)| {
+        let old_rank = old_ranks.get(&id).copied();
+        let new_rank = i + 1;
+        (old_rank != Some(new_rank)).then_some(PositionDelta {
+            id,
+            old_rank,
+            new_rank: Some(new_rank),
+        })
+    });
+    let removed = old
+        .iter()
+        .enumerate()
+        .filter(|(_, id)| !in_new.contains(id))
+        .map(|(i, &id)| PositionDelta {
+            id,
+            old_rank: Some(i + 1),
+            new_rank: None,
+        });
+
+    moved.chain(removed).collect()
+}
+
+/// The `feed-refreshed` payload for a refresh from `old` to `new` IDs.
 ///
-/// # Thread Safety
+/// `None` when the IDs are unchanged. With nothing cached before, every
+/// story counts as new.
+pub fn feed_refreshed(feed: StoryFeed, old: Option<&[u32]>, new: &[u32]) -> Option<FeedRefreshed> {
+    if old == Some(new) {
+        return None;
+    }
+
+    let old: std::collections::HashSet<u32> = old.unwrap_or_default().iter().copied().collect();
+    Some(FeedRefreshed {
+        feed,
+        changed: true,
+        new_count: new.iter().filter(|id| !old.contains(id)).count(),
+    })
+}
+
+/// Interleave several feeds' story IDs into one list.
 ///
-/// `HnClient` is safe to share across threads. Wrap it in [`Arc`] for multi-threaded
-/// use (see [`SharedHnClient`] and [`create_client`]).
+/// Takes one ID from each list in turn (in `lists` order), so every feed is
+/// represented near the top. An ID already taken from an earlier feed is
+/// skipped, and exhausted lists simply drop out of the rotation.
+pub fn interleave_feed_ids(lists: &[Vec<u32>]) -> Vec<u32> {
+    let longest = lists.iter().map(Vec::len).max().unwrap_or(0);
+    let mut seen = std::collections::HashSet::new();
+    let mut merged = Vec::new();
+
+    for i in 0..longest {
+        for list in lists {
+            if let Some(&id) = list.get(i) {
+                if seen.insert(id) {
+                    merged.push(id);
+                }
+            }
+        }
+    }
+
+    merged
+}
+
+/// Build the comment tree for `ids` from `fetched` comments, sorting each
+/// level.
 ///
-/// # Caches
+/// Comments that weren't fetched (past a budget, or missing) are left out;
+/// placed comments are taken out of `fetched`.
+fn assemble_comment_tree(
+    ids: &[u32],
+    fetched: &mut HashMap<u32, HNItem>,
+    sort: CommentSort,
+) -> Vec<CommentWithChildren> {
+    let mut comments = Vec::new();
+    for id in ids {
+        if let Some(item) = fetched.remove(id) {
+            let kids = item.kids.as_deref().unwrap_or_default();
+            let children = assemble_comment_tree(kids, fetched, sort);
+            comments.push(CommentWithChildren { item, children });
+        }
+    }
+
+    sort.sort(&mut comments);
+    comments
+}
+
+/// Count stories per domain, most frequent first.
 ///
-/// - **item_cache**: Individual HN items (stories, comments, jobs, polls)
-/// - **story_ids_cache**: Story ID lists for each feed type
-/// - **user_cache**: User profiles
-pub struct HnClient {
-    http: Client,
-    item_cache: Cache<u32, HNItem>,
-    story_ids_cache: Cache<StoryFeed, Vec<u32>>,
-    user_cache: Cache<String, HNUser>,
-    refresh_tracker: RwLock<RefreshTracker>,
+/// Ties are broken by summed score, then alphabetically. Items without an
+/// external URL (self-posts) are skipped.
+pub fn count_domains(items: &[HNItem]) -> Vec<DomainCount> {
+    let mut by_domain: HashMap<String, DomainCount> = HashMap::new();
+    for item in items {
+        let Some(domain) = item.domain() else {
+            continue;
+        };
+        let entry = by_domain
+            .entry(domain.clone())
+            .or_insert_with(|| DomainCount {
+                domain,
+                count: 0,
+                total_score: 0,
+            });
+        entry.count += 1;
+        entry.total_score += i64::from(item.score);
+    }
+
+    let mut counts: Vec<DomainCount> = by_domain.into_values().collect();
+    counts.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then(b.total_score.cmp(&a.total_score))
+            .then_with(|| a.domain.cmp(&b.domain))
+    });
+    counts
 }
 
-impl HnClient {
-    /// Create a new HN client with default settings.
+/// Outcome of [`HnClient::check_connectivity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Connectivity {
+    /// Whether the HN API is reachable
+    pub online: bool,
+    /// Whether this differs from the previous check's result
+    pub changed: bool,
+}
+
+/// Remembers the last connectivity check so checks can be debounced.
+///
+/// Times are passed in rather than read from the clock, so the debounce
+/// logic can be tested deterministically.
+#[derive(Debug, Default)]
+struct ConnectivityTracker {
+    /// Result and time of the last check.
+    last: Option<(bool, Instant)>,
+}
+
+impl ConnectivityTracker {
+    /// Result of the last check, if it was made less than `ttl` before `now`.
+    fn cached(&self, now: Instant, ttl: Duration) -> Option<bool> {
+        self.last
+            .filter(|(_, at)| now.saturating_duration_since(*at) < ttl)
+            .map(|(online, _)| online)
+    }
+
+    /// Record a check result. Returns `true` if connectivity flipped.
+    ///
+    /// The app is assumed online before the first check, so a first check
+    /// that fails counts as a change.
+    fn record(&mut self, online: bool, now: Instant) -> bool {
+        let previous = self.last.is_none_or(|(online, _)| online);
+        self.last = Some((online, now));
+        previous != online
+    }
+
+    /// Whether the last check, made less than `ttl` before `now`, found the
+    /// network unreachable.
+    ///
+    /// Takes a longer `ttl` than [`cached`](Self::cached), so lookups keep
+    /// falling back to cache between checks, but not indefinitely.
+    fn known_offline(&self, now: Instant, ttl: Duration) -> bool {
+        matches!(self.last, Some((false, at)) if now.saturating_duration_since(at) < ttl)
+    }
+}
+
+/// HN API client with built-in caching, background refresh, and connection pooling.
+///
+/// This is the main interface for fetching HN data. It handles:
+///
+/// - Transparent caching with configurable TTL
+/// - Stale-while-revalidate pattern for background refresh
+/// - Concurrent item fetching with connection pooling
+/// - Rate limit detection and reporting
+///
+/// # Thread Safety
+///
+/// `HnClient` is safe to share across threads. Wrap it in [`Arc`] for multi-threaded
+/// use (see [`SharedHnClient`] and [`create_client`]).
+///
+/// # Caches
+///
+/// - **item_cache**: Individual HN items (stories, comments, jobs, polls)
+/// - **story_ids_cache**: Story ID lists for each feed type
+/// - **user_cache**: User profiles
+/// - **article_cache**: Extracted article content keyed by URL
+pub struct HnClient {
+    http: Client,
+    /// Client that doesn't follow redirects, for resolving short URLs hop by hop.
+    redirect_http: Client,
+    config: std::sync::RwLock<ClientConfig>,
+    item_cache: Cache<u32, Cached<HNItem>>,
+    story_ids_cache: Cache<StoryFeed, Cached<Vec<u32>>>,
+    user_cache: Cache<String, Cached<HNUser>>,
+    article_cache: Cache<String, ArticleContent>,
+    short_url_cache: Cache<String, String>,
+    refresh_tracker: RwLock<RefreshTracker>,
+    connectivity: Mutex<ConnectivityTracker>,
+    favicon_permits: Semaphore,
+    /// Bounds comment trees loading at once, between prefetches and batch
+    /// fetches.
+    comment_tree_permits: Semaphore,
+    /// Bumped by each [`HnClient::prefetch_comments`] call, so a run can
+    /// tell it has been superseded.
+    comment_prefetch_generation: AtomicU64,
+    /// Set when [`ClientConfig::rate_limit`] is.
+    rate_limiter: std::sync::Mutex<Option<TokenBucket>>,
+    evictions: Arc<EvictionLog>,
+}
+
+impl HnClient {
+    /// Create a new HN client with default settings.
     ///
     /// Configures:
     /// - HTTP client with 30s timeout, 10s connect timeout, connection pooling
     /// - Item cache: 10,000 entries, 5 min TTL
     /// - Story IDs cache: 10 entries, 2 min TTL
     /// - User cache: 100 entries, 10 min TTL
+    /// - Article cache: 200 entries, 30 min TTL
     pub fn new() -> Self {
+        Self::with_config(ClientConfig::default())
+    }
+
+    /// Create a new HN client with custom settings.
+    ///
+    /// Uses the same HTTP and cache configuration as [`HnClient::new`].
+    pub fn with_config(config: ClientConfig) -> Self {
         let http = Client::builder()
             .timeout(Duration::from_secs(30))
             .connect_timeout(Duration::from_secs(10))
             .pool_max_idle_per_host(10)
-            .user_agent("pastel-hn/0.3")
+            .redirect(redirect_policy(&config))
+            .user_agent(USER_AGENT)
+            .build()
+            .expect("Failed to create HTTP client");
+
+        let redirect_http = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(10))
+            .redirect(reqwest::redirect::Policy::none())
+            .user_agent(USER_AGENT)
             .build()
             .expect("Failed to create HTTP client");
 
+        let evictions = Arc::new(EvictionLog::default());
+        evictions
+            .enabled
+            .store(config.eviction_events, Ordering::Relaxed);
+        let eviction_log = Arc::clone(&evictions);
+
+        // Entries carry their own expiry (see `Cached`), normally the TTL
         let item_cache = Cache::builder()
             .max_capacity(10_000)
-            .time_to_live(ITEM_CACHE_TTL)
+            .expire_after(CachedExpiry)
+            .eviction_listener(move |id: Arc<u32>, _, cause| eviction_log.record(*id, cause))
             .build();
 
         let story_ids_cache = Cache::builder()
             .max_capacity(10)
-            .time_to_live(STORY_IDS_CACHE_TTL)
+            .expire_after(CachedExpiry)
             .build();
 
         let user_cache = Cache::builder()
             .max_capacity(100)
-            .time_to_live(USER_CACHE_TTL)
+            .expire_after(CachedExpiry)
+            .build();
+
+        let article_cache = Cache::builder()
+            .max_capacity(200)
+            .time_to_live(ARTICLE_CACHE_TTL)
+            .build();
+
+        let short_url_cache = Cache::builder()
+            .max_capacity(1_000)
+            .time_to_live(SHORT_URL_CACHE_TTL)
             .build();
 
+        let rate_limiter = config
+            .rate_limit
+            .map(|limit| TokenBucket::new(limit, Instant::now()));
+
         Self {
             http,
+            redirect_http,
+            config: std::sync::RwLock::new(config),
             item_cache,
             story_ids_cache,
             user_cache,
+            article_cache,
+            short_url_cache,
             refresh_tracker: RwLock::new(RefreshTracker::new()),
+            connectivity: Mutex::new(ConnectivityTracker::default()),
+            favicon_permits: Semaphore::new(FAVICON_CONCURRENCY),
+            comment_tree_permits: Semaphore::new(COMMENT_PREFETCH_CONCURRENCY),
+            comment_prefetch_generation: AtomicU64::new(0),
+            rate_limiter: std::sync::Mutex::new(rate_limiter),
+            evictions,
+        }
+    }
+
+    /// GET an HN or Algolia API URL, once the rate limiter allows it.
+    ///
+    /// 429 and 5xx responses are retried up to [`ClientConfig::max_retries`]
+    /// times (see [`ClientConfig::max_retry_wait`]). When retries run out
+    /// on a 429, fails with [`ApiError::RetriesExhausted`]; otherwise the
+    /// last response is returned for the caller to report.
+    async fn api_get(&self, url: &str) -> Result<reqwest::Response, ApiError> {
+        let (max_retries, max_wait) = {
+            let config = self.config();
+            (config.max_retries, config.max_retry_wait)
+        };
+
+        let mut retries = 0;
+        loop {
+            let wait = self
+                .rate_limiter
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .as_mut()
+                .map(|bucket| bucket.acquire(Instant::now()));
+            if let Some(wait) = wait.filter(|w| !w.is_zero()) {
+                debug!(wait_ms = wait.as_millis() as u64, "Rate limited locally");
+                tokio::time::sleep(wait).await;
+            }
+
+            let response = self.http.get(url).send().await?;
+            let status = response.status();
+            if !is_retryable(status) {
+                return Ok(response);
+            }
+
+            let retry_after = retry_after_secs(&response);
+            let wait = match retry_after {
+                Some(secs) => Duration::from_secs(u64::from(secs)),
+                None => retry_backoff(retries + 1).min(max_wait),
+            };
+            if retries == max_retries || wait > max_wait {
+                if retries > 0 && status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    warn!(url = %url, retries, "Still rate limited, giving up");
+                    return Err(ApiError::RetriesExhausted {
+                        attempts: retries,
+                        retry_after: retry_after.unwrap_or(60),
+                    });
+                }
+                return Ok(response);
+            }
+
+            retries += 1;
+            warn!(
+                url = %url,
+                status = status.as_u16(),
+                retry = retries,
+                wait_ms = wait.as_millis() as u64,
+                "Retrying request"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Read the current client settings.
+    ///
+    /// The guard is a std lock: never hold it across an `.await`.
+    fn config(&self) -> std::sync::RwLockReadGuard<'_, ClientConfig> {
+        self.config.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Base URL of the HN website this client links to and scrapes (see
+    /// [`ClientConfig::hn_web_base_url`]).
+    pub fn hn_web_base_url(&self) -> String {
+        self.config().hn_web_base_url.clone()
+    }
+
+    /// Set the stale threshold as a percentage of the story IDs TTL.
+    ///
+    /// Lower values refresh sooner; higher values save bandwidth on metered
+    /// connections.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::Api` if `percent` is outside 1-99.
+    pub fn set_stale_threshold_percent(&self, percent: u8) -> Result<(), ApiError> {
+        if !STALE_THRESHOLD_RANGE.contains(&percent) {
+            return Err(ApiError::Api(format!(
+                "Stale threshold must be between 1 and 99 percent, got {}",
+                percent
+            )));
+        }
+
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .stale_threshold_percent = percent;
+        info!(percent = percent, "Stale threshold updated");
+        Ok(())
+    }
+
+    /// Whether links opened externally should have tracking parameters removed.
+    pub fn strip_tracking_params(&self) -> bool {
+        self.config().strip_tracking_params
+    }
+
+    /// Enable or disable removal of tracking parameters from opened links.
+    pub fn set_strip_tracking_params(&self, enabled: bool) {
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .strip_tracking_params = enabled;
+        info!(enabled = enabled, "Tracking parameter stripping updated");
+    }
+
+    /// Whether offline (cache-only) mode is on.
+    pub fn is_cache_only(&self) -> bool {
+        self.config().cache_only
+    }
+
+    /// Turn offline (cache-only) mode on or off.
+    ///
+    /// While on, [`fetch_item`](Self::fetch_item),
+    /// [`fetch_story_ids`](Self::fetch_story_ids), and
+    /// [`fetch_user`](Self::fetch_user) never touch the network: they return
+    /// cached data or `ApiError::Offline`. Stale feeds are not refreshed.
+    pub fn set_cache_only(&self, enabled: bool) {
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .cache_only = enabled;
+        info!(enabled = enabled, "Offline mode updated");
+    }
+
+    /// Set or clear the cap on HN and Algolia request rate.
+    ///
+    /// The new limit starts with a full bucket.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::Api` if the rate isn't a positive number or the
+    /// burst is zero.
+    pub fn set_rate_limit(&self, limit: Option<RateLimit>) -> Result<(), ApiError> {
+        if let Some(limit) = limit {
+            let rate = limit.requests_per_second;
+            if !(rate.is_finite() && rate > 0.0) || limit.burst == 0 {
+                return Err(ApiError::Api(format!(
+                    "Invalid rate limit: {} requests/s, burst {}",
+                    rate, limit.burst
+                )));
+            }
+        }
+
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .rate_limit = limit;
+        *self.rate_limiter.lock().unwrap_or_else(|e| e.into_inner()) =
+            limit.map(|limit| TokenBucket::new(limit, Instant::now()));
+        info!(limit = ?limit, "Rate limit updated");
+        Ok(())
+    }
+
+    /// Set the largest article download accepted by
+    /// [`fetch_article_content`](Self::fetch_article_content).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::Api` if `bytes` is zero.
+    pub fn set_max_article_bytes(&self, bytes: usize) -> Result<(), ApiError> {
+        if bytes == 0 {
+            return Err(ApiError::Api(
+                "Article size limit must be at least 1 byte".to_string(),
+            ));
+        }
+
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .max_article_bytes = bytes;
+        info!(bytes = bytes, "Article size limit updated");
+        Ok(())
+    }
+
+    /// Enable or disable leaving links back to an article's own site out of
+    /// [`ArticleContent::links`].
+    ///
+    /// Applies to articles extracted from now on; cached articles keep
+    /// their links.
+    pub fn set_exclude_same_host_links(&self, enabled: bool) {
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .exclude_same_host_links = enabled;
+        info!(enabled = enabled, "Same-host link exclusion updated");
+    }
+
+    /// Check whether the HN API is reachable.
+    ///
+    /// Sends a HEAD request with a short timeout. Results are reused for
+    /// [`CONNECTIVITY_CHECK_TTL`], and concurrent callers share one check.
+    /// Any HTTP response counts as online; only a failed connection or a
+    /// timeout counts as offline.
+    pub async fn check_connectivity(&self) -> Connectivity {
+        let mut tracker = self.connectivity.lock().await;
+        if let Some(online) = tracker.cached(Instant::now(), CONNECTIVITY_CHECK_TTL) {
+            return Connectivity {
+                online,
+                changed: false,
+            };
+        }
+
+        let url = format!("{}/maxitem.json", self.config().hn_base_url);
+        let online = self
+            .http
+            .head(&url)
+            .timeout(CONNECTIVITY_TIMEOUT)
+            .send()
+            .await
+            .is_ok();
+
+        let changed = tracker.record(online, Instant::now());
+        if changed {
+            info!(online = online, "Connectivity changed");
         }
+        Connectivity { online, changed }
+    }
+
+    /// Prime the connection pool with cheap `HEAD` requests to the HN and
+    /// Algolia hosts.
+    ///
+    /// Resolves DNS and completes the TLS handshakes up front, so the first
+    /// real fetch after launch doesn't pay for them. Failures are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The number of hosts that answered.
+    #[instrument(skip(self))]
+    pub async fn warm_connections(&self) -> usize {
+        let urls = {
+            let config = self.config();
+            [
+                format!("{}/maxitem.json", config.hn_base_url),
+                format!("{}/search?hitsPerPage=0", config.algolia_base_url),
+            ]
+        };
+
+        let results = futures::future::join_all(
+            urls.iter()
+                .map(|url| self.http.head(url).timeout(CONNECTIVITY_TIMEOUT).send()),
+        )
+        .await;
+
+        let reached = results.iter().filter(|r| r.is_ok()).count();
+        debug!(reached = reached, "Connections warmed");
+        reached
+    }
+
+    /// Whether the HN API is reachable. See [`check_connectivity`](Self::check_connectivity).
+    pub async fn is_online(&self) -> bool {
+        self.check_connectivity().await.online
+    }
+
+    /// Forget the last connectivity check, so lookups stop falling back to
+    /// cache until the next check finds the network unreachable again.
+    ///
+    /// For when nothing will re-check, e.g. once the refresh timer stops.
+    pub async fn forget_connectivity(&self) {
+        self.connectivity.lock().await.last = None;
+    }
+
+    /// Whether a connectivity check in the last [`OFFLINE_FALLBACK_TTL`]
+    /// failed. Never touches the network.
+    ///
+    /// Returns `false` while a check is in progress.
+    fn known_offline(&self) -> bool {
+        self.connectivity
+            .try_lock()
+            .map(|tracker| tracker.known_offline(Instant::now(), OFFLINE_FALLBACK_TTL))
+            .unwrap_or(false)
+    }
+
+    /// Whether lookups must be answered from cache: offline mode is on, or
+    /// the network was just found unreachable.
+    fn serve_from_cache_only(&self) -> bool {
+        self.is_cache_only() || self.known_offline()
     }
 
     /// Fetch story IDs for a given feed, returning cached data when available.
     ///
-    /// If cached data exists but is stale (>75% of TTL), this method returns
+    /// If cached data exists but is stale (past the configured threshold,
+    /// 75% of TTL by default), this method returns
     /// the cached data immediately and triggers a background refresh.
     ///
     /// # Arguments
@@ -236,13 +1705,18 @@ impl HnClient {
     #[instrument(skip(self))]
     pub async fn fetch_story_ids(&self, feed: StoryFeed) -> Result<Vec<u32>, ApiError> {
         // Check cache first
-        if let Some(ids) = self.story_ids_cache.get(&feed).await {
+        if let Some(Cached { value: ids, .. }) = self.story_ids_cache.get(&feed).await {
             debug!(feed = ?feed, count = ids.len(), "Cache hit for story IDs");
 
             // Check if data is stale and trigger background refresh
-            let should_refresh = {
+            let (threshold, cache_only) = {
+                let config = self.config();
+                (config.stale_threshold_percent, config.cache_only)
+            };
+            let should_refresh = !cache_only && !self.known_offline() && {
                 let tracker = self.refresh_tracker.read().await;
-                tracker.is_stale(&feed, STORY_IDS_CACHE_TTL) && !tracker.is_refreshing(&feed)
+                tracker.is_stale(&feed, STORY_IDS_CACHE_TTL, threshold)
+                    && !tracker.is_refreshing(&feed)
             };
 
             if should_refresh {
@@ -263,16 +1737,22 @@ impl HnClient {
     /// Updates both the cache and the refresh tracker on success.
     #[instrument(skip(self))]
     async fn fetch_story_ids_fresh(&self, feed: StoryFeed) -> Result<Vec<u32>, ApiError> {
-        let url = format!("{}/{}.json", HN_BASE_URL, feed.endpoint());
+        if self.serve_from_cache_only() {
+            return Err(ApiError::Offline(feed.endpoint().to_string()));
+        }
+
+        let url = format!("{}/{}.json", self.config().hn_base_url, feed.endpoint());
         info!(url = %url, "Fetching story IDs");
 
-        let response = self.http.get(&url).send().await?;
+        let response = self.api_get(&url).await?;
         check_response_status(&response)?;
 
         let ids: Vec<u32> = response.json().await?;
 
         debug!(feed = ?feed, count = ids.len(), "Fetched story IDs");
-        self.story_ids_cache.insert(feed, ids.clone()).await;
+        self.story_ids_cache
+            .insert(feed, Cached::new(ids.clone(), STORY_IDS_CACHE_TTL))
+            .await;
 
         // Update refresh tracker
         self.refresh_tracker.write().await.mark_fetched(feed);
@@ -288,18 +1768,34 @@ impl HnClient {
     /// # Returns
     ///
     /// - `Some(new_ids)` if the data changed (for UI update notification)
-    /// - `None` if the data is unchanged or the refresh failed
+    /// - `None` if the data is unchanged, the refresh failed, or a recent
+    ///   connectivity check found the network unreachable
     #[instrument(skip(self))]
     pub async fn background_refresh_feed(&self, feed: StoryFeed) -> Option<Vec<u32>> {
+        if self.known_offline() {
+            debug!(feed = ?feed, "Offline, skipping background refresh");
+            self.refresh_tracker.write().await.refreshing.remove(&feed);
+            return None;
+        }
+
         // Get current cached IDs for comparison
-        let old_ids = self.story_ids_cache.get(&feed).await;
+        let old_ids = self.cached_story_ids(feed).await;
 
         // Fetch fresh data
         match self.fetch_story_ids_fresh(feed).await {
             Ok(new_ids) => {
                 // Check if data actually changed
                 let changed = match old_ids {
-                    Some(old) => old != new_ids,
+                    Some(old) if old != new_ids => {
+                        // Keep the old ordering to diff ranks against
+                        self.refresh_tracker
+                            .write()
+                            .await
+                            .previous_ids
+                            .insert(feed, old);
+                        true
+                    }
+                    Some(_) => false,
                     None => true,
                 };
 
@@ -320,13 +1816,111 @@ impl HnClient {
         }
     }
 
+    /// Refresh a feed in the background unless a refresh is already running.
+    ///
+    /// The `refreshing` guard is checked and set under a single write lock, so
+    /// overlapping callers (the refresh timer and a stale read) never fetch
+    /// the same feed twice. Returns `None` without touching the network when
+    /// another refresh is in flight; otherwise behaves like
+    /// [`background_refresh_feed`](Self::background_refresh_feed).
+    pub async fn refresh_feed_if_idle(&self, feed: StoryFeed) -> Option<Vec<u32>> {
+        {
+            let mut tracker = self.refresh_tracker.write().await;
+            if tracker.is_refreshing(&feed) {
+                debug!(feed = ?feed, "Refresh already in progress, skipping");
+                return None;
+            }
+            tracker.start_refresh(feed);
+        }
+
+        self.background_refresh_feed(feed).await
+    }
+
+    /// A feed's cached story IDs, without fetching or touching freshness.
+    ///
+    /// Read before a background refresh to describe what it changed; see
+    /// [`feed_refreshed`].
+    pub async fn cached_story_ids(&self, feed: StoryFeed) -> Option<Vec<u32>> {
+        self.story_ids_cache.get(&feed).await.map(|c| c.value)
+    }
+
+    /// Rank changes between a feed's ordering before its last change and
+    /// the cached ordering.
+    ///
+    /// Empty until a background refresh has replaced a cached ordering with
+    /// a different one. See [`position_deltas`].
+    pub async fn feed_position_deltas(&self, feed: StoryFeed) -> Vec<PositionDelta> {
+        let previous = self
+            .refresh_tracker
+            .read()
+            .await
+            .previous_ids
+            .get(&feed)
+            .cloned();
+        match (previous, self.story_ids_cache.get(&feed).await) {
+            (Some(old), Some(new)) => position_deltas(&old, &new.value),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Refresh every feed once, one at a time.
+    ///
+    /// # Returns
+    ///
+    /// A [`FeedUpdate`] for each feed whose story IDs changed. Feeds that are
+    /// unchanged, already refreshing, or failed to fetch are omitted.
+    pub async fn refresh_all_feeds(&self) -> Vec<FeedUpdate> {
+        let mut updates = Vec::new();
+
+        for feed in StoryFeed::ALL {
+            let old_ids = self.cached_story_ids(feed).await;
+            if let Some(ids) = self.refresh_feed_if_idle(feed).await {
+                let deltas = self.feed_position_deltas(feed).await;
+                let new_count = feed_refreshed(feed, old_ids.as_deref(), &ids)
+                    .map_or(0, |refreshed| refreshed.new_count);
+                updates.push(FeedUpdate {
+                    feed,
+                    ids,
+                    deltas,
+                    new_count,
+                });
+            }
+        }
+
+        updates
+    }
+
     /// Check if a feed's cached data is stale and should be refreshed.
     ///
-    /// Returns `true` if the data is older than 75% of its TTL and no
-    /// background refresh is currently in progress.
+    /// Returns `true` if the data is older than the configured threshold
+    /// (75% of its TTL by default) and no background refresh is currently
+    /// in progress.
     pub async fn is_feed_stale(&self, feed: &StoryFeed) -> bool {
+        let threshold = self.config().stale_threshold_percent;
         let tracker = self.refresh_tracker.read().await;
-        tracker.is_stale(feed, STORY_IDS_CACHE_TTL) && !tracker.is_refreshing(feed)
+        tracker.is_stale(feed, STORY_IDS_CACHE_TTL, threshold) && !tracker.is_refreshing(feed)
+    }
+
+    /// When `feed`'s story IDs were last fetched from the API, or `None`
+    /// if they haven't been this session.
+    pub async fn feed_last_refreshed(&self, feed: StoryFeed) -> Option<SystemTime> {
+        self.refresh_tracker.read().await.last_refreshed(&feed)
+    }
+
+    /// How long ago each feed was last fetched, for "updated 2 minutes
+    /// ago" labels.
+    pub async fn feed_ages(&self) -> Vec<FeedAge> {
+        let now = SystemTime::now();
+        let mut ages = Vec::with_capacity(StoryFeed::ALL.len());
+        for feed in StoryFeed::ALL {
+            let refreshed = self.feed_last_refreshed(feed).await;
+            ages.push(FeedAge {
+                feed,
+                refreshed_at: refreshed.map(unix_millis),
+                age_secs: refreshed.map(|at| now.duration_since(at).unwrap_or_default().as_secs()),
+            });
+        }
+        ages
     }
 
     /// Fetch a single HN item by ID.
@@ -337,18 +1931,39 @@ impl HnClient {
     ///
     /// - `ApiError::NotFound` if the item doesn't exist or was deleted
     /// - `ApiError::Request` on network failure
+    /// - `ApiError::Offline` if offline mode is on and the item isn't cached
     #[instrument(skip(self))]
     pub async fn fetch_item(&self, id: u32) -> Result<HNItem, ApiError> {
         // Check cache first
-        if let Some(item) = self.item_cache.get(&id).await {
+        if let Some(cached) = self.item_cache.get(&id).await {
             debug!(id = id, "Cache hit for item");
-            return Ok(item);
+            return Ok(cached.value);
+        }
+
+        self.fetch_item_fresh(id).await
+    }
+
+    /// Fetch a single HN item from the network, bypassing the cache.
+    ///
+    /// The cached copy, if any, is replaced with the result. Use this to
+    /// reload one item (an outdated score, say) without clearing the whole
+    /// cache. Goes through the same request path as [`fetch_item`](Self::fetch_item),
+    /// so rate limiting and offline mode still apply.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`fetch_item`](Self::fetch_item). `ApiError::Offline` is
+    /// returned in offline mode even if the item is cached.
+    #[instrument(skip(self))]
+    pub async fn fetch_item_fresh(&self, id: u32) -> Result<HNItem, ApiError> {
+        if self.serve_from_cache_only() {
+            return Err(ApiError::Offline(format!("item {}", id)));
         }
 
-        let url = format!("{}/item/{}.json", HN_BASE_URL, id);
+        let url = format!("{}/item/{}.json", self.config().hn_base_url, id);
         debug!(url = %url, "Fetching item");
 
-        let response = self.http.get(&url).send().await?;
+        let response = self.api_get(&url).await?;
         check_response_status(&response)?;
 
         if !response.status().is_success() {
@@ -359,19 +1974,102 @@ impl HnClient {
         let raw = raw.ok_or(ApiError::NotFound(id))?;
         let item: HNItem = raw.into();
 
-        self.item_cache.insert(id, item.clone()).await;
+        self.item_cache
+            .insert(id, Cached::new(item.clone(), ITEM_CACHE_TTL))
+            .await;
 
         Ok(item)
     }
 
+    /// Fetch a poll and tally its options' votes.
+    ///
+    /// The poll and its options always come from the network, since votes
+    /// keep changing; the copies in the item cache are refreshed.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::NotFound` if the poll (or one of its options) doesn't exist
+    /// - `ApiError::Api` if the item isn't a poll
+    /// - `ApiError::Offline` in offline mode
+    #[instrument(skip(self))]
+    pub async fn fetch_poll(&self, id: u32) -> Result<PollWithOptions, ApiError> {
+        if self.serve_from_cache_only() {
+            return Err(ApiError::Offline(format!("poll {}", id)));
+        }
+
+        let url = format!("{}/item/{}.json", self.config().hn_base_url, id);
+        debug!(url = %url, "Fetching poll");
+
+        let response = self.api_get(&url).await?;
+        check_response_status(&response)?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::NotFound(id));
+        }
+
+        let raw: Option<RawHNItem> = response.json().await?;
+        let mut raw = raw.ok_or(ApiError::NotFound(id))?;
+        let parts = raw.parts.take().unwrap_or_default();
+        let poll: HNItem = raw.into();
+        if poll.item_type != 3 {
+            return Err(ApiError::Api(format!("item {} is not a poll", id)));
+        }
+        self.item_cache
+            .insert(id, Cached::new(poll.clone(), ITEM_CACHE_TTL))
+            .await;
+
+        let options =
+            futures::future::try_join_all(parts.iter().map(|&part| self.fetch_item_fresh(part)))
+                .await?;
+
+        Ok(PollWithOptions::tally(poll, options))
+    }
+
     /// Fetch multiple items concurrently.
     ///
     /// Uses `futures::join_all` to fetch items in parallel, leveraging
     /// HTTP connection pooling for efficiency.
     ///
     /// Missing/deleted items are silently skipped (not included in results).
+    /// Use [`fetch_items_ordered`](Self::fetch_items_ordered) when results
+    /// must line up with the input IDs.
     #[instrument(skip(self, ids))]
     pub async fn fetch_items(&self, ids: &[u32]) -> Result<Vec<HNItem>, ApiError> {
+        let items = self.fetch_items_ordered(ids).await?;
+        Ok(items.into_iter().flatten().collect())
+    }
+
+    /// Fetch multiple items concurrently, reporting failures per item.
+    ///
+    /// Unlike [`fetch_items`](Self::fetch_items), an error for one ID doesn't
+    /// lose the rest: every ID ends up either in `items` or, with the reason,
+    /// in `errors`. Missing/deleted items count as errors here.
+    #[instrument(skip(self, ids))]
+    pub async fn fetch_items_detailed(&self, ids: &[u32]) -> BatchResult {
+        let futures: Vec<_> = ids.iter().map(|&id| self.fetch_item(id)).collect();
+
+        let results = futures::future::join_all(futures).await;
+
+        let mut batch = BatchResult::default();
+        for (&id, result) in ids.iter().zip(results) {
+            match result {
+                Ok(item) => batch.items.push(item),
+                Err(e) => {
+                    debug!(id = id, error = %e, "Batch item failed");
+                    batch.errors.push((id, e.to_string()));
+                }
+            }
+        }
+
+        batch
+    }
+
+    /// Fetch multiple items concurrently, preserving input order.
+    ///
+    /// The result has exactly one entry per input ID, at the same position,
+    /// with `None` for missing/deleted items.
+    #[instrument(skip(self, ids))]
+    pub async fn fetch_items_ordered(&self, ids: &[u32]) -> Result<Vec<Option<HNItem>>, ApiError> {
         let futures: Vec<_> = ids.iter().map(|&id| self.fetch_item(id)).collect();
 
         let results = futures::future::join_all(futures).await;
@@ -379,10 +2077,10 @@ impl HnClient {
         let mut items = Vec::with_capacity(ids.len());
         for result in results {
             match result {
-                Ok(item) => items.push(item),
-                Err(ApiError::NotFound(_)) => {
-                    // Skip deleted/missing items
-                    debug!("Skipping missing item");
+                Ok(item) => items.push(Some(item)),
+                Err(ApiError::NotFound(id)) => {
+                    debug!(id = id, "Missing item");
+                    items.push(None);
                 }
                 Err(e) => return Err(e),
             }
@@ -401,74 +2099,307 @@ impl HnClient {
     /// * `feed` - The feed type (Top, New, Best, etc.)
     /// * `offset` - Starting index (0-based)
     /// * `limit` - Maximum number of stories to return
+    /// * `exclude_dead` - Skip dead/deleted stories, backfilling from later
+    ///   in the feed to fill `limit`. Otherwise they are returned with their
+    ///   `dead`/`deleted` flags set for the UI to mark, and the page covers
+    ///   exactly `limit` IDs (missing items are left out, not replaced).
     ///
     /// # Returns
     ///
     /// A [`StoriesResponse`] with stories, pagination info, and total count.
+    /// Pass `next_offset` as the next page's `offset` so backfilled stories
+    /// aren't repeated.
     #[instrument(skip(self))]
     pub async fn fetch_stories_paginated(
         &self,
         feed: StoryFeed,
         offset: usize,
         limit: usize,
+        exclude_dead: bool,
     ) -> Result<StoriesResponse, ApiError> {
         let ids = self.fetch_story_ids(feed).await?;
         let total = ids.len();
 
-        let page_ids: Vec<u32> = ids.into_iter().skip(offset).take(limit).collect();
-        let stories = self.fetch_items(&page_ids).await?;
+        let (stories, position) = if exclude_dead {
+            self.scan_items(&ids, offset, limit, 0, |i| !(i.dead || i.deleted))
+                .await?
+        } else {
+            let start = offset.min(total);
+            let end = start.saturating_add(limit).min(total);
+            (self.fetch_items(&ids[start..end]).await?, end)
+        };
 
         Ok(StoriesResponse {
             stories,
-            has_more: offset + limit < total,
+            has_more: position < total,
             total,
+            next_offset: position,
         })
     }
 
-    /// Fetch a user profile by username.
-    ///
-    /// User profiles are cached for 10 minutes.
-    ///
-    /// # Errors
+    /// Fetch paginated stories from several feeds blended into one stream.
     ///
-    /// - `ApiError::UserNotFound` if the user doesn't exist
+    /// Each feed's IDs are fetched (from cache when fresh) and merged with
+    /// [`interleave_feed_ids`], so stories on several feeds appear once.
+    /// Pagination works as in
+    /// [`fetch_stories_paginated`](Self::fetch_stories_paginated), over the
+    /// merged list.
     #[instrument(skip(self))]
-    pub async fn fetch_user(&self, id: &str) -> Result<HNUser, ApiError> {
-        // Check cache first
-        if let Some(user) = self.user_cache.get(id).await {
-            debug!(id = %id, "Cache hit for user");
-            return Ok(user);
-        }
-
-        let url = format!("{}/user/{}.json", HN_BASE_URL, id);
-        info!(url = %url, "Fetching user");
-
-        let response = self.http.get(&url).send().await?;
-        check_response_status(&response)?;
-
-        if !response.status().is_success() {
-            return Err(ApiError::UserNotFound(id.to_string()));
+    pub async fn fetch_merged(
+        &self,
+        feeds: &[StoryFeed],
+        offset: usize,
+        limit: usize,
+    ) -> Result<StoriesResponse, ApiError> {
+        let mut unique = Vec::with_capacity(feeds.len());
+        for feed in feeds {
+            if !unique.contains(feed) {
+                unique.push(*feed);
+            }
         }
 
-        let raw: Option<RawHNUser> = response.json().await?;
-        let raw = raw.ok_or_else(|| ApiError::UserNotFound(id.to_string()))?;
-        let user: HNUser = raw.into();
+        let lists =
+            futures::future::try_join_all(unique.iter().map(|&feed| self.fetch_story_ids(feed)))
+                .await?;
+        let ids = interleave_feed_ids(&lists);
+        let total = ids.len();
 
-        self.user_cache.insert(id.to_string(), user.clone()).await;
+        let (stories, position) = self.scan_items(&ids, offset, limit, 0, |_| true).await?;
 
-        Ok(user)
+        Ok(StoriesResponse {
+            stories,
+            has_more: position < total,
+            total,
+            next_offset: position,
+        })
     }
 
-    /// Fetch a user's submissions with pagination and type filtering.
+    /// Re-order stories by `formula`, highest rank first.
     ///
-    /// # Arguments
+    /// Ties keep their original order.
+    pub fn rerank(stories: Vec<HNItem>, formula: RankFormula) -> Vec<HNItem> {
+        rerank_at(stories, formula, persist::now_millis() / 1000)
+    }
+
+    /// Fetch a page of a feed, re-ordered by `formula`.
     ///
-    /// * `user_id` - The username
-    /// * `offset` - Starting index in the user's submission list
-    /// * `limit` - Maximum submissions to return
-    /// * `filter` - Filter by type (All, Stories, Comments)
+    /// Only the page is re-ranked, not the whole feed. Its original order is
+    /// kept in `feed_order`.
     #[instrument(skip(self))]
-    pub async fn fetch_user_submissions(
+    pub async fn fetch_stories_ranked(
+        &self,
+        feed: StoryFeed,
+        offset: usize,
+        limit: usize,
+        exclude_dead: bool,
+        formula: RankFormula,
+    ) -> Result<RankedStoriesResponse, ApiError> {
+        let mut page = self
+            .fetch_stories_paginated(feed, offset, limit, exclude_dead)
+            .await?;
+        let feed_order = page.stories.iter().map(|s| s.id).collect();
+        page.stories = Self::rerank(page.stories, formula);
+
+        Ok(RankedStoriesResponse { page, feed_order })
+    }
+
+    /// Rank the sites linked from a feed's top `sample` stories.
+    ///
+    /// Dead and deleted stories are left out of the sample. See
+    /// [`count_domains`] for the ranking.
+    #[instrument(skip(self))]
+    pub async fn top_domains(
+        &self,
+        feed: StoryFeed,
+        sample: usize,
+    ) -> Result<Vec<DomainCount>, ApiError> {
+        let page = self.fetch_stories_paginated(feed, 0, sample, true).await?;
+        Ok(count_domains(&page.stories))
+    }
+
+    /// Collect up to `limit` items from `ids`, starting at `offset`, that pass `keep`.
+    ///
+    /// Items are fetched in batches of the number still wanted (at least
+    /// `min_batch`), so with no rejections nothing is over-fetched. Missing
+    /// items are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The kept items in list order, and the index just past the last
+    /// scanned ID (the offset to continue from).
+    async fn scan_items<F>(
+        &self,
+        ids: &[u32],
+        offset: usize,
+        limit: usize,
+        min_batch: usize,
+        keep: F,
+    ) -> Result<(Vec<HNItem>, usize), ApiError>
+    where
+        F: Fn(&HNItem) -> bool,
+    {
+        let total = ids.len();
+        let mut items = Vec::with_capacity(limit.min(total));
+        let mut position = offset.min(total);
+
+        'scan: while items.len() < limit && position < total {
+            let batch_size = (limit - items.len()).max(min_batch);
+            let batch_end = (position + batch_size).min(total);
+
+            let batch = self.fetch_items_ordered(&ids[position..batch_end]).await?;
+
+            for item in batch {
+                position += 1;
+                if let Some(item) = item.filter(|i| keep(i)) {
+                    items.push(item);
+                    if items.len() == limit {
+                        break 'scan;
+                    }
+                }
+            }
+        }
+
+        Ok((items, position))
+    }
+
+    /// Prefetch the items for an upcoming page of a feed into the cache.
+    ///
+    /// Called when the user nears the end of the current list so the next
+    /// [`fetch_stories_paginated`](Self::fetch_stories_paginated) call is
+    /// served from cache. Items that are already cached are not refetched;
+    /// if the whole page is cached this is a no-op.
+    ///
+    /// # Returns
+    ///
+    /// The number of items that were fetched from the network.
+    #[instrument(skip(self))]
+    pub async fn prefetch_stories(
+        &self,
+        feed: StoryFeed,
+        offset: usize,
+        limit: usize,
+    ) -> Result<usize, ApiError> {
+        let ids = self.fetch_story_ids(feed).await?;
+
+        let missing: Vec<u32> = ids
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .filter(|id| !self.item_cache.contains_key(id))
+            .collect();
+
+        if missing.is_empty() {
+            debug!(feed = ?feed, offset = offset, "Prefetch: page already cached");
+            return Ok(0);
+        }
+
+        debug!(feed = ?feed, count = missing.len(), "Prefetching stories");
+        let fetched = self.fetch_items(&missing).await?;
+
+        Ok(fetched.len())
+    }
+
+    /// Warm the item cache with the first `count` items of a feed.
+    ///
+    /// Meant for startup, so scrolling past the first page is served from
+    /// cache. Unlike [`prefetch_stories`](Self::prefetch_stories) the items
+    /// are fetched at most `WARM_CONCURRENCY` at a time, since `count` can be
+    /// large. Cached items are skipped, and items that fail to load are
+    /// logged and skipped; only fetching the feed's IDs can fail.
+    ///
+    /// # Returns
+    ///
+    /// The number of items newly added to the cache.
+    #[instrument(skip(self))]
+    pub async fn warm_items(&self, feed: StoryFeed, count: usize) -> Result<usize, ApiError> {
+        let ids = self.fetch_story_ids(feed).await?;
+
+        let missing: Vec<u32> = ids
+            .into_iter()
+            .take(count)
+            .filter(|id| !self.item_cache.contains_key(id))
+            .collect();
+
+        if missing.is_empty() {
+            debug!(feed = ?feed, "Warm: items already cached");
+            return Ok(0);
+        }
+
+        debug!(feed = ?feed, count = missing.len(), "Warming item cache");
+        let warmed = futures::stream::iter(missing)
+            .map(|id| async move {
+                match self.fetch_item(id).await {
+                    Ok(_) => true,
+                    Err(e) => {
+                        debug!(id = id, error = %e, "Warm fetch failed");
+                        false
+                    }
+                }
+            })
+            .buffer_unordered(WARM_CONCURRENCY)
+            .filter(|ok| futures::future::ready(*ok))
+            .count()
+            .await;
+
+        Ok(warmed)
+    }
+
+    /// Fetch a user profile by username.
+    ///
+    /// User profiles are cached for 10 minutes.
+    ///
+    /// # Errors
+    ///
+    /// - `ApiError::UserNotFound` if the user doesn't exist
+    /// - `ApiError::Offline` if offline mode is on and the user isn't cached
+    #[instrument(skip(self))]
+    pub async fn fetch_user(&self, id: &str) -> Result<HNUser, ApiError> {
+        // Check cache first
+        if let Some(cached) = self.user_cache.get(id).await {
+            debug!(id = %id, "Cache hit for user");
+            return Ok(cached.value);
+        }
+
+        if self.serve_from_cache_only() {
+            return Err(ApiError::Offline(format!("user {}", id)));
+        }
+
+        let url = format!("{}/user/{}.json", self.config().hn_base_url, id);
+        info!(url = %url, "Fetching user");
+
+        let response = self.api_get(&url).await?;
+        check_response_status(&response)?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::UserNotFound(id.to_string()));
+        }
+
+        let raw: Option<RawHNUser> = response.json().await?;
+        let raw = raw.ok_or_else(|| ApiError::UserNotFound(id.to_string()))?;
+        let user: HNUser = raw.into();
+
+        self.user_cache
+            .insert(id.to_string(), Cached::new(user.clone(), USER_CACHE_TTL))
+            .await;
+
+        Ok(user)
+    }
+
+    /// Fetch a user's submissions with pagination and type filtering.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The username
+    /// * `offset` - Starting index in the user's submission list
+    /// * `limit` - Maximum submissions to return
+    /// * `filter` - Filter by type (All, Stories, Comments)
+    ///
+    /// Scans the submission list from `offset` in batches until `limit`
+    /// matching items are collected or the list is exhausted, so filtered
+    /// pages are only short at the end of the list. Pass the returned
+    /// `next_offset` to continue where this page stopped.
+    #[instrument(skip(self))]
+    pub async fn fetch_user_submissions(
         &self,
         user_id: &str,
         offset: usize,
@@ -479,38 +2410,81 @@ impl HnClient {
         let all_ids = user.submitted.unwrap_or_default();
         let total = all_ids.len();
 
-        // Fetch extra items for filtering
-        let fetch_limit = if filter == SubmissionFilter::All {
-            limit
+        let min_batch = if filter == SubmissionFilter::All {
+            0
         } else {
-            limit * 2
-        };
-
-        let slice_ids: Vec<u32> = all_ids.into_iter().skip(offset).take(fetch_limit).collect();
-        let items = self.fetch_items(&slice_ids).await?;
-
-        // Filter by type
-        let filtered: Vec<HNItem> = match filter {
-            SubmissionFilter::All => items,
-            SubmissionFilter::Stories => items
-                .into_iter()
-                .filter(|i| i.item_type == 0 || i.item_type == 2) // Story or Job
-                .collect(),
-            SubmissionFilter::Comments => items
-                .into_iter()
-                .filter(|i| i.item_type == 1) // Comment
-                .collect(),
+            SUBMISSIONS_BATCH_SIZE
         };
-
-        let result_items: Vec<HNItem> = filtered.into_iter().take(limit).collect();
+        let (items, position) = self
+            .scan_items(&all_ids, offset, limit, min_batch, |i| filter.matches(i))
+            .await?;
 
         Ok(SubmissionsResponse {
-            items: result_items,
-            has_more: offset + limit < total,
+            items,
+            has_more: position < total,
             total,
+            next_offset: position,
         })
     }
 
+    /// Fetch a user's favorite stories.
+    ///
+    /// The Firebase API doesn't expose favorites, so this scrapes the public
+    /// `favorites?id=<user>` web page for item IDs and hydrates them via
+    /// [`fetch_items`](Self::fetch_items).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The username
+    /// * `page` - 1-based page number (HN lists 30 favorites per page)
+    ///
+    /// # Returns
+    ///
+    /// The favorited items in page order. Empty if the user has no favorites,
+    /// hides them, or the page is past the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::UserNotFound` if HN reports no such user.
+    #[instrument(skip(self))]
+    pub async fn fetch_user_favorites(
+        &self,
+        user_id: &str,
+        page: u32,
+    ) -> Result<Vec<HNItem>, ApiError> {
+        let url = format!(
+            "{}/favorites?id={}&p={}",
+            self.config().hn_web_base_url,
+            urlencoding::encode(user_id),
+            page.max(1)
+        );
+
+        let response = self.api_get(&url).await?;
+        check_response_status(&response)?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::Api(format!(
+                "HTTP {} fetching favorites",
+                response.status()
+            )));
+        }
+
+        let html = response.text().await?;
+
+        // HN answers unknown users with a bare text body instead of a 404
+        if html.trim() == "No such user." {
+            return Err(ApiError::UserNotFound(user_id.to_string()));
+        }
+
+        let ids = parse_favorite_ids(&html);
+        if ids.is_empty() {
+            debug!(user = %user_id, page = page, "No favorites on page");
+            return Ok(vec![]);
+        }
+
+        self.fetch_items(&ids).await
+    }
+
     /// Fetch comments for an item with depth control.
     ///
     /// Recursively fetches nested comments up to the specified depth.
@@ -518,7 +2492,8 @@ impl HnClient {
     /// # Arguments
     ///
     /// * `item` - The parent item (story or comment)
-    /// * `depth` - Maximum nesting depth (0 = no comments, 1 = direct children only)
+    /// * `depth` - Maximum nesting depth (0 = no comments, 1 = direct children
+    ///   only), clamped to [`ClientConfig::max_comment_depth`]
     ///
     /// # Returns
     ///
@@ -528,22 +2503,45 @@ impl HnClient {
         &self,
         item: &HNItem,
         depth: u8,
+        sort: CommentSort,
     ) -> Result<Vec<CommentWithChildren>, ApiError> {
+        let depth = self.clamp_comment_depth(depth);
         if depth == 0 {
             return Ok(vec![]);
         }
 
-        let kids = match &item.kids {
-            Some(kids) if !kids.is_empty() => kids.clone(),
-            _ => return Ok(vec![]),
-        };
+        match &item.kids {
+            Some(kids) if !kids.is_empty() => self.fetch_comment_level(kids, depth, sort).await,
+            _ => Ok(vec![]),
+        }
+    }
+
+    /// `depth` capped at [`ClientConfig::max_comment_depth`].
+    ///
+    /// Guards against requests that would load enormous trees from deep
+    /// threads, whatever the frontend asks for.
+    fn clamp_comment_depth(&self, depth: u8) -> u8 {
+        let max = self.config().max_comment_depth;
+        if depth > max {
+            warn!(requested = depth, max = max, "Comment depth clamped");
+            return max;
+        }
+        depth
+    }
 
-        let items = self.fetch_items(&kids).await?;
+    /// Fetch one level of comments by ID, plus `depth - 1` levels below it.
+    async fn fetch_comment_level(
+        &self,
+        ids: &[u32],
+        depth: u8,
+        sort: CommentSort,
+    ) -> Result<Vec<CommentWithChildren>, ApiError> {
+        let items = self.fetch_items(ids).await?;
         let mut comments = Vec::with_capacity(items.len());
 
         for item in items {
             let children = if depth > 1 {
-                Box::pin(self.fetch_comments(&item, depth - 1)).await?
+                Box::pin(self.fetch_comments(&item, depth - 1, sort)).await?
             } else {
                 vec![]
             };
@@ -551,20 +2549,178 @@ impl HnClient {
             comments.push(CommentWithChildren { item, children });
         }
 
+        sort.sort(&mut comments);
+
         Ok(comments)
     }
 
+    /// Warm the item cache with shallow comment trees for a set of stories.
+    ///
+    /// Call with the stories on screen so opening any of them is instant.
+    /// Each story's comments are loaded breadth-first, at most `depth`
+    /// levels (clamped to [`ClientConfig::max_comment_depth`]) and at most
+    /// `max_nodes` comments per story (capped at
+    /// `MAX_COMMENT_PREFETCH_NODES`), so wide threads only get their top
+    /// comments. At most `MAX_COMMENT_PREFETCH_STORIES` stories are
+    /// prefetched per call.
+    ///
+    /// A newer call cancels any prefetch still running: it stops before its
+    /// next level of comments. Failures are logged and otherwise ignored.
+    ///
+    /// # Returns
+    ///
+    /// The number of comments newly cached.
+    #[instrument(skip(self, ids))]
+    pub async fn prefetch_comments(
+        &self,
+        ids: &[u32],
+        depth: u8,
+        max_nodes: usize,
+    ) -> Result<usize, ApiError> {
+        let generation = self
+            .comment_prefetch_generation
+            .fetch_add(1, Ordering::SeqCst)
+            + 1;
+        let cancelled = || self.comment_prefetch_generation.load(Ordering::SeqCst) != generation;
+
+        let depth = self.clamp_comment_depth(depth);
+        let max_nodes = max_nodes.min(MAX_COMMENT_PREFETCH_NODES);
+        if depth == 0 || max_nodes == 0 {
+            return Ok(0);
+        }
+
+        let ids = &ids[..ids.len().min(MAX_COMMENT_PREFETCH_STORIES)];
+        let stories = self.fetch_items(ids).await?;
+        debug!(count = stories.len(), "Prefetching comments");
+
+        let cancelled = &cancelled;
+        let cached = futures::stream::iter(stories)
+            .map(|story| async move {
+                match self
+                    .fetch_comment_tree_within(
+                        &story,
+                        depth,
+                        max_nodes,
+                        CommentSort::Default,
+                        cancelled,
+                    )
+                    .await
+                {
+                    Ok((_, cached)) => cached,
+                    Err(e) => {
+                        debug!(story = story.id, error = %e, "Comment prefetch failed");
+                        0
+                    }
+                }
+            })
+            .buffer_unordered(COMMENT_PREFETCH_CONCURRENCY)
+            .fold(0, |total, count| futures::future::ready(total + count))
+            .await;
+
+        if cancelled() {
+            debug!("Comment prefetch superseded");
+        }
+        Ok(cached)
+    }
+
     /// Fetch children of a specific comment (for "load more" functionality).
     ///
     /// Used when a comment thread is collapsed and the user wants to expand it.
+    /// Direct children are paginated so very wide threads can be revealed
+    /// incrementally; each child still loads `depth - 1` levels of replies.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The parent comment
+    /// * `depth` - Nesting depth to load (1 = direct children only), clamped
+    ///   to [`ClientConfig::max_comment_depth`]
+    /// * `offset` - Index of the first direct child to return
+    /// * `limit` - Maximum direct children to return
     #[instrument(skip(self))]
     pub async fn fetch_comment_children(
         &self,
         comment_id: u32,
         depth: u8,
-    ) -> Result<Vec<CommentWithChildren>, ApiError> {
+        offset: usize,
+        limit: usize,
+    ) -> Result<CommentChildrenResponse, ApiError> {
+        let depth = self.clamp_comment_depth(depth);
+        let comment = self.fetch_item(comment_id).await?;
+        let kids = comment.kids.unwrap_or_default();
+        let total = kids.len();
+
+        let start = offset.min(total);
+        let end = start.saturating_add(limit).min(total);
+
+        let comments = if depth == 0 || start == end {
+            vec![]
+        } else {
+            self.fetch_comment_level(&kids[start..end], depth, CommentSort::Default)
+                .await?
+        };
+
+        Ok(CommentChildrenResponse {
+            comments,
+            has_more: end < total,
+            total,
+        })
+    }
+
+    /// Fetch a comment and its parent chain up to the story.
+    ///
+    /// Walks `parent` links through [`fetch_item`], so items already seen in
+    /// the story view come from the cache. At most `max_depth` parents are
+    /// fetched; if the story is further up, the result is marked truncated.
+    ///
+    /// [`fetch_item`]: Self::fetch_item
+    #[instrument(skip(self))]
+    pub async fn fetch_comment_context(
+        &self,
+        comment_id: u32,
+        max_depth: usize,
+    ) -> Result<CommentContext, ApiError> {
         let comment = self.fetch_item(comment_id).await?;
-        self.fetch_comments(&comment, depth).await
+        let mut ancestors = Vec::new();
+        let mut story = None;
+        let mut parent = comment.parent;
+
+        for _ in 0..max_depth {
+            let Some(id) = parent else { break };
+            let item = self.fetch_item(id).await?;
+            if item.item_type != 1 {
+                // Anything that isn't a comment is the thread's root
+                story = Some(item);
+                parent = None;
+                break;
+            }
+            parent = item.parent;
+            ancestors.push(item);
+        }
+        ancestors.reverse();
+
+        Ok(CommentContext {
+            comment,
+            ancestors,
+            story,
+            truncated: parent.is_some(),
+        })
+    }
+
+    /// Number of comments posted on a story since it had `last_descendants`.
+    ///
+    /// Always fetches the story from the network, since a cached copy may
+    /// predate the new comments. See [`seen_comments::comment_delta`].
+    #[instrument(skip(self))]
+    pub async fn new_comments_since(
+        &self,
+        story_id: u32,
+        last_descendants: u32,
+    ) -> Result<i64, ApiError> {
+        let story = self.fetch_item_fresh(story_id).await?;
+        Ok(seen_comments::comment_delta(
+            story.descendants,
+            last_descendants,
+        ))
     }
 
     /// Fetch a story with all its comments in one call.
@@ -575,24 +2731,153 @@ impl HnClient {
         &self,
         id: u32,
         depth: u8,
+        sort: CommentSort,
     ) -> Result<StoryWithComments, ApiError> {
         let story = self.fetch_item(id).await?;
-        let comments = self.fetch_comments(&story, depth).await?;
+        let comments = self.fetch_comments(&story, depth, sort).await?;
 
         Ok(StoryWithComments { story, comments })
     }
 
+    /// Fetch several stories, each with a budgeted comment tree.
+    ///
+    /// For loading a batch of stories (say, a read-later list) in one call.
+    /// Each story gets at most `max_nodes` comments (capped at
+    /// `MAX_COMMENT_PREFETCH_NODES`), loaded breadth-first within `depth`
+    /// levels (clamped to [`ClientConfig::max_comment_depth`]), so wide
+    /// threads keep their top comments and replies beyond the budget are
+    /// left for [`Self::fetch_comment_children`]. At most
+    /// `COMMENT_PREFETCH_CONCURRENCY` trees load at once, counting those
+    /// being prefetched.
+    ///
+    /// # Returns
+    ///
+    /// The stories in input order, without missing or deleted ones.
+    #[instrument(skip(self, ids))]
+    pub async fn fetch_stories_with_comments(
+        &self,
+        ids: &[u32],
+        depth: u8,
+        max_nodes: usize,
+        sort: CommentSort,
+    ) -> Result<Vec<StoryWithComments>, ApiError> {
+        let depth = self.clamp_comment_depth(depth);
+        let max_nodes = max_nodes.min(MAX_COMMENT_PREFETCH_NODES);
+        let stories = self.fetch_items(ids).await?;
+
+        futures::stream::iter(stories)
+            .map(|story| async move {
+                let (comments, _) = self
+                    .fetch_comment_tree_within(&story, depth, max_nodes, sort, &|| false)
+                    .await?;
+                Ok::<_, ApiError>(StoryWithComments { story, comments })
+            })
+            .buffered(COMMENT_PREFETCH_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Comments below `story`: up to `max_nodes` within `depth` levels,
+    /// loaded breadth-first so wide threads keep their top comments.
+    ///
+    /// Shared by [`Self::prefetch_comments`] and
+    /// [`Self::fetch_stories_with_comments`]; the `comment_tree_permits`
+    /// semaphore bounds how many trees load at once across both. Stops
+    /// before the next level once `cancelled` returns true.
+    ///
+    /// # Returns
+    ///
+    /// The assembled tree and how many of its comments were newly cached.
+    async fn fetch_comment_tree_within(
+        &self,
+        story: &HNItem,
+        depth: u8,
+        max_nodes: usize,
+        sort: CommentSort,
+        cancelled: &impl Fn() -> bool,
+    ) -> Result<(Vec<CommentWithChildren>, usize), ApiError> {
+        let _permit = self
+            .comment_tree_permits
+            .acquire()
+            .await
+            .map_err(|e| ApiError::Api(e.to_string()))?;
+
+        let mut level = story.kids.clone().unwrap_or_default();
+        let mut fetched = HashMap::new();
+        let mut cached = 0;
+
+        for _ in 0..depth {
+            if level.is_empty() || fetched.len() >= max_nodes || cancelled() {
+                break;
+            }
+            level.truncate(max_nodes - fetched.len());
+            cached += level
+                .iter()
+                .filter(|&id| !self.item_cache.contains_key(id))
+                .count();
+
+            let comments = self.fetch_items(&level).await?;
+            level = comments
+                .iter()
+                .flat_map(|c| c.kids.clone().unwrap_or_default())
+                .collect();
+            fetched.extend(comments.into_iter().map(|c| (c.id, c)));
+        }
+
+        let comments = assemble_comment_tree(
+            story.kids.as_deref().unwrap_or_default(),
+            &mut fetched,
+            sort,
+        );
+        Ok((comments, cached))
+    }
+
+    /// Extract job postings from a "Who is hiring?" thread.
+    ///
+    /// Fetches the thread's top-level comments and parses each one with
+    /// [`hiring::parse_job_posting`]. Deleted comments and comments that
+    /// don't look like postings are skipped; thread order is preserved.
+    #[instrument(skip(self))]
+    pub async fn parse_hiring_thread(&self, story_id: u32) -> Result<Vec<JobPosting>, ApiError> {
+        let story = self.fetch_item(story_id).await?;
+        let kids = story.kids.unwrap_or_default();
+        let comments = self.fetch_items(&kids).await?;
+
+        let postings: Vec<JobPosting> = comments
+            .iter()
+            .filter_map(hiring::parse_job_posting)
+            .collect();
+
+        debug!(
+            story = story_id,
+            comments = comments.len(),
+            postings = postings.len(),
+            "Parsed hiring thread"
+        );
+        Ok(postings)
+    }
+
     /// Search HN using the Algolia Search API.
     ///
     /// Algolia provides faster, full-text search compared to the Firebase API.
+    /// Queries longer than [`MAX_SEARCH_QUERY_LEN`] are trimmed to fit;
+    /// errors reported by Algolia come back as [`ApiError::Api`] with its
+    /// explanation.
     ///
     /// # Arguments
     ///
     /// * `query` - Search query string
     /// * `page` - Page number (0-indexed)
-    /// * `hits_per_page` - Results per page (max ~1000)
+    /// * `hits_per_page` - Results per page; 0 uses
+    ///   [`ClientConfig::default_hits_per_page`], and larger values than
+    ///   [`ClientConfig::max_hits_per_page`] are clamped
     /// * `sort` - Sort by relevance or date
     /// * `filter` - Filter to stories, comments, or all
+    ///
+    /// # Returns
+    ///
+    /// The page of results, with `hits_per_page` set to the page size
+    /// actually used.
     #[instrument(skip(self))]
     pub async fn search(
         &self,
@@ -607,26 +2892,91 @@ impl HnClient {
             SearchSort::Date => "search_by_date",
         };
 
+        let trimmed = truncate_query(query, MAX_SEARCH_QUERY_LEN);
+        if trimmed.len() < query.len() {
+            debug!(
+                len = query.len(),
+                "Search query trimmed to fit Algolia's limit"
+            );
+        }
+
+        let hits_per_page = {
+            let config = self.config();
+            clamp_hits_per_page(
+                hits_per_page,
+                config.default_hits_per_page,
+                config.max_hits_per_page,
+            )
+        };
+
+        let mut response = self
+            .algolia_search(
+                endpoint,
+                trimmed,
+                page,
+                hits_per_page,
+                search_tags(filter, None),
+            )
+            .await?;
+        response.hits_per_page = hits_per_page;
+        Ok(response)
+    }
+
+    /// A user's comments, newest first, via Algolia's `author_` tag.
+    ///
+    /// Much faster than walking the user's `submitted` list with
+    /// [`Self::fetch_user_submissions`] for prolific users, at the cost of
+    /// Algolia's indexing delay for the newest comments.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - Username (case-sensitive)
+    /// * `page` - Page number (0-indexed), [`USER_COMMENTS_PER_PAGE`] per page
+    #[instrument(skip(self))]
+    pub async fn search_user_comments(
+        &self,
+        user: &str,
+        page: u32,
+    ) -> Result<SearchResponse, ApiError> {
+        self.algolia_search(
+            "search_by_date",
+            "",
+            page,
+            USER_COMMENTS_PER_PAGE,
+            search_tags(SearchFilter::Comment, Some(user)),
+        )
+        .await
+    }
+
+    /// Query an Algolia search endpoint (`search` or `search_by_date`).
+    async fn algolia_search(
+        &self,
+        endpoint: &str,
+        query: &str,
+        page: u32,
+        hits_per_page: u32,
+        tags: Option<String>,
+    ) -> Result<SearchResponse, ApiError> {
         let mut url = format!(
             "{}/{}?query={}&page={}&hitsPerPage={}",
-            ALGOLIA_BASE_URL,
+            self.config().algolia_base_url,
             endpoint,
             urlencoding::encode(query),
             page,
             hits_per_page
         );
-
-        // Add filter tags
-        match filter {
-            SearchFilter::All => {}
-            SearchFilter::Story => url.push_str("&tags=story"),
-            SearchFilter::Comment => url.push_str("&tags=comment"),
+        if let Some(tags) = tags {
+            url.push_str("&tags=");
+            url.push_str(&urlencoding::encode(&tags));
         }
 
         info!(url = %url, "Searching HN");
 
-        let response = self.http.get(&url).send().await?;
+        let response = self.api_get(&url).await?;
         check_response_status(&response)?;
+        if !response.status().is_success() {
+            return Err(search_error(response).await);
+        }
 
         let response: AlgoliaResponse = response.json().await?;
 
@@ -648,6 +2998,7 @@ impl HnClient {
         self.item_cache.invalidate_all();
         self.story_ids_cache.invalidate_all();
         self.user_cache.invalidate_all();
+        self.article_cache.invalidate_all();
         info!("All caches cleared");
     }
 
@@ -666,22 +3017,290 @@ impl HnClient {
         }
     }
 
-    /// Get current cache statistics for display in settings/debug UI.
-    pub fn get_cache_stats(&self) -> CacheStats {
-        CacheStats {
-            item_count: self.item_cache.entry_count(),
-            story_ids_count: self.story_ids_cache.entry_count(),
-            user_count: self.user_cache.entry_count(),
+    /// Write the item, story ID, and user caches to `path`.
+    ///
+    /// Each entry is saved with its expiry time, so a restored cache is no
+    /// fresher than the one saved.
+    pub async fn save_cache_snapshot(&self, path: &Path) -> Result<CacheSnapshotCounts, ApiError> {
+        let snapshot = CacheSnapshot {
+            version: CACHE_SNAPSHOT_VERSION,
+            saved_at: persist::now_millis(),
+            items: snapshot_entries(&self.item_cache),
+            story_ids: snapshot_entries(&self.story_ids_cache),
+            users: snapshot_entries(&self.user_cache),
+        };
+        let counts = CacheSnapshotCounts {
+            items: snapshot.items.len(),
+            story_ids: snapshot.story_ids.len(),
+            users: snapshot.users.len(),
+        };
+
+        persist::write_atomic_async(path.to_path_buf(), serde_json::to_vec(&snapshot)?).await?;
+        info!(path = %path.display(), counts = ?counts, "Cache snapshot saved");
+        Ok(counts)
+    }
+
+    /// Load a snapshot written by [`save_cache_snapshot`](Self::save_cache_snapshot)
+    /// into the caches.
+    ///
+    /// Entries that have expired since are skipped; the rest expire when
+    /// they would have in the saved cache, but never later than a fresh
+    /// entry would. Existing entries for the same keys are replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::InvalidBackup` if the file isn't a valid snapshot;
+    /// the caches are untouched in that case.
+    pub async fn load_cache_snapshot(&self, path: &Path) -> Result<CacheSnapshotCounts, ApiError> {
+        let snapshot = parse_cache_snapshot(&tokio::fs::read(path).await?)?;
+        let now = SystemTime::now();
+
+        let counts = CacheSnapshotCounts {
+            items: restore_entries(&self.item_cache, snapshot.items, now, ITEM_CACHE_TTL).await,
+            story_ids: restore_entries(
+                &self.story_ids_cache,
+                snapshot.story_ids,
+                now,
+                STORY_IDS_CACHE_TTL,
+            )
+            .await,
+            users: restore_entries(&self.user_cache, snapshot.users, now, USER_CACHE_TTL).await,
+        };
+        info!(path = %path.display(), counts = ?counts, "Cache snapshot loaded");
+        Ok(counts)
+    }
+
+    /// Turn reporting of item cache evictions on or off.
+    ///
+    /// Evictions before reporting was turned on aren't reported.
+    pub fn set_eviction_events(&self, enabled: bool) {
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .eviction_events = enabled;
+        self.evictions.enabled.store(enabled, Ordering::Relaxed);
+        info!(enabled = enabled, "Cache eviction events updated");
+    }
+
+    /// Item cache evictions since the last call, oldest first.
+    ///
+    /// Empty unless [`ClientConfig::eviction_events`] is on. At most
+    /// `MAX_PENDING_EVICTIONS` are kept between calls.
+    pub fn take_evictions(&self) -> Vec<CacheEviction> {
+        self.evictions.take()
+    }
+
+    /// Get current cache statistics for display in settings/debug UI.
+    pub fn get_cache_stats(&self) -> CacheStats {
+        CacheStats {
+            item_count: self.item_cache.entry_count(),
+            story_ids_count: self.story_ids_cache.entry_count(),
+            user_count: self.user_cache.entry_count(),
+            article_count: self.article_cache.entry_count(),
             item_ttl_secs: ITEM_CACHE_TTL.as_secs(),
             story_ids_ttl_secs: STORY_IDS_CACHE_TTL.as_secs(),
             user_ttl_secs: USER_CACHE_TTL.as_secs(),
         }
     }
 
+    /// Fetch a site's favicon, using the on-disk cache when possible.
+    ///
+    /// Tries `/favicon.ico` first, then the icon declared by the home page's
+    /// `<link rel="icon">`. Icons are cached on disk for 7 days; domains with
+    /// no icon are remembered for a day so they aren't retried on every feed
+    /// load. At most 4 downloads run at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - Host name, e.g. `example.com` (see [`HNItem::domain`])
+    ///
+    /// # Errors
+    ///
+    /// Returns `ApiError::FaviconNotFound` if the site has no usable icon, or
+    /// `ApiError::Api` if `domain` isn't a public host name (IP literals and
+    /// single-label hosts like `localhost` are refused). Network failures
+    /// are returned as-is and not cached.
+    #[instrument(skip(self))]
+    pub async fn fetch_favicon(&self, domain: &str) -> Result<Vec<u8>, ApiError> {
+        let key = favicon_cache_key(domain)
+            .ok_or_else(|| ApiError::Api(format!("Invalid domain: {}", domain)))?;
+
+        let (cache_dir, origin) = {
+            let config = self.config();
+            (
+                config.cache_dir.join("favicons"),
+                config.favicon_origin.replace("{domain}", &key),
+            )
+        };
+        let icon_path = cache_dir.join(format!("{}.icon", key));
+        let miss_path = cache_dir.join(format!("{}.missing", key));
+
+        if is_fresh(&icon_path, FAVICON_CACHE_TTL).await {
+            if let Ok(bytes) = tokio::fs::read(&icon_path).await {
+                debug!(domain = %key, "Favicon cache hit");
+                return Ok(bytes);
+            }
+        }
+        if is_fresh(&miss_path, FAVICON_MISS_TTL).await {
+            debug!(domain = %key, "Favicon negative cache hit");
+            return Err(ApiError::FaviconNotFound(key));
+        }
+
+        let icon = {
+            let _permit = self
+                .favicon_permits
+                .acquire()
+                .await
+                .map_err(|e| ApiError::Api(e.to_string()))?;
+            self.download_favicon(&origin).await?
+        };
+
+        tokio::fs::create_dir_all(&cache_dir).await?;
+        match icon {
+            Some(bytes) => {
+                persist::write_atomic_async(icon_path, bytes.clone()).await?;
+                let _ = tokio::fs::remove_file(&miss_path).await;
+                Ok(bytes)
+            }
+            None => {
+                info!(domain = %key, "No favicon found");
+                persist::write_atomic_async(miss_path, Vec::new()).await?;
+                Err(ApiError::FaviconNotFound(key))
+            }
+        }
+    }
+
+    /// Download a favicon from a site origin, returning `None` if it has none.
+    async fn download_favicon(&self, origin: &str) -> Result<Option<Vec<u8>>, ApiError> {
+        let base = url::Url::parse(&format!("{}/", origin.trim_end_matches('/')))
+            .map_err(|e| ApiError::Api(format!("Invalid favicon origin: {}", e)))?;
+
+        let default_icon = base.join("favicon.ico").expect("static path joins");
+        if let Some(bytes) = self.download_icon(default_icon.as_str()).await? {
+            return Ok(Some(bytes));
+        }
+
+        let response = self.http.get(base.as_str()).send().await?;
+        if !response.status().is_success() {
+            return Ok(None);
+        }
+        let Some(page) = read_body_within(response, MAX_FAVICON_PAGE_BYTES, base.as_str()).await?
+        else {
+            return Ok(None);
+        };
+        let html = String::from_utf8_lossy(&page);
+
+        let Some(icon_url) = parse_icon_href(&html).and_then(|href| base.join(&href).ok()) else {
+            return Ok(None);
+        };
+
+        self.download_icon(icon_url.as_str()).await
+    }
+
+    /// Download one icon URL, returning `None` unless it's a plausible image.
+    async fn download_icon(&self, url: &str) -> Result<Option<Vec<u8>>, ApiError> {
+        let response = self.http.get(url).send().await?;
+
+        let is_html = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|ct| ct.contains("text/html"));
+
+        // Many sites answer missing files with a 200 HTML error page
+        if !response.status().is_success() || is_html {
+            return Ok(None);
+        }
+
+        // Oversized icons are skipped without reading the rest of the body
+        let bytes = read_body_within(response, MAX_FAVICON_BYTES, url).await?;
+        Ok(bytes.filter(|bytes| !bytes.is_empty()))
+    }
+
+    /// Whether `url` is on a known link-shortener host.
+    fn is_short_url(&self, url: &str) -> bool {
+        let Some(host) = url::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(str::to_ascii_lowercase))
+        else {
+            return false;
+        };
+        self.config().shortener_hosts.iter().any(|h| *h == host)
+    }
+
+    /// Resolve a link-shortener URL (`t.co`, `bit.ly`, ...) to its target.
+    ///
+    /// Follows redirects one hop at a time while the current URL is on a
+    /// shortener host, for at most `MAX_SHORTENER_REDIRECTS` hops. Other URLs
+    /// are returned unchanged. Resolutions are cached for a day; if a hop
+    /// fails, the last URL reached is returned and nothing is cached.
+    #[instrument(skip(self))]
+    pub async fn resolve_short_url(&self, url: &str) -> String {
+        if !self.is_short_url(url) {
+            return url.to_string();
+        }
+        if let Some(resolved) = self.short_url_cache.get(url).await {
+            return resolved;
+        }
+
+        let Ok(mut current) = url::Url::parse(url) else {
+            return url.to_string();
+        };
+        for _ in 0..MAX_SHORTENER_REDIRECTS {
+            if !self.is_short_url(current.as_str()) {
+                break;
+            }
+            match self.redirect_target(&current).await {
+                Ok(Some(next)) => current = next,
+                Ok(None) => break,
+                Err(e) => {
+                    warn!(url = %current, error = %e, "Failed to resolve short URL");
+                    return current.into();
+                }
+            }
+        }
+
+        let resolved = String::from(current);
+        debug!(url = %url, resolved = %resolved, "Resolved short URL");
+        self.short_url_cache
+            .insert(url.to_string(), resolved.clone())
+            .await;
+        resolved
+    }
+
+    /// Where `url` redirects to, if it's a redirect.
+    ///
+    /// Uses HEAD, retrying with GET for servers that reject HEAD.
+    async fn redirect_target(&self, url: &url::Url) -> Result<Option<url::Url>, reqwest::Error> {
+        let mut response = self.redirect_http.head(url.as_str()).send().await?;
+        if response.status() == reqwest::StatusCode::METHOD_NOT_ALLOWED {
+            response = self.redirect_http.get(url.as_str()).send().await?;
+        }
+
+        if !response.status().is_redirection() {
+            return Ok(None);
+        }
+        Ok(response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| url.join(location).ok()))
+    }
+
     /// Fetch and extract readable content from an external article URL.
     ///
     /// Uses the [readability] crate to extract the main content from HTML,
-    /// removing navigation, ads, and other non-content elements.
+    /// removing navigation, ads, and other non-content elements. With the
+    /// `pdf` feature, PDF links are converted to plain text. Short URLs
+    /// are resolved first (see [`HnClient::resolve_short_url`]) so the
+    /// final page is fetched and used as readability's base URL.
+    ///
+    /// Sites that answer 403 or serve a page with no text are retried with
+    /// each of [`ClientConfig::fallback_user_agents`] in turn. The user
+    /// agent that got the content is recorded in
+    /// [`ArticleContent::user_agent`].
+    ///
+    /// Extracted articles are cached for 30 minutes, keyed by URL.
     ///
     /// # Arguments
     ///
@@ -693,14 +3312,93 @@ impl HnClient {
     ///
     /// # Errors
     ///
-    /// - `ApiError::ArticleExtraction` if content extraction fails
+    /// - `ApiError::ArticleExtraction` if content extraction fails, the
+    ///   response isn't HTML (or PDF), or it's larger than
+    ///   [`ClientConfig::max_article_bytes`]
+    /// - `ApiError::TooManyRedirects` if the page redirects more than
+    ///   [`ClientConfig::max_redirects`] times
+    /// - `ApiError::Timeout` if the download takes longer than
+    ///   [`ClientConfig::article_timeout`]
     /// - `ApiError::Request` on network failure
     #[instrument(skip(self))]
     pub async fn fetch_article_content(&self, url: &str) -> Result<ArticleContent, ApiError> {
-        info!(url = %url, "Fetching article content");
+        if let Some(article) = self.article_cache.get(url).await {
+            debug!(url = %url, "Cache hit for article");
+            return Ok(article);
+        }
 
-        let response = self.http.get(url).send().await?;
+        let resolved = self.resolve_short_url(url).await;
+        info!(url = %url, resolved = %resolved, "Fetching article content");
+
+        let mut article = self.download_article(&resolved, None).await?;
+        if !article.as_ref().is_some_and(|a| a.word_count > 0) {
+            let fallbacks = self.config().fallback_user_agents.clone();
+            for user_agent in &fallbacks {
+                match self
+                    .download_article(&resolved, Some(user_agent.as_str()))
+                    .await
+                {
+                    Ok(Some(fetched)) if fetched.word_count > 0 => {
+                        info!(
+                            url = %resolved,
+                            user_agent = %user_agent,
+                            "Fetched article with fallback user agent"
+                        );
+                        article = Some(fetched);
+                        break;
+                    }
+                    Ok(_) => debug!(
+                        url = %resolved,
+                        user_agent = %user_agent,
+                        "Fallback user agent got no content"
+                    ),
+                    Err(e) => debug!(
+                        url = %resolved,
+                        user_agent = %user_agent,
+                        error = %e,
+                        "Fallback user agent failed"
+                    ),
+                }
+            }
+        }
+        let article = article.ok_or_else(|| {
+            ApiError::ArticleExtraction(format!(
+                "HTTP {} fetching URL",
+                reqwest::StatusCode::FORBIDDEN
+            ))
+        })?;
+
+        self.article_cache
+            .insert(url.to_string(), article.clone())
+            .await;
+
+        Ok(article)
+    }
+
+    /// One download and extraction of `resolved` for
+    /// [`fetch_article_content`](Self::fetch_article_content), sent with
+    /// `user_agent` instead of the client's own when given.
+    ///
+    /// `Ok(None)` when the site answers 403 Forbidden, which another user
+    /// agent may get past.
+    async fn download_article(
+        &self,
+        resolved: &str,
+        user_agent: Option<&str>,
+    ) -> Result<Option<ArticleContent>, ApiError> {
+        let timeout = self.config().article_timeout;
+        let mut request = self.http.get(resolved).timeout(timeout);
+        if let Some(user_agent) = user_agent {
+            request = request.header(reqwest::header::USER_AGENT, user_agent);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| request_error(e, resolved))?;
         check_response_status(&response)?;
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            return Ok(None);
+        }
 
         if !response.status().is_success() {
             return Err(ApiError::ArticleExtraction(format!(
@@ -709,314 +3407,3119 @@ impl HnClient {
             )));
         }
 
-        let html = response.text().await?;
+        // Reject images and other binaries before downloading them
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|ct| ct.to_ascii_lowercase());
+        let is_pdf = cfg!(feature = "pdf")
+            && content_type
+                .as_deref()
+                .is_some_and(|ct| ct.starts_with("application/pdf"));
+        if let Some(ct) = content_type.as_deref().filter(|_| !is_pdf) {
+            if !(ct.starts_with("text/html") || ct.starts_with("application/xhtml+xml")) {
+                return Err(ApiError::ArticleExtraction(format!(
+                    "unsupported content type: {}",
+                    ct
+                )));
+            }
+        }
+
+        let max_bytes = self.config().max_article_bytes;
+        let body = read_body_capped(response, max_bytes, resolved).await?;
+
+        // Parsing a large page or PDF can take a while, so keep it off the
+        // async workers
+        let (exclude_same_host, min_words) = {
+            let config = self.config();
+            (config.exclude_same_host_links, config.min_article_words)
+        };
+        let base_url = resolved.to_string();
+        let mut article = tokio::task::spawn_blocking(move || {
+            if is_pdf {
+                extract_pdf_article(&body)
+            } else {
+                extract_html_article(
+                    &body,
+                    content_type.as_deref(),
+                    &base_url,
+                    exclude_same_host,
+                    min_words,
+                )
+            }
+        })
+        .await
+        .map_err(|e| ApiError::ArticleExtraction(format!("extraction task failed: {}", e)))??;
+        article.user_agent = Some(user_agent.unwrap_or(USER_AGENT).to_string());
+        Ok(Some(article))
+    }
+
+    /// Reader-mode content for a self-post (Ask HN, text stories), built
+    /// from the item's own `text` without a network fetch.
+    ///
+    /// The text is HN's small HTML subset, so no readability pass is
+    /// needed: entities are decoded for the plain text and word count, and
+    /// links are resolved against the item's HN page. `byline` is the
+    /// author. An item without text yields empty content.
+    pub fn extract_self_post_content(&self, item: &HNItem) -> ArticleContent {
+        let text = item.text.as_deref().unwrap_or_default();
+        // HN omits the opening `<p>` of the first paragraph
+        let content = if text.is_empty() || text.starts_with("<p>") {
+            text.to_string()
+        } else {
+            format!("<p>{}", text)
+        };
+
+        let text_content = html::to_plain_text(text);
+        let word_count = text_content.split_whitespace().count();
+        let (base, exclude_same_host) = {
+            let config = self.config();
+            (
+                links::hn_item_url(&config.hn_web_base_url, item.id),
+                config.exclude_same_host_links,
+            )
+        };
+        let base = url::Url::parse(&base).expect("HN item URLs are valid");
+
+        ArticleContent {
+            title: item.title.clone(),
+            code_blocks: code::extract_code_blocks(&content),
+            links: links::article_links(&content, &base, exclude_same_host),
+            content,
+            text_content,
+            byline: item.by.clone(),
+            excerpt: None,
+            site_name: None,
+            lang: None,
+            word_count,
+            headings: Vec::new(),
+            confidence: 1.0,
+            user_agent: None,
+        }
+    }
+
+    /// Reader-mode content for a story: its linked article, or for
+    /// self-posts, its own text via
+    /// [`extract_self_post_content`](Self::extract_self_post_content).
+    ///
+    /// # Errors
+    ///
+    /// As for [`fetch_item`](Self::fetch_item) and, for link posts,
+    /// [`fetch_article_content`](Self::fetch_article_content).
+    #[instrument(skip(self))]
+    pub async fn fetch_reader_content(&self, id: u32) -> Result<ArticleContent, ApiError> {
+        let item = self.fetch_item(id).await?;
+        match item.url.as_deref().filter(|url| !url.is_empty()) {
+            Some(url) => self.fetch_article_content(url).await,
+            None => Ok(self.extract_self_post_content(&item)),
+        }
+    }
+
+    /// Prefetch reader-mode content for a set of stories.
+    ///
+    /// Fetches each story, then downloads and extracts the linked article for
+    /// stories that have a URL, with bounded concurrency. Self-posts and URLs
+    /// already in the article cache are skipped, and at most
+    /// `MAX_ARTICLE_PREFETCH` articles are downloaded per call. Extraction
+    /// failures are logged and otherwise ignored.
+    ///
+    /// # Returns
+    ///
+    /// The number of articles newly extracted and cached.
+    #[instrument(skip(self, ids))]
+    pub async fn prefetch_articles(&self, ids: &[u32]) -> Result<usize, ApiError> {
+        let items = self.fetch_items(ids).await?;
+
+        let mut seen = std::collections::HashSet::new();
+        let urls: Vec<String> = items
+            .into_iter()
+            .filter_map(|item| item.url)
+            .filter(|url| !self.article_cache.contains_key(url) && seen.insert(url.clone()))
+            .take(MAX_ARTICLE_PREFETCH)
+            .collect();
+
+        if urls.is_empty() {
+            return Ok(0);
+        }
+
+        debug!(count = urls.len(), "Prefetching articles");
+
+        let cached = futures::stream::iter(urls)
+            .map(|url| async move {
+                match self.fetch_article_content(&url).await {
+                    Ok(_) => true,
+                    Err(e) => {
+                        debug!(url = %url, error = %e, "Article prefetch failed");
+                        false
+                    }
+                }
+            })
+            .buffer_unordered(ARTICLE_PREFETCH_CONCURRENCY)
+            .filter(|ok| futures::future::ready(*ok))
+            .count()
+            .await;
+
+        Ok(cached)
+    }
+
+    /// The top `count` front-page stories, each with a preview of its article.
+    ///
+    /// Articles are fetched (through the article cache) with the same bounded
+    /// concurrency as [`prefetch_articles`](Self::prefetch_articles). A story
+    /// whose article can't be extracted, or that has no URL, still appears,
+    /// just without an excerpt or reading time. At most
+    /// [`MAX_DIGEST_STORIES`] stories are returned.
+    #[instrument(skip(self))]
+    pub async fn fetch_front_page_digest(
+        &self,
+        count: usize,
+    ) -> Result<Vec<DigestEntry>, ApiError> {
+        let count = count.min(MAX_DIGEST_STORIES);
+        let stories = self
+            .fetch_stories_paginated(StoryFeed::Top, 0, count, true)
+            .await?
+            .stories;
+
+        let digest = futures::stream::iter(stories)
+            .map(|item| async move {
+                let article = match item.url.as_deref() {
+                    Some(url) => match self.fetch_article_content(url).await {
+                        Ok(article) => Some(article),
+                        Err(e) => {
+                            debug!(url = %url, error = %e, "Digest article failed");
+                            None
+                        }
+                    },
+                    None => None,
+                };
+
+                DigestEntry {
+                    domain: item.domain(),
+                    reading_time_minutes: article.as_ref().map(|a| a.reading_time_minutes()),
+                    excerpt: article
+                        .and_then(|a| article_excerpt(&a.text_content, DIGEST_EXCERPT_CHARS)),
+                    item,
+                }
+            })
+            .buffered(ARTICLE_PREFETCH_CONCURRENCY)
+            .collect()
+            .await;
+
+        Ok(digest)
+    }
+}
+
+impl Default for HnClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Thread-safe shared reference to an [`HnClient`].
+///
+/// Use [`create_client`] to create an instance.
+pub type SharedHnClient = Arc<HnClient>;
+
+/// Create a new shared HN client instance.
+///
+/// This is the primary way to create a client for use with Tauri commands.
+/// The returned `Arc<HnClient>` can be cloned and shared across threads.
+pub fn create_client() -> SharedHnClient {
+    Arc::new(HnClient::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ===== HnClient Construction Tests =====
+
+    #[test]
+    fn hn_client_new_creates_instance() {
+        let client = HnClient::new();
+        // Verify clear_cache doesn't panic on fresh instance
+        client.clear_cache();
+    }
+
+    #[test]
+    fn hn_client_default_creates_instance() {
+        let client = HnClient::default();
+        // Verify clear_cache doesn't panic on fresh instance
+        client.clear_cache();
+    }
+
+    #[test]
+    fn hn_client_default_equals_new() {
+        // Both should create valid clients that behave identically
+        let client1 = HnClient::new();
+        let client2 = HnClient::default();
+        // Both should handle clear_cache without panic
+        client1.clear_cache();
+        client2.clear_cache();
+    }
+
+    // ===== create_client Tests =====
+
+    #[test]
+    fn create_client_returns_arc() {
+        let client = create_client();
+        // Verify it's wrapped in Arc by checking strong count
+        assert_eq!(Arc::strong_count(&client), 1);
+    }
+
+    #[test]
+    fn create_client_arc_can_be_cloned() {
+        let client1 = create_client();
+        let client2 = Arc::clone(&client1);
+        assert_eq!(Arc::strong_count(&client1), 2);
+        assert_eq!(Arc::strong_count(&client2), 2);
+    }
+
+    // ===== Cache Operation Tests =====
+
+    #[test]
+    fn clear_cache_does_not_panic() {
+        let client = HnClient::new();
+        // Should not panic when clearing empty caches
+        client.clear_cache();
+    }
+
+    #[tokio::test]
+    async fn clear_story_ids_cache_specific_feed() {
+        let client = HnClient::new();
+        // Should not panic when clearing cache for specific feed
+        client.clear_story_ids_cache(Some(StoryFeed::Top)).await;
+    }
+
+    #[tokio::test]
+    async fn clear_story_ids_cache_all_feeds() {
+        let client = HnClient::new();
+        // Should not panic when clearing all story ID caches
+        client.clear_story_ids_cache(None).await;
+    }
+
+    // ===== Cache Snapshot Tests =====
+
+    #[tokio::test]
+    async fn cache_snapshot_restores_unexpired_entries_after_clear() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/topstories.json", serde_json::json!([1, 2]), 1).await;
+        for id in [1, 2] {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, story_json(id), 1).await;
+        }
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("cache.json");
+
+        let client = mock_client(&server);
+        client.fetch_story_ids(StoryFeed::Top).await.unwrap();
+        client.fetch_items(&[1, 2]).await.unwrap();
+        let saved = client.save_cache_snapshot(&path).await.unwrap();
+        assert_eq!(
+            saved,
+            CacheSnapshotCounts {
+                items: 2,
+                story_ids: 1,
+                users: 0
+            }
+        );
+
+        // Item 2 has expired by the time the snapshot is loaded
+        let mut json: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        for entry in json["items"].as_array_mut().unwrap() {
+            if entry["key"] == 2 {
+                entry["expiresAt"] = serde_json::json!(1);
+            }
+        }
+        std::fs::write(&path, json.to_string()).unwrap();
+
+        client.clear_cache();
+        let restored = client.load_cache_snapshot(&path).await.unwrap();
+        assert_eq!(restored.items, 1);
+        assert_eq!(restored.story_ids, 1);
+
+        // Served from the restored cache; the mocks expect no second request
+        assert_eq!(
+            client.fetch_story_ids(StoryFeed::Top).await.unwrap(),
+            [1, 2]
+        );
+        assert_eq!(client.fetch_item(1).await.unwrap().id, 1);
+        assert!(!client.item_cache.contains_key(&2));
+    }
+
+    #[tokio::test]
+    async fn restore_entries_clamps_lifetime_to_ttl() {
+        let cache: Cache<u32, Cached<u32>> = Cache::builder().expire_after(CachedExpiry).build();
+        let now = SystemTime::now();
+        let far_future = unix_millis(now + Duration::from_secs(365 * 24 * 60 * 60));
+        let entries = vec![SnapshotEntry {
+            key: 1,
+            value: 10,
+            expires_at: far_future,
+        }];
+
+        assert_eq!(
+            restore_entries(&cache, entries, now, ITEM_CACHE_TTL).await,
+            1
+        );
+        let cached = cache.get(&1).await.unwrap();
+        assert_eq!(cached.expires_at, now + ITEM_CACHE_TTL);
+    }
+
+    #[tokio::test]
+    async fn restore_entries_survives_out_of_range_expiry() {
+        let cache: Cache<u32, Cached<u32>> = Cache::builder().expire_after(CachedExpiry).build();
+        let now = SystemTime::now();
+        let entries = vec![SnapshotEntry {
+            key: 1,
+            value: 10,
+            expires_at: u64::MAX,
+        }];
+
+        // Skipped where `SystemTime` can't hold it, clamped elsewhere
+        let restored = restore_entries(&cache, entries, now, ITEM_CACHE_TTL).await;
+        if restored == 1 {
+            assert_eq!(
+                cache.get(&1).await.unwrap().expires_at,
+                now + ITEM_CACHE_TTL
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn load_cache_snapshot_rejects_other_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("cache.json");
+        let client = HnClient::new();
+
+        std::fs::write(&path, r#"{ "bookmarks": [] }"#).unwrap();
+        assert!(matches!(
+            client.load_cache_snapshot(&path).await,
+            Err(ApiError::InvalidBackup(_))
+        ));
+
+        std::fs::write(
+            &path,
+            r#"{ "version": 99, "savedAt": 0, "items": [], "storyIds": [], "users": [] }"#,
+        )
+        .unwrap();
+        match client.load_cache_snapshot(&path).await {
+            Err(ApiError::InvalidBackup(msg)) => assert!(msg.contains("version 99"), "{}", msg),
+            other => panic!("expected a version error, got {:?}", other),
+        }
+    }
+
+    // ===== Cache Eviction Tests =====
+
+    #[tokio::test]
+    async fn item_evictions_are_reported_when_enabled() {
+        let server = MockServer::start().await;
+        for id in [1, 2] {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, story_json(id), 2).await;
+        }
+        let client = mock_client(&server);
+
+        // Off by default
+        client.fetch_item(1).await.unwrap();
+        client.item_cache.invalidate(&1).await;
+        client.item_cache.run_pending_tasks().await;
+        assert!(client.take_evictions().is_empty());
+
+        client.set_eviction_events(true);
+        client.fetch_items(&[1, 2]).await.unwrap();
+        // Refetching isn't an eviction
+        client.fetch_item_fresh(2).await.unwrap();
+        client.item_cache.invalidate(&2).await;
+        client.item_cache.run_pending_tasks().await;
+
+        assert_eq!(
+            client.take_evictions(),
+            [CacheEviction {
+                id: 2,
+                cause: EvictionCause::Explicit
+            }]
+        );
+        assert!(client.take_evictions().is_empty());
+    }
+
+    // ===== fetch_comments Edge Case Tests =====
+
+    #[tokio::test]
+    async fn fetch_comments_depth_zero_returns_empty() {
+        let client = HnClient::new();
+        let item = HNItem {
+            id: 123,
+            item_type: 0,
+            by: Some("testuser".to_string()),
+            time: 1609459200,
+            text: None,
+            url: Some("https://example.com".to_string()),
+            score: 100,
+            title: Some("Test Story".to_string()),
+            descendants: 50,
+            kids: Some(vec![456, 789]),
+            parent: None,
+            dead: false,
+            deleted: false,
+            post_kind: None,
+        };
+
+        let comments = client
+            .fetch_comments(&item, 0, CommentSort::Default)
+            .await
+            .unwrap();
+        assert!(comments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn fetch_comments_no_kids_returns_empty() {
+        let client = HnClient::new();
+        let item = HNItem {
+            id: 123,
+            item_type: 0,
+            by: Some("testuser".to_string()),
+            time: 1609459200,
+            text: None,
+            url: Some("https://example.com".to_string()),
+            score: 100,
+            title: Some("Test Story".to_string()),
+            descendants: 0,
+            kids: None,
+            parent: None,
+            dead: false,
+            deleted: false,
+            post_kind: None,
+        };
+
+        let comments = client
+            .fetch_comments(&item, 3, CommentSort::Default)
+            .await
+            .unwrap();
+        assert!(comments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn fetch_comments_empty_kids_returns_empty() {
+        let client = HnClient::new();
+        let item = HNItem {
+            id: 123,
+            item_type: 0,
+            by: Some("testuser".to_string()),
+            time: 1609459200,
+            text: None,
+            url: Some("https://example.com".to_string()),
+            score: 100,
+            title: Some("Test Story".to_string()),
+            descendants: 0,
+            kids: Some(vec![]), // empty kids array
+            parent: None,
+            dead: false,
+            deleted: false,
+            post_kind: None,
+        };
+
+        let comments = client
+            .fetch_comments(&item, 3, CommentSort::Default)
+            .await
+            .unwrap();
+        assert!(comments.is_empty());
+    }
+
+    // ===== StoryFeed Cache Key Tests =====
+
+    #[test]
+    fn story_feed_is_hashable_for_cache() {
+        use std::collections::HashMap;
+        let mut map: HashMap<StoryFeed, Vec<u32>> = HashMap::new();
+
+        map.insert(StoryFeed::Top, vec![1, 2, 3]);
+        map.insert(StoryFeed::New, vec![4, 5, 6]);
+        map.insert(StoryFeed::Best, vec![7, 8, 9]);
+        map.insert(StoryFeed::Ask, vec![10, 11, 12]);
+        map.insert(StoryFeed::Show, vec![13, 14, 15]);
+        map.insert(StoryFeed::Jobs, vec![16, 17, 18]);
+
+        assert_eq!(map.len(), 6);
+        assert_eq!(map.get(&StoryFeed::Top), Some(&vec![1, 2, 3]));
+        assert_eq!(map.get(&StoryFeed::Jobs), Some(&vec![16, 17, 18]));
+    }
+
+    // ===== Constants Tests =====
+
+    #[test]
+    fn cache_ttl_constants_are_reasonable() {
+        // Item cache: 5 minutes
+        assert_eq!(ITEM_CACHE_TTL.as_secs(), 5 * 60);
+
+        // Story IDs cache: 2 minutes (shorter for fresher feeds)
+        assert_eq!(STORY_IDS_CACHE_TTL.as_secs(), 2 * 60);
+
+        // User cache: 10 minutes (user data changes less frequently)
+        assert_eq!(USER_CACHE_TTL.as_secs(), 10 * 60);
+
+        // Article cache: 30 minutes (articles rarely change)
+        assert_eq!(ARTICLE_CACHE_TTL.as_secs(), 30 * 60);
+    }
+
+    #[test]
+    fn hn_base_url_is_correct() {
+        assert_eq!(HN_BASE_URL, "https://hacker-news.firebaseio.com/v0");
+    }
+
+    #[test]
+    fn algolia_base_url_is_correct() {
+        assert_eq!(ALGOLIA_BASE_URL, "https://hn.algolia.com/api/v1");
+    }
+
+    // ===== Stale Threshold Tests =====
+
+    #[test]
+    fn stale_threshold_defaults_to_75_percent() {
+        assert_eq!(DEFAULT_STALE_THRESHOLD_PERCENT, 75);
+        assert_eq!(ClientConfig::default().stale_threshold_percent, 75);
+        assert_eq!(HnClient::new().config().stale_threshold_percent, 75);
+    }
+
+    #[test]
+    fn stale_threshold_setter_accepts_valid_range() {
+        let client = HnClient::new();
+        client.set_stale_threshold_percent(1).unwrap();
+        assert_eq!(client.config().stale_threshold_percent, 1);
+        client.set_stale_threshold_percent(99).unwrap();
+        assert_eq!(client.config().stale_threshold_percent, 99);
+    }
+
+    #[test]
+    fn stale_threshold_setter_rejects_out_of_range() {
+        let client = HnClient::new();
+        assert!(client.set_stale_threshold_percent(0).is_err());
+        assert!(client.set_stale_threshold_percent(100).is_err());
+        assert_eq!(client.config().stale_threshold_percent, 75);
+    }
+
+    #[test]
+    fn refresh_tracker_is_stale_uses_custom_threshold() {
+        let mut tracker = RefreshTracker::new();
+        tracker
+            .last_fetch
+            .insert(StoryFeed::Top, Instant::now() - Duration::from_secs(30));
+        let ttl = Duration::from_secs(120);
+
+        // 30s of a 120s TTL is 25%: stale at 20%, fresh at the 75% default
+        assert!(tracker.is_stale(&StoryFeed::Top, ttl, 20));
+        assert!(!tracker.is_stale(&StoryFeed::Top, ttl, DEFAULT_STALE_THRESHOLD_PERCENT));
+    }
+
+    // ===== Position Delta Tests =====
+
+    fn delta(id: u32, old_rank: Option<usize>, new_rank: Option<usize>) -> PositionDelta {
+        PositionDelta {
+            id,
+            old_rank,
+            new_rank,
+        }
+    }
+
+    #[test]
+    fn position_deltas_empty_for_same_order() {
+        assert!(position_deltas(&[1, 2, 3], &[1, 2, 3]).is_empty());
+    }
+
+    #[test]
+    fn position_deltas_reports_reordered_ids() {
+        assert_eq!(
+            position_deltas(&[1, 2, 3], &[3, 2, 1]),
+            vec![delta(3, Some(3), Some(1)), delta(1, Some(1), Some(3))]
+        );
+    }
+
+    #[test]
+    fn position_deltas_reports_inserted_ids() {
+        assert_eq!(
+            position_deltas(&[1, 2], &[9, 1, 2]),
+            vec![
+                delta(9, None, Some(1)),
+                delta(1, Some(1), Some(2)),
+                delta(2, Some(2), Some(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn position_deltas_reports_removed_ids() {
+        assert_eq!(
+            position_deltas(&[1, 2, 3], &[1, 3]),
+            vec![delta(3, Some(3), Some(2)), delta(2, Some(2), None)]
+        );
+    }
+
+    #[test]
+    fn feed_refreshed_only_when_ids_change() {
+        assert_eq!(
+            feed_refreshed(StoryFeed::Top, Some(&[1, 2, 3][..]), &[1, 2, 3]),
+            None
+        );
+
+        assert_eq!(
+            feed_refreshed(StoryFeed::Top, Some(&[1, 2, 3][..]), &[4, 1, 5, 2]),
+            Some(FeedRefreshed {
+                feed: StoryFeed::Top,
+                changed: true,
+                new_count: 2,
+            })
+        );
+        // Reordering alone is a change, with nothing new
+        assert_eq!(
+            feed_refreshed(StoryFeed::New, Some(&[1, 2][..]), &[2, 1]).map(|r| r.new_count),
+            Some(0)
+        );
+        // Nothing cached before: everything is new
+        assert_eq!(
+            feed_refreshed(StoryFeed::Ask, None, &[7, 8]).map(|r| r.new_count),
+            Some(2)
+        );
+    }
+
+    // ===== Merged Feed Tests =====
+
+    #[test]
+    fn interleave_feed_ids_round_robins_and_dedupes() {
+        let top = vec![1, 2, 3, 4];
+        let best = vec![2, 5, 1];
+        let show = vec![6];
+
+        assert_eq!(
+            interleave_feed_ids(&[top, best, show]),
+            vec![1, 2, 6, 5, 3, 4]
+        );
+    }
+
+    #[test]
+    fn interleave_feed_ids_handles_empty_lists() {
+        assert!(interleave_feed_ids(&[]).is_empty());
+        assert_eq!(interleave_feed_ids(&[vec![], vec![3, 4]]), vec![3, 4]);
+    }
+
+    #[tokio::test]
+    async fn fetch_merged_paginates_interleaved_feeds() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/topstories.json", serde_json::json!([1, 2, 3]), 1).await;
+        mount_json(&server, "/showstories.json", serde_json::json!([2, 4]), 1).await;
+        for id in [1, 2, 4] {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, story_json(id), 1).await;
+        }
+
+        let client = mock_client(&server);
+        let page = client
+            .fetch_merged(&[StoryFeed::Top, StoryFeed::Show, StoryFeed::Top], 0, 3)
+            .await
+            .unwrap();
+
+        let ids: Vec<u32> = page.stories.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![1, 2, 4]);
+        assert_eq!(page.total, 4);
+        assert!(page.has_more);
+        assert_eq!(page.next_offset, 3);
+    }
+
+    // ===== Rerank Tests =====
+
+    const RANK_NOW: u64 = 1_700_000_000;
+    const HOUR: u64 = 3600;
+
+    fn ranked_story(id: u32, score: i32, descendants: u32, hours_old: u64) -> HNItem {
+        HNItem {
+            descendants,
+            time: RANK_NOW - hours_old * HOUR,
+            ..linked_story(id, None, score)
+        }
+    }
+
+    fn rerank_ids(stories: &[HNItem], formula: RankFormula) -> Vec<u32> {
+        rerank_at(stories.to_vec(), formula, RANK_NOW)
+            .iter()
+            .map(|s| s.id)
+            .collect()
+    }
+
+    #[test]
+    fn rerank_gravity_favours_fresh_stories() {
+        let stories = [
+            ranked_story(1, 300, 0, 20),
+            ranked_story(2, 50, 0, 1),
+            ranked_story(3, 120, 0, 5),
+        ];
+
+        // 299/22^1.8 ≈ 1.2, 49/3^1.8 ≈ 6.8, 119/7^1.8 ≈ 3.6
+        assert_eq!(
+            rerank_ids(&stories, RankFormula::Gravity { g: 1.8 }),
+            [2, 3, 1]
+        );
+        // Without gravity only the score counts
+        assert_eq!(
+            rerank_ids(&stories, RankFormula::Gravity { g: 0.0 }),
+            [1, 3, 2]
+        );
+    }
+
+    #[test]
+    fn rerank_score_per_hour() {
+        let stories = [
+            ranked_story(1, 100, 0, 10), // 10/h
+            ranked_story(2, 60, 0, 2),   // 30/h
+            ranked_story(3, 15, 0, 0),   // counts as 1h old: 15/h
+        ];
+
+        assert_eq!(rerank_ids(&stories, RankFormula::ScorePerHour), [2, 3, 1]);
+    }
+
+    #[test]
+    fn rerank_comments_per_hour_keeps_ties_in_feed_order() {
+        let stories = [
+            ranked_story(1, 500, 40, 4), // 10/h
+            ranked_story(2, 10, 90, 3),  // 30/h
+            ranked_story(3, 200, 20, 2), // 10/h
+        ];
+
+        assert_eq!(
+            rerank_ids(&stories, RankFormula::CommentsPerHour),
+            [2, 1, 3]
+        );
+    }
+
+    // ===== Domain Count Tests =====
+
+    fn linked_story(id: u32, url: Option<&str>, score: i32) -> HNItem {
+        HNItem {
+            id,
+            item_type: 0,
+            by: Some("author".to_string()),
+            time: 1609459200,
+            text: None,
+            url: url.map(str::to_string),
+            score,
+            title: Some(format!("Story {}", id)),
+            descendants: 0,
+            kids: None,
+            parent: None,
+            dead: false,
+            deleted: false,
+            post_kind: None,
+        }
+    }
+
+    #[test]
+    fn count_domains_ranks_by_frequency_then_score() {
+        let items = vec![
+            linked_story(1, Some("https://github.com/a"), 10),
+            linked_story(2, Some("https://www.github.com/b"), 5),
+            linked_story(3, Some("https://lwn.net/x"), 300),
+            linked_story(4, Some("https://arxiv.org/abs/1"), 40),
+            linked_story(5, Some("https://arxiv.org/abs/2"), 2),
+            linked_story(6, None, 999),
+            linked_story(7, Some("https://blog.rust-lang.org/"), 300),
+        ];
+
+        let counts = count_domains(&items);
+        let ranked: Vec<(&str, usize, i64)> = counts
+            .iter()
+            .map(|c| (c.domain.as_str(), c.count, c.total_score))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![
+                ("arxiv.org", 2, 42),
+                ("github.com", 2, 15),
+                ("blog.rust-lang.org", 1, 300),
+                ("lwn.net", 1, 300),
+            ]
+        );
+    }
+
+    #[test]
+    fn count_domains_skips_self_posts() {
+        let items = vec![linked_story(1, None, 10), linked_story(2, None, 20)];
+        assert!(count_domains(&items).is_empty());
+    }
+
+    // ===== RefreshTracker Tests =====
+
+    #[test]
+    fn refresh_tracker_new_creates_empty() {
+        let tracker = RefreshTracker::new();
+        assert!(tracker.last_fetch.is_empty());
+        assert!(tracker.refreshing.is_empty());
+    }
+
+    #[test]
+    fn refresh_tracker_mark_fetched_records_time() {
+        let mut tracker = RefreshTracker::new();
+        tracker.mark_fetched(StoryFeed::Top);
+        assert!(tracker.last_fetch.contains_key(&StoryFeed::Top));
+    }
+
+    #[test]
+    fn refresh_tracker_mark_fetched_clears_refreshing() {
+        let mut tracker = RefreshTracker::new();
+        tracker.start_refresh(StoryFeed::Top);
+        assert!(tracker.is_refreshing(&StoryFeed::Top));
+
+        tracker.mark_fetched(StoryFeed::Top);
+        assert!(!tracker.is_refreshing(&StoryFeed::Top));
+    }
+
+    #[test]
+    fn refresh_tracker_is_stale_false_for_fresh_data() {
+        let mut tracker = RefreshTracker::new();
+        tracker.mark_fetched(StoryFeed::Top);
+        // Just fetched, should not be stale
+        assert!(!tracker.is_stale(
+            &StoryFeed::Top,
+            Duration::from_secs(120),
+            DEFAULT_STALE_THRESHOLD_PERCENT
+        ));
+    }
+
+    #[test]
+    fn refresh_tracker_is_stale_false_for_unknown_feed() {
+        let tracker = RefreshTracker::new();
+        // Never fetched, should not be considered stale (will be fetched fresh)
+        assert!(!tracker.is_stale(
+            &StoryFeed::Top,
+            Duration::from_secs(120),
+            DEFAULT_STALE_THRESHOLD_PERCENT
+        ));
+    }
+
+    #[test]
+    fn refresh_tracker_start_refresh_sets_flag() {
+        let mut tracker = RefreshTracker::new();
+        assert!(!tracker.is_refreshing(&StoryFeed::Top));
+        tracker.start_refresh(StoryFeed::Top);
+        assert!(tracker.is_refreshing(&StoryFeed::Top));
+    }
+
+    #[tokio::test]
+    async fn feed_ages_report_fetched_feeds_only() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/topstories.json", serde_json::json!([1, 2]), 1).await;
+
+        let client = mock_client(&server);
+        assert_eq!(client.feed_last_refreshed(StoryFeed::Top).await, None);
+
+        let before = SystemTime::now();
+        client.fetch_story_ids(StoryFeed::Top).await.unwrap();
+        let refreshed = client.feed_last_refreshed(StoryFeed::Top).await.unwrap();
+        assert!(refreshed >= before);
+        assert!(refreshed.elapsed().unwrap() < Duration::from_secs(5));
+
+        let ages = client.feed_ages().await;
+        assert_eq!(ages.len(), StoryFeed::ALL.len());
+        let top = ages.iter().find(|a| a.feed == StoryFeed::Top).unwrap();
+        assert!(top.age_secs.is_some_and(|secs| secs < 5));
+        assert_eq!(top.refreshed_at, Some(unix_millis(refreshed)));
+        let new = ages.iter().find(|a| a.feed == StoryFeed::New).unwrap();
+        assert_eq!((new.refreshed_at, new.age_secs), (None, None));
+    }
+
+    #[test]
+    fn refresh_tracker_independent_feeds() {
+        let mut tracker = RefreshTracker::new();
+        tracker.mark_fetched(StoryFeed::Top);
+        tracker.start_refresh(StoryFeed::New);
+
+        assert!(tracker.last_fetch.contains_key(&StoryFeed::Top));
+        assert!(!tracker.last_fetch.contains_key(&StoryFeed::New));
+        assert!(tracker.is_refreshing(&StoryFeed::New));
+        assert!(!tracker.is_refreshing(&StoryFeed::Top));
+    }
+
+    // ===== HnClient Background Refresh Tests =====
+
+    #[tokio::test]
+    async fn is_feed_stale_false_initially() {
+        let client = HnClient::new();
+        // No data cached yet, shouldn't be considered stale
+        assert!(!client.is_feed_stale(&StoryFeed::Top).await);
+    }
+
+    // ===== Mock Server Helpers =====
+
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Create a client that talks to a local mock server instead of HN/Algolia.
+    fn mock_client(server: &MockServer) -> HnClient {
+        HnClient::with_config(ClientConfig {
+            hn_base_url: server.uri(),
+            algolia_base_url: server.uri(),
+            hn_web_base_url: server.uri(),
+            ..ClientConfig::default()
+        })
+    }
+
+    /// Minimal story JSON as returned by the Firebase API.
+    fn story_json(id: u32) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "type": "story",
+            "by": "author",
+            "time": 1609459200,
+            "title": format!("Story {}", id),
+            "url": format!("https://example.com/{}", id),
+            "score": 10,
+            "descendants": 0
+        })
+    }
+
+    /// A small article page that readability can extract.
+    const ARTICLE_HTML: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head><title>Test Article</title></head>
+<body>
+  <nav><a href="/">Home</a> <a href="/about">About</a></nav>
+  <article>
+    <h1>Test Article</h1>
+    <p>This is the first paragraph of the article, which talks about Rust, caching, and prefetching content for a faster reading experience.</p>
+    <p>The second paragraph continues the discussion, adding more detail, more words, and more commas so that the readability scorer picks it up.</p>
+    <p>Finally, the third paragraph wraps up the article with a conclusion, a summary, and a friendly goodbye to the reader.</p>
+  </article>
+  <footer>Copyright</footer>
+</body>
+</html>"#;
+
+    /// Serve `html` as `text/html` for `route`, expecting exactly `times` requests.
+    async fn mount_html(server: &MockServer, route: &str, html: &str, times: u64) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(html, "text/html"))
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    /// Serve `body` for `route`, expecting exactly `times` requests.
+    async fn mount_json(server: &MockServer, route: &str, body: serde_json::Value, times: u64) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    // ===== Prefetch Tests =====
+
+    #[tokio::test]
+    async fn prefetch_stories_makes_next_fetch_a_cache_hit() {
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "/topstories.json",
+            serde_json::json!([1, 2, 3, 4]),
+            1,
+        )
+        .await;
+        // Each item on the next page must be requested exactly once
+        mount_json(&server, "/item/3.json", story_json(3), 1).await;
+        mount_json(&server, "/item/4.json", story_json(4), 1).await;
+
+        let client = mock_client(&server);
+        let fetched = client.prefetch_stories(StoryFeed::Top, 2, 2).await.unwrap();
+        assert_eq!(fetched, 2);
+
+        let items = client.fetch_items(&[3, 4]).await.unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].id, 3);
+        // Expectations are verified when the server is dropped
+    }
+
+    #[tokio::test]
+    async fn prefetch_stories_is_noop_when_already_cached() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/topstories.json", serde_json::json!([1, 2]), 1).await;
+        mount_json(&server, "/item/1.json", story_json(1), 1).await;
+        mount_json(&server, "/item/2.json", story_json(2), 1).await;
+
+        let client = mock_client(&server);
+        assert_eq!(
+            client.prefetch_stories(StoryFeed::Top, 0, 2).await.unwrap(),
+            2
+        );
+        assert_eq!(
+            client.prefetch_stories(StoryFeed::Top, 0, 2).await.unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn warm_items_caches_requested_count() {
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "/topstories.json",
+            serde_json::json!([1, 2, 3, 4, 5]),
+            1,
+        )
+        .await;
+        for id in 1..=3 {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, story_json(id), 1).await;
+        }
+        for id in 4..=5 {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, story_json(id), 0).await;
+        }
+
+        let client = mock_client(&server);
+        // Already cached items aren't fetched again
+        client.fetch_item(1).await.unwrap();
+
+        assert_eq!(client.warm_items(StoryFeed::Top, 3).await.unwrap(), 2);
+        for id in 1..=3 {
+            assert!(client.item_cache.contains_key(&id));
+        }
+        assert!(!client.item_cache.contains_key(&4));
+    }
+
+    #[tokio::test]
+    async fn prefetch_comments_caches_shallow_trees_within_budget() {
+        let server = MockServer::start().await;
+        let with_kids = |mut item: serde_json::Value, kids: &[u32]| {
+            item["kids"] = serde_json::json!(kids);
+            item
+        };
+        mount_json(
+            &server,
+            "/item/1.json",
+            with_kids(story_json(1), &[10, 11]),
+            1,
+        )
+        .await;
+        mount_json(&server, "/item/2.json", with_kids(story_json(2), &[30]), 1).await;
+        mount_json(
+            &server,
+            "/item/10.json",
+            with_kids(comment_json(10), &[20]),
+            1,
+        )
+        .await;
+        mount_json(
+            &server,
+            "/item/11.json",
+            with_kids(comment_json(11), &[21, 22]),
+            1,
+        )
+        .await;
+        mount_json(&server, "/item/30.json", comment_json(30), 1).await;
+        for id in [20, 21] {
+            let comment = with_kids(comment_json(id), &[40]);
+            mount_json(&server, &format!("/item/{}.json", id), comment, 1).await;
+        }
+        // Past the per-story budget, or deeper than requested
+        mount_json(&server, "/item/22.json", comment_json(22), 0).await;
+        mount_json(&server, "/item/40.json", comment_json(40), 0).await;
+
+        let client = mock_client(&server);
+        assert_eq!(client.prefetch_comments(&[1, 2], 2, 4).await.unwrap(), 5);
+        for id in [10, 11, 20, 21, 30] {
+            assert!(client.item_cache.contains_key(&id), "{} not cached", id);
+        }
+        assert!(!client.item_cache.contains_key(&22));
+
+        // Everything is cached now
+        assert_eq!(client.prefetch_comments(&[1, 2], 2, 4).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn fetch_stories_with_comments_skips_missing_and_keeps_budget() {
+        let server = MockServer::start().await;
+        let with_kids = |mut item: serde_json::Value, kids: &[u32]| {
+            item["kids"] = serde_json::json!(kids);
+            item
+        };
+        mount_json(
+            &server,
+            "/item/1.json",
+            with_kids(story_json(1), &[10, 11]),
+            1,
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/item/2.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::Value::Null))
+            .mount(&server)
+            .await;
+        mount_json(&server, "/item/3.json", with_kids(story_json(3), &[30]), 1).await;
+        mount_json(
+            &server,
+            "/item/10.json",
+            with_kids(comment_json(10), &[20]),
+            1,
+        )
+        .await;
+        mount_json(&server, "/item/11.json", comment_json(11), 1).await;
+        mount_json(
+            &server,
+            "/item/30.json",
+            with_kids(comment_json(30), &[31]),
+            1,
+        )
+        .await;
+        mount_json(&server, "/item/31.json", comment_json(31), 1).await;
+        // Past story 1's budget of two comments
+        mount_json(&server, "/item/20.json", comment_json(20), 0).await;
+
+        let client = mock_client(&server);
+        let batch = client
+            .fetch_stories_with_comments(&[1, 2, 3], 2, 2, CommentSort::default())
+            .await
+            .unwrap();
+
+        let ids = |comments: &[CommentWithChildren]| -> Vec<u32> {
+            comments.iter().map(|c| c.item.id).collect()
+        };
+        assert_eq!(batch.iter().map(|s| s.story.id).collect::<Vec<_>>(), [1, 3]);
+        assert_eq!(ids(&batch[0].comments), [10, 11]);
+        assert!(batch[0].comments[0].children.is_empty());
+        assert_eq!(ids(&batch[1].comments), [30]);
+        assert_eq!(ids(&batch[1].comments[0].children), [31]);
+    }
+
+    #[tokio::test]
+    async fn prefetch_articles_populates_cache_and_is_idempotent() {
+        let server = MockServer::start().await;
+        let article_url = format!("{}/article/1", server.uri());
+
+        let mut story = story_json(1);
+        story["url"] = serde_json::json!(article_url);
+        mount_json(&server, "/item/1.json", story, 1).await;
+
+        // Self-posts have no URL and are skipped
+        let mut ask = story_json(2);
+        ask.as_object_mut().unwrap().remove("url");
+        mount_json(&server, "/item/2.json", ask, 1).await;
+
+        mount_html(&server, "/article/1", ARTICLE_HTML, 1).await;
+
+        let client = mock_client(&server);
+        assert_eq!(client.prefetch_articles(&[1, 2]).await.unwrap(), 1);
+        assert!(client.article_cache.contains_key(&article_url));
+
+        // Second run finds everything cached and downloads nothing
+        assert_eq!(client.prefetch_articles(&[1, 2]).await.unwrap(), 0);
+
+        let article = client.fetch_article_content(&article_url).await.unwrap();
+        assert!(article.word_count > 0);
+    }
+
+    // ===== Front-Page Digest Tests =====
+
+    #[test]
+    fn article_excerpt_cuts_at_word_boundary() {
+        assert_eq!(
+            article_excerpt("  one two\n\nthree  ", 100).as_deref(),
+            Some("one two three")
+        );
+        assert_eq!(
+            article_excerpt("one two three", 9).as_deref(),
+            Some("one two…")
+        );
+        assert_eq!(article_excerpt("abcdefgh", 4).as_deref(), Some("abcd…"));
+        assert_eq!(article_excerpt(" \n ", 10), None);
+    }
+
+    #[tokio::test]
+    async fn front_page_digest_tolerates_failed_extraction() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/topstories.json", serde_json::json!([1, 2, 3]), 1).await;
+
+        let mut story = story_json(1);
+        story["url"] = serde_json::json!(format!("{}/article/1", server.uri()));
+        mount_json(&server, "/item/1.json", story, 1).await;
+        mount_html(&server, "/article/1", ARTICLE_HTML, 1).await;
+
+        let mut broken = story_json(2);
+        broken["url"] = serde_json::json!(format!("{}/article/2", server.uri()));
+        mount_json(&server, "/item/2.json", broken, 1).await;
+        Mock::given(method("GET"))
+            .and(path("/article/2"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let mut ask = story_json(3);
+        ask.as_object_mut().unwrap().remove("url");
+        mount_json(&server, "/item/3.json", ask, 1).await;
+
+        let client = mock_client(&server);
+        let digest = client.fetch_front_page_digest(10).await.unwrap();
+
+        let ids: Vec<u32> = digest.iter().map(|e| e.item.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+
+        assert_eq!(digest[0].domain.as_deref(), Some("127.0.0.1"));
+        assert_eq!(digest[0].reading_time_minutes, Some(1));
+        assert!(digest[0]
+            .excerpt
+            .as_deref()
+            .is_some_and(|e| e.contains("first paragraph")));
+
+        assert_eq!(digest[1].excerpt, None);
+        assert_eq!(digest[1].reading_time_minutes, None);
+        assert_eq!(digest[2].domain, None);
+        assert_eq!(digest[2].excerpt, None);
+    }
+
+    // ===== Timer-Driven Refresh Tests =====
+
+    #[tokio::test]
+    async fn refresh_all_feeds_reports_only_changed_feeds() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/topstories.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([1, 2])))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/topstories.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([3, 1, 2])))
+            .mount(&server)
+            .await;
+        for feed in StoryFeed::ALL.into_iter().filter(|f| *f != StoryFeed::Top) {
+            let route = format!("/{}.json", feed.endpoint());
+            mount_json(&server, &route, serde_json::json!([]), 2).await;
+        }
+
+        let client = mock_client(&server);
+
+        // Nothing cached yet, so every feed counts as new data
+        let first = client.refresh_all_feeds().await;
+        assert_eq!(first.len(), StoryFeed::ALL.len());
+
+        // Only Top returns different IDs on the second pass
+        let second = client.refresh_all_feeds().await;
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].feed, StoryFeed::Top);
+        assert_eq!(second[0].ids, vec![3, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn refresh_reports_position_deltas() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/topstories.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([1, 2, 3])))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/topstories.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([3, 1, 4])))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        client.background_refresh_feed(StoryFeed::Top).await;
+        assert!(client.feed_position_deltas(StoryFeed::Top).await.is_empty());
+
+        client.background_refresh_feed(StoryFeed::Top).await;
+        let deltas = client.feed_position_deltas(StoryFeed::Top).await;
+        assert_eq!(deltas.len(), 4);
+        assert_eq!(deltas[0].id, 3);
+        assert_eq!((deltas[0].old_rank, deltas[0].new_rank), (Some(3), Some(1)));
+    }
+
+    #[tokio::test]
+    async fn refresh_all_feeds_skips_feeds_already_refreshing() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/topstories.json", serde_json::json!([1]), 0).await;
+        for feed in StoryFeed::ALL.into_iter().filter(|f| *f != StoryFeed::Top) {
+            let route = format!("/{}.json", feed.endpoint());
+            mount_json(&server, &route, serde_json::json!([1]), 1).await;
+        }
+
+        let client = mock_client(&server);
+        client
+            .refresh_tracker
+            .write()
+            .await
+            .start_refresh(StoryFeed::Top);
+
+        let updates = client.refresh_all_feeds().await;
+        assert!(!updates.iter().any(|u| u.feed == StoryFeed::Top));
+        assert_eq!(updates.len(), StoryFeed::ALL.len() - 1);
+    }
+
+    // ===== Ordered Batch Fetch Tests =====
+
+    #[tokio::test]
+    async fn fetch_items_ordered_preserves_order_and_marks_missing() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/item/1.json", story_json(1), 1).await;
+        // Missing items aren't cached, so the second fetch below asks again
+        mount_json(&server, "/item/2.json", serde_json::Value::Null, 2).await;
+        mount_json(&server, "/item/3.json", story_json(3), 1).await;
+
+        let client = mock_client(&server);
+        let items = client.fetch_items_ordered(&[3, 2, 1]).await.unwrap();
+
+        let ids: Vec<Option<u32>> = items.iter().map(|i| i.as_ref().map(|i| i.id)).collect();
+        assert_eq!(ids, vec![Some(3), None, Some(1)]);
+
+        // The compacting variant drops the gap but keeps relative order
+        let compact = client.fetch_items(&[3, 2, 1]).await.unwrap();
+        let ids: Vec<u32> = compact.iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![3, 1]);
+    }
+
+    #[tokio::test]
+    async fn fetch_items_detailed_keeps_successes_and_reports_failures() {
+        let base_url = start_dropping_server(vec![
+            ("/item/1.json", story_json(1)),
+            ("/item/2.json", serde_json::Value::Null),
+            ("/item/4.json", story_json(4)),
+        ])
+        .await;
+        let client = HnClient::with_config(ClientConfig {
+            hn_base_url: base_url,
+            ..ClientConfig::default()
+        });
+
+        // Item 3 isn't served: its connection is dropped
+        let batch = client.fetch_items_detailed(&[4, 3, 2, 1]).await;
+
+        let ids: Vec<u32> = batch.items.iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![4, 1]);
+
+        let failed: Vec<u32> = batch.errors.iter().map(|(id, _)| *id).collect();
+        assert_eq!(failed, vec![3, 2]);
+        assert!(batch.errors[0].1.starts_with("HTTP request failed"));
+        assert!(!batch.errors[0].1.contains("decoding"));
+        assert_eq!(batch.errors[1].1, "Item not found: 2");
+
+        // The all-or-nothing variant loses everything to the one bad item
+        assert!(client.fetch_items(&[4, 3, 2, 1]).await.is_err());
+    }
+
+    /// Start a bare HTTP server answering each of `routes` with its JSON and
+    /// closing the connection without a response for any other path, which
+    /// clients see as a network error.
+    async fn start_dropping_server(routes: Vec<(&'static str, serde_json::Value)>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let routes: Arc<HashMap<&str, String>> = Arc::new(
+            routes
+                .into_iter()
+                .map(|(route, body)| (route, body.to_string()))
+                .collect(),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let routes = Arc::clone(&routes);
+                tokio::spawn(async move {
+                    let mut buf = vec![0; 4096];
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]);
+                    let route = request.split_whitespace().nth(1).unwrap_or_default();
+                    if let Some(body) = routes.get(route) {
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                             content-length: {}\r\nconnection: close\r\n\r\n{}",
+                            body.len(),
+                            body
+                        );
+                        let _ = socket.write_all(response.as_bytes()).await;
+                    }
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    // ===== Poll Tests =====
+
+    #[tokio::test]
+    async fn fetch_poll_tallies_option_votes() {
+        let server = MockServer::start().await;
+        mount_json(
+            &server,
+            "/item/1.json",
+            serde_json::json!({
+                "id": 1,
+                "type": "poll",
+                "title": "Tabs or spaces?",
+                "score": 40,
+                "parts": [2, 3]
+            }),
+            1,
+        )
+        .await;
+        for (id, votes) in [(2, 10), (3, 30)] {
+            let route = format!("/item/{}.json", id);
+            let option = serde_json::json!({
+                "id": id,
+                "type": "pollopt",
+                "poll": 1,
+                "score": votes,
+                "text": format!("Option {}", id)
+            });
+            mount_json(&server, &route, option, 1).await;
+        }
+
+        let client = mock_client(&server);
+        let poll = client.fetch_poll(1).await.unwrap();
+
+        assert_eq!(poll.poll.title.as_deref(), Some("Tabs or spaces?"));
+        assert_eq!(poll.total_votes, 40);
+        let shares: Vec<(u32, f64)> = poll.options.iter().map(|o| (o.id, o.percent)).collect();
+        assert_eq!(shares, vec![(3, 75.0), (2, 25.0)]);
+    }
+
+    #[tokio::test]
+    async fn fetch_poll_rejects_non_polls() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/item/1.json", story_json(1), 1).await;
+
+        let client = mock_client(&server);
+        assert!(matches!(client.fetch_poll(1).await, Err(ApiError::Api(_))));
+    }
+
+    // ===== User Submissions Tests =====
+
+    /// Minimal comment JSON as returned by the Firebase API.
+    fn comment_json(id: u32) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "type": "comment",
+            "by": "prolific",
+            "time": 1609459200,
+            "parent": 1,
+            "text": "A comment"
+        })
+    }
+
+    #[tokio::test]
+    async fn fetch_user_submissions_scans_past_filtered_out_items() {
+        let server = MockServer::start().await;
+
+        // 40 comments followed by 5 stories
+        let submitted: Vec<u32> = (1..=45).collect();
+        let user = serde_json::json!({
+            "id": "prolific",
+            "created": 1577836800,
+            "karma": 100,
+            "submitted": submitted
+        });
+        mount_json(&server, "/user/prolific.json", user, 1).await;
+        for id in 1..=40 {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, comment_json(id), 1).await;
+        }
+        for id in 41..=45 {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, story_json(id), 1).await;
+        }
+
+        let client = mock_client(&server);
+        let page = client
+            .fetch_user_submissions("prolific", 0, 3, SubmissionFilter::Stories)
+            .await
+            .unwrap();
+
+        let ids: Vec<u32> = page.items.iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![41, 42, 43]);
+        assert_eq!(page.next_offset, 43);
+        assert!(page.has_more);
+        assert_eq!(page.total, 45);
+
+        // Continuing from next_offset returns the rest and reports the end
+        let page = client
+            .fetch_user_submissions("prolific", page.next_offset, 3, SubmissionFilter::Stories)
+            .await
+            .unwrap();
+
+        let ids: Vec<u32> = page.items.iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![44, 45]);
+        assert_eq!(page.next_offset, 45);
+        assert!(!page.has_more);
+    }
+
+    // ===== User Favorites Tests =====
+
+    const FAVORITES_HTML: &str = include_str!("../tests/fixtures/favorites.html");
+
+    #[test]
+    fn parse_favorite_ids_reads_fixture_in_page_order() {
+        assert_eq!(
+            parse_favorite_ids(FAVORITES_HTML),
+            vec![39132345, 8863, 121003]
+        );
+    }
+
+    #[test]
+    fn parse_favorite_ids_empty_page() {
+        let html = r#"<table class="itemlist"><tr class="morespace"></tr></table>"#;
+        assert!(parse_favorite_ids(html).is_empty());
+    }
+
+    #[tokio::test]
+    async fn fetch_user_favorites_hydrates_parsed_ids() {
+        let server = MockServer::start().await;
+        mount_html(&server, "/favorites", FAVORITES_HTML, 1).await;
+        for id in [39132345, 8863, 121003] {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, story_json(id), 1).await;
+        }
+
+        let client = mock_client(&server);
+        let items = client.fetch_user_favorites("pg", 1).await.unwrap();
+
+        let ids: Vec<u32> = items.iter().map(|i| i.id).collect();
+        assert_eq!(ids, vec![39132345, 8863, 121003]);
+    }
+
+    #[tokio::test]
+    async fn fetch_user_favorites_unknown_user() {
+        let server = MockServer::start().await;
+        mount_html(&server, "/favorites", "No such user.", 1).await;
+
+        let client = mock_client(&server);
+        let result = client.fetch_user_favorites("nobody", 1).await;
+
+        assert!(matches!(result, Err(ApiError::UserNotFound(_))));
+    }
+
+    // ===== Comment Children Pagination Tests =====
+
+    #[tokio::test]
+    async fn fetch_comment_children_paginates_direct_kids() {
+        let server = MockServer::start().await;
+
+        let kids: Vec<u32> = (1..=25).collect();
+        let mut parent = comment_json(100);
+        parent["kids"] = serde_json::json!(kids);
+        mount_json(&server, "/item/100.json", parent, 1).await;
+        for id in (1..=10).chain(21..=25) {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, comment_json(id), 1).await;
+        }
+
+        let client = mock_client(&server);
+
+        let page = client.fetch_comment_children(100, 1, 0, 10).await.unwrap();
+        let ids: Vec<u32> = page.comments.iter().map(|c| c.item.id).collect();
+        assert_eq!(ids, (1..=10).collect::<Vec<_>>());
+        assert!(page.has_more);
+        assert_eq!(page.total, 25);
+
+        let page = client.fetch_comment_children(100, 1, 20, 10).await.unwrap();
+        let ids: Vec<u32> = page.comments.iter().map(|c| c.item.id).collect();
+        assert_eq!(ids, vec![21, 22, 23, 24, 25]);
+        assert!(!page.has_more);
+
+        let page = client.fetch_comment_children(100, 1, 30, 10).await.unwrap();
+        assert!(page.comments.is_empty());
+        assert!(!page.has_more);
+    }
+
+    #[tokio::test]
+    async fn comment_depth_is_clamped_to_configured_max() {
+        let server = MockServer::start().await;
+
+        // A reply chain 100 -> 1 -> 2 -> 3 -> 4
+        for id in [100, 1, 2, 3] {
+            let mut comment = comment_json(id);
+            comment["kids"] = serde_json::json!([if id == 100 { 1 } else { id + 1 }]);
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, comment, 1).await;
+        }
+        mount_json(&server, "/item/4.json", comment_json(4), 0).await;
+
+        let client = HnClient::with_config(ClientConfig {
+            hn_base_url: server.uri(),
+            max_comment_depth: 3,
+            ..ClientConfig::default()
+        });
+
+        let page = client
+            .fetch_comment_children(100, u8::MAX, 0, 10)
+            .await
+            .unwrap();
+
+        let mut depth = 0;
+        let mut level = &page.comments;
+        while let Some(comment) = level.first() {
+            depth += 1;
+            level = &comment.children;
+        }
+        assert_eq!(depth, 3);
+    }
+
+    // ===== Comment Context Tests =====
+
+    /// Comment JSON whose `parent` is `parent`.
+    fn reply_json(id: u32, parent: u32) -> serde_json::Value {
+        let mut comment = comment_json(id);
+        comment["parent"] = serde_json::json!(parent);
+        comment
+    }
+
+    #[tokio::test]
+    async fn fetch_comment_context_walks_up_to_the_story() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/item/4.json", reply_json(4, 3), 1).await;
+        mount_json(&server, "/item/3.json", reply_json(3, 2), 1).await;
+        mount_json(&server, "/item/2.json", reply_json(2, 1), 1).await;
+        mount_json(&server, "/item/1.json", story_json(1), 1).await;
+
+        let client = mock_client(&server);
+        let context = client
+            .fetch_comment_context(4, MAX_COMMENT_CONTEXT_DEPTH)
+            .await
+            .unwrap();
+
+        assert_eq!(context.comment.id, 4);
+        let ids: Vec<u32> = context.ancestors.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(context.story.map(|s| s.id), Some(1));
+        assert!(!context.truncated);
+    }
+
+    #[tokio::test]
+    async fn fetch_comment_context_respects_depth_cap() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/item/5.json", reply_json(5, 4), 1).await;
+        mount_json(&server, "/item/4.json", reply_json(4, 3), 1).await;
+        mount_json(&server, "/item/3.json", reply_json(3, 2), 1).await;
+        mount_json(&server, "/item/2.json", reply_json(2, 1), 0).await;
+        mount_json(&server, "/item/1.json", story_json(1), 0).await;
+
+        let client = mock_client(&server);
+        let context = client.fetch_comment_context(5, 2).await.unwrap();
+
+        let ids: Vec<u32> = context.ancestors.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![3, 4]);
+        assert!(context.story.is_none());
+        assert!(context.truncated);
+    }
+
+    // ===== Favicon Tests =====
+
+    /// PNG signature followed by filler, enough to look like an image.
+    const PNG_BYTES: &[u8] = b"\x89PNG\r\n\x1a\nfake-image-data";
+
+    /// Client whose favicon requests go to `server` and cache in `dir`.
+    fn favicon_client(server: &MockServer, dir: &tempfile::TempDir) -> HnClient {
+        HnClient::with_config(ClientConfig {
+            favicon_origin: server.uri(),
+            cache_dir: dir.path().to_path_buf(),
+            ..ClientConfig::default()
+        })
+    }
+
+    async fn mount_status(server: &MockServer, route: &str, status: u16, times: u64) {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(status))
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn favicon_cache_key_normalizes_and_rejects_paths() {
+        assert_eq!(
+            favicon_cache_key("Example.COM."),
+            Some("example.com".to_string())
+        );
+        assert_eq!(
+            favicon_cache_key("sub-1.example.org"),
+            Some("sub-1.example.org".to_string())
+        );
+        assert_eq!(favicon_cache_key("../etc/passwd"), None);
+        assert_eq!(favicon_cache_key("a..b"), None);
+        assert_eq!(favicon_cache_key(""), None);
+    }
+
+    #[test]
+    fn favicon_cache_key_rejects_local_hosts() {
+        assert_eq!(favicon_cache_key("localhost"), None);
+        assert_eq!(favicon_cache_key("intranet."), None);
+        assert_eq!(favicon_cache_key("127.0.0.1"), None);
+        assert_eq!(favicon_cache_key("192.168.1.10"), None);
+        assert_eq!(favicon_cache_key("0x7f.1"), None);
+        assert_eq!(favicon_cache_key("[::1]"), None);
+    }
+
+    #[test]
+    fn parse_icon_href_finds_icon_links() {
+        let html = r#"<head><link rel="stylesheet" href="/a.css">
+            <link href="/static/icon.png?v=1&amp;x=2" rel="Shortcut Icon"></head>"#;
+        assert_eq!(
+            parse_icon_href(html),
+            Some("/static/icon.png?v=1&x=2".to_string())
+        );
+        assert_eq!(
+            parse_icon_href("<link rel='stylesheet' href='/a.css'>"),
+            None
+        );
+    }
+
+    #[test]
+    fn favicon_data_url_sniffs_type() {
+        assert!(favicon_data_url(PNG_BYTES).starts_with("data:image/png;base64,"));
+        assert!(favicon_data_url(&[0, 0, 1, 0]).starts_with("data:image/x-icon;base64,AAABAA"));
+        assert!(favicon_data_url(b"<svg/>").starts_with("data:image/svg+xml;base64,"));
+    }
+
+    #[tokio::test]
+    async fn fetch_favicon_second_call_is_disk_cache_hit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/favicon.ico"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(PNG_BYTES, "image/png"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let client = favicon_client(&server, &dir);
+
+        assert_eq!(
+            client.fetch_favicon("example.com").await.unwrap(),
+            PNG_BYTES
+        );
+        assert_eq!(
+            client.fetch_favicon("example.com").await.unwrap(),
+            PNG_BYTES
+        );
+
+        // A fresh client sharing the cache dir doesn't hit the network either
+        let client = favicon_client(&server, &dir);
+        assert_eq!(
+            client.fetch_favicon("example.com").await.unwrap(),
+            PNG_BYTES
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_favicon_falls_back_to_link_rel_icon() {
+        let server = MockServer::start().await;
+        mount_status(&server, "/favicon.ico", 404, 1).await;
+        mount_html(
+            &server,
+            "/",
+            r#"<html><head><link rel="icon" href="/static/icon.png"></head></html>"#,
+            1,
+        )
+        .await;
+        Mock::given(method("GET"))
+            .and(path("/static/icon.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(PNG_BYTES, "image/png"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let client = favicon_client(&server, &dir);
+
+        assert_eq!(
+            client.fetch_favicon("example.com").await.unwrap(),
+            PNG_BYTES
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_favicon_remembers_domains_without_icons() {
+        let server = MockServer::start().await;
+        mount_status(&server, "/favicon.ico", 404, 1).await;
+        mount_html(&server, "/", "<html><head></head></html>", 1).await;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let client = favicon_client(&server, &dir);
+
+        for _ in 0..2 {
+            let result = client.fetch_favicon("example.com").await;
+            assert!(matches!(result, Err(ApiError::FaviconNotFound(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_favicon_skips_oversized_icons() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/favicon.ico"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(vec![0u8; MAX_FAVICON_BYTES + 1], "image/x-icon"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_html(&server, "/", "<html><head></head></html>", 1).await;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let client = favicon_client(&server, &dir);
+
+        let result = client.fetch_favicon("example.com").await;
+        assert!(matches!(result, Err(ApiError::FaviconNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_favicon_rejects_invalid_domain() {
+        let client = HnClient::new();
+        let result = client.fetch_favicon("../../secrets").await;
+        assert!(matches!(result, Err(ApiError::Api(_))));
+    }
+
+    // ===== Dead/Deleted Story Tests =====
+
+    /// Mount a feed of stories 1-4 where 2 is dead and 3 is deleted.
+    async fn mount_feed_with_dead_stories(server: &MockServer) {
+        mount_json(
+            server,
+            "/topstories.json",
+            serde_json::json!([1, 2, 3, 4]),
+            1,
+        )
+        .await;
+
+        let mut dead = story_json(2);
+        dead["dead"] = serde_json::json!(true);
+        let deleted =
+            serde_json::json!({ "id": 3, "type": "story", "deleted": true, "time": 1609459200 });
+
+        Mock::given(method("GET"))
+            .and(path("/item/1.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(story_json(1)))
+            .mount(server)
+            .await;
+        mount_json(server, "/item/2.json", dead, 1).await;
+        mount_json(server, "/item/3.json", deleted, 1).await;
+        Mock::given(method("GET"))
+            .and(path("/item/4.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(story_json(4)))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn fetch_stories_excluding_dead_backfills_to_limit() {
+        let server = MockServer::start().await;
+        mount_feed_with_dead_stories(&server).await;
+        let client = mock_client(&server);
+
+        let page = client
+            .fetch_stories_paginated(StoryFeed::Top, 0, 2, true)
+            .await
+            .unwrap();
+
+        let ids: Vec<u32> = page.stories.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![1, 4]);
+        assert_eq!(page.next_offset, 4);
+        assert!(!page.has_more);
+    }
+
+    #[tokio::test]
+    async fn fetch_stories_without_exclusion_flags_dead_and_deleted() {
+        let server = MockServer::start().await;
+        mount_feed_with_dead_stories(&server).await;
+        let client = mock_client(&server);
+
+        let page = client
+            .fetch_stories_paginated(StoryFeed::Top, 0, 3, false)
+            .await
+            .unwrap();
+
+        let ids: Vec<u32> = page.stories.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert!(page.stories[1].dead);
+        assert!(page.stories[2].deleted);
+        assert_eq!(page.next_offset, 3);
+        assert!(page.has_more);
+    }
+
+    #[tokio::test]
+    async fn fetch_stories_without_exclusion_does_not_backfill_missing() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/topstories.json", serde_json::json!([1, 2, 3]), 1).await;
+        mount_json(&server, "/item/1.json", story_json(1), 1).await;
+        Mock::given(method("GET"))
+            .and(path("/item/2.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::Value::Null))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let page = client
+            .fetch_stories_paginated(StoryFeed::Top, 0, 2, false)
+            .await
+            .unwrap();
+
+        // Story 3 belongs to the next page, even though 2 is missing
+        let ids: Vec<u32> = page.stories.iter().map(|s| s.id).collect();
+        assert_eq!(ids, vec![1]);
+        assert_eq!(page.next_offset, 2);
+        assert!(page.has_more);
+    }
+
+    #[tokio::test]
+    async fn fetch_item_returns_dead_item_flagged() {
+        let server = MockServer::start().await;
+        let mut dead = story_json(7);
+        dead["dead"] = serde_json::json!(true);
+        mount_json(&server, "/item/7.json", dead, 1).await;
+
+        let client = mock_client(&server);
+        let item = client.fetch_item(7).await.unwrap();
+
+        assert_eq!(item.id, 7);
+        assert!(item.dead);
+    }
+
+    // ===== Hiring Thread Tests =====
+
+    #[tokio::test]
+    async fn parse_hiring_thread_extracts_postings_in_order() {
+        let server = MockServer::start().await;
+        let comments: Vec<serde_json::Value> =
+            serde_json::from_str(include_str!("../tests/fixtures/hiring_thread.json")).unwrap();
+        let kids: Vec<u64> = comments.iter().map(|c| c["id"].as_u64().unwrap()).collect();
+
+        let mut thread = story_json(1000);
+        thread["kids"] = serde_json::json!(kids);
+        mount_json(&server, "/item/1000.json", thread, 1).await;
+        for comment in comments {
+            let route = format!("/item/{}.json", comment["id"]);
+            mount_json(&server, &route, comment, 1).await;
+        }
+
+        let client = mock_client(&server);
+        let postings = client.parse_hiring_thread(1000).await.unwrap();
+
+        let companies: Vec<&str> = postings.iter().map(|p| p.company.as_str()).collect();
+        assert_eq!(
+            companies,
+            vec!["Acme Robotics", "Globex", "Initech", "Hooli"]
+        );
+        assert_eq!(postings[0].id, 1001);
+    }
+
+    // ===== Short URL Resolution Tests =====
+
+    /// Client that treats the mock server's host as a link shortener.
+    fn shortener_client() -> HnClient {
+        HnClient::with_config(ClientConfig {
+            shortener_hosts: vec!["127.0.0.1".to_string()],
+            ..ClientConfig::default()
+        })
+    }
+
+    /// Respond to HEAD `route` with a redirect to `location`.
+    async fn mount_redirect(server: &MockServer, route: &str, location: &str, times: u64) {
+        Mock::given(method("HEAD"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(301).insert_header("Location", location))
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn resolve_short_url_follows_redirects_and_caches() {
+        let server = MockServer::start().await;
+        mount_redirect(&server, "/short", "/hop", 1).await;
+        mount_redirect(&server, "/hop", &format!("{}/final", server.uri()), 1).await;
+        Mock::given(method("HEAD"))
+            .and(path("/final"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = shortener_client();
+        let short = format!("{}/short", server.uri());
+        let expected = format!("{}/final", server.uri());
+
+        assert_eq!(client.resolve_short_url(&short).await, expected);
+        // Second call is served from the cache
+        assert_eq!(client.resolve_short_url(&short).await, expected);
+    }
+
+    #[tokio::test]
+    async fn resolve_short_url_caps_redirect_chain() {
+        let server = MockServer::start().await;
+        for hop in 0..10 {
+            let times = if hop < MAX_SHORTENER_REDIRECTS { 1 } else { 0 };
+            mount_redirect(
+                &server,
+                &format!("/r/{}", hop),
+                &format!("/r/{}", hop + 1),
+                times,
+            )
+            .await;
+        }
+
+        let client = shortener_client();
+        let resolved = client
+            .resolve_short_url(&format!("{}/r/0", server.uri()))
+            .await;
+
+        assert_eq!(
+            resolved,
+            format!("{}/r/{}", server.uri(), MAX_SHORTENER_REDIRECTS)
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_short_url_ignores_other_hosts() {
+        let client = HnClient::new();
+        let url = "https://example.com/article?id=1";
+        assert_eq!(client.resolve_short_url(url).await, url);
+    }
+
+    #[tokio::test]
+    async fn fetch_article_content_extracts_from_resolved_url() {
+        let server = MockServer::start().await;
+        mount_redirect(&server, "/s/abc", "/article/1", 1).await;
+        mount_html(&server, "/article/1", ARTICLE_HTML, 1).await;
+
+        let client = shortener_client();
+        let article = client
+            .fetch_article_content(&format!("{}/s/abc", server.uri()))
+            .await
+            .unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("Test Article"));
+        assert!(article.text_content.contains("third paragraph"));
+        assert_eq!(article.user_agent.as_deref(), Some(USER_AGENT));
+    }
+
+    // ===== Article Download Limit Tests =====
+
+    #[tokio::test]
+    async fn fetch_reader_content_uses_self_post_text_without_fetching() {
+        let server = MockServer::start().await;
+        let mut story = story_json(1);
+        story.as_object_mut().unwrap().remove("url");
+        story["title"] = serde_json::json!("Ask HN: How do you take notes?");
+        story["text"] = serde_json::json!(
+            "I&#x27;ve tried <i>everything</i>.<p>See <a href=\"https:&#x2F;&#x2F;example.com&#x2F;notes\">my notes</a>."
+        );
+        mount_json(&server, "/item/1.json", story, 1).await;
+        let client = mock_client(&server);
+
+        let article = client.fetch_reader_content(1).await.unwrap();
+
+        assert_eq!(
+            article.text_content,
+            "I've tried everything.\n\nSee my notes."
+        );
+        assert_eq!(article.word_count, 6);
+        assert_eq!(
+            article.title.as_deref(),
+            Some("Ask HN: How do you take notes?")
+        );
+        assert!(article.content.starts_with("<p>I&#x27;ve tried"));
+        assert_eq!(article.links.len(), 1);
+        assert_eq!(article.links[0].url, "https://example.com/notes");
+        // Only the item itself was requested
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn set_exclude_same_host_links_drops_links_to_own_site() {
+        let server = MockServer::start().await;
+        let mut story = story_json(1);
+        story.as_object_mut().unwrap().remove("url");
+        story["text"] = serde_json::json!(format!(
+            "See <a href=\"{}/item?id=2\">this thread</a> and <a href=\"https://example.com/\">this</a>.",
+            server.uri()
+        ));
+        mount_json(&server, "/item/1.json", story, 1).await;
+        let client = mock_client(&server);
+        let item = client.fetch_item(1).await.unwrap();
+
+        let article = client.extract_self_post_content(&item);
+        assert_eq!(article.links.len(), 2);
+
+        client.set_exclude_same_host_links(true);
+        let article = client.extract_self_post_content(&item);
+        let urls: Vec<&str> = article.links.iter().map(|l| l.url.as_str()).collect();
+        assert_eq!(urls, vec!["https://example.com/"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_article_content_rejects_non_html() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/image.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(PNG_BYTES.to_vec(), "image/png"))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let result = client
+            .fetch_article_content(&format!("{}/image.png", server.uri()))
+            .await;
+
+        match result {
+            Err(ApiError::ArticleExtraction(msg)) => {
+                assert!(msg.contains("unsupported content type"), "{}", msg);
+                assert!(msg.contains("image/png"), "{}", msg);
+            }
+            other => panic!("expected unsupported content type, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_article_content_enforces_size_cap() {
+        let server = MockServer::start().await;
+        mount_html(&server, "/big", ARTICLE_HTML, 1).await;
+        mount_html(&server, "/article/1", ARTICLE_HTML, 1).await;
+
+        let client = HnClient::with_config(ClientConfig {
+            max_article_bytes: ARTICLE_HTML.len() - 1,
+            ..ClientConfig::default()
+        });
+        let result = client
+            .fetch_article_content(&format!("{}/big", server.uri()))
+            .await;
+        match result {
+            Err(ApiError::ArticleExtraction(msg)) => assert!(msg.contains("byte limit"), "{}", msg),
+            other => panic!("expected size limit error, got {:?}", other),
+        }
+
+        // Exactly at the limit is fine, including when set at runtime
+        assert!(client.set_max_article_bytes(0).is_err());
+        client.set_max_article_bytes(ARTICLE_HTML.len()).unwrap();
+        let article = client
+            .fetch_article_content(&format!("{}/article/1", server.uri()))
+            .await
+            .unwrap();
+        assert_eq!(article.title.as_deref(), Some("Test Article"));
+    }
+
+    #[tokio::test]
+    async fn fetch_article_content_uses_article_timeout() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_raw(ARTICLE_HTML, "text/html")
+                    .set_delay(Duration::from_millis(500)),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+        let url = format!("{}/slow", server.uri());
+
+        let client = HnClient::with_config(ClientConfig {
+            article_timeout: Duration::from_millis(100),
+            ..ClientConfig::default()
+        });
+        match client.fetch_article_content(&url).await {
+            Err(ApiError::Timeout(timed_out)) => assert_eq!(timed_out, url),
+            other => panic!("expected timeout, got {:?}", other),
+        }
+
+        // The default allows slow hosts
+        let article = HnClient::with_config(ClientConfig::default())
+            .fetch_article_content(&url)
+            .await
+            .unwrap();
+        assert_eq!(article.title.as_deref(), Some("Test Article"));
+    }
+
+    #[tokio::test]
+    async fn fetch_article_content_falls_back_to_largest_text_block() {
+        // Readability settles on the teaser paragraph, since list items
+        // aren't scoring candidates
+        let items: String = (1..=8)
+            .map(|n| format!("<li>Point {} of the article, explained in words.</li>", n))
+            .collect();
+        let page = format!(
+            r#"<!DOCTYPE html>
+<html><head><title>Test Article</title></head><body>
+<div id="teaser"><p>Read this, then that, then more, and so on.</p></div>
+<div id="body"><ul>{}</ul></div>
+</body></html>"#,
+            items
+        );
+        let server = MockServer::start().await;
+        mount_html(&server, "/listicle", &page, 1).await;
+
+        let client = mock_client(&server);
+        let article = client
+            .fetch_article_content(&format!("{}/listicle", server.uri()))
+            .await
+            .unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("Test Article"));
+        assert_eq!(article.word_count, 8 * 8);
+        assert!(article
+            .text_content
+            .starts_with("Point 1 of the article, explained in words.\n\n"));
+        assert!(article.content.contains("<p>Point 8 of the article"));
+        assert!(article.confidence < 1.0);
+    }
+
+    #[tokio::test]
+    async fn fetch_article_content_retries_forbidden_with_fallback_user_agent() {
+        use wiremock::matchers::header;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paywall"))
+            .and(header("user-agent", "Browser/1.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(ARTICLE_HTML, "text/html"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // Everything else, including the default user agent, is turned away
+        Mock::given(method("GET"))
+            .and(path("/paywall"))
+            .respond_with(ResponseTemplate::new(403))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let url = format!("{}/paywall", server.uri());
+
+        let client = HnClient::with_config(ClientConfig {
+            fallback_user_agents: vec!["Blocked/1.0".to_string(), "Browser/1.0".to_string()],
+            ..ClientConfig::default()
+        });
+        let article = client.fetch_article_content(&url).await.unwrap();
+        assert_eq!(article.title.as_deref(), Some("Test Article"));
+        assert_eq!(article.user_agent.as_deref(), Some("Browser/1.0"));
+
+        // Without fallbacks the 403 is final
+        let client = HnClient::with_config(ClientConfig {
+            fallback_user_agents: Vec::new(),
+            ..ClientConfig::default()
+        });
+        match client.fetch_article_content(&url).await {
+            Err(ApiError::ArticleExtraction(msg)) => assert!(msg.contains("403"), "{}", msg),
+            other => panic!("expected 403 error, got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "pdf")]
+    #[tokio::test]
+    async fn fetch_article_content_extracts_pdf() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                include_bytes!("../tests/fixtures/sample.pdf").to_vec(),
+                "application/pdf",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let url = format!("{}/paper.pdf", server.uri());
+        let article = client.fetch_article_content(&url).await.unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("A Tiny Test PDF"));
+        assert!(article.text_content.contains("tiny document"));
+
+        // Cached like HTML articles
+        client.fetch_article_content(&url).await.unwrap();
+    }
+
+    // ===== Article Charset Tests =====
+
+    const WINDOWS_1252_HTML: &[u8] = include_bytes!("../tests/fixtures/windows-1252.html");
+
+    #[test]
+    fn decode_html_uses_header_charset() {
+        let decoded = decode_html(
+            b"caf\xe9 \x93quoted\x94 \x80",
+            Some("text/html; charset=windows-1252"),
+        );
+        assert_eq!(decoded, "café “quoted” €");
+
+        let quoted = decode_html(b"caf\xe9", Some("text/html; Charset=\"ISO-8859-1\""));
+        assert_eq!(quoted, "café");
+    }
+
+    #[test]
+    fn decode_html_falls_back_to_meta_charset_then_utf8() {
+        let decoded = decode_html(WINDOWS_1252_HTML, Some("text/html"));
+        assert!(decoded.contains("<title>Café Culture</title>"));
+        assert!(decoded.contains("“bonjour”"));
+
+        let http_equiv = decode_html(
+            b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset=iso-8859-1\">caf\xe9",
+            None,
+        );
+        assert!(http_equiv.ends_with("café"));
+
+        assert_eq!(decode_html("café".as_bytes(), None), "café");
+        assert_eq!(
+            decode_html(b"caf\xe9", Some("text/html; charset=bogus")),
+            "caf\u{FFFD}"
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_article_content_decodes_windows_1252() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cafe"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                WINDOWS_1252_HTML.to_vec(),
+                "text/html; charset=windows-1252",
+            ))
+            .mount(&server)
+            .await;
+
+        let client = mock_client(&server);
+        let article = client
+            .fetch_article_content(&format!("{}/cafe", server.uri()))
+            .await
+            .unwrap();
+
+        assert!(article.text_content.contains("naïve barista"));
+        assert!(article.text_content.contains("€3 for a café crème"));
+        assert!(!article.text_content.contains('\u{FFFD}'));
+    }
+
+    // ===== Redirect Policy Tests =====
+
+    fn urls(list: &[&str]) -> Vec<url::Url> {
+        list.iter().map(|u| url::Url::parse(u).unwrap()).collect()
+    }
+
+    #[test]
+    fn check_redirect_enforces_limit() {
+        let next = url::Url::parse("https://example.com/next").unwrap();
+
+        let two = urls(&["https://example.com/a", "https://example.com/b"]);
+        assert!(check_redirect(&two, &next, 2, false).is_ok());
+
+        let three = urls(&["https://a.com/", "https://b.com/", "https://c.com/"]);
+        assert!(matches!(
+            check_redirect(&three, &next, 2, false),
+            Err(RedirectError::TooMany(2))
+        ));
+    }
+
+    #[test]
+    fn check_redirect_refuses_https_downgrade_unless_allowed() {
+        let previous = urls(&["https://example.com/a"]);
+        let insecure = url::Url::parse("http://example.com/b").unwrap();
+
+        assert!(matches!(
+            check_redirect(&previous, &insecure, 10, false),
+            Err(RedirectError::Downgrade)
+        ));
+        assert!(check_redirect(&previous, &insecure, 10, true).is_ok());
+
+        // Upgrades and plain HTTP chains are fine
+        let http = urls(&["http://example.com/a"]);
+        assert!(check_redirect(&http, &insecure, 10, false).is_ok());
+        let secure = url::Url::parse("https://example.com/b").unwrap();
+        assert!(check_redirect(&http, &secure, 10, false).is_ok());
+    }
+
+    #[tokio::test]
+    async fn fetch_article_content_stops_redirect_loop() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/loop"))
+            .respond_with(ResponseTemplate::new(302).insert_header("Location", "/loop"))
+            // The original request plus `max_redirects` follows
+            .expect(4)
+            .mount(&server)
+            .await;
+
+        let client = HnClient::with_config(ClientConfig {
+            max_redirects: 3,
+            ..ClientConfig::default()
+        });
+        let url = format!("{}/loop", server.uri());
+        let result = client.fetch_article_content(&url).await;
+
+        match result {
+            Err(ApiError::TooManyRedirects(failed)) => assert_eq!(failed, url),
+            other => panic!("expected TooManyRedirects, got {:?}", other),
+        }
+    }
 
-        // Parse the URL for readability
-        let parsed_url = url::Url::parse(url)
-            .map_err(|e| ApiError::ArticleExtraction(format!("Invalid URL: {}", e)))?;
+    // ===== Offline Mode Tests =====
 
-        // Use readability to extract the main content
-        let mut cursor = std::io::Cursor::new(html.as_bytes());
-        let extracted = readability::extractor::extract(&mut cursor, &parsed_url)
-            .map_err(|e| ApiError::ArticleExtraction(e.to_string()))?;
+    #[tokio::test]
+    async fn cache_only_serves_cached_item() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/item/1.json", story_json(1), 1).await;
+        let client = mock_client(&server);
 
-        // Count words in the text content
-        let word_count = extracted.text.split_whitespace().count();
+        client.fetch_item(1).await.unwrap();
+        client.set_cache_only(true);
 
-        Ok(ArticleContent {
-            title: if extracted.title.is_empty() {
-                None
-            } else {
-                Some(extracted.title)
-            },
-            content: extracted.content,
-            text_content: extracted.text,
-            byline: None, // readability-rs doesn't expose byline directly
-            excerpt: None,
-            site_name: None,
-            lang: None,
-            word_count,
-        })
+        let item = client.fetch_item(1).await.unwrap();
+        assert_eq!(item.id, 1);
     }
-}
 
-impl Default for HnClient {
-    fn default() -> Self {
-        Self::new()
+    #[tokio::test]
+    async fn cache_only_miss_is_offline_without_network() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/item/2.json", story_json(2), 0).await;
+        mount_json(&server, "/topstories.json", serde_json::json!([2]), 0).await;
+        mount_json(&server, "/user/pg.json", serde_json::json!({"id": "pg"}), 0).await;
+        let client = mock_client(&server);
+        client.set_cache_only(true);
+
+        assert!(matches!(
+            client.fetch_item(2).await,
+            Err(ApiError::Offline(_))
+        ));
+        assert!(matches!(
+            client.fetch_story_ids(StoryFeed::Top).await,
+            Err(ApiError::Offline(_))
+        ));
+        assert!(matches!(
+            client.fetch_user("pg").await,
+            Err(ApiError::Offline(_))
+        ));
     }
-}
 
-/// Thread-safe shared reference to an [`HnClient`].
-///
-/// Use [`create_client`] to create an instance.
-pub type SharedHnClient = Arc<HnClient>;
+    #[tokio::test]
+    async fn leaving_cache_only_uses_network_again() {
+        let server = MockServer::start().await;
+        mount_json(&server, "/item/3.json", story_json(3), 1).await;
+        let client = mock_client(&server);
 
-/// Create a new shared HN client instance.
-///
-/// This is the primary way to create a client for use with Tauri commands.
-/// The returned `Arc<HnClient>` can be cloned and shared across threads.
-pub fn create_client() -> SharedHnClient {
-    Arc::new(HnClient::new())
-}
+        client.set_cache_only(true);
+        assert!(client.fetch_item(3).await.is_err());
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        client.set_cache_only(false);
+        assert_eq!(client.fetch_item(3).await.unwrap().id, 3);
+    }
 
-    // ===== HnClient Construction Tests =====
+    // ===== Connectivity Tests =====
 
     #[test]
-    fn hn_client_new_creates_instance() {
-        let client = HnClient::new();
-        // Verify clear_cache doesn't panic on fresh instance
-        client.clear_cache();
+    fn connectivity_tracker_reuses_recent_result() {
+        let start = Instant::now();
+        let mut tracker = ConnectivityTracker::default();
+        assert_eq!(tracker.cached(start, CONNECTIVITY_CHECK_TTL), None);
+
+        tracker.record(true, start);
+        let later = start + Duration::from_secs(4);
+        assert_eq!(tracker.cached(later, CONNECTIVITY_CHECK_TTL), Some(true));
+
+        let expired = start + CONNECTIVITY_CHECK_TTL;
+        assert_eq!(tracker.cached(expired, CONNECTIVITY_CHECK_TTL), None);
     }
 
     #[test]
-    fn hn_client_default_creates_instance() {
-        let client = HnClient::default();
-        // Verify clear_cache doesn't panic on fresh instance
-        client.clear_cache();
+    fn connectivity_tracker_reports_flips() {
+        let start = Instant::now();
+        let mut tracker = ConnectivityTracker::default();
+
+        // Assumed online before the first check
+        assert!(!tracker.record(true, start));
+        assert!(tracker.record(false, start + Duration::from_secs(10)));
+        assert!(!tracker.record(false, start + Duration::from_secs(20)));
+        assert!(tracker.record(true, start + Duration::from_secs(30)));
     }
 
     #[test]
-    fn hn_client_default_equals_new() {
-        // Both should create valid clients that behave identically
-        let client1 = HnClient::new();
-        let client2 = HnClient::default();
-        // Both should handle clear_cache without panic
-        client1.clear_cache();
-        client2.clear_cache();
+    fn connectivity_tracker_known_offline_expires() {
+        let start = Instant::now();
+        let mut tracker = ConnectivityTracker::default();
+        assert!(!tracker.known_offline(start, OFFLINE_FALLBACK_TTL));
+
+        tracker.record(false, start);
+        // Past the debounce window the result still stands
+        let debounced = start + CONNECTIVITY_CHECK_TTL;
+        assert_eq!(tracker.cached(debounced, CONNECTIVITY_CHECK_TTL), None);
+        assert!(tracker.known_offline(debounced, OFFLINE_FALLBACK_TTL));
+
+        // ...but not forever, even if nothing checks again
+        let expired = start + OFFLINE_FALLBACK_TTL;
+        assert!(!tracker.known_offline(expired, OFFLINE_FALLBACK_TTL));
+
+        tracker.record(false, expired);
+        assert!(tracker.known_offline(expired, OFFLINE_FALLBACK_TTL));
+        let back = expired + Duration::from_secs(1);
+        tracker.record(true, back);
+        assert!(!tracker.known_offline(back, OFFLINE_FALLBACK_TTL));
     }
 
-    // ===== create_client Tests =====
+    #[tokio::test]
+    async fn warm_connections_heads_both_hosts() {
+        let server = MockServer::start().await;
+        for route in ["/maxitem.json", "/search"] {
+            Mock::given(method("HEAD"))
+                .and(path(route))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
 
-    #[test]
-    fn create_client_returns_arc() {
-        let client = create_client();
-        // Verify it's wrapped in Arc by checking strong count
-        assert_eq!(Arc::strong_count(&client), 1);
+        assert_eq!(mock_client(&server).warm_connections().await, 2);
     }
 
-    #[test]
-    fn create_client_arc_can_be_cloned() {
-        let client1 = create_client();
-        let client2 = Arc::clone(&client1);
-        assert_eq!(Arc::strong_count(&client1), 2);
-        assert_eq!(Arc::strong_count(&client2), 2);
+    #[tokio::test]
+    async fn warm_connections_ignores_unreachable_hosts() {
+        // Nothing listens on the discard port
+        let client = HnClient::with_config(ClientConfig {
+            hn_base_url: "http://127.0.0.1:9".to_string(),
+            algolia_base_url: "http://127.0.0.1:9".to_string(),
+            ..ClientConfig::default()
+        });
+
+        assert_eq!(client.warm_connections().await, 0);
     }
 
-    // ===== Cache Operation Tests =====
-
-    #[test]
-    fn clear_cache_does_not_panic() {
-        let client = HnClient::new();
-        // Should not panic when clearing empty caches
-        client.clear_cache();
+    #[tokio::test]
+    async fn check_connectivity_is_debounced() {
+        let server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .and(path("/maxitem.json"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let first = client.check_connectivity().await;
+        let second = client.check_connectivity().await;
+
+        assert_eq!(
+            first,
+            Connectivity {
+                online: true,
+                changed: false
+            }
+        );
+        assert_eq!(second, first);
     }
 
     #[tokio::test]
-    async fn clear_story_ids_cache_specific_feed() {
-        let client = HnClient::new();
-        // Should not panic when clearing cache for specific feed
-        client.clear_story_ids_cache(Some(StoryFeed::Top)).await;
+    async fn unreachable_api_is_offline_and_skips_refresh() {
+        let client = HnClient::with_config(ClientConfig {
+            // Nothing listens on the discard port
+            hn_base_url: "http://127.0.0.1:9".to_string(),
+            ..ClientConfig::default()
+        });
+
+        let status = client.check_connectivity().await;
+        assert_eq!(
+            status,
+            Connectivity {
+                online: false,
+                changed: true
+            }
+        );
+
+        assert_eq!(client.background_refresh_feed(StoryFeed::Top).await, None);
+        assert!(matches!(
+            client.fetch_item(1).await,
+            Err(ApiError::Offline(_))
+        ));
+        // Nothing will re-check once forgotten, so lookups go back online
+        client.forget_connectivity().await;
+        assert!(!client.known_offline());
     }
 
-    #[tokio::test]
-    async fn clear_story_ids_cache_all_feeds() {
-        let client = HnClient::new();
-        // Should not panic when clearing all story ID caches
-        client.clear_story_ids_cache(None).await;
+    // ===== Rate Limit Tests =====
+
+    fn bucket(requests_per_second: f64, burst: u32, now: Instant) -> TokenBucket {
+        TokenBucket::new(
+            RateLimit {
+                requests_per_second,
+                burst,
+            },
+            now,
+        )
     }
 
-    // ===== fetch_comments Edge Case Tests =====
+    #[test]
+    fn token_bucket_allows_a_burst_then_spaces_requests() {
+        let start = Instant::now();
+        let mut bucket = bucket(10.0, 2, start);
+
+        assert_eq!(bucket.acquire(start), Duration::ZERO);
+        assert_eq!(bucket.acquire(start), Duration::ZERO);
+        assert_eq!(bucket.acquire(start), Duration::from_millis(100));
+        assert_eq!(bucket.acquire(start), Duration::from_millis(200));
+
+        // Queued requests have drained by now
+        assert_eq!(
+            bucket.acquire(start + Duration::from_millis(300)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn token_bucket_refills_no_further_than_burst() {
+        let start = Instant::now();
+        let mut bucket = bucket(10.0, 2, start);
+        let later = start + Duration::from_secs(10);
+
+        assert_eq!(bucket.acquire(later), Duration::ZERO);
+        assert_eq!(bucket.acquire(later), Duration::ZERO);
+        assert_eq!(bucket.acquire(later), Duration::from_millis(100));
+    }
 
     #[tokio::test]
-    async fn fetch_comments_depth_zero_returns_empty() {
-        let client = HnClient::new();
-        let item = HNItem {
-            id: 123,
-            item_type: 0,
-            by: Some("testuser".to_string()),
-            time: 1609459200,
-            text: None,
-            url: Some("https://example.com".to_string()),
-            score: 100,
-            title: Some("Test Story".to_string()),
-            descendants: 50,
-            kids: Some(vec![456, 789]),
-            parent: None,
-            dead: false,
-            deleted: false,
-        };
+    async fn rate_limit_applies_to_api_requests() {
+        let server = MockServer::start().await;
+        for id in 1..=3 {
+            let route = format!("/item/{}.json", id);
+            mount_json(&server, &route, story_json(id), 1).await;
+        }
 
-        let comments = client.fetch_comments(&item, 0).await.unwrap();
-        assert!(comments.is_empty());
+        let client = HnClient::with_config(ClientConfig {
+            hn_base_url: server.uri(),
+            rate_limit: Some(RateLimit {
+                requests_per_second: 20.0,
+                burst: 1,
+            }),
+            ..ClientConfig::default()
+        });
+
+        let start = Instant::now();
+        let items = client.fetch_items(&[1, 2, 3]).await.unwrap();
+        assert_eq!(items.len(), 3);
+        // The second and third requests wait 50ms and 100ms for tokens
+        assert!(start.elapsed() >= Duration::from_millis(100));
     }
 
-    #[tokio::test]
-    async fn fetch_comments_no_kids_returns_empty() {
+    #[test]
+    fn set_rate_limit_rejects_invalid_limits() {
         let client = HnClient::new();
-        let item = HNItem {
-            id: 123,
-            item_type: 0,
-            by: Some("testuser".to_string()),
-            time: 1609459200,
-            text: None,
-            url: Some("https://example.com".to_string()),
-            score: 100,
-            title: Some("Test Story".to_string()),
-            descendants: 0,
-            kids: None,
-            parent: None,
-            dead: false,
-            deleted: false,
+        let limit = |requests_per_second, burst| {
+            Some(RateLimit {
+                requests_per_second,
+                burst,
+            })
         };
 
-        let comments = client.fetch_comments(&item, 3).await.unwrap();
-        assert!(comments.is_empty());
+        assert!(client.set_rate_limit(limit(0.0, 1)).is_err());
+        assert!(client.set_rate_limit(limit(f64::NAN, 1)).is_err());
+        assert!(client.set_rate_limit(limit(5.0, 0)).is_err());
+        assert!(client.config().rate_limit.is_none());
+
+        client.set_rate_limit(limit(5.0, 10)).unwrap();
+        assert_eq!(client.config().rate_limit, limit(5.0, 10));
+        client.set_rate_limit(None).unwrap();
+        assert!(client.rate_limiter.lock().unwrap().is_none());
     }
 
+    // ===== Forced Item Refresh Tests =====
+
     #[tokio::test]
-    async fn fetch_comments_empty_kids_returns_empty() {
-        let client = HnClient::new();
-        let item = HNItem {
-            id: 123,
-            item_type: 0,
-            by: Some("testuser".to_string()),
-            time: 1609459200,
-            text: None,
-            url: Some("https://example.com".to_string()),
-            score: 100,
-            title: Some("Test Story".to_string()),
-            descendants: 0,
-            kids: Some(vec![]), // empty kids array
-            parent: None,
-            dead: false,
-            deleted: false,
-        };
+    async fn fetch_item_fresh_refetches_cached_item() {
+        let server = MockServer::start().await;
+        let client = mock_client(&server);
+
+        let mut stale = story_json(7);
+        stale["score"] = serde_json::json!(10);
+        Mock::given(method("GET"))
+            .and(path("/item/7.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(stale))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        assert_eq!(client.fetch_item(7).await.unwrap().score, 10);
+
+        let mut fresh = story_json(7);
+        fresh["score"] = serde_json::json!(250);
+        mount_json(&server, "/item/7.json", fresh, 1).await;
+
+        // A plain fetch still serves the cached copy
+        assert_eq!(client.fetch_item(7).await.unwrap().score, 10);
+
+        assert_eq!(client.fetch_item_fresh(7).await.unwrap().score, 250);
+        // ...and the cache now holds the refreshed item
+        assert_eq!(client.fetch_item(7).await.unwrap().score, 250);
+    }
 
-        let comments = client.fetch_comments(&item, 3).await.unwrap();
-        assert!(comments.is_empty());
+    #[tokio::test]
+    async fn fetch_item_fresh_reports_rate_limiting() {
+        let server = MockServer::start().await;
+        let client = HnClient::with_config(ClientConfig {
+            hn_base_url: server.uri(),
+            max_retries: 0,
+            ..ClientConfig::default()
+        });
+        Mock::given(method("GET"))
+            .and(path("/item/8.json"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "30"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        assert!(matches!(
+            client.fetch_item_fresh(8).await,
+            Err(ApiError::RateLimited(30))
+        ));
     }
 
-    // ===== StoryFeed Cache Key Tests =====
+    // ===== Retry Tests =====
 
     #[test]
-    fn story_feed_is_hashable_for_cache() {
-        use std::collections::HashMap;
-        let mut map: HashMap<StoryFeed, Vec<u32>> = HashMap::new();
+    fn retry_backoff_doubles_from_one_second() {
+        assert_eq!(retry_backoff(1), Duration::from_secs(1));
+        assert_eq!(retry_backoff(2), Duration::from_secs(2));
+        assert_eq!(retry_backoff(4), Duration::from_secs(8));
+        // Capped rather than overflowing
+        assert_eq!(retry_backoff(100), Duration::from_secs(1 << 16));
+    }
 
-        map.insert(StoryFeed::Top, vec![1, 2, 3]);
-        map.insert(StoryFeed::New, vec![4, 5, 6]);
-        map.insert(StoryFeed::Best, vec![7, 8, 9]);
-        map.insert(StoryFeed::Ask, vec![10, 11, 12]);
-        map.insert(StoryFeed::Show, vec![13, 14, 15]);
-        map.insert(StoryFeed::Jobs, vec![16, 17, 18]);
+    #[test]
+    fn only_rate_limits_and_server_errors_are_retried() {
+        assert!(is_retryable(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable(reqwest::StatusCode::NOT_FOUND));
+        assert!(!is_retryable(reqwest::StatusCode::OK));
+    }
 
-        assert_eq!(map.len(), 6);
-        assert_eq!(map.get(&StoryFeed::Top), Some(&vec![1, 2, 3]));
-        assert_eq!(map.get(&StoryFeed::Jobs), Some(&vec![16, 17, 18]));
+    /// Client for `server` that retries without waiting.
+    fn retrying_client(server: &MockServer, max_retries: u32) -> HnClient {
+        HnClient::with_config(ClientConfig {
+            hn_base_url: server.uri(),
+            max_retries,
+            max_retry_wait: Duration::ZERO,
+            ..ClientConfig::default()
+        })
     }
 
-    // ===== Constants Tests =====
+    #[tokio::test]
+    async fn api_requests_retry_server_errors() {
+        let server = MockServer::start().await;
+        let client = retrying_client(&server, 2);
+        Mock::given(method("GET"))
+            .and(path("/item/8.json"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        mount_json(&server, "/item/8.json", story_json(8), 1).await;
+
+        assert_eq!(client.fetch_item_fresh(8).await.unwrap().id, 8);
+    }
 
-    #[test]
-    fn cache_ttl_constants_are_reasonable() {
-        // Item cache: 5 minutes
-        assert_eq!(ITEM_CACHE_TTL.as_secs(), 5 * 60);
+    #[tokio::test]
+    async fn api_requests_are_not_retried_with_retries_disabled() {
+        let server = MockServer::start().await;
+        let client = retrying_client(&server, 0);
+        mount_status(&server, "/item/8.json", 503, 1).await;
 
-        // Story IDs cache: 2 minutes (shorter for fresher feeds)
-        assert_eq!(STORY_IDS_CACHE_TTL.as_secs(), 2 * 60);
+        assert!(client.fetch_item_fresh(8).await.is_err());
+    }
 
-        // User cache: 10 minutes (user data changes less frequently)
-        assert_eq!(USER_CACHE_TTL.as_secs(), 10 * 60);
+    #[tokio::test]
+    async fn api_requests_retry_after_rate_limiting() {
+        let server = MockServer::start().await;
+        let client = mock_client(&server);
+        Mock::given(method("GET"))
+            .and(path("/item/8.json"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        mount_json(&server, "/item/8.json", story_json(8), 1).await;
+
+        assert_eq!(client.fetch_item_fresh(8).await.unwrap().id, 8);
     }
 
-    #[test]
-    fn hn_base_url_is_correct() {
-        assert_eq!(HN_BASE_URL, "https://hacker-news.firebaseio.com/v0");
+    #[tokio::test]
+    async fn api_requests_give_up_with_retry_metadata() {
+        let server = MockServer::start().await;
+        let client = mock_client(&server);
+        Mock::given(method("GET"))
+            .and(path("/item/8.json"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .expect(u64::from(DEFAULT_MAX_RETRIES) + 1)
+            .mount(&server)
+            .await;
+
+        assert!(matches!(
+            client.fetch_item_fresh(8).await,
+            Err(ApiError::RetriesExhausted {
+                attempts: DEFAULT_MAX_RETRIES,
+                retry_after: 0,
+            })
+        ));
     }
 
-    #[test]
-    fn algolia_base_url_is_correct() {
-        assert_eq!(ALGOLIA_BASE_URL, "https://hn.algolia.com/api/v1");
+    #[tokio::test]
+    async fn api_requests_stop_retrying_past_the_longest_wait() {
+        let server = MockServer::start().await;
+        let client = mock_client(&server);
+        Mock::given(method("GET"))
+            .and(path("/item/8.json"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "3600"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // Not worth waiting an hour for: reported right away
+        assert!(matches!(
+            client.fetch_item_fresh(8).await,
+            Err(ApiError::RateLimited(3600))
+        ));
     }
 
-    // ===== Stale Threshold Constant Test =====
+    // ===== New Comments Tests =====
 
-    #[test]
-    fn stale_threshold_is_75_percent() {
-        assert_eq!(STALE_THRESHOLD_PERCENT, 75);
+    #[tokio::test]
+    async fn new_comments_since_uses_current_story() {
+        let server = MockServer::start().await;
+        let client = mock_client(&server);
+
+        let mut story = story_json(7);
+        story["descendants"] = serde_json::json!(12);
+        mount_json(&server, "/item/7.json", story, 2).await;
+
+        assert_eq!(client.new_comments_since(7, 9).await.unwrap(), 3);
+        // Comments were deleted since the last visit
+        assert_eq!(client.new_comments_since(7, 15).await.unwrap(), 0);
     }
 
-    // ===== RefreshTracker Tests =====
+    // ===== Search Error Tests =====
 
     #[test]
-    fn refresh_tracker_new_creates_empty() {
-        let tracker = RefreshTracker::new();
-        assert!(tracker.last_fetch.is_empty());
-        assert!(tracker.refreshing.is_empty());
+    fn truncate_query_keeps_short_queries() {
+        assert_eq!(truncate_query("rust async", 512), "rust async");
     }
 
     #[test]
-    fn refresh_tracker_mark_fetched_records_time() {
-        let mut tracker = RefreshTracker::new();
-        tracker.mark_fetched(StoryFeed::Top);
-        assert!(tracker.last_fetch.contains_key(&StoryFeed::Top));
+    fn truncate_query_cuts_at_a_word_boundary() {
+        assert_eq!(truncate_query("alpha beta gamma", 13), "alpha beta");
+        // No space in the second half: cut mid-word rather than lose half
+        assert_eq!(truncate_query("alpha betagamma", 12), "alpha betaga");
     }
 
     #[test]
-    fn refresh_tracker_mark_fetched_clears_refreshing() {
-        let mut tracker = RefreshTracker::new();
-        tracker.start_refresh(StoryFeed::Top);
-        assert!(tracker.is_refreshing(&StoryFeed::Top));
+    fn truncate_query_respects_char_boundaries() {
+        let query = "é".repeat(300);
+        let cut = truncate_query(&query, MAX_SEARCH_QUERY_LEN);
+        assert!(cut.len() <= MAX_SEARCH_QUERY_LEN);
+        assert_eq!(cut.chars().count(), 256);
+    }
 
-        tracker.mark_fetched(StoryFeed::Top);
-        assert!(!tracker.is_refreshing(&StoryFeed::Top));
+    #[tokio::test]
+    async fn search_maps_query_too_long_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "message": "Query is too long",
+                "status": 400
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let result = client
+            .search("rust", 0, 20, SearchSort::Relevance, SearchFilter::All)
+            .await;
+
+        match result {
+            Err(ApiError::Api(message)) => assert!(message.contains("too long")),
+            other => panic!("expected a too-long error, got {:?}", other),
+        }
     }
 
-    #[test]
-    fn refresh_tracker_is_stale_false_for_fresh_data() {
-        let mut tracker = RefreshTracker::new();
-        tracker.mark_fetched(StoryFeed::Top);
-        // Just fetched, should not be stale
-        assert!(!tracker.is_stale(&StoryFeed::Top, Duration::from_secs(120)));
+    #[tokio::test]
+    async fn search_reports_other_algolia_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search_by_date"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "message": "Invalid value for parameter page",
+                "status": 400
+            })))
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let result = client
+            .search("rust", 0, 20, SearchSort::Date, SearchFilter::Story)
+            .await;
+
+        match result {
+            Err(ApiError::Api(message)) => {
+                assert_eq!(
+                    message,
+                    "invalid search query: Invalid value for parameter page"
+                )
+            }
+            other => panic!("expected an invalid-query error, got {:?}", other),
+        }
     }
 
-    #[test]
-    fn refresh_tracker_is_stale_false_for_unknown_feed() {
-        let tracker = RefreshTracker::new();
-        // Never fetched, should not be considered stale (will be fetched fresh)
-        assert!(!tracker.is_stale(&StoryFeed::Top, Duration::from_secs(120)));
+    #[tokio::test]
+    async fn search_trims_overlong_queries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .and(wiremock::matchers::query_param(
+                "query",
+                "word ".repeat(102).trim_end(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "hits": [],
+                "nbHits": 0,
+                "page": 0,
+                "nbPages": 0,
+                "hitsPerPage": 20,
+                "query": "word"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let result = client
+            .search(
+                &"word ".repeat(200),
+                0,
+                20,
+                SearchSort::Relevance,
+                SearchFilter::All,
+            )
+            .await;
+
+        assert!(result.is_ok());
     }
 
     #[test]
-    fn refresh_tracker_start_refresh_sets_flag() {
-        let mut tracker = RefreshTracker::new();
-        assert!(!tracker.is_refreshing(&StoryFeed::Top));
-        tracker.start_refresh(StoryFeed::Top);
-        assert!(tracker.is_refreshing(&StoryFeed::Top));
+    fn clamp_hits_per_page_defaults_zero_and_caps_large_requests() {
+        assert_eq!(clamp_hits_per_page(0, 20, 100), 20);
+        assert_eq!(clamp_hits_per_page(50, 20, 100), 50);
+        assert_eq!(clamp_hits_per_page(101, 20, 100), 100);
+
+        // Algolia's own cap wins over a larger configured max
+        assert_eq!(clamp_hits_per_page(1000, 20, 5000), 1000);
+        assert_eq!(clamp_hits_per_page(1001, 20, 5000), 1000);
+        assert_eq!(clamp_hits_per_page(u32::MAX, 20, 5000), 1000);
     }
 
-    #[test]
-    fn refresh_tracker_independent_feeds() {
-        let mut tracker = RefreshTracker::new();
-        tracker.mark_fetched(StoryFeed::Top);
-        tracker.start_refresh(StoryFeed::New);
+    #[tokio::test]
+    async fn search_sends_and_reports_effective_page_size() {
+        use wiremock::matchers::query_param;
+
+        let server = MockServer::start().await;
+        for hits_per_page in ["1000", "20"] {
+            Mock::given(method("GET"))
+                .and(path("/search"))
+                .and(query_param("hitsPerPage", hits_per_page))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "hits": [],
+                    "nbHits": 0,
+                    "page": 0,
+                    "nbPages": 0,
+                    "hitsPerPage": 0,
+                    "query": "rust"
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+        let client = HnClient::with_config(ClientConfig {
+            algolia_base_url: server.uri(),
+            max_hits_per_page: ALGOLIA_MAX_HITS_PER_PAGE,
+            ..ClientConfig::default()
+        });
+        let search = |hits_per_page| {
+            client.search(
+                "rust",
+                0,
+                hits_per_page,
+                SearchSort::Relevance,
+                SearchFilter::All,
+            )
+        };
 
-        assert!(tracker.last_fetch.contains_key(&StoryFeed::Top));
-        assert!(!tracker.last_fetch.contains_key(&StoryFeed::New));
-        assert!(tracker.is_refreshing(&StoryFeed::New));
-        assert!(!tracker.is_refreshing(&StoryFeed::Top));
+        assert_eq!(search(5000).await.unwrap().hits_per_page, 1000);
+        assert_eq!(search(0).await.unwrap().hits_per_page, 20);
     }
 
-    // ===== HnClient Background Refresh Tests =====
+    // ===== User Comment Search Tests =====
+
+    #[test]
+    fn search_tags_combine_type_and_author() {
+        assert_eq!(search_tags(SearchFilter::All, None), None);
+        assert_eq!(
+            search_tags(SearchFilter::Story, None).as_deref(),
+            Some("story")
+        );
+        assert_eq!(
+            search_tags(SearchFilter::Comment, Some("pg")).as_deref(),
+            Some("comment,author_pg")
+        );
+        assert_eq!(
+            search_tags(SearchFilter::All, Some("dang")).as_deref(),
+            Some("author_dang")
+        );
+    }
 
     #[tokio::test]
-    async fn is_feed_stale_false_initially() {
-        let client = HnClient::new();
-        // No data cached yet, shouldn't be considered stale
-        assert!(!client.is_feed_stale(&StoryFeed::Top).await);
+    async fn search_user_comments_queries_by_date_with_author_tag() {
+        use wiremock::matchers::query_param;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search_by_date"))
+            .and(query_param("tags", "comment,author_pg"))
+            .and(query_param("page", "2"))
+            .and(query_param(
+                "hitsPerPage",
+                USER_COMMENTS_PER_PAGE.to_string(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "hits": [{
+                    "objectID": "42",
+                    "author": "pg",
+                    "comment_text": "A comment",
+                    "created_at_i": 1609459200,
+                    "story_id": 1,
+                    "_tags": ["comment", "author_pg", "story_1"]
+                }],
+                "nbHits": 61,
+                "page": 2,
+                "nbPages": 3,
+                "hitsPerPage": USER_COMMENTS_PER_PAGE,
+                "query": ""
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = mock_client(&server);
+
+        let response = client.search_user_comments("pg", 2).await.unwrap();
+
+        assert_eq!(response.page, 2);
+        assert_eq!(response.nb_pages, 3);
+        assert_eq!(response.hits.len(), 1);
+        assert_eq!(response.hits[0].id, 42);
+        assert_eq!(response.hits[0].result_type, "comment");
+        assert_eq!(response.hits[0].author.as_deref(), Some("pg"));
     }
 }
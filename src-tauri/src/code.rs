@@ -0,0 +1,244 @@
+//! Code block extraction for reader mode and TTS.
+//!
+//! [`extract_code_blocks`] finds the `<pre>` blocks of an extracted article
+//! and works out their language, so the reader can syntax-highlight them and
+//! text-to-speech can skip them. The language comes from, in order:
+//!
+//! 1. A `language-*` / `lang-*` / `highlight-source-*` class on the `<code>`
+//!    or `<pre>` element
+//! 2. A Markdown fence (` ```python `) left in the rendered block
+//! 3. Sniffing the code itself for telltale syntax
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::html;
+use crate::types::CodeBlock;
+
+static PRE_BLOCK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?is)<pre\b([^>]*)>(?:\s*<code\b([^>]*)>)?(.*?)(?:</code>\s*)?</pre\s*>").unwrap()
+});
+static CLASS_ATTR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\bclass\s*=\s*["']([^"']*)["']"#).unwrap());
+static LANGUAGE_CLASS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^(?:language|lang|highlight-source)-([a-z0-9_+#-]+)$").unwrap());
+static FENCE_OPEN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^```([A-Za-z0-9_+#-]*)[ \t]*\n").unwrap());
+static FENCE_CLOSE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n```\s*$").unwrap());
+
+/// Syntax signatures used when a block carries no language hint.
+///
+/// Checked in order; the first match wins, so more specific languages come
+/// before the ones they resemble (TypeScript before JavaScript).
+static SIGNATURES: Lazy<Vec<(&'static str, Regex)>> = Lazy::new(|| {
+    [
+        ("bash", r"^#!.*\b(?:ba|z)?sh\b"),
+        ("python", r"^#!.*\bpython"),
+        (
+            "rust",
+            r"\bfn \w+\s*(?:<[^>]*>)?\(|\blet mut\b|\bimpl\b.*\{|\buse \w+::",
+        ),
+        (
+            "go",
+            r"(?m)^package \w+$|\bfunc (?:\([^)]*\) )?\w+\(|\w+ := ",
+        ),
+        (
+            "python",
+            r"(?m)^\s*(?:def \w+\(.*\):|class \w+(?:\(.*\))?:|from \w+(?:\.\w+)* import )",
+        ),
+        ("c", r"(?m)^#include\s*[<\x22]"),
+        (
+            "typescript",
+            r"\binterface \w+\s*\{|:\s*(?:string|number|boolean)\b",
+        ),
+        (
+            "javascript",
+            r"\b(?:const|let|var) \w+ = |\bfunction\s*\w*\(|=>|console\.log\(",
+        ),
+        (
+            "sql",
+            r"(?i)^\s*(?:select\b.*\bfrom\b|insert into\b|create table\b|update \w+ set\b)",
+        ),
+        ("html", r"(?i)^\s*<(?:!doctype|html|div|head|body)\b"),
+        (
+            "bash",
+            r"(?m)^\s*(?:\$ |sudo |apt(?:-get)? |brew |npm |pip |cargo |git |curl )",
+        ),
+    ]
+    .into_iter()
+    .map(|(lang, pattern)| (lang, Regex::new(pattern).unwrap()))
+    .collect()
+});
+
+/// Canonical name for a language hint (`rs` -> `rust`, `sh` -> `bash`, ...).
+fn normalize_language(hint: &str) -> Option<String> {
+    let hint = hint.trim().to_ascii_lowercase();
+    let canonical = match hint.as_str() {
+        "" | "text" | "plaintext" | "plain" | "none" | "nohighlight" => return None,
+        "rs" => "rust",
+        "py" | "python3" => "python",
+        "js" | "jsx" | "node" => "javascript",
+        "ts" | "tsx" => "typescript",
+        "sh" | "shell" | "zsh" | "console" | "shell-session" => "bash",
+        "golang" => "go",
+        "yml" => "yaml",
+        "c++" | "cc" | "hpp" => "cpp",
+        "cs" => "csharp",
+        "rb" => "ruby",
+        "md" => "markdown",
+        other => other,
+    };
+    Some(canonical.to_string())
+}
+
+/// Language named by a `class` attribute, if any.
+fn class_language(attrs: &str) -> Option<String> {
+    let classes = CLASS_ATTR.captures(attrs)?;
+    classes[1]
+        .split_whitespace()
+        .find_map(|class| LANGUAGE_CLASS.captures(class))
+        .and_then(|caps| normalize_language(&caps[1]))
+}
+
+/// Guess the language of unlabeled code from its syntax.
+fn sniff_language(code: &str) -> Option<String> {
+    let trimmed = code.trim();
+    if (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(trimmed).is_ok()
+    {
+        return Some("json".to_string());
+    }
+
+    SIGNATURES
+        .iter()
+        .find(|(_, re)| re.is_match(trimmed))
+        .map(|(lang, _)| (*lang).to_string())
+}
+
+/// Collect the code blocks of an article's HTML content, in document order.
+///
+/// Block content is plain text: highlighter markup is stripped, entities are
+/// decoded, and `<br>` becomes a newline. Indentation is preserved. Empty
+/// blocks are skipped.
+pub fn extract_code_blocks(content: &str) -> Vec<CodeBlock> {
+    PRE_BLOCK
+        .captures_iter(content)
+        .filter_map(|caps| {
+            let class_hint = caps
+                .get(2)
+                .and_then(|code_attrs| class_language(code_attrs.as_str()))
+                .or_else(|| class_language(&caps[1]));
+
+            let mut text = html::strip_tags(&caps[3]);
+            let mut language = class_hint;
+
+            if let Some(fence) = FENCE_OPEN.captures(&text) {
+                let fence_language = normalize_language(&fence[1]);
+                let body = text[fence[0].len()..].to_string();
+                text = FENCE_CLOSE.replace(&body, "").into_owned();
+                language = language.or(fence_language);
+            }
+
+            let text = text.trim_matches('\n').trim_end().to_string();
+            if text.trim().is_empty() {
+                return None;
+            }
+
+            let language = language.or_else(|| sniff_language(&text));
+            Some(CodeBlock {
+                language,
+                content: text,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/code_blocks.html");
+
+    fn languages(blocks: &[CodeBlock]) -> Vec<Option<&str>> {
+        blocks.iter().map(|b| b.language.as_deref()).collect()
+    }
+
+    #[test]
+    fn extracts_classed_fenced_and_sniffed_blocks_in_order() {
+        let blocks = extract_code_blocks(FIXTURE);
+
+        assert_eq!(
+            languages(&blocks),
+            vec![
+                Some("bash"),
+                Some("rust"),
+                Some("python"),
+                Some("go"),
+                Some("json"),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn strips_highlighter_markup_and_decodes_entities() {
+        let blocks = extract_code_blocks(FIXTURE);
+
+        assert_eq!(blocks[0].content, "cargo install pastel-hn");
+        assert_eq!(
+            blocks[1].content,
+            "fn main() {\n    let greeting = \"hello\";\n    println!(\"{} <world>\", greeting);\n}"
+        );
+        assert_eq!(
+            blocks[5].content,
+            "Just some preformatted prose,\nnot really code."
+        );
+    }
+
+    #[test]
+    fn removes_markdown_fences() {
+        let blocks = extract_code_blocks(FIXTURE);
+        assert_eq!(
+            blocks[2].content,
+            "def greet(name):\n    return f\"hi {name}\""
+        );
+    }
+
+    #[test]
+    fn class_hint_wins_over_fence_and_sniffing() {
+        let blocks =
+            extract_code_blocks("<pre class=\"lang-js\">```python\nconst x = 1\n```</pre>");
+        assert_eq!(languages(&blocks), vec![Some("javascript")]);
+    }
+
+    #[test]
+    fn sniffs_common_languages() {
+        assert_eq!(
+            sniff_language("#!/usr/bin/env bash\necho hi").as_deref(),
+            Some("bash")
+        );
+        assert_eq!(
+            sniff_language("#include <stdio.h>\nint main() {}").as_deref(),
+            Some("c")
+        );
+        assert_eq!(
+            sniff_language("interface User {\n  name: string\n}").as_deref(),
+            Some("typescript")
+        );
+        assert_eq!(
+            sniff_language("const add = (a, b) => a + b;").as_deref(),
+            Some("javascript")
+        );
+        assert_eq!(
+            sniff_language("SELECT id, title FROM stories WHERE score > 100;").as_deref(),
+            Some("sql")
+        );
+        assert_eq!(sniff_language("$ npm install").as_deref(), Some("bash"));
+        assert_eq!(sniff_language("hello world"), None);
+    }
+
+    #[test]
+    fn skips_empty_blocks() {
+        assert!(extract_code_blocks("<pre><code>  \n </code></pre>").is_empty());
+    }
+}
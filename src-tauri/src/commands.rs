@@ -8,14 +8,58 @@
 //! | Command | Description |
 //! |---------|-------------|
 //! | [`fetch_stories`] | Paginated stories for a feed (top/new/best/ask/show/jobs) |
-//! | [`fetch_item`] | Single item by ID |
+//! | [`fetch_stories_ranked`] | A feed page re-ranked by a client-side formula |
+//! | [`fetch_merged`] | Paginated stories from several feeds blended together |
+//! | [`prefetch_stories`] | Warm the cache with the next page of a feed |
+//! | [`top_domains`] | Most-linked sites among a feed's top stories |
+//! | [`warm_feed`] | Warm the cache with a feed's first items (on startup) |
+//! | [`warm_connections`] | Prime DNS and the connection pool for the API hosts |
+//! | [`fetch_item`] | Single item by ID (optionally bypassing the cache) |
 //! | [`fetch_items`] | Multiple items by ID (batch) |
+//! | [`fetch_items_detailed`] | Batch fetch with per-item errors |
+//! | [`fetch_poll`] | Poll with vote tallies and percentages |
 //! | [`fetch_story_with_comments`] | Story with nested comment tree |
+//! | [`fetch_stories_with_comments`] | Several stories with budgeted comment trees (batch) |
 //! | [`fetch_comment_children`] | Load more comments for a thread |
+//! | [`prefetch_comments`] | Warm the cache with shallow comment trees for on-screen stories |
+//! | [`fetch_comment_context`] | A comment with its parent chain up to the story |
+//! | [`new_comments_since`] | Comments posted on a story since it was last seen |
+//! | [`mark_comments_seen`] | Remember a story's comment count as seen |
 //! | [`fetch_user`] | User profile |
 //! | [`fetch_user_submissions`] | User's submissions with filtering |
-//! | [`search_hn`] | Full-text search via Algolia |
+//! | [`fetch_user_favorites`] | User's favorite stories |
+//! | [`fetch_user_comments_search`] | User's comments via Algolia, newest first |
+//! | [`search_hn`] | Full-text search via Algolia (recorded in search history) |
+//! | [`get_search_history`] | Recent searches, most recent first |
+//! | [`clear_search_history`] | Forget all recent searches |
 //! | [`fetch_article_content`] | Extract readable content from URL |
+//! | [`fetch_reader_content`] | Reader-mode content for a story, including self-posts |
+//! | [`set_max_article_bytes`] | Set the largest article download accepted |
+//! | [`set_exclude_same_host_links`] | Leave links to the article's own site out of its links |
+//! | [`prefetch_articles`] | Warm the article cache for a set of stories |
+//! | [`fetch_front_page_digest`] | Top stories with article excerpts and reading times |
+//! | [`fetch_favicon`] | Site favicon as a `data:` URL (disk-cached) |
+//! | [`export_feed_rss`] | Render a feed as an RSS 2.0 document |
+//! | [`format_story_markdown`] | Shareable Markdown blurb for a story |
+//! | [`parse_hiring_thread`] | Structured job postings from a "Who is hiring?" thread |
+//!
+//! # Backup Commands
+//!
+//! | Command | Description |
+//! |---------|-------------|
+//! | [`export_bookmarks_json`] | Write bookmarks to a backup file |
+//! | [`export_reading_history_json`] | Write read history to a backup file |
+//! | [`import_bookmarks_json`] | Merge bookmarks from a backup file |
+//!
+//! # Reading Stats Commands
+//!
+//! | Command | Description |
+//! |---------|-------------|
+//! | [`record_article_read`] | Count an article read in reader mode |
+//! | [`record_story_opened`] | Count an opened story |
+//! | [`record_session`] | Add a reading session's duration |
+//! | [`get_reading_stats`] | Totals, daily buckets, and current streak |
+//! | [`reset_reading_stats`] | Forget all reading activity |
 //!
 //! # Cache Commands
 //!
@@ -24,8 +68,15 @@
 //! | [`clear_cache`] | Clear all caches |
 //! | [`clear_story_ids_cache`] | Clear feed cache (specific or all) |
 //! | [`get_cache_stats`] | Get cache statistics |
+//! | [`save_cache_snapshot`] | Write the caches to a file, with their expiry times |
+//! | [`load_cache_snapshot`] | Restore unexpired entries from a cache snapshot |
+//! | [`set_cache_eviction_events`] | Enable/disable `cache-evicted` events (debugging) |
 //! | [`is_feed_stale`] | Check if feed needs refresh |
+//! | [`get_feed_ages`] | When each feed was last fetched, for freshness labels |
+//! | [`set_stale_threshold`] | Configure the stale threshold percentage |
 //! | [`background_refresh_feed`] | Trigger background refresh |
+//! | [`feed_position_deltas`] | Rank changes from a feed's last refresh |
+//! | [`set_auto_refresh`] | Enable/disable timer-driven refresh of all feeds |
 //!
 //! # Copilot AI Commands
 //!
@@ -33,11 +84,18 @@
 //! |---------|-------------|
 //! | [`copilot_check`] | Check if Copilot is available |
 //! | [`copilot_init`] | Initialize the Copilot service |
+//! | [`copilot_ping`] | Measure assistant round-trip latency |
 //! | [`copilot_summarize`] | Summarize an article |
 //! | [`copilot_analyze_discussion`] | Analyze a discussion thread |
 //! | [`copilot_explain`] | Explain a term/concept |
 //! | [`copilot_draft_reply`] | Help draft a reply |
 //! | [`copilot_ask`] | General question |
+//! | [`copilot_set_output_language`] | Set the default response language |
+//! | [`copilot_set_transcript_logging`] | Enable/disable the prompt transcript |
+//! | [`copilot_get_transcript_path`] | Get the transcript file path |
+//! | [`copilot_clear_transcript`] | Delete the transcript |
+//! | [`copilot_set_queue_depth`] | Limit queued Copilot requests |
+//! | [`copilot_set_max_retries`] | Set retries for transient Copilot errors |
 //! | [`copilot_shutdown`] | Shutdown Copilot service |
 //!
 //! # TTS (Text-to-Speech) Commands
@@ -47,29 +105,69 @@
 //! | [`tts_init`] | Initialize the TTS engine |
 //! | [`tts_status`] | Get TTS status and capabilities |
 //! | [`tts_speak`] | Speak text aloud |
+//! | [`tts_speak_sentences`] | Speak sentences with progress events |
+//! | [`speak_article`] | Speak text with a voice in its language |
 //! | [`tts_stop`] | Stop current speech |
 //! | [`tts_get_voices`] | List available voices |
+//! | [`list_all_voices`] | List native and neural voices together |
 //! | [`tts_set_voice`] | Set the active voice |
 //! | [`tts_set_rate`] | Set speech rate |
+//! | [`tts_voice_settings`] | Get the saved voices and rates |
 //!
 //! # Utility Commands
 //!
 //! | Command | Description |
 //! |---------|-------------|
-//! | [`open_external`] | Open URL in system browser |
+//! | [`open_external`] | Open URL in system browser (tracking params stripped) |
+//! | [`open_story_window`] | Open a story in its own window |
+//! | [`dispatch_launch_deep_links`] | Route the deep links the app was opened with |
+//! | [`set_strip_tracking_params`] | Enable/disable tracking param stripping |
+//! | [`set_offline_mode`] | Serve items, feeds, and users from cache only |
+//! | [`set_rate_limit`] | Cap the rate of HN and Algolia requests |
+//! | [`get_global_shortcuts`] | Get the global shortcut accelerators |
+//! | [`get_default_global_shortcuts`] | Get the default global shortcut accelerators |
+//! | [`set_global_shortcuts`] | Change the global shortcut accelerators |
+//! | [`get_settings`] | Get the persisted app settings |
+//! | [`set_default_comment_depth`] | Set the default comment depth (global or per feed) |
+//! | [`is_online`] | Check whether the HN API is reachable |
+//! | [`open_on_hn`] | Open an item's HN discussion in the browser |
+//! | [`hn_item_url`] | HN discussion URL for an item |
+//! | [`hn_user_url`] | HN profile URL for a user |
+//! | [`comment_permalink`] | Permalink to a comment |
+//! | [`primary_link`] | Link to open for an item (URL, or Show HN text link) |
 //! | [`get_app_version`] | Get the app version |
 
-use tauri::State;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use crate::client::SharedHnClient;
+use tauri::{Emitter, State};
+
+use crate::auto_refresh::{self, AutoRefresh};
+use crate::backup::{self, BookmarkEntry, ReadEntry};
+use crate::client::{favicon_data_url, RateLimit, SharedHnClient, MAX_COMMENT_CONTEXT_DEPTH};
 use crate::copilot::{
-    self, AssistantResponse, CopilotStatus, DiscussionContext, ReplyContext, StoryContext,
+    self, AssistantResponse, CopilotStatus, DiscussionContext, PingResult, ReplyContext,
+    StoryContext,
 };
-use crate::tts::{self, TtsStatus, VoiceInfo};
+use crate::deep_link;
+use crate::export;
+use crate::hiring::JobPosting;
+use crate::links;
+use crate::reading_stats::{ReadingStats, ReadingStatsStore};
+use crate::search_history::{SearchHistory, SearchHistoryEntry};
+use crate::seen_comments::SeenComments;
+use crate::settings::{Settings, SettingsStore, VoiceSettings};
+use crate::shortcuts::{GlobalShortcuts, ShortcutConfig};
+use crate::story_windows;
+use crate::tray;
+use crate::tts::neural::pronunciation::{PronunciationEntry, PronunciationStore};
+use crate::tts::{self, TtsStatus, UnifiedVoice, VoiceInfo};
 use crate::types::{
-    ApiError, ArticleContent, CacheStats, CommentWithChildren, HNItem, HNUser, SearchFilter,
-    SearchResponse, SearchSort, StoriesResponse, StoryFeed, StoryWithComments, SubmissionFilter,
-    SubmissionsResponse,
+    ApiError, ArticleContent, BatchResult, CacheSnapshotCounts, CacheStats,
+    CommentChildrenResponse, CommentContext, CommentSort, DigestEntry, DomainCount, FeedAge,
+    HNItem, HNUser, PollWithOptions, PositionDelta, RankFormula, RankedStoriesResponse,
+    SearchFilter, SearchResponse, SearchSort, StoriesResponse, StoryFeed, StoryWithComments,
+    SubmissionFilter, SubmissionsResponse,
 };
 
 /// Fetch paginated stories for a feed.
@@ -79,26 +177,287 @@ use crate::types::{
 /// * `feed` - Feed type: "top", "new", "best", "ask", "show", "jobs"
 /// * `offset` - Starting index (0-based)
 /// * `limit` - Maximum stories to return
+/// * `exclude_dead` - Skip dead/deleted stories and backfill (defaults to false)
 ///
 /// # Returns
 ///
-/// [`StoriesResponse`] with stories array, `has_more` flag, and `total` count.
+/// [`StoriesResponse`] with stories array, `has_more` flag, `total` count,
+/// and the `next_offset` to request the following page with.
 #[tauri::command]
 pub async fn fetch_stories(
     client: State<'_, SharedHnClient>,
     feed: StoryFeed,
     offset: usize,
     limit: usize,
+    exclude_dead: Option<bool>,
 ) -> Result<StoriesResponse, ApiError> {
-    client.fetch_stories_paginated(feed, offset, limit).await
+    client
+        .fetch_stories_paginated(feed, offset, limit, exclude_dead.unwrap_or(false))
+        .await
+}
+
+/// Fetch a page of a feed re-ordered by a client-side ranking formula.
+///
+/// # Arguments
+///
+/// * `feed` - Feed type: "top", "new", "best", "ask", "show", "jobs"
+/// * `offset` - Starting index (0-based)
+/// * `limit` - Maximum stories to return
+/// * `formula` - `{ type: "gravity", g }`, `{ type: "scorePerHour" }`, or
+///   `{ type: "commentsPerHour" }`
+/// * `exclude_dead` - Skip dead/deleted stories and backfill (defaults to false)
+///
+/// # Returns
+///
+/// The page as from [`fetch_stories`], with `stories` ranked and the feed's
+/// original order in `feed_order`.
+#[tauri::command]
+pub async fn fetch_stories_ranked(
+    client: State<'_, SharedHnClient>,
+    feed: StoryFeed,
+    offset: usize,
+    limit: usize,
+    formula: RankFormula,
+    exclude_dead: Option<bool>,
+) -> Result<RankedStoriesResponse, ApiError> {
+    client
+        .fetch_stories_ranked(feed, offset, limit, exclude_dead.unwrap_or(false), formula)
+        .await
+}
+
+/// Render the first stories of a feed as an RSS 2.0 document.
+///
+/// # Arguments
+///
+/// * `feed` - Feed type: "top", "new", "best", "ask", "show", "jobs"
+/// * `limit` - Maximum stories to include (defaults to 30)
+///
+/// # Returns
+///
+/// The RSS XML as a string, for the frontend to save or copy.
+#[tauri::command]
+pub async fn export_feed_rss(
+    client: State<'_, SharedHnClient>,
+    feed: StoryFeed,
+    limit: Option<usize>,
+) -> Result<String, ApiError> {
+    let response = client
+        .fetch_stories_paginated(feed, 0, limit.unwrap_or(30), true)
+        .await?;
+    Ok(export::render_feed_rss(
+        feed,
+        &response.stories,
+        &client.hn_web_base_url(),
+    ))
+}
+
+/// Format a story as a shareable Markdown blurb.
+///
+/// Includes the title linked to the article, score/author/comments, the HN
+/// discussion link, and the article's reading time and excerpt when the
+/// article can be extracted.
+///
+/// # Arguments
+///
+/// * `id` - Story ID
+#[tauri::command]
+pub async fn format_story_markdown(
+    client: State<'_, SharedHnClient>,
+    id: u32,
+) -> Result<String, ApiError> {
+    let story = client.fetch_item(id).await?;
+
+    let article = match &story.url {
+        Some(url) => match client.fetch_article_content(url).await {
+            Ok(article) => Some(article),
+            Err(e) => {
+                tracing::debug!(id = id, "No article details for Markdown: {}", e);
+                None
+            }
+        },
+        None => None,
+    };
+
+    Ok(export::render_story_markdown(
+        &story,
+        article.as_ref(),
+        &client.hn_web_base_url(),
+    ))
+}
+
+// ===== Backup Commands =====
+
+/// Write bookmarks to a backup file at a user-chosen path.
+///
+/// # Arguments
+///
+/// * `path` - Destination file (replaced atomically)
+/// * `bookmarks` - Bookmark entries from the frontend store
+///
+/// # Returns
+///
+/// The number of bookmarks written.
+#[tauri::command]
+pub fn export_bookmarks_json(
+    path: String,
+    bookmarks: Vec<BookmarkEntry>,
+) -> Result<usize, ApiError> {
+    backup::export_bookmarks(Path::new(&path), bookmarks)
+}
+
+/// Write read history to a backup file at a user-chosen path.
+///
+/// # Arguments
+///
+/// * `path` - Destination file (replaced atomically)
+/// * `entries` - Read story entries from the frontend store
+///
+/// # Returns
+///
+/// The number of entries written.
+#[tauri::command]
+pub fn export_reading_history_json(
+    path: String,
+    entries: Vec<ReadEntry>,
+) -> Result<usize, ApiError> {
+    backup::export_reading_history(Path::new(&path), entries)
+}
+
+/// Merge bookmarks from a backup file into the current ones.
+///
+/// # Arguments
+///
+/// * `path` - Backup file to read
+/// * `existing` - Current bookmark entries from the frontend store
+///
+/// # Returns
+///
+/// The merged bookmarks (deduplicated by story ID, newest first) for the
+/// frontend to persist.
+///
+/// # Errors
+///
+/// Returns an "Invalid backup file" error if the file is malformed.
+#[tauri::command]
+pub fn import_bookmarks_json(
+    path: String,
+    existing: Vec<BookmarkEntry>,
+) -> Result<Vec<BookmarkEntry>, ApiError> {
+    backup::import_bookmarks(Path::new(&path), existing)
+}
+
+/// Fetch paginated stories from several feeds as one blended stream.
+///
+/// Feeds are interleaved round-robin and stories on more than one feed
+/// appear once, at their first position.
+///
+/// # Arguments
+///
+/// * `feeds` - Feeds to blend, in rotation order (e.g. `["top", "best", "show"]`)
+/// * `offset` - Starting index in the merged list (0-based)
+/// * `limit` - Maximum stories to return
+#[tauri::command]
+pub async fn fetch_merged(
+    client: State<'_, SharedHnClient>,
+    feeds: Vec<StoryFeed>,
+    offset: usize,
+    limit: usize,
+) -> Result<StoriesResponse, ApiError> {
+    client.fetch_merged(&feeds, offset, limit).await
+}
+
+/// Rank the sites linked from a feed's top stories.
+///
+/// # Arguments
+///
+/// * `feed` - Feed to sample
+/// * `sample` - Number of top stories to look at (defaults to 60)
+///
+/// # Returns
+///
+/// One [`DomainCount`] per domain, most frequent first. Self-posts are
+/// not counted.
+#[tauri::command]
+pub async fn top_domains(
+    client: State<'_, SharedHnClient>,
+    feed: StoryFeed,
+    sample: Option<usize>,
+) -> Result<Vec<DomainCount>, ApiError> {
+    client.top_domains(feed, sample.unwrap_or(60)).await
+}
+
+/// Prefetch the next page of a feed into the item cache.
+///
+/// Call this when the user nears the end of the current list so the
+/// following [`fetch_stories`] call is served from cache. Already-cached
+/// items are skipped.
+///
+/// # Returns
+///
+/// The number of items fetched from the network (0 if the page was cached).
+#[tauri::command]
+pub async fn prefetch_stories(
+    client: State<'_, SharedHnClient>,
+    feed: StoryFeed,
+    offset: usize,
+    limit: usize,
+) -> Result<usize, ApiError> {
+    client.prefetch_stories(feed, offset, limit).await
+}
+
+/// Warm the item cache with the first `count` items of a feed.
+///
+/// Called once on startup so scrolling past the first page doesn't wait on
+/// the network. Already-cached items are skipped.
+///
+/// # Arguments
+///
+/// * `feed` - Feed to warm
+/// * `count` - Number of leading items to cache (defaults to 100)
+///
+/// # Returns
+///
+/// The number of items newly cached.
+#[tauri::command]
+pub async fn warm_feed(
+    client: State<'_, SharedHnClient>,
+    feed: StoryFeed,
+    count: Option<usize>,
+) -> Result<usize, ApiError> {
+    client.warm_items(feed, count.unwrap_or(100)).await
+}
+
+/// Prime the HTTP connection pool for the HN and Algolia hosts.
+///
+/// Returns immediately; the requests run in the background and failures
+/// are ignored. Also done once at startup.
+#[tauri::command]
+pub fn warm_connections(client: State<'_, SharedHnClient>) {
+    let client = client.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        client.warm_connections().await;
+    });
 }
 
 /// Fetch a single HN item by ID.
 ///
 /// Items include stories, comments, jobs, polls, and poll options.
+///
+/// # Arguments
+///
+/// * `id` - Item ID
+/// * `force` - Bypass the cache and reload the item from the network
 #[tauri::command]
-pub async fn fetch_item(client: State<'_, SharedHnClient>, id: u32) -> Result<HNItem, ApiError> {
-    client.fetch_item(id).await
+pub async fn fetch_item(
+    client: State<'_, SharedHnClient>,
+    id: u32,
+    force: Option<bool>,
+) -> Result<HNItem, ApiError> {
+    if force.unwrap_or(false) {
+        client.fetch_item_fresh(id).await
+    } else {
+        client.fetch_item(id).await
+    }
 }
 
 /// Fetch multiple items by ID in a single batch request.
@@ -113,31 +472,183 @@ pub async fn fetch_items(
     client.fetch_items(&ids).await
 }
 
+/// Fetch multiple items by ID, keeping partial results.
+///
+/// Every ID ends up either in `items` or in `errors` with the reason, so a
+/// single failing item doesn't hide the others.
+#[tauri::command]
+pub async fn fetch_items_detailed(
+    client: State<'_, SharedHnClient>,
+    ids: Vec<u32>,
+) -> Result<BatchResult, ApiError> {
+    Ok(client.fetch_items_detailed(&ids).await)
+}
+
+/// Fetch a poll with its options, most votes first.
+///
+/// Each option carries its share of the votes, so the UI can draw a bar
+/// chart as is. Votes are always fetched fresh.
+#[tauri::command]
+pub async fn fetch_poll(
+    client: State<'_, SharedHnClient>,
+    id: u32,
+) -> Result<PollWithOptions, ApiError> {
+    client.fetch_poll(id).await
+}
+
 /// Fetch a story with its full comment tree.
 ///
 /// # Arguments
 ///
 /// * `id` - Story ID
-/// * `depth` - Maximum comment nesting depth (e.g., 3 for typical views)
+/// * `depth` - Maximum comment nesting depth (defaults to the configured
+///   default for `feed`, see [`set_default_comment_depth`])
+/// * `sort` - "default", "newestFirst", or "highestScore" (defaults to "default")
+/// * `feed` - Feed the story was opened from, for its default depth
 #[tauri::command]
 pub async fn fetch_story_with_comments(
     client: State<'_, SharedHnClient>,
+    settings: State<'_, SettingsStore>,
     id: u32,
-    depth: u8,
+    depth: Option<u8>,
+    sort: Option<CommentSort>,
+    feed: Option<StoryFeed>,
 ) -> Result<StoryWithComments, ApiError> {
-    client.fetch_story_with_comments(id, depth).await
+    let depth = depth.unwrap_or_else(|| settings.comment_depth_for(feed));
+    client
+        .fetch_story_with_comments(id, depth, sort.unwrap_or_default())
+        .await
+}
+
+/// Fetch several stories with their comments in one call.
+///
+/// For loading a batch such as a read-later list for offline reading.
+/// Each story's comments are budgeted: at most `max_nodes` (capped at 200),
+/// loaded breadth-first to `depth` levels, so replies past the budget are
+/// loaded on demand with [`fetch_comment_children`].
+///
+/// # Arguments
+///
+/// * `ids` - Story IDs
+/// * `depth` - Maximum comment nesting depth (defaults to the configured
+///   default, see [`set_default_comment_depth`])
+/// * `max_nodes` - Most comments loaded per story (defaults to 50)
+/// * `sort` - "default", "newestFirst", or "highestScore" (defaults to "default")
+///
+/// # Returns
+///
+/// The stories in input order; missing or deleted stories are omitted.
+#[tauri::command]
+pub async fn fetch_stories_with_comments(
+    client: State<'_, SharedHnClient>,
+    settings: State<'_, SettingsStore>,
+    ids: Vec<u32>,
+    depth: Option<u8>,
+    max_nodes: Option<usize>,
+    sort: Option<CommentSort>,
+) -> Result<Vec<StoryWithComments>, ApiError> {
+    let depth = depth.unwrap_or_else(|| settings.comment_depth_for(None));
+    client
+        .fetch_stories_with_comments(
+            &ids,
+            depth,
+            max_nodes.unwrap_or(50),
+            sort.unwrap_or_default(),
+        )
+        .await
+}
+
+/// Extract structured job postings from a "Who is hiring?" thread.
+///
+/// # Arguments
+///
+/// * `story_id` - ID of the hiring thread story
+///
+/// # Returns
+///
+/// One [`JobPosting`] per top-level comment that looks like a posting,
+/// in thread order.
+#[tauri::command]
+pub async fn parse_hiring_thread(
+    client: State<'_, SharedHnClient>,
+    story_id: u32,
+) -> Result<Vec<JobPosting>, ApiError> {
+    client.parse_hiring_thread(story_id).await
 }
 
 /// Fetch children of a specific comment for "load more" functionality.
 ///
 /// Used when expanding a collapsed comment thread.
+///
+/// # Arguments
+///
+/// * `id` - Parent comment ID
+/// * `depth` - Nesting depth to load (1 = direct children only)
+/// * `offset` - Index of the first direct child (defaults to 0)
+/// * `limit` - Maximum direct children to return (defaults to all)
 #[tauri::command]
 pub async fn fetch_comment_children(
     client: State<'_, SharedHnClient>,
     id: u32,
     depth: u8,
-) -> Result<Vec<CommentWithChildren>, ApiError> {
-    client.fetch_comment_children(id, depth).await
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Result<CommentChildrenResponse, ApiError> {
+    client
+        .fetch_comment_children(id, depth, offset.unwrap_or(0), limit.unwrap_or(usize::MAX))
+        .await
+}
+
+/// Fetch a comment with its parent chain up to the root story.
+///
+/// Used to show a comment search hit in the thread it belongs to. The walk
+/// is capped at [`MAX_COMMENT_CONTEXT_DEPTH`] parents.
+#[tauri::command]
+pub async fn fetch_comment_context(
+    client: State<'_, SharedHnClient>,
+    comment_id: u32,
+) -> Result<CommentContext, ApiError> {
+    client
+        .fetch_comment_context(comment_id, MAX_COMMENT_CONTEXT_DEPTH)
+        .await
+}
+
+/// Count comments posted on a story since the user last saw it.
+///
+/// # Arguments
+///
+/// * `story_id` - Story ID
+/// * `last_descendants` - Baseline comment count; defaults to the count
+///   recorded by [`mark_comments_seen`]
+///
+/// # Returns
+///
+/// The number of new comments, never negative. 0 for stories with no
+/// baseline (never seen), without a network request.
+#[tauri::command]
+pub async fn new_comments_since(
+    client: State<'_, SharedHnClient>,
+    seen: State<'_, SeenComments>,
+    story_id: u32,
+    last_descendants: Option<u32>,
+) -> Result<i64, ApiError> {
+    match last_descendants.or_else(|| seen.last_seen(story_id)) {
+        Some(last) => client.new_comments_since(story_id, last).await,
+        None => Ok(0),
+    }
+}
+
+/// Record the comment count the user has seen on a story.
+///
+/// Called when a story is opened, as the baseline for
+/// [`new_comments_since`].
+#[tauri::command]
+pub fn mark_comments_seen(
+    seen: State<'_, SeenComments>,
+    story_id: u32,
+    descendants: u32,
+) -> Result<(), ApiError> {
+    seen.mark_seen(story_id, descendants)
 }
 
 /// Fetch a user profile by username.
@@ -167,106 +678,650 @@ pub async fn fetch_user_submissions(
         .await
 }
 
+/// Fetch a user's comments, newest first, via Algolia.
+///
+/// A much faster alternative to [`fetch_user_submissions`] with the
+/// comments filter for prolific users; the newest comments may lag behind
+/// by Algolia's indexing delay.
+///
+/// # Arguments
+///
+/// * `user` - Username
+/// * `page` - Page number (0-indexed, 30 comments per page; defaults to 0)
+#[tauri::command]
+pub async fn fetch_user_comments_search(
+    client: State<'_, SharedHnClient>,
+    user: String,
+    page: Option<u32>,
+) -> Result<SearchResponse, ApiError> {
+    client.search_user_comments(&user, page.unwrap_or(0)).await
+}
+
+/// Fetch a user's favorite stories, scraped from the HN website.
+///
+/// # Arguments
+///
+/// * `user_id` - Username
+/// * `page` - 1-based page number (defaults to 1)
+#[tauri::command]
+pub async fn fetch_user_favorites(
+    client: State<'_, SharedHnClient>,
+    user_id: String,
+    page: Option<u32>,
+) -> Result<Vec<HNItem>, ApiError> {
+    client
+        .fetch_user_favorites(&user_id, page.unwrap_or(1))
+        .await
+}
+
 /// Search HN using the Algolia Search API.
 ///
-/// Provides full-text search across stories and comments.
+/// Provides full-text search across stories and comments. The first page
+/// of each search is recorded in the search history.
 ///
 /// # Arguments
 ///
 /// * `query` - Search query
 /// * `page` - Page number (0-indexed)
-/// * `hits_per_page` - Results per page
+/// * `hits_per_page` - Results per page (0 for the default; large values
+///   are clamped, and the response reports the size used)
 /// * `sort` - "relevance" or "date"
 /// * `filter` - "all", "story", or "comment"
 #[tauri::command]
 pub async fn search_hn(
     client: State<'_, SharedHnClient>,
+    history: State<'_, SearchHistory>,
     query: String,
     page: u32,
     hits_per_page: u32,
     sort: SearchSort,
     filter: SearchFilter,
 ) -> Result<SearchResponse, ApiError> {
+    if page == 0 {
+        if let Err(e) = history.record(&query, sort, filter) {
+            tracing::warn!("Failed to save search history: {}", e);
+        }
+    }
+
     client
         .search(&query, page, hits_per_page, sort, filter)
         .await
 }
 
-/// Clear all caches (items, story IDs, users).
-///
-/// Forces fresh data on subsequent requests.
+/// Get the persisted app settings.
+#[tauri::command]
+pub fn get_settings(settings: State<'_, SettingsStore>) -> Settings {
+    settings.get()
+}
+
+/// Set the default comment depth used by [`fetch_story_with_comments`].
+///
+/// # Arguments
+///
+/// * `feed` - Feed to set an override for, or `None` for the global default
+/// * `depth` - New depth (clamped to 10), or `None` to remove the feed
+///   override / reset the global default
+///
+/// # Returns
+///
+/// The updated settings.
+#[tauri::command]
+pub fn set_default_comment_depth(
+    settings: State<'_, SettingsStore>,
+    feed: Option<StoryFeed>,
+    depth: Option<u8>,
+) -> Result<Settings, ApiError> {
+    settings.set_comment_depth(feed, depth)
+}
+
+/// Get recent searches, most recent first.
+#[tauri::command]
+pub fn get_search_history(history: State<'_, SearchHistory>) -> Vec<SearchHistoryEntry> {
+    history.entries()
+}
+
+/// Forget all recent searches.
+#[tauri::command]
+pub fn clear_search_history(history: State<'_, SearchHistory>) -> Result<(), ApiError> {
+    history.clear()
+}
+
+/// Count an article read in reader mode.
+///
+/// # Arguments
+///
+/// * `word_count` - Words in the article, for the reading time estimate
+#[tauri::command]
+pub fn record_article_read(
+    stats: State<'_, ReadingStatsStore>,
+    word_count: u64,
+) -> Result<(), ApiError> {
+    stats.record_article_read(word_count)
+}
+
+/// Count a story detail view being opened.
+#[tauri::command]
+pub fn record_story_opened(stats: State<'_, ReadingStatsStore>) -> Result<(), ApiError> {
+    stats.record_story_opened()
+}
+
+/// Record a reading session.
+///
+/// # Arguments
+///
+/// * `duration_secs` - How long the session lasted
+#[tauri::command]
+pub fn record_session(
+    stats: State<'_, ReadingStatsStore>,
+    duration_secs: u64,
+) -> Result<(), ApiError> {
+    stats.record_session(duration_secs)
+}
+
+/// Get all-time reading totals, per-day buckets, and the current streak.
+#[tauri::command]
+pub fn get_reading_stats(stats: State<'_, ReadingStatsStore>) -> ReadingStats {
+    stats.snapshot()
+}
+
+/// Forget all recorded reading activity.
+#[tauri::command]
+pub fn reset_reading_stats(stats: State<'_, ReadingStatsStore>) -> Result<(), ApiError> {
+    stats.reset()
+}
+
+/// Clear all caches (items, story IDs, users).
+///
+/// Forces fresh data on subsequent requests.
+#[tauri::command]
+pub fn clear_cache(client: State<'_, SharedHnClient>) {
+    client.clear_cache();
+}
+
+/// Get current cache statistics.
+///
+/// Returns entry counts and TTL values for all caches.
+#[tauri::command]
+pub fn get_cache_stats(client: State<'_, SharedHnClient>) -> CacheStats {
+    client.get_cache_stats()
+}
+
+/// Write the item, story ID, and user caches to a snapshot file.
+///
+/// For backing up or sharing a warm cache, or capturing a reproducible
+/// state for a bug report.
+///
+/// # Returns
+///
+/// The number of entries written from each cache.
+#[tauri::command]
+pub async fn save_cache_snapshot(
+    client: State<'_, SharedHnClient>,
+    path: String,
+) -> Result<CacheSnapshotCounts, ApiError> {
+    client.save_cache_snapshot(Path::new(&path)).await
+}
+
+/// Restore the caches from a snapshot file.
+///
+/// Entries that have expired since the snapshot was saved are skipped.
+///
+/// # Returns
+///
+/// The number of entries restored into each cache.
+///
+/// # Errors
+///
+/// Returns an "Invalid backup file" error if the file isn't a cache
+/// snapshot.
+#[tauri::command]
+pub async fn load_cache_snapshot(
+    client: State<'_, SharedHnClient>,
+    path: String,
+) -> Result<CacheSnapshotCounts, ApiError> {
+    client.load_cache_snapshot(Path::new(&path)).await
+}
+
+/// Enable or disable `cache-evicted` events.
+///
+/// While enabled, items leaving the item cache are reported about once a
+/// second as a batch of `{ id, cause }` (`expired`, `explicit`, or `size`).
+/// Meant for debugging; expiry alone makes the events frequent.
+#[tauri::command]
+pub fn set_cache_eviction_events(client: State<'_, SharedHnClient>, enabled: bool) {
+    client.set_eviction_events(enabled);
+}
+
+/// Clear story IDs cache for a specific feed or all feeds.
+///
+/// # Arguments
+///
+/// * `feed` - Specific feed to clear, or `null` to clear all
+#[tauri::command]
+pub async fn clear_story_ids_cache(
+    client: State<'_, SharedHnClient>,
+    feed: Option<StoryFeed>,
+) -> Result<(), ApiError> {
+    client.clear_story_ids_cache(feed).await;
+    Ok(())
+}
+
+/// Check if a feed's cached data is stale.
+///
+/// Returns `true` if the data is older than the stale threshold
+/// (75% of its TTL by default, see [`set_stale_threshold`]).
+#[tauri::command]
+pub async fn is_feed_stale(
+    client: State<'_, SharedHnClient>,
+    feed: StoryFeed,
+) -> Result<bool, ApiError> {
+    Ok(client.is_feed_stale(&feed).await)
+}
+
+/// When each feed's story IDs were last fetched, for "updated 2 minutes
+/// ago" indicators.
+///
+/// Returns one [`FeedAge`] per feed; feeds not fetched this session have
+/// no time or age.
+#[tauri::command]
+pub async fn get_feed_ages(client: State<'_, SharedHnClient>) -> Result<Vec<FeedAge>, ApiError> {
+    Ok(client.feed_ages().await)
+}
+
+/// Set how far into a feed's TTL its data counts as stale.
+///
+/// # Arguments
+///
+/// * `percent` - Threshold as a percentage of the TTL (1-99)
+///
+/// # Errors
+///
+/// Returns an error if `percent` is outside 1-99.
+#[tauri::command]
+pub fn set_stale_threshold(client: State<'_, SharedHnClient>, percent: u8) -> Result<(), ApiError> {
+    client.set_stale_threshold_percent(percent)
+}
+
+/// Trigger a background refresh for a feed.
+///
+/// Fetches fresh data and returns the new story IDs if the data changed.
+///
+/// # Returns
+///
+/// * `Some(ids)` - New story IDs if data changed
+/// * `None` - Data unchanged or refresh failed
+///
+/// A change is also announced with a `feed-refreshed` event, and a changed
+/// Top feed rebuilds the tray's "Recent" submenu.
+#[tauri::command]
+pub async fn background_refresh_feed(
+    app_handle: tauri::AppHandle,
+    client: State<'_, SharedHnClient>,
+    feed: StoryFeed,
+) -> Result<Option<Vec<u32>>, ApiError> {
+    let ids = auto_refresh::refresh_and_notify(&app_handle, &client, feed).await;
+    if ids.is_some() && feed == StoryFeed::Top {
+        tray::refresh_recent_stories(&app_handle, &client).await;
+    }
+    Ok(ids)
+}
+
+/// Get how stories moved in a feed when its IDs last changed.
+///
+/// Compares the ordering before the last change with the current one, so
+/// the UI can show movement indicators after a background refresh. The
+/// same deltas are included in `feed-updated` events.
+#[tauri::command]
+pub async fn feed_position_deltas(
+    client: State<'_, SharedHnClient>,
+    feed: StoryFeed,
+) -> Result<Vec<PositionDelta>, ApiError> {
+    Ok(client.feed_position_deltas(feed).await)
+}
+
+/// Enable or disable timer-driven background refresh of all feeds.
+///
+/// # Arguments
+///
+/// * `interval_secs` - Refresh interval in seconds (minimum 30), or `null` to disable
+///
+/// # Events
+///
+/// Emits `feed-updated` with `{ feed, ids }` whenever a feed's story IDs change.
+///
+/// Disabling also forgets the last connectivity check, so lookups stop
+/// falling back to cache straight away rather than once it expires.
+#[tauri::command]
+pub async fn set_auto_refresh(
+    app_handle: tauri::AppHandle,
+    client: State<'_, SharedHnClient>,
+    auto_refresh: State<'_, AutoRefresh>,
+    interval_secs: Option<u64>,
+) -> Result<(), ApiError> {
+    match interval_secs {
+        Some(secs) => auto_refresh.start(
+            app_handle,
+            client.inner().clone(),
+            Duration::from_secs(secs),
+        ),
+        None => {
+            auto_refresh.stop();
+            client.forget_connectivity().await;
+        }
+    }
+    Ok(())
+}
+
+/// Fetch a site's favicon as a `data:` URL for feed rows.
+///
+/// Icons are cached on disk; see [`HnClient::fetch_favicon`](crate::client::HnClient::fetch_favicon).
+///
+/// # Arguments
+///
+/// * `domain` - Host name, e.g. "example.com"
+///
+/// # Returns
+///
+/// The icon as a `data:` URL, or `null` if the site has no favicon.
+#[tauri::command]
+pub async fn fetch_favicon(
+    client: State<'_, SharedHnClient>,
+    domain: String,
+) -> Result<Option<String>, ApiError> {
+    match client.fetch_favicon(&domain).await {
+        Ok(bytes) => Ok(Some(favicon_data_url(&bytes))),
+        Err(ApiError::FaviconNotFound(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Extract readable article content from an external URL.
+///
+/// Uses readability algorithms to extract the main content,
+/// removing navigation, ads, and other non-content elements.
+#[tauri::command]
+pub async fn fetch_article_content(
+    client: State<'_, SharedHnClient>,
+    url: String,
+) -> Result<ArticleContent, ApiError> {
+    client.fetch_article_content(&url).await
+}
+
+/// Set the largest article download [`fetch_article_content`] accepts.
+///
+/// # Errors
+///
+/// Returns an error if `bytes` is zero.
+#[tauri::command]
+pub fn set_max_article_bytes(
+    client: State<'_, SharedHnClient>,
+    bytes: usize,
+) -> Result<(), ApiError> {
+    client.set_max_article_bytes(bytes)
+}
+
+/// Enable or disable leaving links back to an article's own site out of
+/// the links [`fetch_article_content`] collects.
+#[tauri::command]
+pub fn set_exclude_same_host_links(client: State<'_, SharedHnClient>, enabled: bool) {
+    client.set_exclude_same_host_links(enabled);
+}
+
+/// Reader-mode content for a story.
+///
+/// Link posts get their article extracted as by [`fetch_article_content`];
+/// self-posts (Ask HN, text stories) are rendered from their own text
+/// without a network fetch.
+#[tauri::command]
+pub async fn fetch_reader_content(
+    client: State<'_, SharedHnClient>,
+    id: u32,
+) -> Result<ArticleContent, ApiError> {
+    client.fetch_reader_content(id).await
+}
+
+/// Prefetch reader-mode content for the given stories in the background.
+///
+/// Call this after a feed loads so opening reader mode is instant. Returns
+/// immediately; stories without a URL and already-cached articles are skipped.
+#[tauri::command]
+pub fn prefetch_articles(client: State<'_, SharedHnClient>, ids: Vec<u32>) {
+    let client = client.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        match client.prefetch_articles(&ids).await {
+            Ok(count) => tracing::debug!("Prefetched {} articles", count),
+            Err(e) => tracing::warn!("Article prefetch failed: {}", e),
+        }
+    });
+}
+
+/// Prefetch shallow comment trees for the given stories in the background.
+///
+/// Call this with the on-screen story IDs so opening any of them is
+/// instant. Returns immediately; a newer call cancels one still running.
+///
+/// # Arguments
+///
+/// * `ids` - Stories to prefetch comments for
+/// * `depth` - Levels of comments to load (defaults to 2)
+/// * `max_nodes` - Most comments loaded per story (defaults to 50)
+#[tauri::command]
+pub fn prefetch_comments(
+    client: State<'_, SharedHnClient>,
+    ids: Vec<u32>,
+    depth: Option<u8>,
+    max_nodes: Option<usize>,
+) {
+    let client = client.inner().clone();
+    tauri::async_runtime::spawn(async move {
+        let result = client
+            .prefetch_comments(&ids, depth.unwrap_or(2), max_nodes.unwrap_or(50))
+            .await;
+        match result {
+            Ok(count) => tracing::debug!("Prefetched {} comments", count),
+            Err(e) => tracing::warn!("Comment prefetch failed: {}", e),
+        }
+    });
+}
+
+/// Fetch the top front-page stories with previews of their articles.
+///
+/// Each entry has the story's domain and, when its article could be
+/// extracted, an excerpt and reading-time estimate.
+///
+/// # Arguments
+///
+/// * `count` - Number of stories (at most 30)
+#[tauri::command]
+pub async fn fetch_front_page_digest(
+    client: State<'_, SharedHnClient>,
+    count: usize,
+) -> Result<Vec<DigestEntry>, ApiError> {
+    client.fetch_front_page_digest(count).await
+}
+
+/// Open a story in a dedicated window.
+///
+/// Focuses the story's window if it is already open. Story windows share
+/// the app's state and theme; at most
+/// [`MAX_STORY_WINDOWS`](story_windows::MAX_STORY_WINDOWS) can be open.
+///
+/// # Arguments
+///
+/// * `id` - The story ID
+#[tauri::command]
+pub async fn open_story_window(
+    app_handle: tauri::AppHandle,
+    client: State<'_, SharedHnClient>,
+    id: u32,
+) -> Result<(), String> {
+    story_windows::open(&app_handle, &client, id).await
+}
+
+/// Route the deep links the app was opened with.
+///
+/// Emits `deep-link-target` (or `deep-link-error`) for each, like links
+/// received while running. Called by the frontend once it listens for
+/// those events.
+#[tauri::command]
+pub fn dispatch_launch_deep_links(app_handle: tauri::AppHandle) {
+    deep_link::dispatch_launch_links(&app_handle);
+}
+
+/// Open a URL in the system's default browser.
+///
+/// Used for opening article links, user profiles on HN, etc. Unless
+/// disabled with [`set_strip_tracking_params`], tracking parameters
+/// (`utm_*`, `fbclid`, ...) are removed first.
+///
+/// # Returns
+///
+/// The URL that was actually opened, for display.
+#[tauri::command]
+pub fn open_external(client: State<'_, SharedHnClient>, url: &str) -> Result<String, String> {
+    let url = if client.strip_tracking_params() {
+        links::canonicalize_url(url)
+    } else {
+        url.to_string()
+    };
+    open::that(&url).map_err(|e| e.to_string())?;
+    Ok(url)
+}
+
+/// Enable or disable stripping tracking parameters in [`open_external`].
+#[tauri::command]
+pub fn set_strip_tracking_params(client: State<'_, SharedHnClient>, enabled: bool) {
+    client.set_strip_tracking_params(enabled);
+}
+
+/// Enable or disable offline (cache-only) mode.
+///
+/// While enabled, item, feed, and user lookups return cached data or an
+/// "Offline" error instead of using the network.
+#[tauri::command]
+pub fn set_offline_mode(client: State<'_, SharedHnClient>, enabled: bool) {
+    client.set_cache_only(enabled);
+}
+
+/// Cap the rate of HN and Algolia requests, or remove the cap.
+///
+/// # Arguments
+///
+/// * `requests_per_second` - Sustained rate; `None` removes the cap
+/// * `burst` - Requests allowed at once after a quiet period (defaults to
+///   one second's worth)
+///
+/// # Errors
+///
+/// Returns an error if the rate isn't positive or the burst is zero.
+#[tauri::command]
+pub fn set_rate_limit(
+    client: State<'_, SharedHnClient>,
+    requests_per_second: Option<f64>,
+    burst: Option<u32>,
+) -> Result<(), ApiError> {
+    client.set_rate_limit(requests_per_second.map(|rate| RateLimit {
+        requests_per_second: rate,
+        burst: burst.unwrap_or_else(|| rate.ceil().max(1.0) as u32),
+    }))
+}
+
+/// Get the accelerators of the global shortcuts.
 #[tauri::command]
-pub fn clear_cache(client: State<'_, SharedHnClient>) {
-    client.clear_cache();
+pub fn get_global_shortcuts(shortcuts: State<'_, GlobalShortcuts>) -> ShortcutConfig {
+    shortcuts.config()
 }
 
-/// Get current cache statistics.
-///
-/// Returns entry counts and TTL values for all caches.
+/// Get the accelerators the global shortcuts are registered with at startup.
 #[tauri::command]
-pub fn get_cache_stats(client: State<'_, SharedHnClient>) -> CacheStats {
-    client.get_cache_stats()
+pub fn get_default_global_shortcuts() -> ShortcutConfig {
+    ShortcutConfig::default()
 }
 
-/// Clear story IDs cache for a specific feed or all feeds.
+/// Change the global shortcuts (show window, refresh, next/previous story).
 ///
-/// # Arguments
+/// Accelerators look like `"CommandOrControl+Shift+J"`. The previous
+/// shortcuts are unregistered first. An invalid or duplicated accelerator
+/// is rejected and leaves the current shortcuts in place.
 ///
-/// * `feed` - Specific feed to clear, or `null` to clear all
+/// # Events
+///
+/// The next/previous story shortcuts emit `tray-next-story` and
+/// `tray-prev-story`.
 #[tauri::command]
-pub async fn clear_story_ids_cache(
-    client: State<'_, SharedHnClient>,
-    feed: Option<StoryFeed>,
-) -> Result<(), ApiError> {
-    client.clear_story_ids_cache(feed).await;
-    Ok(())
+pub fn set_global_shortcuts(
+    app_handle: tauri::AppHandle,
+    shortcuts: State<'_, GlobalShortcuts>,
+    config: ShortcutConfig,
+) -> Result<(), String> {
+    shortcuts.apply(&app_handle, config)
 }
 
-/// Check if a feed's cached data is stale.
+/// Check whether the HN API is reachable.
 ///
-/// Returns `true` if the data is older than 75% of its TTL.
+/// Results are reused for a few seconds, so this is cheap to poll.
+///
+/// # Events
+///
+/// Emits `online-changed` with the new state (`true`/`false`) when
+/// connectivity flips.
 #[tauri::command]
-pub async fn is_feed_stale(
+pub async fn is_online(
+    app_handle: tauri::AppHandle,
     client: State<'_, SharedHnClient>,
-    feed: StoryFeed,
 ) -> Result<bool, ApiError> {
-    Ok(client.is_feed_stale(&feed).await)
+    let connectivity = client.check_connectivity().await;
+    if connectivity.changed {
+        if let Err(e) = app_handle.emit("online-changed", connectivity.online) {
+            tracing::warn!("Failed to emit online-changed event: {}", e);
+        }
+    }
+    Ok(connectivity.online)
 }
 
-/// Trigger a background refresh for a feed.
-///
-/// Fetches fresh data and returns the new story IDs if the data changed.
+/// Get the HN discussion URL for an item.
+#[tauri::command]
+pub fn hn_item_url(client: State<'_, SharedHnClient>, id: u32) -> String {
+    links::hn_item_url(&client.hn_web_base_url(), id)
+}
+
+/// Get the HN profile URL for a user.
+#[tauri::command]
+pub fn hn_user_url(client: State<'_, SharedHnClient>, user: &str) -> String {
+    links::hn_user_url(&client.hn_web_base_url(), user)
+}
+
+/// Get a permalink to a comment.
 ///
-/// # Returns
+/// # Arguments
 ///
-/// * `Some(ids)` - New story IDs if data changed
-/// * `None` - Data unchanged or refresh failed
+/// * `id` - Comment ID
+/// * `story_id` - Optional story ID, to link to the comment's anchor in the full thread
 #[tauri::command]
-pub async fn background_refresh_feed(
+pub fn comment_permalink(
     client: State<'_, SharedHnClient>,
-    feed: StoryFeed,
-) -> Result<Option<Vec<u32>>, ApiError> {
-    Ok(client.background_refresh_feed(feed).await)
+    id: u32,
+    story_id: Option<u32>,
+) -> String {
+    links::comment_permalink(&client.hn_web_base_url(), id, story_id)
 }
 
-/// Extract readable article content from an external URL.
+/// Get the link to open for an item.
 ///
-/// Uses readability algorithms to extract the main content,
-/// removing navigation, ads, and other non-content elements.
+/// The item's URL, or for Show HN self-posts the first link in the text.
+/// `None` means the item should open in the discussion view.
 #[tauri::command]
-pub async fn fetch_article_content(
+pub async fn primary_link(
     client: State<'_, SharedHnClient>,
-    url: String,
-) -> Result<ArticleContent, ApiError> {
-    client.fetch_article_content(&url).await
+    id: u32,
+) -> Result<Option<String>, ApiError> {
+    Ok(client.fetch_item(id).await?.primary_link())
 }
 
-/// Open a URL in the system's default browser.
-///
-/// Used for opening article links, user profiles on HN, etc.
+/// Open an item's discussion page on HN in the system browser.
 #[tauri::command]
-pub fn open_external(url: &str) -> Result<(), String> {
-    open::that(url).map_err(|e| e.to_string())
+pub fn open_on_hn(client: State<'_, SharedHnClient>, id: u32) -> Result<(), String> {
+    open::that(links::hn_item_url(&client.hn_web_base_url(), id)).map_err(|e| e.to_string())
 }
 
 /// Get the application version from Cargo.toml.
@@ -299,14 +1354,36 @@ pub async fn copilot_init() -> Result<CopilotStatus, String> {
     copilot::init().await.map_err(|e| e.to_string())
 }
 
+/// Check that the assistant actually responds, and how quickly.
+///
+/// Sends a trivial prompt with a short timeout. Returns `ok: false` (with
+/// the error) when the backend is unavailable, failing, or hung, so the UI
+/// can warn instead of waiting on a request that never finishes.
+#[tauri::command]
+pub async fn copilot_ping() -> PingResult {
+    copilot::get_service().ping().await
+}
+
 /// Generate an AI summary of an article based on story context.
 ///
 /// Works even without article content by using title, URL, and metadata.
+/// `output_language` (e.g. `"fr"`) asks for the summary in that language.
+/// With `structured`, the response also carries a parsed
+/// `{ tldr, key_points, sentiment }` summary when the model's reply is
+/// valid JSON; otherwise it falls back to the raw text.
 #[tauri::command]
-pub async fn copilot_summarize(context: StoryContext) -> Result<AssistantResponse, String> {
+pub async fn copilot_summarize(
+    context: StoryContext,
+    structured: Option<bool>,
+    output_language: Option<String>,
+) -> Result<AssistantResponse, String> {
     let service = copilot::get_service();
     service
-        .summarize_article(context)
+        .summarize_article(
+            context,
+            structured.unwrap_or(false),
+            output_language.as_deref(),
+        )
         .await
         .map_err(|e| e.to_string())
 }
@@ -314,13 +1391,18 @@ pub async fn copilot_summarize(context: StoryContext) -> Result<AssistantRespons
 /// Analyze a discussion thread for key themes and viewpoints.
 ///
 /// Provides a summary of the main perspectives in a comment thread.
+/// Only the `max_comments` most-replied-to comments are sent (default 20)
+/// and long previews are trimmed; the response reports how many were
+/// included.
 #[tauri::command]
 pub async fn copilot_analyze_discussion(
     context: DiscussionContext,
+    max_comments: Option<usize>,
+    output_language: Option<String>,
 ) -> Result<AssistantResponse, String> {
     let service = copilot::get_service();
     service
-        .analyze_discussion(context)
+        .analyze_discussion(context, max_comments, output_language.as_deref())
         .await
         .map_err(|e| e.to_string())
 }
@@ -331,14 +1413,17 @@ pub async fn copilot_analyze_discussion(
 ///
 /// * `text` - The term/concept to explain
 /// * `context` - Optional surrounding context for better explanation
+/// * `output_language` - Language to answer in (name or code such as `"fr"`);
+///   defaults to the language set with [`copilot_set_output_language`]
 #[tauri::command]
 pub async fn copilot_explain(
     text: String,
     context: Option<String>,
+    output_language: Option<String>,
 ) -> Result<AssistantResponse, String> {
     let service = copilot::get_service();
     service
-        .explain(&text, context.as_deref())
+        .explain(&text, context.as_deref(), output_language.as_deref())
         .await
         .map_err(|e| e.to_string())
 }
@@ -367,6 +1452,62 @@ pub async fn copilot_ask(prompt: String) -> Result<AssistantResponse, String> {
         .map_err(|e| e.to_string())
 }
 
+/// Set the language Copilot answers in when a request has no
+/// `output_language` of its own (normally the UI language).
+///
+/// Pass `None` to go back to the model's default (English).
+#[tauri::command]
+pub fn copilot_set_output_language(language: Option<String>) {
+    copilot::get_service().set_output_language(language);
+}
+
+/// Enable or disable the Copilot transcript.
+///
+/// While enabled, every prompt and response is appended, unredacted, to a
+/// JSONL file in the app's log directory. Off by default.
+#[tauri::command]
+pub fn copilot_set_transcript_logging(enabled: bool) {
+    copilot::get_service().transcript().set_enabled(enabled);
+}
+
+/// Get the path of the Copilot transcript file (it may not exist yet).
+#[tauri::command]
+pub fn copilot_get_transcript_path() -> String {
+    copilot::get_service()
+        .transcript()
+        .path()
+        .display()
+        .to_string()
+}
+
+/// Delete the Copilot transcript file.
+#[tauri::command]
+pub fn copilot_clear_transcript() -> Result<(), String> {
+    copilot::get_service()
+        .transcript()
+        .clear()
+        .map_err(|e| e.to_string())
+}
+
+/// Set how many Copilot requests may be running or waiting at once.
+///
+/// Requests run one at a time; once `depth` are in flight, further requests
+/// fail with a "Copilot is busy" error. Queue progress is reported through
+/// `copilot-queue` events.
+#[tauri::command]
+pub fn copilot_set_queue_depth(depth: usize) -> Result<(), String> {
+    copilot::get_service().set_max_queue_depth(depth)
+}
+
+/// Set how many times a Copilot request is retried after a transient
+/// session error (e.g. a dropped connection to the CLI). `0` disables
+/// retries, and more than [`copilot::MAX_RETRIES`] are capped; auth and
+/// other fatal errors are never retried.
+#[tauri::command]
+pub fn copilot_set_max_retries(retries: usize) {
+    copilot::get_service().set_max_retries(retries);
+}
+
 /// Shutdown the Copilot service gracefully.
 ///
 /// Call this when the app is closing to clean up resources.
@@ -384,10 +1525,21 @@ pub async fn copilot_shutdown() -> Result<(), String> {
 
 /// Initialize the TTS engine.
 ///
-/// Must be called before using other TTS commands.
+/// Must be called before using other TTS commands. Re-applies the voice
+/// and rate saved by [`tts_set_voice`] and [`tts_set_rate`]; a saved voice
+/// that is no longer installed is forgotten.
 #[tauri::command]
-pub fn tts_init() -> Result<(), String> {
-    tts::init()
+pub fn tts_init(settings: State<'_, SettingsStore>) -> Result<(), String> {
+    tts::init()?;
+
+    let saved = settings.voice_settings();
+    let applied = tts::restore(saved.native_voice.as_deref(), saved.native_rate)?;
+    if saved.native_voice.is_some() && !applied {
+        settings
+            .update_voice(|tts| tts.native_voice = None)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
 }
 
 /// Get TTS status and capabilities.
@@ -410,6 +1562,54 @@ pub fn tts_speak(text: String, interrupt: bool) -> Result<(), String> {
     Ok(())
 }
 
+/// Speak an article with a voice in its language.
+///
+/// # Arguments
+///
+/// * `text` - Text to speak
+/// * `lang` - The article's language tag (e.g. `fr` or `en-GB`), if known
+/// * `neural` - Prefer a neural voice (defaults to false)
+///
+/// A voice for `lang` is chosen on the preferred backend, then on the
+/// other; if neither has one, the current voice is used. Returns which
+/// backend spoke.
+#[tauri::command]
+pub async fn speak_article(
+    text: String,
+    lang: Option<String>,
+    neural: Option<bool>,
+) -> Result<crate::tts::neural::SpeakOutcome, String> {
+    tts::speak_article(&text, lang.as_deref(), neural.unwrap_or(false)).await
+}
+
+/// Speak sentences one-by-one with progress events.
+///
+/// The native counterpart of [`tts_neural_speak_sentences`], emitting the
+/// same `tts-sentence` events so the UI can highlight the current sentence.
+/// Where the platform can't report when speech ends, sentence timing is
+/// estimated from word count and the current rate.
+///
+/// # Arguments
+///
+/// * `sentences` - Array of sentences to speak
+///
+/// # Events
+///
+/// Emits `tts-sentence` events with payloads:
+/// - `{ type: "start", index: number, text: string }` - Sentence started
+/// - `{ type: "end", index: number }` - Sentence finished
+/// - `{ type: "finished" }` - All sentences done
+/// - `{ type: "stopped" }` - Playback was stopped
+#[tauri::command]
+pub async fn tts_speak_sentences(
+    app_handle: tauri::AppHandle,
+    sentences: Vec<String>,
+) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || tts::speak_sentences(&sentences, &app_handle))
+        .await
+        .map_err(|e| format!("TTS task failed: {}", e))?
+}
+
 /// Stop any current speech.
 #[tauri::command]
 pub fn tts_stop() -> Result<(), String> {
@@ -424,21 +1624,48 @@ pub fn tts_get_voices() -> Result<Vec<VoiceInfo>, String> {
     tts::get_voices()
 }
 
+/// List system and neural voices together, for a single voice picker.
+///
+/// Each voice is tagged with its `backend` ("neural" or "native"); neural
+/// voices report whether their model is `downloaded`.
+#[tauri::command]
+pub fn list_all_voices() -> Vec<UnifiedVoice> {
+    tts::list_all_voices()
+}
+
 /// Set the active voice by ID.
 ///
-/// Voice IDs can be obtained from [`tts_get_voices`].
+/// Voice IDs can be obtained from [`tts_get_voices`]. The voice is saved
+/// and re-applied on the next [`tts_init`].
 #[tauri::command]
-pub fn tts_set_voice(voice_id: String) -> Result<(), String> {
-    tts::set_voice(&voice_id)
+pub fn tts_set_voice(settings: State<'_, SettingsStore>, voice_id: String) -> Result<(), String> {
+    tts::set_voice(&voice_id)?;
+    settings
+        .update_voice(|tts| tts.native_voice = Some(voice_id))
+        .map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 /// Set the speech rate.
 ///
 /// Rate is normalized to 0.0-1.0 where 0.5 is normal speed.
-/// Lower values are slower, higher values are faster.
+/// Lower values are slower, higher values are faster. The rate is saved
+/// and re-applied on the next [`tts_init`].
+#[tauri::command]
+pub fn tts_set_rate(settings: State<'_, SettingsStore>, rate: f32) -> Result<(), String> {
+    tts::set_rate(rate)?;
+    settings
+        .update_voice(|tts| tts.native_rate = Some(rate.clamp(0.0, 1.0)))
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Get the voices and rates saved for both TTS backends.
+///
+/// Unset fields mean the backend's default.
 #[tauri::command]
-pub fn tts_set_rate(rate: f32) -> Result<(), String> {
-    tts::set_rate(rate)
+pub fn tts_voice_settings(settings: State<'_, SettingsStore>) -> VoiceSettings {
+    settings.voice_settings()
 }
 
 // ============================================================================
@@ -452,9 +1679,53 @@ pub fn tts_set_rate(rate: f32) -> Result<(), String> {
 ///
 /// Prepares the engine but doesn't download models yet.
 /// Call `tts_neural_status` to check if models are available.
+/// Re-applies the voice and rate saved by [`tts_neural_set_voice`] and
+/// [`tts_neural_set_rate`]; a saved voice that is no longer registered is
+/// forgotten.
+#[tauri::command]
+pub async fn tts_neural_init(settings: State<'_, SettingsStore>) -> Result<(), String> {
+    crate::tts::neural::init_neural().await?;
+
+    let saved = settings.voice_settings();
+    let applied =
+        crate::tts::neural::restore(saved.neural_voice.as_deref(), saved.neural_rate).await?;
+    if saved.neural_voice.is_some() && !applied {
+        settings
+            .update_voice(|tts| tts.neural_voice = None)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Set the neural voice used when none is passed to speak.
+///
+/// The voice is saved and re-applied on the next [`tts_neural_init`].
+#[tauri::command]
+pub async fn tts_neural_set_voice(
+    settings: State<'_, SettingsStore>,
+    voice_id: String,
+) -> Result<(), String> {
+    crate::tts::neural::set_voice(&voice_id).await?;
+    settings
+        .update_voice(|tts| tts.neural_voice = Some(voice_id))
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Set the neural speech rate used when none is passed to speak.
+///
+/// Rate is from 0.5 to 2.0 (1.0 is normal). The rate is saved and
+/// re-applied on the next [`tts_neural_init`].
 #[tauri::command]
-pub async fn tts_neural_init() -> Result<(), String> {
-    crate::tts::neural::init_neural().await
+pub async fn tts_neural_set_rate(
+    settings: State<'_, SettingsStore>,
+    rate: f32,
+) -> Result<(), String> {
+    crate::tts::neural::set_rate(rate).await?;
+    settings
+        .update_voice(|tts| tts.neural_rate = Some(rate.clamp(0.5, 2.0)))
+        .map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 /// Get neural TTS status.
@@ -475,6 +1746,48 @@ pub fn tts_neural_voices() -> Vec<crate::tts::neural::NeuralVoiceInfo> {
     crate::tts::neural::list_neural_voices()
 }
 
+/// List the pronunciation dictionary, sorted by word.
+#[tauri::command]
+pub fn tts_neural_pronunciations(
+    pronunciations: State<'_, PronunciationStore>,
+) -> Vec<PronunciationEntry> {
+    pronunciations.entries()
+}
+
+/// Add a word to the pronunciation dictionary, replacing any entry for it.
+///
+/// # Arguments
+///
+/// * `word` - Word or phrase as it appears in text (matched as whole words)
+/// * `pronunciation` - Respelling, e.g. "engine x" for "nginx", or IPA
+/// * `ipa` - Whether `pronunciation` is IPA (defaults to false)
+///
+/// # Returns
+///
+/// The updated dictionary. Applies to the next sentence synthesized.
+#[tauri::command]
+pub fn tts_neural_add_pronunciation(
+    pronunciations: State<'_, PronunciationStore>,
+    word: String,
+    pronunciation: String,
+    ipa: Option<bool>,
+) -> Result<Vec<PronunciationEntry>, ApiError> {
+    pronunciations.add(&word, &pronunciation, ipa.unwrap_or(false))
+}
+
+/// Remove a word from the pronunciation dictionary.
+///
+/// # Returns
+///
+/// The updated dictionary.
+#[tauri::command]
+pub fn tts_neural_remove_pronunciation(
+    pronunciations: State<'_, PronunciationStore>,
+    word: String,
+) -> Result<Vec<PronunciationEntry>, ApiError> {
+    pronunciations.remove(&word)
+}
+
 /// Download a neural voice model.
 ///
 /// # Arguments
@@ -483,13 +1796,33 @@ pub fn tts_neural_voices() -> Vec<crate::tts::neural::NeuralVoiceInfo> {
 ///
 /// This is an async operation that can take a minute depending
 /// on the model size (~63MB for Piper).
-/// Check status with `tts_neural_status` for download progress.
+///
+/// # Events
+///
+/// Emits `tts-download-progress` with
+/// `{ modelId, progress, bytesDownloaded, totalBytes, bytesPerSec, etaSecs }`
+/// each time the overall percentage changes.
 #[tauri::command]
-pub async fn tts_download_model(model_id: String) -> Result<(), String> {
-    // Create a progress callback that emits events
-    let progress_callback = move |progress: u8| {
-        // In production, would emit Tauri event for frontend progress
-        tracing::info!("Model download progress: {}%", progress);
+pub async fn tts_download_model(
+    app_handle: tauri::AppHandle,
+    model_id: String,
+) -> Result<(), String> {
+    use crate::tts::neural::{DownloadProgress, ModelDownloadEvent};
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    let event_model_id = model_id.clone();
+    let last_percent = AtomicU8::new(u8::MAX);
+    let progress_callback = move |progress: DownloadProgress| {
+        // One event per percent step keeps IPC traffic low
+        if last_percent.swap(progress.percent, Ordering::Relaxed) == progress.percent {
+            return;
+        }
+        tracing::debug!("Model download progress: {}%", progress.percent);
+
+        let event = ModelDownloadEvent::new(&event_model_id, progress);
+        if let Err(e) = app_handle.emit("tts-download-progress", &event) {
+            tracing::warn!("Failed to emit download progress event: {}", e);
+        }
     };
 
     crate::tts::neural::download_model(&model_id, Some(progress_callback)).await
@@ -503,6 +1836,16 @@ pub fn tts_is_model_ready(model_id: String) -> Result<bool, String> {
     crate::tts::neural::is_model_ready(&model_id)
 }
 
+/// Check the configured sizes of all downloadable models against the server.
+///
+/// Sends a HEAD request for every model file and reports those whose live
+/// `Content-Length` differs from the registry (or couldn't be read). A
+/// mismatch means downloads of that model will never count as ready.
+#[tauri::command]
+pub async fn tts_validate_models() -> crate::tts::neural::ModelValidationReport {
+    crate::tts::neural::validate_models().await
+}
+
 /// Speak text using neural TTS.
 ///
 /// Falls back to native TTS if neural TTS is unavailable.
@@ -521,7 +1864,145 @@ pub async fn tts_neural_speak(
     crate::tts::neural::speak(&text, voice_id.as_deref(), rate).await
 }
 
+/// Warm up the neural TTS engine.
+///
+/// Loads `model_id` (the configured model if omitted) and runs a tiny
+/// inference so the first sentence spoken has no startup delay. Downloads
+/// warm up automatically, and startup does when enabled with
+/// [`tts_neural_set_warmup_on_startup`].
+#[tauri::command]
+pub async fn tts_neural_warmup(model_id: Option<String>) -> Result<(), String> {
+    crate::tts::neural::warmup(model_id.as_deref()).await
+}
+
+/// Set whether the saved neural voice is warmed up when the app starts.
+///
+/// Off by default, since warming up loads the model into memory.
+#[tauri::command]
+pub fn tts_neural_set_warmup_on_startup(
+    settings: State<'_, SettingsStore>,
+    enabled: bool,
+) -> Result<(), String> {
+    settings
+        .update(|s| s.neural_warmup_on_startup = enabled)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Configure the ONNX session used for neural TTS.
+///
+/// # Arguments
+///
+/// * `intra_threads` - Threads per operator (defaults to the number of cores)
+/// * `optimization_level` - "disable", "level1", "level2", or "level3" (default)
+///
+/// A loaded model is reloaded with the new settings.
+#[tauri::command]
+pub async fn tts_neural_set_session_options(
+    intra_threads: Option<usize>,
+    optimization_level: Option<crate::tts::neural::OptimizationLevel>,
+) -> Result<(), String> {
+    crate::tts::neural::set_session_options(intra_threads, optimization_level).await
+}
+
+/// Set how long neural audio takes to become audible after it is queued.
+///
+/// Sentence start events wait this long, so highlighting stays in sync on
+/// high-latency outputs such as Bluetooth headphones.
+///
+/// # Arguments
+///
+/// * `latency_ms` - Output latency in ms; `None` derives it from the output
+///   stream (50ms when the device doesn't report a buffer size)
+#[tauri::command]
+pub async fn tts_neural_set_output_latency(latency_ms: Option<u64>) -> Result<(), String> {
+    crate::tts::neural::set_output_latency(latency_ms).await
+}
+
+/// Set whether [`tts_neural_speak_sentences`] continues to the next
+/// sentence after one fails to synthesize or play.
+///
+/// Off by default: a failed sentence emits an `error` event and stops
+/// playback.
+#[tauri::command]
+pub async fn tts_neural_set_continue_on_error(enabled: bool) -> Result<(), String> {
+    crate::tts::neural::set_continue_on_error(enabled).await
+}
+
+/// Benchmark neural TTS on this machine.
+///
+/// Returns the real-time factor (audio seconds per wall second; below 1
+/// means speech can't keep up) and approximate memory use, to help choose
+/// between neural and native voices.
+///
+/// # Arguments
+///
+/// * `sample_text` - Text to synthesize (defaults to a fixed phrase)
+#[tauri::command]
+pub async fn tts_neural_benchmark(
+    sample_text: Option<String>,
+) -> Result<crate::tts::neural::BenchmarkResult, String> {
+    crate::tts::neural::benchmark(sample_text.as_deref()).await
+}
+
+/// Unload the neural TTS model to free memory.
+///
+/// Call when TTS has been idle for a while; the next speak reloads the
+/// model automatically.
+#[tauri::command]
+pub async fn tts_neural_unload() -> Result<(), String> {
+    crate::tts::neural::unload().await
+}
+
+/// Speak text with neural TTS, falling back to the system voice.
+///
+/// Like [`tts_neural_speak`], but reports which backend spoke and why it
+/// fell back, e.g. `{ backendUsed: "native", fellBack: true, reason: "Neural
+/// voice not downloaded — using system voice" }`.
+#[tauri::command]
+pub async fn tts_neural_speak_auto(
+    text: String,
+    voice_id: Option<String>,
+    rate: Option<f32>,
+) -> Result<crate::tts::neural::SpeakOutcome, String> {
+    crate::tts::neural::speak_auto(&text, voice_id.as_deref(), rate).await
+}
+
+/// Jump neural TTS playback to a position in the current narration.
+///
+/// # Arguments
+///
+/// * `position_ms` - Offset from the start of the narration; positions past
+///   the end finish it
+///
+/// Fails while speaking sentences; skip between sentences there instead.
+#[tauri::command]
+pub fn tts_neural_seek(position_ms: u64) -> Result<(), String> {
+    crate::tts::neural::seek(position_ms)
+}
+
+/// Skip to the next sentence during [`tts_neural_speak_sentences`].
+///
+/// Emits `end` for the current sentence, then `start` for the next (or
+/// `finished` after the last).
+#[tauri::command]
+pub fn tts_neural_skip_next() -> Result<(), String> {
+    crate::tts::neural::skip_next()
+}
+
+/// Go back a sentence during [`tts_neural_speak_sentences`].
+///
+/// Emits `end` for the current sentence, then `start` for the previous one
+/// (the first sentence restarts).
+#[tauri::command]
+pub fn tts_neural_skip_prev() -> Result<(), String> {
+    crate::tts::neural::skip_prev()
+}
+
 /// Stop neural TTS playback.
+///
+/// Also cancels synthesis that hasn't started playing yet, so a long
+/// article stops right away rather than after its first audio.
 #[tauri::command]
 pub async fn tts_neural_stop() -> Result<(), String> {
     crate::tts::neural::stop().await
@@ -544,6 +2025,8 @@ pub async fn tts_neural_stop() -> Result<(), String> {
 /// Emits `tts-sentence` events with payloads:
 /// - `{ type: "start", index: number, text: string }` - Sentence started
 /// - `{ type: "end", index: number }` - Sentence finished
+/// - `{ type: "error", index: number, stage: "synthesis" | "playback", reason: string }` -
+///   Sentence failed; followed by `stopped` unless continuing on errors
 /// - `{ type: "finished" }` - All sentences done
 /// - `{ type: "stopped" }` - Playback was stopped
 #[tauri::command]
@@ -556,6 +2039,28 @@ pub async fn tts_neural_speak_sentences(
     crate::tts::neural::speak_sentences(sentences, voice_id.as_deref(), rate, app_handle).await
 }
 
+/// Pre-generate narration for a whole article.
+///
+/// Splits `text` into sentences and synthesizes them all into the audio
+/// cache without playing anything, so the UI can show a ready state and an
+/// accurate progress bar. Pass the returned sentences to
+/// [`tts_neural_speak_sentences`] (with the same voice and rate) to play
+/// them straight from the cache.
+///
+/// # Arguments
+///
+/// * `text` - Article text to narrate
+/// * `voice_id` - Optional voice ID (uses default if not specified)
+/// * `rate` - Speech rate from 0.5 to 2.0 (1.0 is normal)
+#[tauri::command]
+pub async fn tts_neural_prepare(
+    text: String,
+    voice_id: Option<String>,
+    rate: Option<f32>,
+) -> Result<crate::tts::neural::PreparedNarration, String> {
+    crate::tts::neural::prepare(&text, voice_id.as_deref(), rate).await
+}
+
 /// Get the neural TTS model directory path.
 ///
 /// Returns the platform-specific path where models are stored.
@@ -565,6 +2070,45 @@ pub fn tts_model_directory() -> Result<String, String> {
     Ok(path.to_string_lossy().to_string())
 }
 
+/// Change where neural TTS models are stored.
+///
+/// Downloaded models are moved to the new directory, which must be
+/// writable. The choice is saved and applied at startup.
+///
+/// # Arguments
+///
+/// * `path` - New model directory, or `None` for the platform default
+///
+/// # Returns
+///
+/// The model directory now in use.
+#[tauri::command]
+pub async fn tts_set_model_directory(
+    settings: State<'_, SettingsStore>,
+    path: Option<String>,
+) -> Result<String, String> {
+    let dir = path.filter(|p| !p.trim().is_empty()).map(PathBuf::from);
+
+    // Saved first, so a failed save never leaves models where the next
+    // start won't look; rolled back if they can't be moved
+    let previous = settings.get().model_dir;
+    settings
+        .update(|settings| settings.model_dir = dir.clone())
+        .map_err(|e| e.to_string())?;
+    match crate::tts::neural::set_model_dir(dir).await {
+        Ok(applied) => Ok(applied.to_string_lossy().to_string()),
+        Err(e) => {
+            if let Err(rollback) = settings.update(|settings| settings.model_dir = previous) {
+                tracing::warn!(
+                    "Failed to restore the model directory setting: {}",
+                    rollback
+                );
+            }
+            Err(e)
+        }
+    }
+}
+
 /// Get disk usage for neural TTS models.
 ///
 /// Returns total bytes used by downloaded models.
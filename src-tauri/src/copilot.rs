@@ -11,12 +11,18 @@ use copilot_sdk::{
 };
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 use std::process::Command;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 
+use crate::copilot_transcript::{TranscriptEntry, TranscriptLog};
+use crate::persist;
+
 /// Global Copilot service instance
 static COPILOT_SERVICE: OnceCell<CopilotService> = OnceCell::new();
 
@@ -37,6 +43,52 @@ pub enum CopilotError {
     SendFailed(String),
     #[error("Session timeout")]
     Timeout,
+    #[error("Copilot is busy: {0} requests already queued")]
+    QueueFull(usize),
+}
+
+/// Message fragments that mark a session error as permanent
+const FATAL_ERROR_MARKERS: &[&str] = &[
+    "auth",
+    "unauthorized",
+    "forbidden",
+    "401",
+    "403",
+    "permission",
+    "not found",
+    "invalid",
+];
+
+/// Message fragments that mark a session error as worth retrying
+const TRANSIENT_ERROR_MARKERS: &[&str] = &[
+    "connection",
+    "broken pipe",
+    "reset",
+    "closed",
+    "eof",
+    "temporar",
+    "timed out",
+    "unavailable",
+    "overloaded",
+    "try again",
+];
+
+impl CopilotError {
+    /// Whether retrying with a fresh session might succeed.
+    ///
+    /// Only session and send failures whose message looks like a dropped
+    /// connection or a busy backend qualify; auth and configuration
+    /// problems, timeouts and a full queue are never retried.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            CopilotError::SessionFailed(msg) | CopilotError::SendFailed(msg) => {
+                let msg = msg.to_lowercase();
+                !FATAL_ERROR_MARKERS.iter().any(|m| msg.contains(m))
+                    && TRANSIENT_ERROR_MARKERS.iter().any(|m| msg.contains(m))
+            }
+            _ => false,
+        }
+    }
 }
 
 /// Context about a story for AI operations
@@ -64,7 +116,20 @@ pub struct DiscussionContext {
 pub struct CommentSummary {
     pub author: String,
     pub text_preview: String,
+    /// Direct replies
     pub reply_count: u32,
+    /// Replies at any depth in the comment's thread. HN doesn't expose
+    /// comment scores, so this stands in for how engaging a comment was.
+    #[serde(default)]
+    pub descendant_count: u32,
+}
+
+impl CommentSummary {
+    /// Engagement used to rank comments: the thread size, or the direct
+    /// replies when no thread size was given.
+    fn engagement(&self) -> u32 {
+        self.descendant_count.max(self.reply_count)
+    }
 }
 
 /// Context for drafting a reply
@@ -80,6 +145,109 @@ pub struct ReplyContext {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssistantResponse {
     pub content: String,
+    /// Parsed summary, present only when a structured summary was requested
+    /// and the model's reply was valid JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured: Option<StructuredSummary>,
+    /// For discussion analysis, how many of the supplied comments made it
+    /// into the prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments_included: Option<usize>,
+}
+
+/// A summary broken into fields the frontend can lay out itself
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructuredSummary {
+    pub tldr: String,
+    #[serde(default)]
+    pub key_points: Vec<String>,
+    /// "positive", "negative", "neutral" or "mixed"
+    #[serde(default)]
+    pub sentiment: String,
+}
+
+/// Instructions appended to the summary prompt in structured mode
+const STRUCTURED_SUMMARY_INSTRUCTIONS: &str = r#"
+
+Respond with ONLY a JSON object, no markdown and no other text, in exactly this shape:
+{"tldr": "<one or two sentence summary>", "key_points": ["<point>", "..."], "sentiment": "<positive|negative|neutral|mixed>"}"#;
+
+/// Parse a structured summary out of the model's reply.
+///
+/// Tolerates a surrounding ```json fence or stray text around the object.
+/// Returns `None` when no usable JSON object is found, so the caller can
+/// fall back to the raw text.
+pub fn parse_structured_summary(raw: &str) -> Option<StructuredSummary> {
+    let start = raw.find('{')?;
+    let end = raw.rfind('}')?;
+    if end < start {
+        return None;
+    }
+
+    let mut summary: StructuredSummary = serde_json::from_str(&raw[start..=end]).ok()?;
+    summary.tldr = summary.tldr.trim().to_string();
+    if summary.tldr.is_empty() {
+        return None;
+    }
+    summary.key_points = summary
+        .key_points
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    summary.sentiment = summary.sentiment.trim().to_lowercase();
+    Some(summary)
+}
+
+impl StructuredSummary {
+    /// Render as Markdown, for consumers that only display `content`
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("**TL;DR:** {}\n", self.tldr);
+        if !self.key_points.is_empty() {
+            out.push('\n');
+            for point in &self.key_points {
+                out.push_str(&format!("- {}\n", point));
+            }
+        }
+        if !self.sentiment.is_empty() {
+            out.push_str(&format!("\n**Sentiment:** {}\n", self.sentiment));
+        }
+        out
+    }
+}
+
+impl AssistantResponse {
+    /// Turn a raw structured-mode reply into a response.
+    ///
+    /// When the reply parses, `content` holds a Markdown rendering of it;
+    /// otherwise the raw text is passed through unchanged.
+    fn from_structured_reply(raw: String) -> Self {
+        match parse_structured_summary(&raw) {
+            Some(summary) => Self {
+                content: summary.to_markdown(),
+                structured: Some(summary),
+                comments_included: None,
+            },
+            None => {
+                warn!("Structured summary was not valid JSON; returning raw text");
+                Self {
+                    content: raw,
+                    structured: None,
+                    comments_included: None,
+                }
+            }
+        }
+    }
+}
+
+/// Result of a [`CopilotService::ping`] round trip
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PingResult {
+    pub ok: bool,
+    /// Time until the reply arrived, or until the ping gave up
+    pub latency_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 /// Result of checking Copilot CLI availability
@@ -173,11 +341,343 @@ pub fn check_availability() -> CopilotAvailability {
     }
 }
 
+/// Default number of top-level comments included in a discussion analysis
+pub const DEFAULT_MAX_DISCUSSION_COMMENTS: usize = 20;
+
+/// Total characters of comment previews allowed in a discussion prompt
+pub const DISCUSSION_PREVIEW_CHAR_BUDGET: usize = 12_000;
+
+/// Pick the comments to include in a discussion analysis prompt.
+///
+/// Keeps the `max_comments` comments with the most engagement (see
+/// [`CommentSummary::descendant_count`]; ties keep thread order). If their previews together exceed `char_budget`
+/// characters, each preview is cut to its proportional share of the budget.
+fn select_discussion_comments(
+    comments: &[CommentSummary],
+    max_comments: usize,
+    char_budget: usize,
+) -> Vec<CommentSummary> {
+    let mut selected: Vec<CommentSummary> = comments.to_vec();
+    selected.sort_by_key(|c| std::cmp::Reverse(c.engagement()));
+    selected.truncate(max_comments);
+
+    let total: usize = selected
+        .iter()
+        .map(|c| c.text_preview.chars().count())
+        .sum();
+    if total <= char_budget {
+        return selected;
+    }
+
+    for comment in &mut selected {
+        let len = comment.text_preview.chars().count();
+        let share = len * char_budget / total;
+        if share < len {
+            let mut truncated: String = comment.text_preview.chars().take(share).collect();
+            truncated.push('…');
+            comment.text_preview = truncated;
+        }
+    }
+    selected
+}
+
+/// How long to wait for a normal assistant response
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How long a health-check ping may take before the assistant counts as hung
+pub const PING_TIMEOUT: Duration = Duration::from_secs(10);
+
+const PING_PROMPT: &str = "Reply with the single word: pong";
+
+/// Time `request`, giving up after `timeout`.
+async fn measure_round_trip<T>(
+    request: impl Future<Output = Result<T, CopilotError>>,
+    timeout: Duration,
+) -> PingResult {
+    let started = Instant::now();
+    let outcome = tokio::time::timeout(timeout, request).await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let error = match outcome {
+        Ok(Ok(_)) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(_) => Some(CopilotError::Timeout.to_string()),
+    };
+    if let Some(e) = &error {
+        warn!("Copilot ping failed after {}ms: {}", latency_ms, e);
+    }
+
+    PingResult {
+        ok: error.is_none(),
+        latency_ms,
+        error,
+    }
+}
+
+/// Default number of times a request is retried after a transient error
+pub const DEFAULT_MAX_RETRIES: usize = 2;
+
+/// Most retries [`CopilotService::set_max_retries`] accepts
+pub const MAX_RETRIES: usize = 5;
+
+/// Pause before the first retry; doubles with each further attempt
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest pause between retries, however many there are
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(8);
+
+/// Pause before retry number `tries` (1-based): `delay` doubled for each
+/// earlier retry, capped at [`MAX_RETRY_DELAY`] without overflowing.
+fn retry_delay(delay: Duration, tries: usize) -> Duration {
+    u32::try_from(tries.saturating_sub(1))
+        .ok()
+        .and_then(|exponent| 2u32.checked_pow(exponent))
+        .and_then(|factor| delay.checked_mul(factor))
+        .map_or(MAX_RETRY_DELAY, |d| d.min(MAX_RETRY_DELAY))
+}
+
+/// Run `attempt`, re-running it up to `retries` more times while it fails
+/// with a transient error.
+async fn with_retries<T, F, Fut>(
+    retries: usize,
+    delay: Duration,
+    mut attempt: F,
+) -> Result<T, CopilotError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, CopilotError>>,
+{
+    let mut tries = 0;
+    loop {
+        match attempt().await {
+            Err(e) if e.is_transient() && tries < retries => {
+                tries += 1;
+                warn!(
+                    "Transient Copilot error ({}), retrying ({}/{})",
+                    e, tries, retries
+                );
+                tokio::time::sleep(retry_delay(delay, tries)).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Default number of requests (running plus waiting) accepted at once
+pub const DEFAULT_MAX_QUEUE_DEPTH: usize = 4;
+
+/// Progress of a request through the Copilot queue.
+///
+/// Emitted to the frontend as a `copilot-queue` event when a request is
+/// queued and again when it starts (with `position` 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueEvent {
+    pub request_id: u64,
+    /// Number of requests ahead of this one
+    pub position: usize,
+}
+
+type QueueListener = Box<dyn Fn(QueueEvent) + Send + Sync>;
+
+/// Runs Copilot requests one at a time, in arrival order.
+///
+/// `tokio::sync::Mutex` is fair, so waiters acquire the turn in FIFO order.
+/// Requests beyond `max_depth` are rejected instead of piling up behind a
+/// slow session.
+struct RequestQueue {
+    turn: Mutex<()>,
+    depth: AtomicUsize,
+    max_depth: AtomicUsize,
+    next_id: AtomicU64,
+    listener: std::sync::RwLock<Option<QueueListener>>,
+}
+
+/// Holds a queue slot; releases it when dropped, even if the request fails
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl RequestQueue {
+    fn new(max_depth: usize) -> Self {
+        Self {
+            turn: Mutex::new(()),
+            depth: AtomicUsize::new(0),
+            max_depth: AtomicUsize::new(max_depth),
+            next_id: AtomicU64::new(1),
+            listener: std::sync::RwLock::new(None),
+        }
+    }
+
+    fn notify(&self, event: QueueEvent) {
+        let listener = self.listener.read().unwrap_or_else(|e| e.into_inner());
+        if let Some(listener) = listener.as_ref() {
+            listener(event);
+        }
+    }
+
+    /// Wait for this request's turn, then run it.
+    async fn run<F: Future>(&self, request: F) -> Result<F::Output, CopilotError> {
+        let max_depth = self.max_depth.load(Ordering::SeqCst);
+        let ahead = self.depth.fetch_add(1, Ordering::SeqCst);
+        let slot = QueueSlot(&self.depth);
+        if ahead >= max_depth {
+            warn!("Copilot queue full ({} requests), rejecting", ahead);
+            return Err(CopilotError::QueueFull(ahead));
+        }
+
+        let request_id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        if ahead > 0 {
+            debug!("Copilot request {} queued behind {}", request_id, ahead);
+            self.notify(QueueEvent {
+                request_id,
+                position: ahead,
+            });
+        }
+
+        let _turn = self.turn.lock().await;
+        self.notify(QueueEvent {
+            request_id,
+            position: 0,
+        });
+        let output = request.await;
+        drop(slot);
+        Ok(output)
+    }
+}
+
+/// Well-known language codes, so `"fr"` or `"fr-CA"` reads as "French"
+const LANGUAGE_NAMES: &[(&str, &str)] = &[
+    ("de", "German"),
+    ("en", "English"),
+    ("es", "Spanish"),
+    ("fr", "French"),
+    ("it", "Italian"),
+    ("ja", "Japanese"),
+    ("ko", "Korean"),
+    ("nl", "Dutch"),
+    ("pl", "Polish"),
+    ("pt", "Portuguese"),
+    ("ru", "Russian"),
+    ("sv", "Swedish"),
+    ("tr", "Turkish"),
+    ("uk", "Ukrainian"),
+    ("zh", "Chinese"),
+];
+
+/// The instruction telling the model which language to answer in.
+///
+/// Accepts a language name ("French") or a code ("fr", "fr-FR"). Returns
+/// `None` for a blank language, leaving the model's default (English).
+fn language_directive(language: Option<&str>) -> Option<String> {
+    let language = language.map(str::trim).filter(|l| !l.is_empty())?;
+    let primary = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(language)
+        .to_lowercase();
+    let name = LANGUAGE_NAMES
+        .iter()
+        .find(|(code, _)| *code == primary)
+        .map(|(_, name)| *name)
+        .unwrap_or(language);
+    Some(format!("Respond in {}.", name))
+}
+
+fn push_language_directive(prompt: &mut String, language: Option<&str>) {
+    if let Some(directive) = language_directive(language) {
+        prompt.push_str("\n\n");
+        prompt.push_str(&directive);
+    }
+}
+
+fn summary_prompt(context: &StoryContext, structured: bool, language: Option<&str>) -> String {
+    let mut prompt = format!(
+        "Summarize what this Hacker News story is likely about:\n\nTitle: {}\n",
+        context.title
+    );
+
+    if let Some(url) = &context.url {
+        prompt.push_str(&format!("URL: {}\n", url));
+    }
+    if let Some(domain) = &context.domain {
+        prompt.push_str(&format!("Domain: {}\n", domain));
+    }
+    if let Some(text) = &context.text {
+        prompt.push_str(&format!("\nStory text:\n{}\n", text));
+    }
+
+    prompt.push_str(&format!(
+        "\nScore: {} points, {} comments\n",
+        context.score, context.comment_count
+    ));
+
+    prompt.push_str("\nProvide a concise summary (2-3 paragraphs) of what this article likely covers based on the title and context. If it's an Ask HN or Show HN, explain the nature of the post.");
+    push_language_directive(&mut prompt, language);
+
+    if structured {
+        prompt.push_str(STRUCTURED_SUMMARY_INSTRUCTIONS);
+    }
+    prompt
+}
+
+fn discussion_prompt(
+    context: &DiscussionContext,
+    comments: &[CommentSummary],
+    language: Option<&str>,
+) -> String {
+    let mut prompt = format!(
+        "Analyze this Hacker News discussion:\n\nStory: {}\nTotal comments: {}\n\nTop-level comments, most engaged first (reply counts show how many readers responded):\n",
+        context.story_title, context.comment_count
+    );
+
+    for (i, comment) in comments.iter().enumerate() {
+        prompt.push_str(&format!(
+            "\n{}. {} ({} replies) [{} comments in thread]:\n\"{}\"\n",
+            i + 1,
+            comment.author,
+            comment.reply_count,
+            comment.engagement(),
+            comment.text_preview
+        ));
+    }
+
+    prompt.push_str("\nProvide a brief analysis of this discussion, weighted by replies (a viewpoint drawing large threads matters more than one nobody answered):\n1. What are the main viewpoints or themes?\n2. Are there areas of agreement or contention?\n3. Any particularly notable perspectives?");
+    push_language_directive(&mut prompt, language);
+    prompt
+}
+
+fn explain_prompt(text: &str, context: Option<&str>, language: Option<&str>) -> String {
+    let mut prompt = if let Some(ctx) = context {
+        format!(
+            "Explain this term/concept in the context of a Hacker News discussion:\n\nTerm: \"{}\"\nContext: {}\n\nProvide a brief explanation (1-2 paragraphs) that would help a technically-competent reader who may not be familiar with this specific topic.",
+            text, ctx
+        )
+    } else {
+        format!(
+            "Explain this term/concept for a Hacker News reader:\n\nTerm: \"{}\"\n\nProvide a brief explanation (1-2 paragraphs) that would help a technically-competent reader.",
+            text
+        )
+    };
+
+    push_language_directive(&mut prompt, language);
+    prompt
+}
+
 /// The Copilot service manages client lifecycle and sessions
 pub struct CopilotService {
     client: Arc<Mutex<Option<Client>>>,
     is_running: Arc<RwLock<bool>>,
     system_prompt: String,
+    queue: RequestQueue,
+    max_retries: AtomicUsize,
+    /// Language used when a request doesn't name one (the UI language)
+    output_language: std::sync::RwLock<Option<String>>,
+    transcript: TranscriptLog,
 }
 
 impl CopilotService {
@@ -214,9 +714,68 @@ impl CopilotService {
             client: Arc::new(Mutex::new(None)),
             is_running: Arc::new(RwLock::new(false)),
             system_prompt,
+            queue: RequestQueue::new(DEFAULT_MAX_QUEUE_DEPTH),
+            max_retries: AtomicUsize::new(DEFAULT_MAX_RETRIES),
+            output_language: std::sync::RwLock::new(None),
+            transcript: TranscriptLog::new(TranscriptLog::default_path()),
         }
     }
 
+    /// The opt-in prompt/response transcript
+    pub fn transcript(&self) -> &TranscriptLog {
+        &self.transcript
+    }
+
+    /// Set the language responses default to when a request doesn't name one
+    pub fn set_output_language(&self, language: Option<String>) {
+        let language = language.filter(|l| !l.trim().is_empty());
+        info!("Copilot default output language set to {:?}", language);
+        *self
+            .output_language
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = language;
+    }
+
+    /// A request's own language, else the default
+    fn resolve_output_language(&self, requested: Option<&str>) -> Option<String> {
+        requested
+            .filter(|l| !l.trim().is_empty())
+            .map(str::to_string)
+            .or_else(|| {
+                self.output_language
+                    .read()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .clone()
+            })
+    }
+
+    /// Set how many times a request is retried after a transient error,
+    /// at most [`MAX_RETRIES`]
+    pub fn set_max_retries(&self, retries: usize) {
+        let retries = retries.min(MAX_RETRIES);
+        self.max_retries.store(retries, Ordering::SeqCst);
+        info!("Copilot max retries set to {}", retries);
+    }
+
+    /// Set how many requests may be running or waiting at once
+    pub fn set_max_queue_depth(&self, depth: usize) -> Result<(), String> {
+        if depth == 0 {
+            return Err("Queue depth must be at least 1".to_string());
+        }
+        self.queue.max_depth.store(depth, Ordering::SeqCst);
+        info!("Copilot max queue depth set to {}", depth);
+        Ok(())
+    }
+
+    /// Register a callback for [`QueueEvent`]s
+    pub fn set_queue_listener(&self, listener: impl Fn(QueueEvent) + Send + Sync + 'static) {
+        *self
+            .queue
+            .listener
+            .write()
+            .unwrap_or_else(|e| e.into_inner()) = Some(Box::new(listener));
+    }
+
     /// Initialize and start the Copilot client
     pub async fn start(&self) -> Result<(), CopilotError> {
         let mut client_lock = self.client.lock().await;
@@ -287,7 +846,46 @@ impl CopilotService {
     }
 
     /// Send a message to Copilot and get a response
+    ///
+    /// Requests are queued so only one session talks to the CLI at a time.
+    /// Each exchange is appended to the transcript when logging is enabled.
     async fn ask(&self, prompt: &str) -> Result<AssistantResponse, CopilotError> {
+        let sent_at = persist::now_millis();
+        let result = self
+            .queue
+            .run(self.ask_now(prompt, RESPONSE_TIMEOUT))
+            .await
+            .and_then(|r| r);
+
+        self.transcript.record(&TranscriptEntry {
+            sent_at,
+            received_at: persist::now_millis(),
+            prompt: prompt.to_string(),
+            response: result.as_ref().ok().map(|r| r.content.clone()),
+            error: result.as_ref().err().map(|e| e.to_string()),
+        });
+        result
+    }
+
+    /// Ask without queueing, retrying transient failures with a new session
+    async fn ask_now(
+        &self,
+        prompt: &str,
+        timeout: Duration,
+    ) -> Result<AssistantResponse, CopilotError> {
+        let retries = self.max_retries.load(Ordering::SeqCst);
+        with_retries(retries, RETRY_DELAY, || self.ask_once(prompt, timeout)).await
+    }
+
+    /// One session round trip.
+    ///
+    /// Each attempt collects deltas into its own buffer, so a partial reply
+    /// from a failed attempt never leaks into a retry.
+    async fn ask_once(
+        &self,
+        prompt: &str,
+        timeout: Duration,
+    ) -> Result<AssistantResponse, CopilotError> {
         let client_lock = self.client.lock().await;
         let client = client_lock.as_ref().ok_or(CopilotError::NotInitialized)?;
 
@@ -321,7 +919,7 @@ impl CopilotService {
         let mut response_content = String::new();
 
         loop {
-            match tokio::time::timeout(std::time::Duration::from_secs(60), events.recv()).await {
+            match tokio::time::timeout(timeout, events.recv()).await {
                 Ok(Ok(event)) => {
                     debug!("Event: {:?}", std::mem::discriminant(&event.data));
                     match &event.data {
@@ -361,62 +959,78 @@ impl CopilotService {
 
         Ok(AssistantResponse {
             content: response_content,
+            structured: None,
+            comments_included: None,
         })
     }
 
+    /// Send a trivial prompt and measure how long the reply takes.
+    ///
+    /// Gives up after [`PING_TIMEOUT`] (including time spent queued) and
+    /// reports `ok: false` instead of hanging.
+    pub async fn ping(&self) -> PingResult {
+        measure_round_trip(
+            async {
+                self.queue
+                    .run(self.ask_now(PING_PROMPT, PING_TIMEOUT))
+                    .await?
+            },
+            PING_TIMEOUT,
+        )
+        .await
+    }
+
     /// Summarize an article based on its metadata
+    ///
+    /// With `structured`, the model is asked for JSON and the reply is
+    /// parsed into [`StructuredSummary`], falling back to the raw text.
     pub async fn summarize_article(
         &self,
         context: StoryContext,
+        structured: bool,
+        output_language: Option<&str>,
     ) -> Result<AssistantResponse, CopilotError> {
-        let mut prompt = format!(
-            "Summarize what this Hacker News story is likely about:\n\nTitle: {}\n",
-            context.title
-        );
+        let language = self.resolve_output_language(output_language);
+        let prompt = summary_prompt(&context, structured, language.as_deref());
 
-        if let Some(url) = &context.url {
-            prompt.push_str(&format!("URL: {}\n", url));
-        }
-        if let Some(domain) = &context.domain {
-            prompt.push_str(&format!("Domain: {}\n", domain));
-        }
-        if let Some(text) = &context.text {
-            prompt.push_str(&format!("\nStory text:\n{}\n", text));
+        let response = self.ask(&prompt).await?;
+        if !structured {
+            return Ok(response);
         }
-
-        prompt.push_str(&format!(
-            "\nScore: {} points, {} comments\n",
-            context.score, context.comment_count
-        ));
-
-        prompt.push_str("\nProvide a concise summary (2-3 paragraphs) of what this article likely covers based on the title and context. If it's an Ask HN or Show HN, explain the nature of the post.");
-
-        self.ask(&prompt).await
+        Ok(AssistantResponse::from_structured_reply(response.content))
     }
 
     /// Analyze a discussion thread
+    ///
+    /// At most `max_comments` top-level comments (default
+    /// [`DEFAULT_MAX_DISCUSSION_COMMENTS`]) are sent, favouring those with
+    /// the most replies, and previews are trimmed to fit
+    /// [`DISCUSSION_PREVIEW_CHAR_BUDGET`].
     pub async fn analyze_discussion(
         &self,
         context: DiscussionContext,
+        max_comments: Option<usize>,
+        output_language: Option<&str>,
     ) -> Result<AssistantResponse, CopilotError> {
-        let mut prompt = format!(
-            "Analyze this Hacker News discussion:\n\nStory: {}\nTotal comments: {}\n\nTop-level comments:\n",
-            context.story_title, context.comment_count
+        let comments = select_discussion_comments(
+            &context.top_comments,
+            max_comments.unwrap_or(DEFAULT_MAX_DISCUSSION_COMMENTS),
+            DISCUSSION_PREVIEW_CHAR_BUDGET,
         );
-
-        for (i, comment) in context.top_comments.iter().enumerate() {
-            prompt.push_str(&format!(
-                "\n{}. {} ({} replies):\n\"{}\"\n",
-                i + 1,
-                comment.author,
-                comment.reply_count,
-                comment.text_preview
-            ));
+        if comments.len() < context.top_comments.len() {
+            debug!(
+                "Analyzing {} of {} top-level comments",
+                comments.len(),
+                context.top_comments.len()
+            );
         }
 
-        prompt.push_str("\nProvide a brief analysis of this discussion:\n1. What are the main viewpoints or themes?\n2. Are there areas of agreement or contention?\n3. Any particularly notable perspectives?");
+        let language = self.resolve_output_language(output_language);
+        let prompt = discussion_prompt(&context, &comments, language.as_deref());
 
-        self.ask(&prompt).await
+        let mut response = self.ask(&prompt).await?;
+        response.comments_included = Some(comments.len());
+        Ok(response)
     }
 
     /// Explain a term or concept
@@ -424,18 +1038,10 @@ impl CopilotService {
         &self,
         text: &str,
         context: Option<&str>,
+        output_language: Option<&str>,
     ) -> Result<AssistantResponse, CopilotError> {
-        let prompt = if let Some(ctx) = context {
-            format!(
-                "Explain this term/concept in the context of a Hacker News discussion:\n\nTerm: \"{}\"\nContext: {}\n\nProvide a brief explanation (1-2 paragraphs) that would help a technically-competent reader who may not be familiar with this specific topic.",
-                text, ctx
-            )
-        } else {
-            format!(
-                "Explain this term/concept for a Hacker News reader:\n\nTerm: \"{}\"\n\nProvide a brief explanation (1-2 paragraphs) that would help a technically-competent reader.",
-                text
-            )
-        };
+        let language = self.resolve_output_language(output_language);
+        let prompt = explain_prompt(text, context, language.as_deref());
 
         self.ask(&prompt).await
     }
@@ -523,3 +1129,458 @@ pub async fn shutdown() -> Result<(), CopilotError> {
     let service = get_service();
     service.stop().await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ===== Request Queue Tests =====
+
+    #[tokio::test]
+    async fn concurrent_requests_run_sequentially() {
+        let queue = RequestQueue::new(DEFAULT_MAX_QUEUE_DEPTH);
+        let log = std::sync::Mutex::new(Vec::new());
+
+        let request = |name: &'static str| {
+            let log = &log;
+            async move {
+                log.lock().unwrap().push(format!("{name} start"));
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                log.lock().unwrap().push(format!("{name} end"));
+            }
+        };
+
+        let (first, second) = tokio::join!(queue.run(request("a")), queue.run(request("b")));
+        assert!(first.is_ok() && second.is_ok());
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["a start", "a end", "b start", "b end"]
+        );
+        assert_eq!(queue.depth.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn requests_beyond_max_depth_are_rejected() {
+        let queue = RequestQueue::new(1);
+        let (release, wait) = tokio::sync::oneshot::channel::<()>();
+
+        let (first, second) = tokio::join!(
+            queue.run(async move {
+                let _ = wait.await;
+            }),
+            async {
+                let rejected = queue.run(async {}).await;
+                release.send(()).unwrap();
+                rejected
+            }
+        );
+
+        assert!(first.is_ok());
+        assert!(matches!(second, Err(CopilotError::QueueFull(1))));
+        assert!(queue.run(async {}).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn queued_requests_report_their_position() {
+        let queue = RequestQueue::new(DEFAULT_MAX_QUEUE_DEPTH);
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        *queue.listener.write().unwrap() = Some(Box::new(move |e: QueueEvent| {
+            sink.lock().unwrap().push(e.position)
+        }));
+
+        let slow = || tokio::time::sleep(Duration::from_millis(10));
+        let _ = tokio::join!(queue.run(slow()), queue.run(slow()), queue.run(slow()));
+
+        // First starts immediately; the others are queued at 1 and 2, then start
+        assert_eq!(*events.lock().unwrap(), vec![0, 1, 2, 0, 0]);
+    }
+
+    // ===== Output Language Tests =====
+
+    fn story() -> StoryContext {
+        StoryContext {
+            title: "Show HN: A tiny database".to_string(),
+            score: 42,
+            comment_count: 7,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn language_directive_names_the_language() {
+        assert_eq!(
+            language_directive(Some("fr")).as_deref(),
+            Some("Respond in French.")
+        );
+        assert_eq!(
+            language_directive(Some("pt-BR")).as_deref(),
+            Some("Respond in Portuguese.")
+        );
+        assert_eq!(
+            language_directive(Some("Esperanto")).as_deref(),
+            Some("Respond in Esperanto.")
+        );
+        assert_eq!(language_directive(Some("  ")), None);
+        assert_eq!(language_directive(None), None);
+    }
+
+    #[test]
+    fn summary_prompt_contains_language_directive() {
+        let prompt = summary_prompt(&story(), false, Some("fr"));
+        assert!(prompt.ends_with("Respond in French."));
+
+        assert!(!summary_prompt(&story(), false, None).contains("Respond in"));
+    }
+
+    #[test]
+    fn structured_summary_prompt_keeps_json_instructions_last() {
+        let prompt = summary_prompt(&story(), true, Some("de"));
+        let directive = prompt.find("Respond in German.").unwrap();
+        let json = prompt.find("Respond with ONLY a JSON object").unwrap();
+        assert!(directive < json);
+    }
+
+    #[test]
+    fn discussion_prompt_contains_language_directive() {
+        let context = DiscussionContext {
+            story_title: "A tiny database".to_string(),
+            comment_count: 1,
+            top_comments: Vec::new(),
+        };
+        let comments = vec![comment("alice", "Nice work", 0)];
+
+        let prompt = discussion_prompt(&context, &comments, Some("ja"));
+        assert!(prompt.contains("alice (0 replies)"));
+        assert!(prompt.ends_with("Respond in Japanese."));
+    }
+
+    #[test]
+    fn explain_prompt_contains_language_directive() {
+        let with_context = explain_prompt("CRDT", Some("collaborative editing"), Some("es"));
+        assert!(with_context.contains("Context: collaborative editing"));
+        assert!(with_context.ends_with("Respond in Spanish."));
+
+        let without_context = explain_prompt("CRDT", None, Some("es"));
+        assert!(without_context.ends_with("Respond in Spanish."));
+    }
+
+    #[test]
+    fn unset_language_falls_back_to_default() {
+        let service = CopilotService::new();
+        assert_eq!(service.resolve_output_language(None), None);
+
+        service.set_output_language(Some("fr".to_string()));
+        assert_eq!(service.resolve_output_language(None).as_deref(), Some("fr"));
+        assert_eq!(
+            service.resolve_output_language(Some("de")).as_deref(),
+            Some("de")
+        );
+        assert_eq!(
+            service.resolve_output_language(Some("")).as_deref(),
+            Some("fr")
+        );
+    }
+
+    // ===== Retry Tests =====
+
+    #[test]
+    fn classifies_transient_and_fatal_errors() {
+        assert!(CopilotError::SendFailed("Connection reset by peer".into()).is_transient());
+        assert!(CopilotError::SessionFailed("broken pipe".into()).is_transient());
+        assert!(CopilotError::SendFailed("Service temporarily unavailable".into()).is_transient());
+
+        assert!(!CopilotError::SendFailed("Authentication required".into()).is_transient());
+        assert!(
+            !CopilotError::SessionFailed("403 Forbidden: connection refused".into()).is_transient()
+        );
+        assert!(!CopilotError::SendFailed("model produced nonsense".into()).is_transient());
+        assert!(!CopilotError::NotAuthenticated.is_transient());
+        assert!(!CopilotError::Timeout.is_transient());
+        assert!(!CopilotError::QueueFull(4).is_transient());
+    }
+
+    #[tokio::test]
+    async fn transient_error_is_retried() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = with_retries(2, Duration::ZERO, || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(CopilotError::SendFailed("connection closed".into()))
+            } else {
+                Ok("reply")
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), "reply");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn fatal_error_is_not_retried() {
+        let attempts = AtomicUsize::new(0);
+
+        let result: Result<(), _> = with_retries(2, Duration::ZERO, || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(CopilotError::SendFailed("unauthorized".into()))
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn retries_stop_at_the_limit() {
+        let attempts = AtomicUsize::new(0);
+
+        let result: Result<(), _> = with_retries(2, Duration::ZERO, || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err(CopilotError::SessionFailed("connection reset".into()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(CopilotError::SessionFailed(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_cap() {
+        let base = Duration::from_millis(500);
+        assert_eq!(retry_delay(base, 1), base);
+        assert_eq!(retry_delay(base, 2), Duration::from_secs(1));
+        assert_eq!(retry_delay(base, 4), Duration::from_secs(4));
+        assert_eq!(retry_delay(base, 6), MAX_RETRY_DELAY);
+        // Past where the multiplier would overflow
+        assert_eq!(retry_delay(base, 33), MAX_RETRY_DELAY);
+        assert_eq!(retry_delay(base, usize::MAX), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn max_retries_is_clamped() {
+        let service = CopilotService::new();
+        service.set_max_retries(usize::MAX);
+        assert_eq!(service.max_retries.load(Ordering::SeqCst), MAX_RETRIES);
+    }
+
+    // ===== Ping Tests =====
+
+    #[tokio::test]
+    async fn ping_reports_success_latency() {
+        let result = measure_round_trip(
+            async {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                Ok::<_, CopilotError>(())
+            },
+            Duration::from_secs(1),
+        )
+        .await;
+
+        assert!(result.ok);
+        assert!(result.latency_ms >= 5);
+        assert!(result.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn ping_times_out_instead_of_hanging() {
+        let result = measure_round_trip(
+            std::future::pending::<Result<(), CopilotError>>(),
+            Duration::from_millis(20),
+        )
+        .await;
+
+        assert!(!result.ok);
+        assert!(result.latency_ms >= 20);
+        assert_eq!(result.error.as_deref(), Some("Session timeout"));
+    }
+
+    #[tokio::test]
+    async fn ping_reports_backend_errors() {
+        let result = measure_round_trip(
+            async { Err::<(), _>(CopilotError::NotInitialized) },
+            Duration::from_secs(1),
+        )
+        .await;
+
+        assert!(!result.ok);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Copilot service not initialized")
+        );
+    }
+
+    // ===== Discussion Comment Selection Tests =====
+
+    fn comment(author: &str, preview: &str, reply_count: u32) -> CommentSummary {
+        CommentSummary {
+            author: author.to_string(),
+            text_preview: preview.to_string(),
+            reply_count,
+            descendant_count: 0,
+        }
+    }
+
+    #[test]
+    fn selects_most_replied_comments_first() {
+        let comments = vec![
+            comment("a", "quiet", 1),
+            comment("b", "busy", 40),
+            comment("c", "tied first", 5),
+            comment("d", "tied second", 5),
+        ];
+
+        let selected = select_discussion_comments(&comments, 3, 1_000);
+        let authors: Vec<&str> = selected.iter().map(|c| c.author.as_str()).collect();
+        assert_eq!(authors, vec!["b", "c", "d"]);
+    }
+
+    #[test]
+    fn thread_size_outranks_direct_replies() {
+        let comments = vec![
+            comment("a", "many direct replies", 6),
+            CommentSummary {
+                descendant_count: 30,
+                ..comment("b", "deep thread", 2)
+            },
+        ];
+
+        let selected = select_discussion_comments(&comments, 2, 1_000);
+        assert_eq!(selected[0].author, "b");
+    }
+
+    #[test]
+    fn discussion_prompt_includes_engagement() {
+        let context = DiscussionContext {
+            story_title: "A tiny database".to_string(),
+            comment_count: 40,
+            top_comments: Vec::new(),
+        };
+        let comments = vec![
+            CommentSummary {
+                descendant_count: 31,
+                ..comment("alice", "Nice work", 4)
+            },
+            comment("bob", "Meh", 0),
+        ];
+
+        let prompt = discussion_prompt(&context, &comments, None);
+        assert!(prompt.contains("1. alice (4 replies) [31 comments in thread]:\n\"Nice work\""));
+        assert!(prompt.contains("2. bob (0 replies) [0 comments in thread]:"));
+        assert!(prompt.contains("weighted by replies"));
+    }
+
+    #[test]
+    fn previews_within_budget_are_untouched() {
+        let comments = vec![comment("a", "short", 1), comment("b", "also short", 2)];
+
+        let selected = select_discussion_comments(&comments, 10, 1_000);
+        assert_eq!(selected.len(), 2);
+        assert!(selected.iter().all(|c| !c.text_preview.ends_with('…')));
+    }
+
+    #[test]
+    fn over_budget_previews_are_truncated_proportionally() {
+        let comments: Vec<CommentSummary> = (0..500)
+            .map(|i| {
+                let len = if i % 2 == 0 { 400 } else { 100 };
+                comment(&format!("user{i}"), &"x".repeat(len), i)
+            })
+            .collect();
+
+        let selected = select_discussion_comments(&comments, 20, 1_000);
+        assert_eq!(selected.len(), 20);
+        assert_eq!(selected[0].author, "user499");
+
+        let total: usize = selected
+            .iter()
+            .map(|c| c.text_preview.trim_end_matches('…').chars().count())
+            .sum();
+        assert!(total <= 1_000);
+
+        // 10 long (400) and 10 short (100) previews: 5000 chars into 1000
+        let long = selected.iter().find(|c| c.reply_count % 2 == 0).unwrap();
+        let short = selected.iter().find(|c| c.reply_count % 2 == 1).unwrap();
+        assert_eq!(long.text_preview, format!("{}…", "x".repeat(80)));
+        assert_eq!(short.text_preview, format!("{}…", "x".repeat(20)));
+    }
+
+    #[test]
+    fn truncation_respects_multibyte_characters() {
+        let comments = vec![comment("a", &"é".repeat(50), 1)];
+
+        let selected = select_discussion_comments(&comments, 5, 10);
+        assert_eq!(selected[0].text_preview, format!("{}…", "é".repeat(10)));
+    }
+
+    // ===== Structured Summary Tests =====
+
+    #[test]
+    fn parses_plain_json_reply() {
+        let raw = r#"{"tldr": " A new Rust release. ", "key_points": ["Faster builds", " "], "sentiment": "Positive"}"#;
+
+        let summary = parse_structured_summary(raw).unwrap();
+        assert_eq!(summary.tldr, "A new Rust release.");
+        assert_eq!(summary.key_points, vec!["Faster builds"]);
+        assert_eq!(summary.sentiment, "positive");
+    }
+
+    #[test]
+    fn parses_fenced_json_with_surrounding_text() {
+        let raw = "Here is the summary:\n```json\n{\"tldr\": \"Short\", \"key_points\": [], \"sentiment\": \"neutral\"}\n```\n";
+
+        let summary = parse_structured_summary(raw).unwrap();
+        assert_eq!(summary.tldr, "Short");
+        assert!(summary.key_points.is_empty());
+    }
+
+    #[test]
+    fn missing_optional_fields_default() {
+        let summary = parse_structured_summary(r#"{"tldr": "Only a tldr"}"#).unwrap();
+        assert!(summary.key_points.is_empty());
+        assert_eq!(summary.sentiment, "");
+    }
+
+    #[test]
+    fn malformed_output_is_rejected() {
+        assert!(parse_structured_summary("## Summary\nJust markdown").is_none());
+        assert!(parse_structured_summary(r#"{"tldr": "unterminated"#).is_none());
+        assert!(parse_structured_summary(r#"{"key_points": ["no tldr"]}"#).is_none());
+        assert!(parse_structured_summary(r#"{"tldr": "   "}"#).is_none());
+        assert!(parse_structured_summary("} backwards {").is_none());
+    }
+
+    #[test]
+    fn structured_reply_renders_markdown_content() {
+        let raw = r#"{"tldr": "Short", "key_points": ["One", "Two"], "sentiment": "mixed"}"#;
+
+        let response = AssistantResponse::from_structured_reply(raw.to_string());
+        assert!(response.structured.is_some());
+        assert_eq!(
+            response.content,
+            "**TL;DR:** Short\n\n- One\n- Two\n\n**Sentiment:** mixed\n"
+        );
+    }
+
+    #[test]
+    fn malformed_reply_falls_back_to_raw_text() {
+        let raw = "The model ignored the JSON instructions.".to_string();
+
+        let response = AssistantResponse::from_structured_reply(raw.clone());
+        assert!(response.structured.is_none());
+        assert_eq!(response.content, raw);
+    }
+
+    #[test]
+    fn plain_response_omits_structured_field() {
+        let response = AssistantResponse {
+            content: "text".to_string(),
+            structured: None,
+            comments_included: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&response).unwrap(),
+            r#"{"content":"text"}"#
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! Opt-in transcript of Copilot exchanges, for debugging and audit.
+//!
+//! When enabled, every prompt sent to the assistant and the reply (or
+//! error) that came back is appended as one JSON object per line to
+//! `copilot_transcript.jsonl` in the app's log directory.
+//!
+//! # Privacy
+//!
+//! Nothing is redacted. Prompts contain whatever the user asked about:
+//! story titles and text, comment previews, reply drafts and free-form
+//! questions. The file stays on the local machine and is never uploaded,
+//! but anyone with access to the user's data directory can read it.
+//! Logging is off by default and the file can be deleted at any time with
+//! [`TranscriptLog::clear`].
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::persist;
+
+/// One prompt/response exchange.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptEntry {
+    /// When the prompt was sent (Unix milliseconds)
+    pub sent_at: u64,
+    /// When the reply or error arrived (Unix milliseconds)
+    pub received_at: u64,
+    pub prompt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Appends exchanges to a JSONL file while enabled.
+pub struct TranscriptLog {
+    path: PathBuf,
+    enabled: AtomicBool,
+    /// Serializes appends so concurrent writers never interleave lines
+    write_lock: Mutex<()>,
+}
+
+impl TranscriptLog {
+    /// A disabled log that will write to `path` once enabled.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            enabled: AtomicBool::new(false),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Default location: `logs/copilot_transcript.jsonl` in the app data
+    /// directory.
+    pub fn default_path() -> PathBuf {
+        persist::data_dir()
+            .join("logs")
+            .join("copilot_transcript.jsonl")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+        info!(
+            "Copilot transcript logging {} ({})",
+            if enabled { "enabled" } else { "disabled" },
+            self.path.display()
+        );
+    }
+
+    /// Append an exchange if logging is enabled.
+    ///
+    /// Write failures are logged and otherwise ignored; a broken transcript
+    /// must never fail the request it describes.
+    pub fn record(&self, entry: &TranscriptEntry) {
+        if !self.is_enabled() {
+            return;
+        }
+        if let Err(e) = self.append(entry) {
+            warn!(
+                "Failed to write Copilot transcript {}: {}",
+                self.path.display(),
+                e
+            );
+        }
+    }
+
+    fn append(&self, entry: &TranscriptEntry) -> std::io::Result<()> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(&line)
+    }
+
+    /// Delete the transcript file.
+    pub fn clear(&self) -> std::io::Result<()> {
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+        match std::fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn log(dir: &TempDir) -> TranscriptLog {
+        TranscriptLog::new(dir.path().join("logs").join("copilot_transcript.jsonl"))
+    }
+
+    fn entry(prompt: &str, response: Result<&str, &str>) -> TranscriptEntry {
+        TranscriptEntry {
+            sent_at: 1_700_000_000_000,
+            received_at: 1_700_000_001_500,
+            prompt: prompt.to_string(),
+            response: response.ok().map(str::to_string),
+            error: response.err().map(str::to_string),
+        }
+    }
+
+    fn read_entries(log: &TranscriptLog) -> Vec<TranscriptEntry> {
+        std::fs::read_to_string(log.path())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn enabled_log_writes_one_json_line_per_exchange() {
+        let dir = TempDir::new().unwrap();
+        let log = log(&dir);
+        log.set_enabled(true);
+
+        let first = entry("Summarize this\nstory", Ok("It is about \"Rust\""));
+        let second = entry("Explain CRDT", Err("Session timeout"));
+        log.record(&first);
+        log.record(&second);
+
+        let raw = std::fs::read_to_string(log.path()).unwrap();
+        assert_eq!(raw.lines().count(), 2);
+        assert!(raw.ends_with('\n'));
+        assert_eq!(read_entries(&log), vec![first, second]);
+    }
+
+    #[test]
+    fn disabled_log_writes_nothing() {
+        let dir = TempDir::new().unwrap();
+        let log = log(&dir);
+
+        log.record(&entry("prompt", Ok("reply")));
+
+        assert!(!log.path().exists());
+    }
+
+    #[test]
+    fn entries_omit_missing_fields() {
+        let json = serde_json::to_string(&entry("p", Ok("r"))).unwrap();
+        assert_eq!(
+            json,
+            r#"{"sentAt":1700000000000,"receivedAt":1700000001500,"prompt":"p","response":"r"}"#
+        );
+    }
+
+    #[test]
+    fn clear_removes_the_transcript() {
+        let dir = TempDir::new().unwrap();
+        let log = log(&dir);
+        log.set_enabled(true);
+        log.record(&entry("prompt", Ok("reply")));
+
+        log.clear().unwrap();
+        assert!(!log.path().exists());
+        // Clearing an already-empty transcript is fine
+        log.clear().unwrap();
+
+        log.record(&entry("again", Ok("reply")));
+        assert_eq!(read_entries(&log).len(), 1);
+    }
+}
@@ -0,0 +1,274 @@
+//! Routing of `pastelhn://` deep links.
+//!
+//! Links are parsed in Rust and emitted to the main window as a typed
+//! `deep-link-target` event the frontend routes on:
+//!
+//! | Link | Target |
+//! |------|--------|
+//! | `pastelhn://item/<id>` (or `story/<id>`) | `{ type: "item", id }` |
+//! | `pastelhn://user/<name>` | `{ type: "user", username }` |
+//! | `pastelhn://feed/<feed>` | `{ type: "feed", feed }` |
+//! | `pastelhn://search?q=<query>` | `{ type: "search", query }` |
+//!
+//! Links that don't parse emit a `deep-link-error` event with the URL and
+//! the reason instead.
+//!
+//! Links received while the app runs are dispatched as they arrive. The
+//! links it was launched with are dispatched by [`dispatch_launch_links`]
+//! once the frontend is listening.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_deep_link::DeepLinkExt;
+use thiserror::Error;
+use tracing::{info, warn};
+use url::Url;
+
+/// The scheme registered for the app.
+pub const SCHEME: &str = "pastelhn";
+
+/// Feeds a `feed/<feed>` link can switch to (the API feeds plus "saved").
+pub const FEEDS: [&str; 7] = ["top", "new", "best", "ask", "show", "jobs", "saved"];
+
+/// Where a deep link points.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum DeepLinkTarget {
+    Item { id: u32 },
+    User { username: String },
+    Feed { feed: String },
+    Search { query: String },
+}
+
+/// Why a deep link couldn't be routed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DeepLinkError {
+    #[error("Not a valid URL")]
+    InvalidUrl,
+
+    #[error("Unsupported scheme: {0}")]
+    UnsupportedScheme(String),
+
+    #[error("Unknown route: {0}")]
+    UnknownRoute(String),
+
+    #[error("Invalid {route} link: {value:?}")]
+    InvalidValue { route: String, value: String },
+}
+
+/// Payload of the `deep-link-error` event.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeepLinkErrorEvent {
+    pub url: String,
+    pub message: String,
+}
+
+/// Parse a `pastelhn://` link into its target.
+///
+/// The route is the URL's host (`pastelhn://item/1` has host `item`) and is
+/// matched case-insensitively. Search links take the query from `q`, or
+/// from the path (`pastelhn://search/rust`) when `q` is absent.
+pub fn parse_deep_link(url: &str) -> Result<DeepLinkTarget, DeepLinkError> {
+    let parsed = Url::parse(url).map_err(|_| DeepLinkError::InvalidUrl)?;
+    if parsed.scheme() != SCHEME {
+        return Err(DeepLinkError::UnsupportedScheme(
+            parsed.scheme().to_string(),
+        ));
+    }
+
+    let route = parsed.host_str().unwrap_or_default().to_lowercase();
+    let value = urlencoding::decode(parsed.path().trim_matches('/'))
+        .map_err(|_| DeepLinkError::InvalidUrl)?
+        .into_owned();
+    let invalid = |value: String| DeepLinkError::InvalidValue {
+        route: route.clone(),
+        value,
+    };
+
+    match route.as_str() {
+        "item" | "story" => match value.parse::<u32>() {
+            Ok(id) if id > 0 => Ok(DeepLinkTarget::Item { id }),
+            _ => Err(invalid(value)),
+        },
+        "user" if !value.is_empty() => Ok(DeepLinkTarget::User { username: value }),
+        "user" => Err(invalid(value)),
+        "feed" => {
+            let feed = value.to_lowercase();
+            if FEEDS.contains(&feed.as_str()) {
+                Ok(DeepLinkTarget::Feed { feed })
+            } else {
+                Err(invalid(value))
+            }
+        }
+        "search" => {
+            let query = parsed
+                .query_pairs()
+                .find(|(key, _)| key == "q")
+                .map(|(_, q)| q.trim().to_string())
+                .unwrap_or_else(|| value.trim().to_string());
+            if query.is_empty() {
+                Err(invalid(query))
+            } else {
+                Ok(DeepLinkTarget::Search { query })
+            }
+        }
+        _ => Err(DeepLinkError::UnknownRoute(route.clone())),
+    }
+}
+
+/// Emit the event for a received deep link.
+pub fn dispatch(app: &AppHandle, url: &Url) {
+    info!("Deep link received: {}", url);
+
+    let result = match parse_deep_link(url.as_str()) {
+        Ok(target) => app.emit_to("main", "deep-link-target", target),
+        Err(e) => {
+            warn!("Ignoring deep link {}: {}", url, e);
+            app.emit_to(
+                "main",
+                "deep-link-error",
+                DeepLinkErrorEvent {
+                    url: url.to_string(),
+                    message: e.to_string(),
+                },
+            )
+        }
+    };
+    if let Err(e) = result {
+        warn!("Failed to emit deep link event for {}: {}", url, e);
+    }
+}
+
+/// Emit the events for the links the app was launched with.
+///
+/// Events emitted during setup would arrive before the frontend listens, so
+/// the frontend asks for these once it is ready.
+pub fn dispatch_launch_links(app: &AppHandle) {
+    match app.deep_link().get_current() {
+        Ok(urls) => {
+            for url in urls.unwrap_or_default() {
+                info!("App opened via deep link: {}", url);
+                dispatch(app, &url);
+            }
+        }
+        Err(e) => warn!("Failed to read launch deep links: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_item_links() {
+        assert_eq!(
+            parse_deep_link("pastelhn://item/12345"),
+            Ok(DeepLinkTarget::Item { id: 12345 })
+        );
+        assert_eq!(
+            parse_deep_link("pastelhn://story/42/"),
+            Ok(DeepLinkTarget::Item { id: 42 })
+        );
+        assert_eq!(
+            parse_deep_link("pastelhn://ITEM/7"),
+            Ok(DeepLinkTarget::Item { id: 7 })
+        );
+    }
+
+    #[test]
+    fn parses_user_links() {
+        assert_eq!(
+            parse_deep_link("pastelhn://user/dang"),
+            Ok(DeepLinkTarget::User {
+                username: "dang".to_string()
+            })
+        );
+        assert_eq!(
+            parse_deep_link("pastelhn://user/some%20one"),
+            Ok(DeepLinkTarget::User {
+                username: "some one".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_feed_links() {
+        assert_eq!(
+            parse_deep_link("pastelhn://feed/best"),
+            Ok(DeepLinkTarget::Feed {
+                feed: "best".to_string()
+            })
+        );
+        assert_eq!(
+            parse_deep_link("pastelhn://feed/Saved"),
+            Ok(DeepLinkTarget::Feed {
+                feed: "saved".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_search_links() {
+        assert_eq!(
+            parse_deep_link("pastelhn://search?q=rust%20async"),
+            Ok(DeepLinkTarget::Search {
+                query: "rust async".to_string()
+            })
+        );
+        assert_eq!(
+            parse_deep_link("pastelhn://search/tauri"),
+            Ok(DeepLinkTarget::Search {
+                query: "tauri".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_links() {
+        assert_eq!(parse_deep_link("not a url"), Err(DeepLinkError::InvalidUrl));
+        assert_eq!(
+            parse_deep_link("https://item/1"),
+            Err(DeepLinkError::UnsupportedScheme("https".to_string()))
+        );
+        assert_eq!(
+            parse_deep_link("pastelhn://settings/theme"),
+            Err(DeepLinkError::UnknownRoute("settings".to_string()))
+        );
+        assert!(matches!(
+            parse_deep_link("pastelhn://item/abc"),
+            Err(DeepLinkError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse_deep_link("pastelhn://item/0"),
+            Err(DeepLinkError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse_deep_link("pastelhn://user/"),
+            Err(DeepLinkError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse_deep_link("pastelhn://feed/frontpage"),
+            Err(DeepLinkError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse_deep_link("pastelhn://search?q=%20"),
+            Err(DeepLinkError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn targets_serialize_with_type_tag() {
+        let json = serde_json::to_value(DeepLinkTarget::Item { id: 1 }).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "item", "id": 1 }));
+
+        let json = serde_json::to_value(DeepLinkTarget::Search {
+            query: "rust".to_string(),
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "type": "search", "query": "rust" })
+        );
+    }
+}
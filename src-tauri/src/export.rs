@@ -0,0 +1,403 @@
+//! Rendering HN data into formats for other tools.
+//!
+//! These are pure functions over already-fetched items; the commands in
+//! [`crate::commands`] do the fetching and hand the results here.
+//!
+//! # Formats
+//!
+//! - [`render_feed_rss`] - RSS 2.0 document for a story feed
+//! - [`render_story_markdown`] - Shareable Markdown blurb for a story
+
+use crate::links::hn_item_url;
+use crate::types::{ArticleContent, HNItem, StoryFeed};
+
+/// Human-readable feed title and its page on the HN website.
+fn feed_info(feed: StoryFeed) -> (&'static str, &'static str) {
+    match feed {
+        StoryFeed::Top => ("Top Stories", "news"),
+        StoryFeed::New => ("New Stories", "newest"),
+        StoryFeed::Best => ("Best Stories", "best"),
+        StoryFeed::Ask => ("Ask HN", "ask"),
+        StoryFeed::Show => ("Show HN", "show"),
+        StoryFeed::Jobs => ("Jobs", "jobs"),
+    }
+}
+
+/// Escape text for use in XML element content and attribute values.
+pub(crate) fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tab, CR and LF are invalid in XML 1.0
+            c if c.is_control() && c != '\t' && c != '\n' && c != '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Format a Unix timestamp as an RFC 2822 date in UTC, as RSS requires.
+///
+/// Example: `Wed, 24 Jan 2024 18:00:00 +0000`
+fn rfc2822_date(timestamp: u64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let days = timestamp / 86_400;
+    let secs = timestamp % 86_400;
+
+    // Civil-from-days (Howard Hinnant), valid for all dates after 1970
+    let z = days as i64 + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} +0000",
+        WEEKDAYS[(days % 7) as usize],
+        day,
+        MONTHS[(month - 1) as usize],
+        year,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+/// Render stories from a feed as an RSS 2.0 document.
+///
+/// Each `<item>` links to the article (or the HN discussion for text posts),
+/// with the discussion in `<comments>`/`<guid>` and the author in
+/// `<dc:creator>`, since RSS `<author>` must be an email address. HN links
+/// point at the site at `hn_base`.
+pub fn render_feed_rss(feed: StoryFeed, stories: &[HNItem], hn_base: &str) -> String {
+    let (title, path) = feed_info(feed);
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n");
+    xml.push_str("<channel>\n");
+    xml.push_str(&format!("<title>Hacker News: {}</title>\n", title));
+    xml.push_str(&format!("<link>{}/{}</link>\n", hn_base, path));
+    xml.push_str(&format!(
+        "<description>{} from Hacker News</description>\n",
+        title
+    ));
+
+    for story in stories {
+        let discussion = hn_item_url(hn_base, story.id);
+        let link = story.url.clone().unwrap_or_else(|| discussion.clone());
+
+        let mut description = format!("{} points", story.score);
+        if let Some(by) = &story.by {
+            description.push_str(&format!(" by {}", by));
+        }
+        if let Some(domain) = story.domain() {
+            description.push_str(&format!(" ({})", domain));
+        }
+        description.push_str(&format!(" | {} comments", story.descendants));
+
+        xml.push_str("<item>\n");
+        xml.push_str(&format!(
+            "<title>{}</title>\n",
+            xml_escape(story.title.as_deref().unwrap_or("Untitled"))
+        ));
+        xml.push_str(&format!("<link>{}</link>\n", xml_escape(&link)));
+        xml.push_str(&format!("<comments>{}</comments>\n", discussion));
+        xml.push_str(&format!(
+            "<guid isPermaLink=\"true\">{}</guid>\n",
+            discussion
+        ));
+        xml.push_str(&format!(
+            "<pubDate>{}</pubDate>\n",
+            rfc2822_date(story.time)
+        ));
+        if let Some(by) = &story.by {
+            xml.push_str(&format!("<dc:creator>{}</dc:creator>\n", xml_escape(by)));
+        }
+        xml.push_str(&format!(
+            "<description>{}</description>\n",
+            xml_escape(&description)
+        ));
+        xml.push_str("</item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+/// Escape characters that would break Markdown link text.
+fn markdown_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '[' | ']' | '\\' | '*' | '_' | '`') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Render a story as a shareable Markdown blurb.
+///
+/// The title links to the article (or the discussion for text posts),
+/// followed by a stats line, the discussion link, and the article excerpt
+/// quoted if one was extracted. `article` also supplies the reading time.
+/// The discussion link points at the HN site at `hn_base`.
+///
+/// ```text
+/// [Title](https://example.com/post) (example.com)
+///
+/// 42 points by pg | 7 comments | 5 min read
+///
+/// Discussion: https://news.ycombinator.com/item?id=1
+///
+/// > Excerpt text
+/// ```
+pub fn render_story_markdown(
+    story: &HNItem,
+    article: Option<&ArticleContent>,
+    hn_base: &str,
+) -> String {
+    let discussion = hn_item_url(hn_base, story.id);
+    let link = story.url.as_deref().unwrap_or(&discussion);
+    let title = markdown_escape(story.title.as_deref().unwrap_or("Untitled"));
+
+    let mut md = format!("[{}](<{}>)", title, link);
+    if let Some(domain) = story.domain() {
+        md.push_str(&format!(" ({})", domain));
+    }
+    md.push_str("\n\n");
+
+    let mut stats = vec![format!("{} points", story.score)];
+    if let Some(by) = &story.by {
+        stats[0].push_str(&format!(" by {}", markdown_escape(by)));
+    }
+    stats.push(format!("{} comments", story.descendants));
+    if let Some(article) = article {
+        stats.push(format!("{} min read", article.reading_time_minutes()));
+    }
+    md.push_str(&stats.join(" | "));
+    md.push_str("\n\n");
+
+    md.push_str(&format!("Discussion: {}\n", discussion));
+
+    if let Some(excerpt) = article
+        .and_then(|a| a.excerpt.as_deref())
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+    {
+        md.push('\n');
+        for line in excerpt.lines() {
+            md.push_str(&format!("> {}\n", line));
+        }
+    }
+
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::links::HN_WEB_URL;
+    use crate::types::RawHNItem;
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    fn story(id: u32, title: &str, url: Option<&str>) -> HNItem {
+        let raw: RawHNItem = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "type": "story",
+            "by": "author",
+            "time": 1706119200,
+            "title": title,
+            "url": url,
+            "score": 42,
+            "descendants": 7
+        }))
+        .unwrap();
+        raw.into()
+    }
+
+    /// Parse `xml`, panicking if it's malformed, and count `<item>` elements.
+    fn count_items(xml: &str) -> usize {
+        let mut reader = Reader::from_str(xml);
+        let mut items = 0;
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(e)) if e.name().as_ref() == b"item" => items += 1,
+                Ok(Event::Eof) => break,
+                Ok(_) => {}
+                Err(e) => panic!("Malformed XML at {}: {}", reader.buffer_position(), e),
+            }
+        }
+        items
+    }
+
+    // ===== XML Escape Tests =====
+
+    #[test]
+    fn xml_escape_special_characters() {
+        assert_eq!(
+            xml_escape(r#"Tom & Jerry's <"show">"#),
+            "Tom &amp; Jerry&apos;s &lt;&quot;show&quot;&gt;"
+        );
+    }
+
+    #[test]
+    fn xml_escape_drops_invalid_control_characters() {
+        assert_eq!(xml_escape("a\u{0}b\u{1b}c\td"), "abc\td");
+    }
+
+    // ===== Date Tests =====
+
+    #[test]
+    fn rfc2822_date_epoch() {
+        assert_eq!(rfc2822_date(0), "Thu, 01 Jan 1970 00:00:00 +0000");
+    }
+
+    #[test]
+    fn rfc2822_date_leap_day() {
+        assert_eq!(rfc2822_date(1709210096), "Thu, 29 Feb 2024 12:34:56 +0000");
+    }
+
+    // ===== RSS Tests =====
+
+    #[test]
+    fn render_feed_rss_is_well_formed_with_one_item_per_story() {
+        let stories = vec![
+            story(
+                1,
+                "Rust <3 & C++ \"interop\"",
+                Some("https://example.com/a?x=1&y=2"),
+            ),
+            story(2, "Ask HN: What's next?", None),
+            story(3, "Plain title", Some("https://www.example.org/b")),
+        ];
+
+        let xml = render_feed_rss(StoryFeed::Top, &stories, HN_WEB_URL);
+
+        assert_eq!(count_items(&xml), 3);
+    }
+
+    #[test]
+    fn render_feed_rss_escapes_titles_and_links() {
+        let stories = vec![story(1, "A & B <c>", Some("https://example.com/?a=1&b=2"))];
+
+        let xml = render_feed_rss(StoryFeed::New, &stories, HN_WEB_URL);
+
+        assert!(xml.contains("<title>A &amp; B &lt;c&gt;</title>"));
+        assert!(xml.contains("<link>https://example.com/?a=1&amp;b=2</link>"));
+        assert!(xml.contains("<title>Hacker News: New Stories</title>"));
+    }
+
+    #[test]
+    fn render_feed_rss_text_posts_link_to_discussion() {
+        let xml = render_feed_rss(StoryFeed::Ask, &[story(99, "Ask HN: Hi", None)], HN_WEB_URL);
+
+        assert!(xml.contains("<link>https://news.ycombinator.com/item?id=99</link>"));
+        assert!(xml.contains("<dc:creator>author</dc:creator>"));
+        assert!(xml.contains("<pubDate>Wed, 24 Jan 2024 18:00:00 +0000</pubDate>"));
+    }
+
+    #[test]
+    fn render_feed_rss_description_includes_domain() {
+        let xml = render_feed_rss(
+            StoryFeed::Top,
+            &[story(1, "T", Some("https://www.example.org/b"))],
+            HN_WEB_URL,
+        );
+
+        assert!(xml
+            .contains("<description>42 points by author (example.org) | 7 comments</description>"));
+    }
+
+    #[test]
+    fn render_feed_rss_empty_feed() {
+        let xml = render_feed_rss(StoryFeed::Jobs, &[], HN_WEB_URL);
+        assert_eq!(count_items(&xml), 0);
+    }
+
+    #[test]
+    fn render_feed_rss_links_to_given_hn_base() {
+        let xml = render_feed_rss(
+            StoryFeed::Ask,
+            &[story(99, "Ask HN: Hi", None)],
+            "http://127.0.0.1:8080",
+        );
+
+        assert!(xml.contains("<link>http://127.0.0.1:8080/ask</link>"));
+        assert!(xml.contains("<comments>http://127.0.0.1:8080/item?id=99</comments>"));
+    }
+
+    // ===== Markdown Tests =====
+
+    fn article(excerpt: Option<&str>, word_count: usize) -> ArticleContent {
+        ArticleContent {
+            title: None,
+            content: String::new(),
+            text_content: String::new(),
+            byline: None,
+            excerpt: excerpt.map(String::from),
+            site_name: None,
+            lang: None,
+            word_count,
+            headings: vec![],
+            code_blocks: vec![],
+            links: vec![],
+            confidence: 1.0,
+            user_agent: None,
+        }
+    }
+
+    #[test]
+    fn render_story_markdown_link_story_with_article() {
+        let story = story(
+            1,
+            "Show HN: [Beta] my_project",
+            Some("https://www.example.com/post"),
+        );
+        let article = article(Some("A short summary."), 950);
+
+        let md = render_story_markdown(&story, Some(&article), HN_WEB_URL);
+
+        assert_eq!(
+            md,
+            "[Show HN: \\[Beta\\] my\\_project](<https://www.example.com/post>) (example.com)\n\
+             \n\
+             42 points by author | 7 comments | 5 min read\n\
+             \n\
+             Discussion: https://news.ycombinator.com/item?id=1\n\
+             \n\
+             > A short summary.\n"
+        );
+    }
+
+    #[test]
+    fn render_story_markdown_text_post_without_article() {
+        let story = story(2, "Ask HN: Favorite books?", None);
+
+        let md = render_story_markdown(&story, None, HN_WEB_URL);
+
+        assert_eq!(
+            md,
+            "[Ask HN: Favorite books?](<https://news.ycombinator.com/item?id=2>)\n\
+             \n\
+             42 points by author | 7 comments\n\
+             \n\
+             Discussion: https://news.ycombinator.com/item?id=2\n"
+        );
+    }
+}
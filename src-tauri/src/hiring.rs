@@ -0,0 +1,342 @@
+//! Structured extraction from monthly "Ask HN: Who is hiring?" threads.
+//!
+//! Each top-level comment in a hiring thread is a job posting, by convention
+//! starting with a header line of `|`-separated fields:
+//!
+//! ```text
+//! Acme | Senior Rust Engineer | Berlin, Germany | REMOTE (EU) | Full-time | https://acme.dev/careers
+//! ```
+//!
+//! Field order and separators vary wildly, so extraction is heuristic:
+//!
+//! - **company**: the first header field, minus a parenthesized website
+//!   (or the subject of "X is hiring" when there are no separators)
+//! - **location**: the first later field that isn't a role, employment type,
+//!   salary, URL, tech list, or bare remote/onsite marker
+//! - **remote**: "remote" in the header (or, failing that, the body), unless
+//!   negated ("no remote")
+//! - **technologies**: known language/framework/platform names in the text
+//! - **apply link**: a careers/ATS link, else an email address, else the
+//!   first link
+//!
+//! Comments where no company can be identified (meta discussion, replies to
+//! the thread itself) are skipped.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::html;
+use crate::types::HNItem;
+
+/// A job posting extracted from a hiring thread comment.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobPosting {
+    /// Comment ID of the posting
+    pub id: u32,
+    /// Username of the poster
+    pub by: Option<String>,
+    /// Posting time (Unix seconds)
+    pub time: u64,
+    /// Hiring company
+    pub company: String,
+    /// Office location, if stated
+    pub location: Option<String>,
+    /// Whether remote work is offered
+    pub remote: bool,
+    /// Recognized technologies, in [`TECHNOLOGIES`] order
+    pub technologies: Vec<String>,
+    /// Where to apply (URL or `mailto:` link)
+    pub apply_url: Option<String>,
+}
+
+/// Technology names recognized in postings, with their match patterns.
+///
+/// Ambiguous short names (Go, AWS) are matched case-sensitively.
+const TECHNOLOGIES: &[(&str, &str)] = &[
+    ("Rust", r"(?i)\brust\b"),
+    ("Go", r"\bGo\b|(?i:\bgolang\b)"),
+    ("Python", r"(?i)\bpython\b"),
+    ("TypeScript", r"(?i)\btypescript\b"),
+    ("JavaScript", r"(?i)\bjavascript\b"),
+    ("Ruby", r"(?i)\bruby\b"),
+    ("Java", r"(?i)\bjava\b"),
+    ("Kotlin", r"(?i)\bkotlin\b"),
+    ("Swift", r"(?i)\bswift\b"),
+    ("C++", r"(?i)\bc\+\+"),
+    ("C#", r"(?i)\bc#"),
+    ("Elixir", r"(?i)\belixir\b"),
+    ("Haskell", r"(?i)\bhaskell\b"),
+    ("Scala", r"(?i)\bscala\b"),
+    ("PHP", r"(?i)\bphp\b"),
+    ("React", r"(?i)\breact\b"),
+    ("Vue", r"(?i)\bvue(\.?js)?\b"),
+    ("Node.js", r"(?i:\bnode\.?js\b)|\bNode\b"),
+    ("Django", r"(?i)\bdjango\b"),
+    ("Rails", r"(?i)\brails\b"),
+    ("PostgreSQL", r"(?i)\bpostgres(ql)?\b"),
+    ("Kubernetes", r"(?i)\bkubernetes\b|\bk8s\b"),
+    ("Docker", r"(?i)\bdocker\b"),
+    ("Terraform", r"(?i)\bterraform\b"),
+    ("AWS", r"\bAWS\b"),
+    ("GCP", r"\bGCP\b"),
+];
+
+static TECH_PATTERNS: Lazy<Vec<(&'static str, Regex)>> = Lazy::new(|| {
+    TECHNOLOGIES
+        .iter()
+        .map(|(name, pattern)| (*name, Regex::new(pattern).unwrap()))
+        .collect()
+});
+
+static FIELD_SEPARATOR: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s*[|•]\s*").unwrap());
+static DASH_SEPARATOR: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+[-–—]\s+").unwrap());
+static IS_HIRING: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^(.{1,60}?)\s+(?:is|are)\s+hiring\b").unwrap());
+static WEBSITE_IN_PARENS: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\s*\((?:https?://)?[\w-]+(?:\.[\w-]+)+[^)]*\)").unwrap());
+
+static REMOTE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\bremote\b").unwrap());
+static NO_REMOTE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(?:no|not)\s+remote\b|\bremote:?\s*no\b").unwrap());
+
+static URL_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)https?://|\bwww\.|^\S+\.(?:com|io|dev|org|net|co|ai|app)\b").unwrap()
+});
+static SALARY_FIELD: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)[$€£]|\b\d+\s?k\b").unwrap());
+static EMPLOYMENT_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:full[- ]?time|part[- ]?time|contract(?:or)?s?|intern(?:ship)?s?|freelance|permanent|fte)\b",
+    )
+    .unwrap()
+});
+static ROLE_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:engineer|developer|designer|manager|scientist|lead|architect|devops|sre|analyst|head of|cto|director|founding|researcher|recruiter|programmer)",
+    )
+    .unwrap()
+});
+
+static APPLY_LINK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)apply|career|jobs|hiring|greenhouse\.io|lever\.co|ashbyhq\.com|workable\.com")
+        .unwrap()
+});
+static EMAIL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}").unwrap()
+});
+
+/// Words that only describe the work arrangement, not a place.
+const WORK_MODE_WORDS: &[&str] = &[
+    "remote", "ok", "friendly", "only", "fully", "first", "onsite", "on", "site", "hybrid", "in",
+    "office", "allowed", "possible",
+];
+
+/// Extract a job posting from a hiring thread comment.
+///
+/// Returns `None` for dead, deleted, or empty comments and for comments
+/// that don't look like a posting.
+pub fn parse_job_posting(comment: &HNItem) -> Option<JobPosting> {
+    if comment.dead || comment.deleted {
+        return None;
+    }
+    let text = comment.text.as_deref()?;
+
+    let paragraphs = html::paragraphs(text);
+    let header = paragraphs.first()?;
+    let plain = paragraphs.join("\n");
+
+    let fields = header_fields(header);
+    let company = if fields.len() > 1 {
+        clean_company(&fields[0])
+    } else {
+        IS_HIRING
+            .captures(header)
+            .map(|caps| clean_company(&caps[1]))?
+    };
+    if company.is_empty() {
+        return None;
+    }
+
+    let location = fields
+        .iter()
+        .skip(1)
+        .find(|field| is_location(field))
+        .cloned();
+
+    let remote = if REMOTE.is_match(header) {
+        !NO_REMOTE.is_match(header)
+    } else {
+        REMOTE.is_match(&plain) && !NO_REMOTE.is_match(&plain)
+    };
+
+    Some(JobPosting {
+        id: comment.id,
+        by: comment.by.clone(),
+        time: comment.time,
+        company,
+        location,
+        remote,
+        technologies: technologies(&plain),
+        apply_url: apply_url(text, &plain),
+    })
+}
+
+/// Split a header line into trimmed, non-empty fields.
+fn header_fields(header: &str) -> Vec<String> {
+    let split = |re: &Regex| -> Vec<String> {
+        re.split(header)
+            .map(|f| f.trim().to_string())
+            .filter(|f| !f.is_empty())
+            .collect()
+    };
+
+    let fields = split(&FIELD_SEPARATOR);
+    if fields.len() > 1 {
+        fields
+    } else {
+        split(&DASH_SEPARATOR)
+    }
+}
+
+fn clean_company(field: &str) -> String {
+    WEBSITE_IN_PARENS.replace_all(field, "").trim().to_string()
+}
+
+/// Whether a header field names a place rather than something else.
+fn is_location(field: &str) -> bool {
+    if URL_FIELD.is_match(field)
+        || SALARY_FIELD.is_match(field)
+        || EMPLOYMENT_FIELD.is_match(field)
+        || ROLE_FIELD.is_match(field)
+    {
+        return false;
+    }
+
+    let lower = field.to_lowercase();
+    let work_mode_only = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .all(|w| WORK_MODE_WORDS.contains(&w));
+    if work_mode_only {
+        return false;
+    }
+
+    // A list of technologies ("React, Node.js")
+    let without_tech = TECH_PATTERNS
+        .iter()
+        .fold(field.to_string(), |rest, (_, re)| {
+            re.replace_all(&rest, "").into_owned()
+        });
+    without_tech.chars().any(char::is_alphanumeric)
+}
+
+/// Recognized technologies mentioned in `text`, in [`TECHNOLOGIES`] order.
+fn technologies(text: &str) -> Vec<String> {
+    TECH_PATTERNS
+        .iter()
+        .filter(|(_, re)| re.is_match(text))
+        .map(|(name, _)| (*name).to_string())
+        .collect()
+}
+
+/// Pick the best application link from a posting.
+fn apply_url(html_text: &str, plain: &str) -> Option<String> {
+    let links = html::extract_links(html_text);
+
+    links
+        .iter()
+        .find(|link| !link.starts_with("mailto:") && APPLY_LINK.is_match(link))
+        .or_else(|| links.iter().find(|link| link.starts_with("mailto:")))
+        .cloned()
+        .or_else(|| EMAIL.find(plain).map(|m| format!("mailto:{}", m.as_str())))
+        .or_else(|| links.first().cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::RawHNItem;
+
+    const HIRING_THREAD: &str = include_str!("../tests/fixtures/hiring_thread.json");
+
+    fn fixture_comments() -> Vec<HNItem> {
+        let raw: Vec<RawHNItem> = serde_json::from_str(HIRING_THREAD).unwrap();
+        raw.into_iter().map(HNItem::from).collect()
+    }
+
+    fn posting(id: u32) -> Option<JobPosting> {
+        let comments = fixture_comments();
+        let comment = comments.iter().find(|c| c.id == id).unwrap();
+        parse_job_posting(comment)
+    }
+
+    #[test]
+    fn parses_pipe_separated_header() {
+        let job = posting(1001).unwrap();
+
+        assert_eq!(job.company, "Acme Robotics");
+        assert_eq!(job.location.as_deref(), Some("Berlin, Germany"));
+        assert!(job.remote);
+        assert_eq!(
+            job.technologies,
+            vec!["Rust", "TypeScript", "Kubernetes", "AWS"]
+        );
+        assert_eq!(job.apply_url.as_deref(), Some("https://acme.dev/careers"));
+        assert_eq!(job.by.as_deref(), Some("acme_hiring"));
+    }
+
+    #[test]
+    fn strips_website_from_company_and_honors_no_remote() {
+        let job = posting(1002).unwrap();
+
+        assert_eq!(job.company, "Globex");
+        assert_eq!(job.location.as_deref(), Some("New York, NY"));
+        assert!(!job.remote);
+        assert_eq!(job.technologies, vec!["Python", "Django", "PostgreSQL"]);
+        // The careers link wins over the company homepage
+        assert_eq!(
+            job.apply_url.as_deref(),
+            Some("https://jobs.lever.co/globex")
+        );
+    }
+
+    #[test]
+    fn parses_free_form_is_hiring_sentence() {
+        let job = posting(1003).unwrap();
+
+        assert_eq!(job.company, "Initech");
+        assert_eq!(job.location, None);
+        assert!(job.remote);
+        assert_eq!(job.technologies, vec!["Go"]);
+        assert_eq!(job.apply_url.as_deref(), Some("mailto:hiring@initech.io"));
+    }
+
+    #[test]
+    fn parses_bullet_separated_header_with_tech_field() {
+        let job = posting(1006).unwrap();
+
+        assert_eq!(job.company, "Hooli");
+        assert_eq!(job.location.as_deref(), Some("Palo Alto, CA or Remote"));
+        assert!(job.remote);
+        assert_eq!(job.technologies, vec!["React", "Node.js"]);
+        assert_eq!(job.apply_url, None);
+    }
+
+    #[test]
+    fn skips_deleted_and_non_posting_comments() {
+        assert_eq!(posting(1004), None);
+        assert_eq!(posting(1005), None);
+    }
+
+    #[test]
+    fn bare_work_mode_fields_are_not_locations() {
+        assert!(!is_location("REMOTE"));
+        assert!(!is_location("Onsite only"));
+        assert!(!is_location("Full-time"));
+        assert!(!is_location("$120k - $160k"));
+        assert!(!is_location("Staff Software Engineer"));
+        assert!(is_location("London, UK"));
+        assert!(is_location("REMOTE (US)"));
+    }
+}
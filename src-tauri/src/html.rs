@@ -0,0 +1,120 @@
+//! Helpers for the small HTML subset HN uses in item text.
+//!
+//! Comment and self-post `text` fields contain `<p>` paragraph breaks,
+//! `<a href>` links, `<i>`, `<pre><code>`, and entity-encoded characters
+//! (`&#x2F;`, `&quot;`, ...). These helpers turn that into plain text and
+//! pull out links without a full HTML parser.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static ENTITY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"&(#[xX][0-9a-fA-F]+|#[0-9]+|[a-zA-Z]+);").unwrap());
+static TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+static LINE_BREAK: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<br\s*/?>").unwrap());
+static PARAGRAPH: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<p\s*/?>").unwrap());
+static ANCHOR_HREF: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)<a\b[^>]*\bhref=["']([^"']+)["']"#).unwrap());
+
+/// Decode HTML character references (`&amp;`, `&#x27;`, `&#39;`, ...).
+///
+/// Unknown named entities are left as-is.
+pub fn decode_entities(text: &str) -> String {
+    ENTITY
+        .replace_all(text, |caps: &regex::Captures| {
+            let entity = &caps[1];
+            let decoded = if let Some(hex) = entity.strip_prefix("#x").or(entity.strip_prefix("#X"))
+            {
+                u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+            } else if let Some(dec) = entity.strip_prefix('#') {
+                dec.parse().ok().and_then(char::from_u32)
+            } else {
+                match entity {
+                    "amp" => Some('&'),
+                    "lt" => Some('<'),
+                    "gt" => Some('>'),
+                    "quot" => Some('"'),
+                    "apos" => Some('\''),
+                    "nbsp" => Some(' '),
+                    _ => None,
+                }
+            };
+            decoded.map_or_else(|| caps[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
+/// Split HN item text into plain-text paragraphs.
+///
+/// Tags are stripped and entities decoded. Empty paragraphs are dropped.
+pub fn paragraphs(html: &str) -> Vec<String> {
+    PARAGRAPH
+        .split(html)
+        .map(|p| decode_entities(&TAG.replace_all(p, "")).trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+/// Strip tags and decode entities, keeping whitespace as-is.
+///
+/// For preformatted text such as code, where [`paragraphs`] would lose
+/// indentation. `<br>` becomes a newline.
+pub fn strip_tags(html: &str) -> String {
+    let text = LINE_BREAK.replace_all(html, "\n");
+    decode_entities(&TAG.replace_all(&text, ""))
+}
+
+/// Convert HN item text to plain text, with paragraphs separated by blank lines.
+pub fn to_plain_text(html: &str) -> String {
+    paragraphs(html).join("\n\n")
+}
+
+/// Extract link targets from `<a href>` tags, in document order.
+pub fn extract_links(html: &str) -> Vec<String> {
+    ANCHOR_HREF
+        .captures_iter(html)
+        .map(|caps| decode_entities(&caps[1]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_entities_handles_named_and_numeric() {
+        assert_eq!(
+            decode_entities("a &amp; b &lt;c&gt; &quot;d&quot; &#x27;e&#39; https:&#x2F;&#x2F;x"),
+            "a & b <c> \"d\" 'e' https://x"
+        );
+        assert_eq!(decode_entities("&bogus; &#xZZ;"), "&bogus; &#xZZ;");
+    }
+
+    #[test]
+    fn paragraphs_strips_tags_and_splits_on_p() {
+        let html = "First <i>line</i><p>Second &amp; more<p><p>  <a href=\"x\">Third</a>";
+        assert_eq!(
+            paragraphs(html),
+            vec!["First line", "Second & more", "Third"]
+        );
+        assert_eq!(to_plain_text(html), "First line\n\nSecond & more\n\nThird");
+    }
+
+    #[test]
+    fn strip_tags_preserves_whitespace() {
+        assert_eq!(
+            strip_tags("  <b>if</b> a &lt; b {<br>\tgo()<br/>}"),
+            "  if a < b {\n\tgo()\n}"
+        );
+    }
+
+    #[test]
+    fn extract_links_decodes_hrefs_in_order() {
+        let html = r#"See <a href="https:&#x2F;&#x2F;a.com&#x2F;?x=1&amp;y=2" rel="nofollow">a</a> and <a href='https://b.org'>b</a>"#;
+        assert_eq!(
+            extract_links(html),
+            vec!["https://a.com/?x=1&y=2", "https://b.org"]
+        );
+        assert!(extract_links("no links here").is_empty());
+    }
+}
@@ -0,0 +1,358 @@
+//! Links to pages on the Hacker News website, and handling of outbound links.
+//!
+//! Centralizes URL building so the frontend and the export formats don't
+//! hand-assemble HN URLs. Also cleans tracking parameters from outbound
+//! links and collects the links found in extracted articles.
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::html;
+use crate::types::ArticleLink;
+
+/// Base URL of the HN website.
+///
+/// The default for `ClientConfig::hn_web_base_url`. The builders below take
+/// the base as an argument so links follow the configured site.
+pub const HN_WEB_URL: &str = "https://news.ycombinator.com";
+
+/// Discussion page for an item (story, comment, poll, job) on the HN site
+/// at `base`.
+pub fn hn_item_url(base: &str, id: u32) -> String {
+    format!("{}/item?id={}", base, id)
+}
+
+/// Profile page for a user on the HN site at `base`.
+pub fn hn_user_url(base: &str, user: &str) -> String {
+    format!("{}/user?id={}", base, urlencoding::encode(user))
+}
+
+/// Permalink to a comment on the HN site at `base`.
+///
+/// With the story ID, this links into the full thread and scrolls to the
+/// comment's anchor; without it, it links to the comment's own page.
+pub fn comment_permalink(base: &str, id: u32, story_id: Option<u32>) -> String {
+    match story_id {
+        Some(story_id) => format!("{}#{}", hn_item_url(base, story_id), id),
+        None => hn_item_url(base, id),
+    }
+}
+
+/// Query parameters that only record where a click came from, on any site.
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "gbraid", "wbraid", "msclkid", "yclid", "twclid", "igshid",
+    "mc_cid", "mc_eid", "_hsenc", "_hsmi", "mkt_tok",
+];
+
+/// Prefixes of tracking parameter families (`utm_source`, `utm_medium`, ...).
+const TRACKING_PREFIXES: &[&str] = &["utm_"];
+
+/// `(host, param)` pairs for names that are only tracking on that site.
+/// Elsewhere `ref` or `source` can be a real parameter, so they're kept.
+/// Hosts also match their subdomains.
+const SITE_TRACKING_PARAMS: &[(&str, &str)] = &[
+    ("youtube.com", "feature"),
+    ("youtube.com", "si"),
+    ("youtu.be", "feature"),
+    ("youtu.be", "si"),
+    ("spotify.com", "si"),
+    ("twitter.com", "ref_src"),
+    ("twitter.com", "ref_url"),
+    ("x.com", "ref_src"),
+    ("x.com", "ref_url"),
+    ("medium.com", "source"),
+    ("producthunt.com", "ref"),
+];
+
+/// Whether `host` is `site` or one of its subdomains.
+fn is_on_site(host: &str, site: &str) -> bool {
+    host == site
+        || host
+            .strip_suffix(site)
+            .is_some_and(|sub| sub.ends_with('.'))
+}
+
+fn is_tracking_param(host: &str, param: &str) -> bool {
+    let param = param.to_ascii_lowercase();
+    TRACKING_PARAMS.contains(&param.as_str())
+        || TRACKING_PREFIXES
+            .iter()
+            .any(|prefix| param.starts_with(prefix))
+        || SITE_TRACKING_PARAMS
+            .iter()
+            .any(|(site, name)| *name == param && is_on_site(host, site))
+}
+
+/// Remove tracking query parameters (`utm_*`, `fbclid`, ...) from a URL.
+///
+/// Site-specific names in [`SITE_TRACKING_PARAMS`] are only removed on
+/// their site. The fragment and every other parameter survive as written,
+/// percent-encoding included. Non-HTTP and unparseable URLs, and URLs with
+/// nothing to strip, are returned unchanged.
+pub fn canonicalize_url(url: &str) -> String {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return url.to_string();
+    };
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return url.to_string();
+    }
+    let Some(query) = parsed.query() else {
+        return url.to_string();
+    };
+
+    let host = parsed.host_str().unwrap_or_default().to_ascii_lowercase();
+    let segments: Vec<&str> = query.split('&').collect();
+    // Match on decoded names, but keep the raw segments
+    let kept: Vec<&str> = segments
+        .iter()
+        .copied()
+        .filter(|segment| {
+            let name = segment.split('=').next().unwrap_or_default();
+            let name: String = url::form_urlencoded::parse(name.as_bytes())
+                .map(|(name, _)| name.into_owned())
+                .collect();
+            !is_tracking_param(&host, &name)
+        })
+        .collect();
+
+    if kept.len() == segments.len() {
+        return url.to_string();
+    }
+    let kept = kept.join("&");
+    parsed.set_query((!kept.is_empty()).then_some(kept.as_str()));
+    parsed.into()
+}
+
+static ANCHOR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)<a\b[^>]*?\bhref\s*=\s*["']([^"']*)["'][^>]*>(.*?)</a\s*>"#).unwrap()
+});
+
+/// Host without a leading `www.`, for same-site comparisons.
+fn site_host(url: &url::Url) -> Option<String> {
+    let host = url.host_str()?.to_ascii_lowercase();
+    Some(host.strip_prefix("www.").unwrap_or(&host).to_string())
+}
+
+/// Collect the web links in an article's HTML content.
+///
+/// Hrefs are resolved against `base_url` (the article's own URL) and
+/// deduplicated ignoring fragments, keeping document order. In-page
+/// anchors and non-HTTP links (`mailto:`, `javascript:`) are skipped, as
+/// are links back to the article's own site when `exclude_same_host` is
+/// set. Link text falls back to the URL when the anchor has none.
+pub fn article_links(
+    content: &str,
+    base_url: &url::Url,
+    exclude_same_host: bool,
+) -> Vec<ArticleLink> {
+    let base_host = site_host(base_url);
+    let mut links: Vec<ArticleLink> = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for caps in ANCHOR.captures_iter(content) {
+        let href = html::decode_entities(caps[1].trim());
+        if href.is_empty() || href.starts_with('#') {
+            continue;
+        }
+        let Ok(mut url) = base_url.join(&href) else {
+            continue;
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            continue;
+        }
+        if exclude_same_host && site_host(&url) == base_host {
+            continue;
+        }
+        url.set_fragment(None);
+
+        let text = html::to_plain_text(&caps[2])
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let url = String::from(url);
+
+        match seen.get(&url) {
+            Some(&index) => {
+                // A later anchor may carry the text an earlier image link lacked
+                if links[index].text == links[index].url && !text.is_empty() {
+                    links[index].text = text;
+                }
+            }
+            None => {
+                seen.insert(url.clone(), links.len());
+                links.push(ArticleLink {
+                    text: if text.is_empty() { url.clone() } else { text },
+                    url,
+                });
+            }
+        }
+    }
+
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hn_item_url_format() {
+        assert_eq!(
+            hn_item_url(HN_WEB_URL, 8863),
+            "https://news.ycombinator.com/item?id=8863"
+        );
+    }
+
+    #[test]
+    fn hn_user_url_format() {
+        assert_eq!(
+            hn_user_url(HN_WEB_URL, "pg"),
+            "https://news.ycombinator.com/user?id=pg"
+        );
+    }
+
+    #[test]
+    fn hn_user_url_encodes_unusual_names() {
+        assert_eq!(
+            hn_user_url(HN_WEB_URL, "a b&c"),
+            "https://news.ycombinator.com/user?id=a%20b%26c"
+        );
+    }
+
+    #[test]
+    fn comment_permalink_anchors_into_thread() {
+        assert_eq!(
+            comment_permalink(HN_WEB_URL, 9000, Some(8863)),
+            "https://news.ycombinator.com/item?id=8863#9000"
+        );
+    }
+
+    #[test]
+    fn comment_permalink_without_story() {
+        assert_eq!(
+            comment_permalink(HN_WEB_URL, 9000, None),
+            "https://news.ycombinator.com/item?id=9000"
+        );
+    }
+
+    #[test]
+    fn canonicalize_url_strips_tracking_params() {
+        assert_eq!(
+            canonicalize_url(
+                "https://example.com/post?id=7&utm_source=hn&UTM_Medium=social&fbclid=abc#section"
+            ),
+            "https://example.com/post?id=7#section"
+        );
+        assert_eq!(
+            canonicalize_url("https://example.com/?utm_campaign=x&gclid=y"),
+            "https://example.com/"
+        );
+    }
+
+    #[test]
+    fn canonicalize_url_strips_site_params_only_on_their_site() {
+        assert_eq!(
+            canonicalize_url(
+                "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42&feature=share&si=xyz"
+            ),
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42"
+        );
+        assert_eq!(
+            canonicalize_url("https://x.com/rustlang/status/1?ref_src=twsrc%5Etfw"),
+            "https://x.com/rustlang/status/1"
+        );
+        // Elsewhere the same names can be real parameters
+        assert_eq!(
+            canonicalize_url("https://github.com/rust-lang/rust/blob/x.rs?ref=main&utm_source=hn"),
+            "https://github.com/rust-lang/rust/blob/x.rs?ref=main"
+        );
+        assert_eq!(
+            canonicalize_url("https://docs.example.com/api?source=v2&feature=search"),
+            "https://docs.example.com/api?source=v2&feature=search"
+        );
+        // A lookalike host doesn't count as the site
+        assert_eq!(
+            canonicalize_url("https://notyoutube.com/a?si=1"),
+            "https://notyoutube.com/a?si=1"
+        );
+    }
+
+    #[test]
+    fn canonicalize_url_keeps_encoding_of_kept_params() {
+        assert_eq!(
+            canonicalize_url("https://example.com/search?q=a%20b&tag=c%2Bd&utm_source=hn"),
+            "https://example.com/search?q=a%20b&tag=c%2Bd"
+        );
+    }
+
+    #[test]
+    fn canonicalize_url_leaves_clean_and_non_http_urls_untouched() {
+        let clean = "https://example.com/search?q=a+b&page=2";
+        assert_eq!(canonicalize_url(clean), clean);
+        assert_eq!(
+            canonicalize_url("mailto:me@example.com?utm_source=x"),
+            "mailto:me@example.com?utm_source=x"
+        );
+        assert_eq!(canonicalize_url("not a url"), "not a url");
+    }
+
+    fn base() -> url::Url {
+        url::Url::parse("https://blog.example.com/posts/2024/intro.html").unwrap()
+    }
+
+    fn urls(links: &[ArticleLink]) -> Vec<&str> {
+        links.iter().map(|l| l.url.as_str()).collect()
+    }
+
+    #[test]
+    fn article_links_resolves_relative_urls() {
+        let content = r#"<p>See <a href="../2023/old.html">last year</a>, <a href="/about">about</a>,
+            <a href="//cdn.example.org/x.js">a CDN</a> and <a href="https://rust-lang.org/">Rust</a>.</p>"#;
+        let links = article_links(content, &base(), false);
+
+        assert_eq!(
+            urls(&links),
+            vec![
+                "https://blog.example.com/posts/2023/old.html",
+                "https://blog.example.com/about",
+                "https://cdn.example.org/x.js",
+                "https://rust-lang.org/",
+            ]
+        );
+        assert_eq!(links[0].text, "last year");
+    }
+
+    #[test]
+    fn article_links_dedupes_ignoring_fragments() {
+        let content = r##"<a href="https://example.org/paper#s1"><img src="fig.png"></a>
+            <a href="https://example.org/paper#s2">The <b>paper</b></a>
+            <a href="https://example.org/paper">paper again</a>
+            <a href="#top">Back to top</a>
+            <a href="mailto:me@example.com">Email</a>
+            <a href="javascript:void(0)">Menu</a>"##;
+        let links = article_links(content, &base(), false);
+
+        assert_eq!(urls(&links), vec!["https://example.org/paper"]);
+        // The image-only link picked up text from a later duplicate
+        assert_eq!(links[0].text, "The paper");
+    }
+
+    #[test]
+    fn article_links_can_exclude_same_host() {
+        let content = r#"<a href="/archive">Archive</a>
+            <a href="https://www.blog.example.com/feed">Feed</a>
+            <a href="https://example.com/">Parent site</a>
+            <a href="https://news.ycombinator.com/item?id=1&amp;p=2">HN</a>"#;
+        let links = article_links(content, &base(), true);
+
+        assert_eq!(
+            urls(&links),
+            vec![
+                "https://example.com/",
+                "https://news.ycombinator.com/item?id=1&p=2"
+            ]
+        );
+    }
+}
@@ -44,14 +44,36 @@
 //! | Cache | TTL | Max Size | Notes |
 //! |-------|-----|----------|-------|
 //! | Items (stories/comments) | 5 min | 10,000 | Stale-while-revalidate |
-//! | Story IDs per feed | 2 min | 10 | Background refresh at 75% TTL |
+//! | Story IDs per feed | 2 min | 10 | Background refresh at 75% TTL, optional timer |
 //! | User profiles | 10 min | 100 | - |
+//! | Extracted articles | 30 min | 200 | Keyed by URL |
 
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod auto_refresh;
+mod backup;
 mod client;
+mod code;
 mod commands;
 mod copilot;
+mod copilot_transcript;
+mod deep_link;
+mod export;
+mod hiring;
+mod html;
+mod links;
+#[cfg(feature = "pdf")]
+mod pdf;
+mod persist;
+mod reading_stats;
+mod search_history;
+mod seen_comments;
+mod settings;
+mod shortcuts;
+mod story_windows;
+mod text_block;
+mod toc;
+mod tray;
 mod tts;
 mod types;
 
@@ -63,7 +85,7 @@ use tauri::{
     Emitter, Manager, Url, WebviewUrl, WebviewWindowBuilder,
 };
 use tauri_plugin_deep_link::DeepLinkExt;
-use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut, ShortcutState};
+use tauri_plugin_global_shortcut::ShortcutState;
 use tauri_plugin_window_state::StateFlags;
 use tracing::info;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
@@ -80,6 +102,24 @@ fn main() {
     // Create the HN client
     let hn_client = client::create_client();
 
+    // Publish the saved pronunciations for the neural engine
+    let pronunciations = tts::neural::pronunciation::PronunciationStore::load(
+        tts::neural::pronunciation::PronunciationStore::default_path(),
+    );
+    pronunciations.activate();
+
+    // Use the model directory chosen in settings, if any
+    let settings = settings::SettingsStore::load(settings::SettingsStore::default_path());
+    tts::neural::ModelManager::set_model_dir(settings.get().model_dir);
+
+    // The neural voice to warm up once the engine is ready, if enabled
+    let startup_warmup = {
+        let saved = settings.get();
+        saved
+            .neural_warmup_on_startup
+            .then_some(saved.tts.neural_voice)
+    };
+
     // Only save/restore position and size, not decorations or fullscreen
     // This ensures the window always starts with decorations visible
     // (zen mode should not persist across app restarts)
@@ -107,26 +147,11 @@ fn main() {
                         return;
                     }
 
-                    let show_window =
-                        Shortcut::new(Some(Modifiers::SUPER | Modifiers::SHIFT), Code::KeyH);
-                    let refresh =
-                        Shortcut::new(Some(Modifiers::SUPER | Modifiers::SHIFT), Code::KeyR);
-
-                    if shortcut == &show_window {
-                        info!("Global shortcut: Show window");
-                        if let Some(window) = app.get_webview_window("main") {
-                            let _ = window.unminimize();
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                        }
-                    } else if shortcut == &refresh {
-                        info!("Global shortcut: Refresh");
-                        if let Some(window) = app.get_webview_window("main") {
-                            let _ = window.emit("tray-refresh", ());
-                            let _ = window.unminimize();
-                            let _ = window.show();
-                            let _ = window.set_focus();
-                        }
+                    let action = app
+                        .try_state::<shortcuts::GlobalShortcuts>()
+                        .and_then(|registered| registered.action_for(shortcut));
+                    if let Some(action) = action {
+                        shortcuts::perform(app, action);
                     }
                 })
                 .build(),
@@ -134,10 +159,46 @@ fn main() {
         .plugin(
             tauri_plugin_window_state::Builder::new()
                 .with_state_flags(window_state_flags)
+                // Story windows are short-lived; don't accumulate their state
+                .with_filter(|label| !story_windows::is_story_window(label))
                 .build(),
         )
         .manage(hn_client)
+        .manage(auto_refresh::AutoRefresh::new())
+        .manage(shortcuts::GlobalShortcuts::new())
+        .manage(search_history::SearchHistory::load(
+            search_history::SearchHistory::default_path(),
+            search_history::DEFAULT_MAX_ENTRIES,
+        ))
+        .manage(settings)
+        .manage(seen_comments::SeenComments::load(
+            seen_comments::SeenComments::default_path(),
+        ))
+        .manage(reading_stats::ReadingStatsStore::load(
+            reading_stats::ReadingStatsStore::default_path(),
+        ))
+        .manage(pronunciations)
         .setup(move |app| {
+            // Get DNS and TLS out of the way before the first feed loads
+            let warm_client = app.state::<client::SharedHnClient>().inner().clone();
+            tauri::async_runtime::spawn(async move {
+                warm_client.warm_connections().await;
+            });
+
+            // Report item cache evictions in batches, when enabled
+            let eviction_client = app.state::<client::SharedHnClient>().inner().clone();
+            let eviction_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let mut ticker = tokio::time::interval(client::EVICTION_EVENT_INTERVAL);
+                loop {
+                    ticker.tick().await;
+                    let evictions = eviction_client.take_evictions();
+                    if !evictions.is_empty() {
+                        let _ = eviction_handle.emit("cache-evicted", evictions);
+                    }
+                }
+            });
+
             // Create the main window programmatically
             // In dev mode, use the default app URL (which points to Vite dev server)
             // In release mode, use localhost server for YouTube embed compatibility
@@ -153,12 +214,16 @@ fn main() {
                 app.add_capability(
                     CapabilityBuilder::new("localhost")
                         .remote(localhost_url.to_string())
-                        .window("main"),
+                        .window("main")
+                        .window("story-*"),
                 )?;
 
                 WebviewUrl::External(localhost_url)
             };
 
+            // Story windows load the same frontend
+            app.manage(story_windows::FrontendUrl(url.clone()));
+
             // Build the main window with the appropriate URL
             WebviewWindowBuilder::new(app, "main", url)
                 .title("pastel-hn | The Best Hacker News Client")
@@ -170,6 +235,12 @@ fn main() {
 
             info!("Main window created");
 
+            // Forward Copilot queue progress to the frontend
+            let queue_handle = app.handle().clone();
+            copilot::get_service().set_queue_listener(move |event| {
+                let _ = queue_handle.emit("copilot-queue", event);
+            });
+
             // Create tray menu items for feeds
             let top_i = MenuItem::with_id(app, "feed_top", "Top Stories", true, None::<&str>)?;
             let new_i = MenuItem::with_id(app, "feed_new", "New Stories", true, None::<&str>)?;
@@ -187,6 +258,11 @@ fn main() {
                 &[&top_i, &new_i, &best_i, &ask_i, &show_i, &jobs_i, &saved_i],
             )?;
 
+            // Recent top stories, filled in once the Top feed loads
+            let recent_menu = Submenu::with_id(app, "recent", "Recent", true)?;
+            app.manage(tray::RecentStoriesMenu(recent_menu.clone()));
+            tray::set_recent_stories(app.handle(), &[])?;
+
             // Create other menu items
             let refresh_i = MenuItem::with_id(app, "refresh", "Refresh", true, None::<&str>)?;
             let search_i = MenuItem::with_id(app, "search", "Search...", true, None::<&str>)?;
@@ -202,6 +278,7 @@ fn main() {
                 app,
                 &[
                     &feeds_menu,
+                    &recent_menu,
                     &sep1,
                     &refresh_i,
                     &search_i,
@@ -225,6 +302,18 @@ fn main() {
                     let id = event.id.as_ref();
                     info!("Tray menu event: {}", id);
 
+                    if let Some(story_id) = tray::parse_story_item_id(id) {
+                        info!("Story selected from tray: {}", story_id);
+                        // Emit event to frontend to open the story
+                        if let Some(window) = app.get_webview_window("main") {
+                            let _ = app.emit_to("main", "tray-open-story", story_id);
+                            let _ = window.unminimize();
+                            let _ = window.show();
+                            let _ = window.set_focus();
+                        }
+                        return;
+                    }
+
                     match id {
                         "quit" => {
                             info!("Quit requested from tray");
@@ -242,7 +331,7 @@ fn main() {
                             info!("Feed selected from tray: {}", feed);
                             // Emit event to frontend to switch feed
                             if let Some(window) = app.get_webview_window("main") {
-                                let _ = window.emit("tray-feed-change", feed);
+                                let _ = app.emit_to("main", "tray-feed-change", feed);
                                 let _ = window.unminimize();
                                 let _ = window.show();
                                 let _ = window.set_focus();
@@ -251,7 +340,7 @@ fn main() {
                         "refresh" => {
                             info!("Refresh requested from tray");
                             if let Some(window) = app.get_webview_window("main") {
-                                let _ = window.emit("tray-refresh", ());
+                                let _ = app.emit_to("main", "tray-refresh", ());
                                 let _ = window.unminimize();
                                 let _ = window.show();
                                 let _ = window.set_focus();
@@ -260,7 +349,7 @@ fn main() {
                         "search" => {
                             info!("Search requested from tray");
                             if let Some(window) = app.get_webview_window("main") {
-                                let _ = window.emit("tray-search", ());
+                                let _ = app.emit_to("main", "tray-search", ());
                                 let _ = window.unminimize();
                                 let _ = window.show();
                                 let _ = window.set_focus();
@@ -291,20 +380,19 @@ fn main() {
 
             info!("System tray initialized");
 
-            // Register global shortcuts
-            let show_window = Shortcut::new(Some(Modifiers::SUPER | Modifiers::SHIFT), Code::KeyH);
-            let refresh = Shortcut::new(Some(Modifiers::SUPER | Modifiers::SHIFT), Code::KeyR);
-
-            if let Err(e) = app.global_shortcut().register(show_window) {
-                info!("Failed to register Cmd+Shift+H shortcut: {}", e);
-            } else {
-                info!("Registered global shortcut: Cmd+Shift+H (show window)");
-            }
+            // Populate the Recent submenu in the background
+            let tray_handle = app.handle().clone();
+            let tray_client = app.state::<client::SharedHnClient>().inner().clone();
+            tauri::async_runtime::spawn(async move {
+                tray::refresh_recent_stories(&tray_handle, &tray_client).await;
+            });
 
-            if let Err(e) = app.global_shortcut().register(refresh) {
-                info!("Failed to register Cmd+Shift+R shortcut: {}", e);
-            } else {
-                info!("Registered global shortcut: Cmd+Shift+R (refresh)");
+            // Register global shortcuts (reconfigurable via set_global_shortcuts)
+            if let Err(e) = app
+                .state::<shortcuts::GlobalShortcuts>()
+                .apply(app.handle(), shortcuts::ShortcutConfig::default())
+            {
+                info!("Failed to register global shortcuts: {}", e);
             }
 
             // Register deep link schemes for development (on Linux/Windows)
@@ -319,23 +407,15 @@ fn main() {
             }
 
             // Set up deep link handler
-            app.deep_link().on_open_url(|event| {
-                let urls = event.urls();
-                for url in urls {
-                    info!("Deep link received: {}", url);
+            let deep_link_handle = app.handle().clone();
+            app.deep_link().on_open_url(move |event| {
+                for url in event.urls() {
+                    deep_link::dispatch(&deep_link_handle, &url);
                 }
             });
 
-            // Check if app was opened via deep link
-            if let Ok(Some(urls)) = app.deep_link().get_current() {
-                for url in urls {
-                    info!("App opened via deep link: {}", url);
-                    // Emit event to frontend to handle the URL
-                    if let Some(window) = app.get_webview_window("main") {
-                        let _ = window.emit("deep-link", url.to_string());
-                    }
-                }
-            }
+            // Links the app was opened with are dispatched once the frontend
+            // is listening (dispatch_launch_deep_links)
 
             info!("Deep link handler initialized");
 
@@ -350,7 +430,7 @@ fn main() {
             }
 
             // Initialize neural TTS (async - spawn on tauri runtime)
-            tauri::async_runtime::spawn(async {
+            tauri::async_runtime::spawn(async move {
                 if let Err(e) = tts::neural::init_neural().await {
                     info!(
                         "Neural TTS initialization failed (models not downloaded): {}",
@@ -359,6 +439,12 @@ fn main() {
                 } else {
                     info!("Neural TTS engine initialized successfully");
                 }
+
+                if let Some(voice) = startup_warmup {
+                    if let Err(e) = tts::neural::warmup(voice.as_deref()).await {
+                        info!("Neural TTS startup warmup skipped: {}", e);
+                    }
+                }
             });
 
             Ok(())
@@ -366,51 +452,138 @@ fn main() {
         .invoke_handler(tauri::generate_handler![
             // HN API commands
             commands::fetch_stories,
+            commands::fetch_stories_ranked,
+            commands::fetch_merged,
+            commands::prefetch_stories,
+            commands::top_domains,
+            commands::warm_feed,
+            commands::warm_connections,
             commands::fetch_item,
             commands::fetch_items,
+            commands::fetch_items_detailed,
+            commands::fetch_poll,
             commands::fetch_story_with_comments,
+            commands::fetch_stories_with_comments,
             commands::fetch_comment_children,
+            commands::fetch_comment_context,
+            commands::new_comments_since,
+            commands::mark_comments_seen,
+            commands::parse_hiring_thread,
             commands::fetch_user,
             commands::fetch_user_submissions,
+            commands::fetch_user_favorites,
+            commands::fetch_user_comments_search,
             commands::search_hn,
+            commands::get_search_history,
+            commands::clear_search_history,
+            commands::record_article_read,
+            commands::record_story_opened,
+            commands::record_session,
+            commands::get_reading_stats,
+            commands::reset_reading_stats,
             commands::clear_cache,
             commands::get_cache_stats,
+            commands::save_cache_snapshot,
+            commands::load_cache_snapshot,
+            commands::set_cache_eviction_events,
             commands::clear_story_ids_cache,
             commands::is_feed_stale,
+            commands::get_feed_ages,
+            commands::set_stale_threshold,
             commands::background_refresh_feed,
+            commands::feed_position_deltas,
+            commands::set_auto_refresh,
             // Article extraction
             commands::fetch_article_content,
+            commands::fetch_reader_content,
+            commands::set_max_article_bytes,
+            commands::set_exclude_same_host_links,
+            commands::prefetch_articles,
+            commands::prefetch_comments,
+            commands::fetch_front_page_digest,
+            commands::fetch_favicon,
+            commands::export_feed_rss,
+            commands::format_story_markdown,
+            commands::export_bookmarks_json,
+            commands::export_reading_history_json,
+            commands::import_bookmarks_json,
             // Utility commands
+            commands::open_story_window,
+            commands::dispatch_launch_deep_links,
             commands::open_external,
+            commands::set_strip_tracking_params,
+            commands::set_offline_mode,
+            commands::set_rate_limit,
+            commands::get_global_shortcuts,
+            commands::get_default_global_shortcuts,
+            commands::set_global_shortcuts,
+            commands::get_settings,
+            commands::set_default_comment_depth,
+            commands::is_online,
+            commands::open_on_hn,
+            commands::hn_item_url,
+            commands::hn_user_url,
+            commands::comment_permalink,
+            commands::primary_link,
             commands::get_app_version,
             // Copilot AI assistant
             commands::copilot_check,
             commands::copilot_init,
+            commands::copilot_ping,
             commands::copilot_summarize,
             commands::copilot_analyze_discussion,
             commands::copilot_explain,
             commands::copilot_draft_reply,
             commands::copilot_ask,
+            commands::copilot_set_output_language,
+            commands::copilot_set_transcript_logging,
+            commands::copilot_get_transcript_path,
+            commands::copilot_clear_transcript,
+            commands::copilot_set_queue_depth,
+            commands::copilot_set_max_retries,
             commands::copilot_shutdown,
             // TTS (Text-to-Speech) - Native OS voices
             commands::tts_init,
             commands::tts_status,
             commands::tts_speak,
+            commands::tts_speak_sentences,
+            commands::speak_article,
             commands::tts_stop,
             commands::tts_get_voices,
+            commands::list_all_voices,
             commands::tts_set_voice,
             commands::tts_set_rate,
+            commands::tts_voice_settings,
             // Neural TTS (Piper + ONNX Runtime)
             commands::tts_neural_init,
+            commands::tts_neural_set_voice,
+            commands::tts_neural_set_rate,
             commands::tts_neural_status,
             commands::tts_neural_voices,
+            commands::tts_neural_pronunciations,
+            commands::tts_neural_add_pronunciation,
+            commands::tts_neural_remove_pronunciation,
             commands::tts_download_model,
             commands::tts_is_model_ready,
+            commands::tts_validate_models,
             commands::tts_neural_speak,
+            commands::tts_neural_speak_auto,
             commands::tts_neural_speak_sentences,
+            commands::tts_neural_prepare,
             commands::tts_neural_stop,
+            commands::tts_neural_seek,
+            commands::tts_neural_skip_next,
+            commands::tts_neural_skip_prev,
+            commands::tts_neural_warmup,
+            commands::tts_neural_set_warmup_on_startup,
+            commands::tts_neural_unload,
+            commands::tts_neural_set_session_options,
+            commands::tts_neural_set_output_latency,
+            commands::tts_neural_set_continue_on_error,
+            commands::tts_neural_benchmark,
             commands::tts_model_directory,
             commands::tts_model_disk_usage,
+            commands::tts_set_model_directory,
             commands::tts_delete_model,
         ])
         .run(tauri::generate_context!())
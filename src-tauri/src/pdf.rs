@@ -0,0 +1,183 @@
+//! Reader-mode extraction for PDF articles.
+//!
+//! A good share of HN links point straight at papers and reports. This
+//! module turns a downloaded PDF into an [`ArticleContent`]: the text of
+//! every page, a title from the document metadata, and a simple
+//! paragraph-wrapped HTML rendering for the reader view.
+//!
+//! Only compiled with the `pdf` feature (enabled by default).
+
+use lopdf::{Document, Object};
+
+use crate::export::xml_escape;
+use crate::types::{ApiError, ArticleContent};
+
+/// Extract readable text from a PDF.
+///
+/// # Errors
+///
+/// Returns `ApiError::ArticleExtraction` if the PDF can't be parsed or has
+/// no extractable text (e.g. scanned images).
+pub fn extract_article(bytes: &[u8]) -> Result<ArticleContent, ApiError> {
+    let doc = Document::load_mem(bytes)
+        .map_err(|e| ApiError::ArticleExtraction(format!("Invalid PDF: {}", e)))?;
+
+    let pages: Vec<u32> = doc.get_pages().keys().copied().collect();
+    let text = doc
+        .extract_text(&pages)
+        .map_err(|e| ApiError::ArticleExtraction(format!("PDF text extraction failed: {}", e)))?;
+
+    let paragraphs = paragraphs(&text);
+    if paragraphs.is_empty() {
+        return Err(ApiError::ArticleExtraction(
+            "PDF has no extractable text".to_string(),
+        ));
+    }
+
+    let content = paragraphs
+        .iter()
+        .map(|p| format!("<p>{}</p>", xml_escape(p)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let text_content = paragraphs.join("\n\n");
+    let word_count = text_content.split_whitespace().count();
+
+    Ok(ArticleContent {
+        title: metadata_title(&doc),
+        content,
+        text_content,
+        byline: None,
+        excerpt: None,
+        site_name: None,
+        lang: None,
+        word_count,
+        headings: vec![],
+        code_blocks: vec![],
+        links: vec![],
+        confidence: 1.0,
+        user_agent: None,
+    })
+}
+
+/// Group extracted lines into paragraphs.
+///
+/// Blank lines separate paragraphs; lines within a paragraph are joined
+/// with spaces, undoing the PDF's hard line wrapping.
+fn paragraphs(text: &str) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join(" "));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join(" "));
+    }
+
+    paragraphs
+}
+
+/// The `/Title` entry of the document information dictionary.
+///
+/// The trailer's `/Info` is normally a reference to the dictionary; an
+/// inline dictionary is accepted too.
+fn metadata_title(doc: &Document) -> Option<String> {
+    let info = doc.trailer.get(b"Info").ok()?;
+    let info = match info.as_reference() {
+        Ok(id) => doc.get_dictionary(id).ok()?,
+        Err(_) => info.as_dict().ok()?,
+    };
+    let Object::String(bytes, _) = info.get(b"Title").ok()? else {
+        return None;
+    };
+
+    let title = decode_text_string(bytes);
+    let title = title.trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Decode a PDF text string: UTF-16BE with a byte order mark, otherwise
+/// PDFDocEncoding (treated as Latin-1, which it matches for common text).
+fn decode_text_string(bytes: &[u8]) -> String {
+    if let Some(utf16) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = utf16
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        bytes.iter().map(|&b| char::from(b)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::Dictionary;
+
+    const SAMPLE_PDF: &[u8] = include_bytes!("../tests/fixtures/sample.pdf");
+
+    #[test]
+    fn extracts_text_and_metadata_title() {
+        let article = extract_article(SAMPLE_PDF).unwrap();
+
+        assert_eq!(article.title.as_deref(), Some("A Tiny Test PDF"));
+        assert!(article.text_content.contains("Reader Mode for PDFs"));
+        assert!(article
+            .text_content
+            .contains("This tiny document exists to test text extraction."));
+        assert!(article.content.starts_with("<p>"));
+        assert!(article.word_count >= 17);
+    }
+
+    #[test]
+    fn metadata_title_resolves_referenced_and_inline_info() {
+        let mut doc = Document::with_version("1.5");
+        let info = doc.add_object(Dictionary::from_iter(vec![(
+            "Title",
+            Object::string_literal("Referenced"),
+        )]));
+        doc.trailer.set("Info", info);
+        assert_eq!(metadata_title(&doc).as_deref(), Some("Referenced"));
+
+        doc.trailer.set(
+            "Info",
+            Dictionary::from_iter(vec![("Title", Object::string_literal("Inline"))]),
+        );
+        assert_eq!(metadata_title(&doc).as_deref(), Some("Inline"));
+
+        doc.trailer.remove(b"Info");
+        assert_eq!(metadata_title(&doc), None);
+    }
+
+    #[test]
+    fn rejects_invalid_pdf() {
+        let result = extract_article(b"%PDF-1.7 definitely not a real document");
+        assert!(matches!(result, Err(ApiError::ArticleExtraction(_))));
+    }
+
+    #[test]
+    fn paragraphs_joins_wrapped_lines() {
+        let text = "First line\nwraps here\n\n  \nSecond paragraph\n";
+        assert_eq!(
+            paragraphs(text),
+            vec!["First line wraps here", "Second paragraph"]
+        );
+    }
+
+    #[test]
+    fn decode_text_string_handles_utf16_and_latin1() {
+        assert_eq!(
+            decode_text_string(&[0xFE, 0xFF, 0x00, b'H', 0x00, b'i', 0x20, 0xAC]),
+            "Hi€"
+        );
+        assert_eq!(decode_text_string(b"Caf\xe9"), "Café");
+    }
+}
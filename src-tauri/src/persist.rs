@@ -0,0 +1,208 @@
+//! Helpers for persisting local data.
+//!
+//! Writes go to a temporary sibling file that is then renamed over the
+//! target, so a crash or full disk mid-write never leaves a truncated file
+//! behind: readers see either the old contents or the new ones.
+//!
+//! The JSON stores (settings, search history, seen comments, reading stats,
+//! pronunciations) keep one file each in [`data_dir`], each through a
+//! [`JsonStore`].
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::warn;
+
+/// Path of the temporary file used while writing `path`.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+/// Atomically replace the contents of `path` with `contents`.
+///
+/// The parent directory must already exist.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let temp = temp_path(path);
+
+    let result = (|| {
+        let mut file = fs::File::create(&temp)?;
+        file.write_all(contents)?;
+        file.sync_all()?;
+        fs::rename(&temp, path)
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+
+    result
+}
+
+/// [`write_atomic`] on the blocking thread pool, for async callers.
+pub async fn write_atomic_async(path: PathBuf, contents: Vec<u8>) -> io::Result<()> {
+    tokio::task::spawn_blocking(move || write_atomic(&path, &contents))
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// The app data directory, where the JSON stores live.
+///
+/// Falls back to the temporary directory on platforms without one.
+pub fn data_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("pastel-hn")
+}
+
+/// Read the JSON stored at `path`.
+///
+/// A missing or unreadable file gives the default rather than an error, so
+/// a damaged store never fails app startup. Corrupt files are logged,
+/// naming them as `what`.
+pub fn load_json<T: DeserializeOwned + Default>(path: &Path, what: &str) -> T {
+    match fs::read(path) {
+        Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+            warn!("Ignoring corrupt {} {}: {}", what, path.display(), e);
+            T::default()
+        }),
+        Err(_) => T::default(),
+    }
+}
+
+/// Write `value` to `path` as pretty-printed JSON, atomically.
+///
+/// Creates the parent directory if needed.
+pub fn save_json<T: Serialize + ?Sized>(path: &Path, value: &T) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_atomic(path, &serde_json::to_vec_pretty(value)?)
+}
+
+/// A JSON file and its contents, held in memory and saved after every
+/// change.
+pub struct JsonStore<T> {
+    path: PathBuf,
+    value: Mutex<T>,
+}
+
+impl<T: Serialize + DeserializeOwned + Default> JsonStore<T> {
+    /// Load the JSON stored at `path`.
+    ///
+    /// A missing or corrupt file starts from the default rather than failing
+    /// app startup; corrupt files are logged, naming them as `what`.
+    pub fn load(path: PathBuf, what: &str) -> Self {
+        let value = load_json(&path, what);
+        Self {
+            path,
+            value: Mutex::new(value),
+        }
+    }
+
+    /// The contents, locked for reading or changing.
+    ///
+    /// Changes stay in memory until passed to [`Self::save`].
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        self.value.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Write `value`, usually the locked contents, to the file with
+    /// [`save_json`].
+    pub fn save(&self, value: &T) -> io::Result<()> {
+        save_json(&self.path, value)
+    }
+}
+
+/// `time` as milliseconds since the Unix epoch, the format stored on disk.
+///
+/// Times before the epoch map to 0.
+pub fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// The current time as milliseconds since the Unix epoch.
+pub fn now_millis() -> u64 {
+    unix_millis(SystemTime::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn write_atomic_creates_and_replaces() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+
+        write_atomic(&path, b"first").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"first");
+
+        write_atomic(&path, b"second").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"second");
+    }
+
+    #[test]
+    fn write_atomic_leaves_no_temp_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+
+        write_atomic(&path, b"{}").unwrap();
+
+        assert!(!temp_path(&path).exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn write_atomic_missing_parent_fails_cleanly() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("missing").join("data.json");
+
+        assert!(write_atomic(&path, b"{}").is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn save_json_round_trips_and_creates_parent() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nested").join("data.json");
+
+        save_json(&path, &vec![1u32, 2, 3]).unwrap();
+        assert_eq!(load_json::<Vec<u32>>(&path, "data"), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn json_store_saves_changes() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+
+        let store: JsonStore<Vec<u32>> = JsonStore::load(path.clone(), "data");
+        {
+            let mut value = store.lock();
+            value.push(7);
+            store.save(&value).unwrap();
+        }
+
+        let reloaded: JsonStore<Vec<u32>> = JsonStore::load(path, "data");
+        assert_eq!(*reloaded.lock(), vec![7]);
+    }
+
+    #[test]
+    fn load_json_defaults_for_missing_or_corrupt_files() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("data.json");
+        assert_eq!(load_json::<Vec<u32>>(&path, "data"), Vec::<u32>::new());
+
+        fs::write(&path, b"not json").unwrap();
+        assert_eq!(load_json::<Vec<u32>>(&path, "data"), Vec::<u32>::new());
+    }
+}
@@ -0,0 +1,312 @@
+//! Reading statistics, persisted across restarts.
+//!
+//! The frontend reports reading activity (articles read in reader mode,
+//! stories opened, time spent reading) and the totals are kept both overall
+//! and per day, so the UI can show a streak or a simple chart.
+//!
+//! Days are UTC calendar days keyed as `YYYY-MM-DD`; the last [`MAX_DAYS`]
+//! active days are kept. Overall totals are kept separately, so they don't
+//! shrink as old days are dropped. The stats are saved to a JSON file in
+//! the app data directory after every change, through a
+//! [`persist::JsonStore`].
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::persist;
+use crate::types::ApiError;
+
+/// Reading speed used to estimate minutes from word counts.
+///
+/// Matches the frontend's reading-time estimate.
+pub const WORDS_PER_MINUTE: u64 = 200;
+
+/// Number of daily buckets kept.
+pub const MAX_DAYS: usize = 365;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Reading activity over some period (one day, or all time).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ReadingCounts {
+    /// Articles read in reader mode
+    pub articles_read: u64,
+    /// Words in those articles
+    pub words_read: u64,
+    /// Estimated reading time of those articles
+    pub estimated_minutes: u64,
+    /// Story detail views opened
+    pub stories_opened: u64,
+    /// Reading sessions recorded
+    pub sessions: u64,
+    /// Time spent in those sessions
+    pub session_seconds: u64,
+}
+
+impl ReadingCounts {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// One day's activity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyReading {
+    /// UTC date, `YYYY-MM-DD`
+    pub date: String,
+    #[serde(flatten)]
+    pub counts: ReadingCounts,
+}
+
+/// Snapshot returned by `get_reading_stats`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingStats {
+    /// All-time totals
+    pub totals: ReadingCounts,
+    /// Active days, oldest first
+    pub days: Vec<DailyReading>,
+    /// Consecutive active days up to today (or yesterday, if nothing has
+    /// been read yet today)
+    pub current_streak: u32,
+}
+
+/// What is stored on disk.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct StoredStats {
+    totals: ReadingCounts,
+    days: BTreeMap<String, ReadingCounts>,
+}
+
+/// Estimated minutes to read `word_count` words, rounded up.
+pub fn estimated_minutes(word_count: u64) -> u64 {
+    word_count.div_ceil(WORDS_PER_MINUTE)
+}
+
+/// `YYYY-MM-DD` for a number of days since the Unix epoch.
+fn date_string(days: i64) -> String {
+    // Civil-from-days conversion for the proleptic Gregorian calendar
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Days since the Unix epoch, today (UTC).
+fn today() -> i64 {
+    (persist::now_millis() / 1000 / SECONDS_PER_DAY) as i64
+}
+
+/// The persisted reading statistics.
+///
+/// Managed as Tauri state.
+pub struct ReadingStatsStore {
+    store: persist::JsonStore<StoredStats>,
+}
+
+impl ReadingStatsStore {
+    /// Load the stats stored at `path`.
+    pub fn load(path: PathBuf) -> Self {
+        Self {
+            store: persist::JsonStore::load(path, "reading stats"),
+        }
+    }
+
+    /// Default location: `reading_stats.json` in the app data directory.
+    pub fn default_path() -> PathBuf {
+        persist::data_dir().join("reading_stats.json")
+    }
+
+    /// Record an article read in reader mode.
+    pub fn record_article_read(&self, word_count: u64) -> Result<(), ApiError> {
+        self.record(today(), |counts| {
+            counts.articles_read += 1;
+            counts.words_read += word_count;
+            counts.estimated_minutes += estimated_minutes(word_count);
+        })
+    }
+
+    /// Record that a story's detail view was opened.
+    pub fn record_story_opened(&self) -> Result<(), ApiError> {
+        self.record(today(), |counts| counts.stories_opened += 1)
+    }
+
+    /// Record a reading session that lasted `duration_secs`.
+    pub fn record_session(&self, duration_secs: u64) -> Result<(), ApiError> {
+        self.record(today(), |counts| {
+            counts.sessions += 1;
+            counts.session_seconds += duration_secs;
+        })
+    }
+
+    /// Current totals, daily buckets, and streak.
+    pub fn snapshot(&self) -> ReadingStats {
+        self.snapshot_on(today())
+    }
+
+    /// Forget all recorded activity.
+    pub fn reset(&self) -> Result<(), ApiError> {
+        let mut stats = self.store.lock();
+        *stats = StoredStats::default();
+        info!("Reading stats reset");
+        self.store.save(&stats)?;
+        Ok(())
+    }
+
+    /// Apply `update` to the totals and to `day`'s bucket, then persist.
+    fn record(&self, day: i64, update: impl Fn(&mut ReadingCounts)) -> Result<(), ApiError> {
+        let mut stats = self.store.lock();
+        update(&mut stats.totals);
+        update(stats.days.entry(date_string(day)).or_default());
+
+        // Keys are ISO dates, so the first ones are the oldest
+        while stats.days.len() > MAX_DAYS {
+            stats.days.pop_first();
+        }
+
+        self.store.save(&stats)?;
+        Ok(())
+    }
+
+    fn snapshot_on(&self, today: i64) -> ReadingStats {
+        let stats = self.store.lock();
+        let active = |day: i64| {
+            stats
+                .days
+                .get(&date_string(day))
+                .is_some_and(|counts| !counts.is_empty())
+        };
+
+        let mut day = if active(today) { today } else { today - 1 };
+        let mut current_streak = 0;
+        while active(day) {
+            current_streak += 1;
+            day -= 1;
+        }
+
+        ReadingStats {
+            totals: stats.totals,
+            days: stats
+                .days
+                .iter()
+                .map(|(date, counts)| DailyReading {
+                    date: date.clone(),
+                    counts: *counts,
+                })
+                .collect(),
+            current_streak,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn store(dir: &TempDir) -> ReadingStatsStore {
+        ReadingStatsStore::load(dir.path().join("reading_stats.json"))
+    }
+
+    #[test]
+    fn date_string_converts_epoch_days() {
+        assert_eq!(date_string(0), "1970-01-01");
+        assert_eq!(date_string(19_723), "2024-01-01");
+        assert_eq!(date_string(19_782), "2024-02-29");
+    }
+
+    #[test]
+    fn estimated_minutes_rounds_up() {
+        assert_eq!(estimated_minutes(0), 0);
+        assert_eq!(estimated_minutes(1), 1);
+        assert_eq!(estimated_minutes(400), 2);
+        assert_eq!(estimated_minutes(401), 3);
+    }
+
+    #[test]
+    fn activity_accumulates_in_totals_and_days() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+
+        store.record_article_read(1000).unwrap();
+        store.record_article_read(250).unwrap();
+        store.record_story_opened().unwrap();
+        store.record_session(90).unwrap();
+
+        let stats = store.snapshot();
+        assert_eq!(stats.totals.articles_read, 2);
+        assert_eq!(stats.totals.words_read, 1250);
+        assert_eq!(stats.totals.estimated_minutes, 5 + 2);
+        assert_eq!(stats.totals.stories_opened, 1);
+        assert_eq!(stats.totals.sessions, 1);
+        assert_eq!(stats.totals.session_seconds, 90);
+        assert_eq!(stats.days.len(), 1);
+        assert_eq!(stats.days[0].counts, stats.totals);
+        assert_eq!(stats.current_streak, 1);
+
+        // Survives a restart
+        assert_eq!(self::store(&dir).snapshot().totals, stats.totals);
+    }
+
+    #[test]
+    fn streak_counts_consecutive_days() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+        let opened = |counts: &mut ReadingCounts| counts.stories_opened += 1;
+
+        store.record(100, opened).unwrap();
+        store.record(102, opened).unwrap();
+        store.record(103, opened).unwrap();
+
+        assert_eq!(store.snapshot_on(103).current_streak, 2);
+        // Nothing yet today: the streak through yesterday still counts
+        assert_eq!(store.snapshot_on(104).current_streak, 2);
+        assert_eq!(store.snapshot_on(105).current_streak, 0);
+    }
+
+    #[test]
+    fn old_days_are_dropped_but_totals_kept() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+
+        for day in 0..(MAX_DAYS as i64 + 5) {
+            store.record(day, |counts| counts.sessions += 1).unwrap();
+        }
+
+        let stats = store.snapshot_on(MAX_DAYS as i64 + 4);
+        assert_eq!(stats.days.len(), MAX_DAYS);
+        assert_eq!(stats.days[0].date, date_string(5));
+        assert_eq!(stats.totals.sessions, MAX_DAYS as u64 + 5);
+    }
+
+    #[test]
+    fn reset_clears_everything() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+        store.record_article_read(500).unwrap();
+        store.record_story_opened().unwrap();
+
+        store.reset().unwrap();
+
+        let stats = store.snapshot();
+        assert_eq!(stats.totals, ReadingCounts::default());
+        assert!(stats.days.is_empty());
+        assert_eq!(stats.current_streak, 0);
+        assert_eq!(
+            self::store(&dir).snapshot().totals,
+            ReadingCounts::default()
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Recent searches, persisted across restarts.
+//!
+//! Every query passed to the `search_hn` command is recorded together with
+//! its sort and filter, so the frontend can offer a recent-searches dropdown.
+//! The history is most-recent-first, holds each (query, sort, filter) once,
+//! and is capped at [`SearchHistory::max_entries`] entries.
+//!
+//! The history is saved to a JSON file in the app data directory after
+//! every change, through a [`persist::JsonStore`].
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::persist;
+use crate::types::{ApiError, SearchFilter, SearchSort};
+
+/// Number of searches kept by default.
+pub const DEFAULT_MAX_ENTRIES: usize = 20;
+
+/// A search the user ran.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHistoryEntry {
+    /// Query text, trimmed
+    pub query: String,
+    pub sort: SearchSort,
+    pub filter: SearchFilter,
+    /// When the search was last run (Unix milliseconds)
+    pub searched_at: u64,
+}
+
+impl SearchHistoryEntry {
+    fn same_search(&self, query: &str, sort: SearchSort, filter: SearchFilter) -> bool {
+        self.query == query && self.sort == sort && self.filter == filter
+    }
+}
+
+/// The persisted search history.
+///
+/// Managed as Tauri state.
+pub struct SearchHistory {
+    store: persist::JsonStore<Vec<SearchHistoryEntry>>,
+    max_entries: usize,
+}
+
+impl SearchHistory {
+    /// Load the history stored at `path`, keeping at most `max_entries`.
+    pub fn load(path: PathBuf, max_entries: usize) -> Self {
+        let store: persist::JsonStore<Vec<SearchHistoryEntry>> =
+            persist::JsonStore::load(path, "search history");
+        store.lock().truncate(max_entries);
+
+        Self { store, max_entries }
+    }
+
+    /// Default location: `search_history.json` in the app data directory.
+    pub fn default_path() -> PathBuf {
+        persist::data_dir().join("search_history.json")
+    }
+
+    /// Maximum number of searches kept.
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    /// Recorded searches, most recent first.
+    pub fn entries(&self) -> Vec<SearchHistoryEntry> {
+        self.store.lock().clone()
+    }
+
+    /// Record a search and persist the history.
+    ///
+    /// Blank queries are ignored. Re-running a search moves it to the top
+    /// instead of adding a duplicate. The oldest searches are dropped once
+    /// the cap is reached.
+    pub fn record(
+        &self,
+        query: &str,
+        sort: SearchSort,
+        filter: SearchFilter,
+    ) -> Result<(), ApiError> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(());
+        }
+
+        let mut entries = self.store.lock();
+        entries.retain(|e| !e.same_search(query, sort, filter));
+        entries.insert(
+            0,
+            SearchHistoryEntry {
+                query: query.to_string(),
+                sort,
+                filter,
+                searched_at: persist::now_millis(),
+            },
+        );
+        entries.truncate(self.max_entries);
+
+        self.store.save(&entries)?;
+        Ok(())
+    }
+
+    /// Remove all recorded searches.
+    pub fn clear(&self) -> Result<(), ApiError> {
+        let mut entries = self.store.lock();
+        entries.clear();
+        self.store.save(&entries)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn history(dir: &TempDir, max_entries: usize) -> SearchHistory {
+        SearchHistory::load(dir.path().join("search_history.json"), max_entries)
+    }
+
+    fn queries(history: &SearchHistory) -> Vec<String> {
+        history.entries().into_iter().map(|e| e.query).collect()
+    }
+
+    #[test]
+    fn duplicate_searches_collapse_to_most_recent() {
+        let dir = TempDir::new().unwrap();
+        let history = history(&dir, DEFAULT_MAX_ENTRIES);
+
+        history
+            .record("rust", SearchSort::Relevance, SearchFilter::All)
+            .unwrap();
+        history
+            .record("  rust ", SearchSort::Relevance, SearchFilter::All)
+            .unwrap();
+        history
+            .record("zig", SearchSort::Relevance, SearchFilter::All)
+            .unwrap();
+        history
+            .record("rust", SearchSort::Relevance, SearchFilter::All)
+            .unwrap();
+
+        assert_eq!(queries(&history), vec!["rust", "zig"]);
+    }
+
+    #[test]
+    fn same_query_with_other_options_is_kept() {
+        let dir = TempDir::new().unwrap();
+        let history = history(&dir, DEFAULT_MAX_ENTRIES);
+
+        history
+            .record("rust", SearchSort::Relevance, SearchFilter::All)
+            .unwrap();
+        history
+            .record("rust", SearchSort::Date, SearchFilter::Comment)
+            .unwrap();
+
+        let entries = history.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].sort, SearchSort::Date);
+        assert_eq!(entries[0].filter, SearchFilter::Comment);
+    }
+
+    #[test]
+    fn cap_drops_oldest_searches() {
+        let dir = TempDir::new().unwrap();
+        let history = history(&dir, 3);
+
+        for query in ["a", "b", "c", "d", "e"] {
+            history
+                .record(query, SearchSort::Relevance, SearchFilter::All)
+                .unwrap();
+        }
+
+        assert_eq!(queries(&history), vec!["e", "d", "c"]);
+    }
+
+    #[test]
+    fn blank_queries_are_not_recorded() {
+        let dir = TempDir::new().unwrap();
+        let history = history(&dir, DEFAULT_MAX_ENTRIES);
+
+        history
+            .record("   ", SearchSort::Relevance, SearchFilter::All)
+            .unwrap();
+
+        assert!(history.entries().is_empty());
+        assert!(!dir.path().join("search_history.json").exists());
+    }
+
+    #[test]
+    fn history_persists_across_loads() {
+        let dir = TempDir::new().unwrap();
+        history(&dir, DEFAULT_MAX_ENTRIES)
+            .record("sqlite", SearchSort::Date, SearchFilter::Story)
+            .unwrap();
+
+        let reloaded = history(&dir, DEFAULT_MAX_ENTRIES);
+        let entries = reloaded.entries();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].query, "sqlite");
+        assert_eq!(entries[0].filter, SearchFilter::Story);
+
+        reloaded.clear().unwrap();
+        assert!(history(&dir, DEFAULT_MAX_ENTRIES).entries().is_empty());
+    }
+
+    #[test]
+    fn corrupt_file_starts_empty() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("search_history.json"), b"not json").unwrap();
+
+        assert!(history(&dir, DEFAULT_MAX_ENTRIES).entries().is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+//! Last-seen comment counts for visited stories, persisted across restarts.
+//!
+//! When the user opens a story, the frontend records its `descendants`
+//! count with `mark_comments_seen`. Later, `new_comments_since` compares the
+//! story's current count against that baseline to show "N new comments".
+//!
+//! At most [`MAX_ENTRIES`] stories are remembered; the least recently seen
+//! are forgotten first. The counts are saved to a JSON file in the app
+//! data directory after every change, through a [`persist::JsonStore`].
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::persist;
+use crate::types::ApiError;
+
+/// Number of stories remembered.
+pub const MAX_ENTRIES: usize = 1000;
+
+/// What the user last saw of a story.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeenEntry {
+    /// The story's `descendants` count at the time
+    pub descendants: u32,
+    /// When the story was seen (Unix milliseconds)
+    pub seen_at: u64,
+}
+
+/// Number of comments added since `last_descendants`.
+///
+/// Deleted comments lower `descendants`, so the difference can be
+/// negative; that is reported as no new comments.
+pub fn comment_delta(descendants: u32, last_descendants: u32) -> i64 {
+    (i64::from(descendants) - i64::from(last_descendants)).max(0)
+}
+
+/// The persisted last-seen counts, keyed by story id.
+///
+/// Managed as Tauri state.
+pub struct SeenComments {
+    store: persist::JsonStore<HashMap<u32, SeenEntry>>,
+}
+
+impl SeenComments {
+    /// Load the counts stored at `path`.
+    pub fn load(path: PathBuf) -> Self {
+        Self {
+            store: persist::JsonStore::load(path, "seen comments"),
+        }
+    }
+
+    /// Default location: `seen_comments.json` in the app data directory.
+    pub fn default_path() -> PathBuf {
+        persist::data_dir().join("seen_comments.json")
+    }
+
+    /// The last-seen `descendants` count for a story, if it was visited.
+    pub fn last_seen(&self, story_id: u32) -> Option<u32> {
+        self.store.lock().get(&story_id).map(|e| e.descendants)
+    }
+
+    /// Record that the user saw `descendants` comments on a story.
+    pub fn mark_seen(&self, story_id: u32, descendants: u32) -> Result<(), ApiError> {
+        let mut entries = self.store.lock();
+        entries.insert(
+            story_id,
+            SeenEntry {
+                descendants,
+                seen_at: persist::now_millis(),
+            },
+        );
+
+        if entries.len() > MAX_ENTRIES {
+            let mut by_age: Vec<(u64, u32)> =
+                entries.iter().map(|(id, e)| (e.seen_at, *id)).collect();
+            by_age.sort_unstable();
+            let excess = entries.len() - MAX_ENTRIES;
+            for (_, id) in by_age.into_iter().take(excess) {
+                entries.remove(&id);
+            }
+        }
+
+        self.store.save(&entries)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn comment_delta_counts_new_comments() {
+        assert_eq!(comment_delta(42, 30), 12);
+        assert_eq!(comment_delta(30, 30), 0);
+        assert_eq!(comment_delta(5, 0), 5);
+    }
+
+    #[test]
+    fn comment_delta_clamps_deleted_comments_to_zero() {
+        assert_eq!(comment_delta(28, 30), 0);
+        assert_eq!(comment_delta(0, u32::MAX), 0);
+    }
+
+    #[test]
+    fn seen_counts_survive_reload() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("seen_comments.json");
+
+        let seen = SeenComments::load(path.clone());
+        assert_eq!(seen.last_seen(8863), None);
+        seen.mark_seen(8863, 71).unwrap();
+        seen.mark_seen(8863, 75).unwrap();
+
+        let reloaded = SeenComments::load(path);
+        assert_eq!(reloaded.last_seen(8863), Some(75));
+    }
+
+    #[test]
+    fn oldest_entries_are_evicted() {
+        let dir = TempDir::new().unwrap();
+        let seen = SeenComments::load(dir.path().join("seen_comments.json"));
+
+        {
+            let mut entries = seen.store.lock();
+            for id in 0..MAX_ENTRIES as u32 {
+                entries.insert(
+                    id,
+                    SeenEntry {
+                        descendants: 1,
+                        seen_at: u64::from(id) + 1,
+                    },
+                );
+            }
+        }
+        seen.mark_seen(99_999, 3).unwrap();
+
+        assert_eq!(seen.store.lock().len(), MAX_ENTRIES);
+        assert_eq!(seen.last_seen(0), None);
+        assert_eq!(seen.last_seen(1), Some(1));
+        assert_eq!(seen.last_seen(99_999), Some(3));
+    }
+}
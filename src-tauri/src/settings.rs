@@ -0,0 +1,271 @@
+//! App preferences persisted across restarts.
+//!
+//! Preferences that the backend applies itself (rather than ones the
+//! frontend keeps in local storage) live in a single JSON file in the app
+//! data directory, saved after every change through a
+//! [`persist::JsonStore`].
+//!
+//! Currently this holds the default comment depth used when
+//! `fetch_story_with_comments` is called without one, globally and per feed,
+//! the last-used TTS voices and rates, re-applied when each TTS backend
+//! is initialized, where neural TTS models are stored, and whether the
+//! neural voice is warmed up at startup.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::persist;
+use crate::types::{ApiError, StoryFeed};
+
+/// Comment depth used when none is configured.
+pub const DEFAULT_COMMENT_DEPTH: u8 = 2;
+
+/// Deepest configurable default; deeper trees are loaded on demand.
+pub const MAX_COMMENT_DEPTH: u8 = 10;
+
+/// The persisted preferences.
+///
+/// Missing fields take their defaults, so older files keep loading as new
+/// preferences are added.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    /// Comment depth for feeds without an override
+    pub comment_depth: u8,
+    /// Per-feed comment depth overrides
+    pub feed_comment_depth: HashMap<StoryFeed, u8>,
+    /// Last-used TTS voices and rates
+    pub tts: VoiceSettings,
+    /// Where neural TTS models are stored; `None` for the platform default
+    pub model_dir: Option<PathBuf>,
+    /// Warm up the saved neural voice when the app starts
+    pub neural_warmup_on_startup: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            comment_depth: DEFAULT_COMMENT_DEPTH,
+            feed_comment_depth: HashMap::new(),
+            tts: VoiceSettings::default(),
+            model_dir: None,
+            neural_warmup_on_startup: false,
+        }
+    }
+}
+
+/// The TTS voice and rate last chosen for each backend.
+///
+/// `None` means the backend's default.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct VoiceSettings {
+    /// System voice id
+    pub native_voice: Option<String>,
+    /// System speech rate (0.0 - 1.0, where 0.5 is normal)
+    pub native_rate: Option<f32>,
+    /// Neural voice id
+    pub neural_voice: Option<String>,
+    /// Neural speech rate (0.5 - 2.0, where 1.0 is normal)
+    pub neural_rate: Option<f32>,
+}
+
+impl Settings {
+    /// Default comment depth for `feed`, or the global default.
+    pub fn comment_depth_for(&self, feed: Option<StoryFeed>) -> u8 {
+        feed.and_then(|feed| self.feed_comment_depth.get(&feed).copied())
+            .unwrap_or(self.comment_depth)
+    }
+}
+
+/// The settings file and its current contents.
+///
+/// Managed as Tauri state.
+pub struct SettingsStore {
+    store: persist::JsonStore<Settings>,
+}
+
+impl SettingsStore {
+    /// Load the settings stored at `path`.
+    pub fn load(path: PathBuf) -> Self {
+        Self {
+            store: persist::JsonStore::load(path, "settings"),
+        }
+    }
+
+    /// Default location: `settings.json` in the app data directory.
+    pub fn default_path() -> PathBuf {
+        persist::data_dir().join("settings.json")
+    }
+
+    /// A copy of the current settings.
+    pub fn get(&self) -> Settings {
+        self.store.lock().clone()
+    }
+
+    /// Change the settings with `f` and persist the result.
+    pub fn update(&self, f: impl FnOnce(&mut Settings)) -> Result<Settings, ApiError> {
+        let mut settings = self.store.lock();
+        f(&mut settings);
+        self.store.save(&settings)?;
+        Ok(settings.clone())
+    }
+
+    /// Default comment depth for `feed`, or the global default.
+    pub fn comment_depth_for(&self, feed: Option<StoryFeed>) -> u8 {
+        self.store.lock().comment_depth_for(feed)
+    }
+
+    /// Set the default comment depth, for one feed or globally.
+    ///
+    /// Depths above [`MAX_COMMENT_DEPTH`] are clamped. Passing `None`
+    /// removes the feed's override, or resets the global default to
+    /// [`DEFAULT_COMMENT_DEPTH`].
+    pub fn set_comment_depth(
+        &self,
+        feed: Option<StoryFeed>,
+        depth: Option<u8>,
+    ) -> Result<Settings, ApiError> {
+        let depth = depth.map(|d| d.min(MAX_COMMENT_DEPTH));
+        info!("Default comment depth for {:?} set to {:?}", feed, depth);
+
+        self.update(|settings| match (feed, depth) {
+            (Some(feed), Some(depth)) => {
+                settings.feed_comment_depth.insert(feed, depth);
+            }
+            (Some(feed), None) => {
+                settings.feed_comment_depth.remove(&feed);
+            }
+            (None, depth) => settings.comment_depth = depth.unwrap_or(DEFAULT_COMMENT_DEPTH),
+        })
+    }
+
+    /// The saved TTS voices and rates.
+    pub fn voice_settings(&self) -> VoiceSettings {
+        self.store.lock().tts.clone()
+    }
+
+    /// Change the saved TTS voices and rates with `f` and persist them.
+    pub fn update_voice(
+        &self,
+        f: impl FnOnce(&mut VoiceSettings),
+    ) -> Result<VoiceSettings, ApiError> {
+        self.update(|settings| f(&mut settings.tts))
+            .map(|settings| settings.tts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn store(dir: &TempDir) -> SettingsStore {
+        SettingsStore::load(dir.path().join("settings.json"))
+    }
+
+    #[test]
+    fn unset_depth_resolves_to_default() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+
+        assert_eq!(store.comment_depth_for(None), DEFAULT_COMMENT_DEPTH);
+        assert_eq!(
+            store.comment_depth_for(Some(StoryFeed::Ask)),
+            DEFAULT_COMMENT_DEPTH
+        );
+    }
+
+    #[test]
+    fn feed_override_takes_precedence_over_global() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+
+        store.set_comment_depth(None, Some(4)).unwrap();
+        store
+            .set_comment_depth(Some(StoryFeed::Ask), Some(6))
+            .unwrap();
+
+        assert_eq!(store.comment_depth_for(None), 4);
+        assert_eq!(store.comment_depth_for(Some(StoryFeed::Top)), 4);
+        assert_eq!(store.comment_depth_for(Some(StoryFeed::Ask)), 6);
+
+        store.set_comment_depth(Some(StoryFeed::Ask), None).unwrap();
+        assert_eq!(store.comment_depth_for(Some(StoryFeed::Ask)), 4);
+    }
+
+    #[test]
+    fn depth_is_clamped() {
+        let dir = TempDir::new().unwrap();
+        let store = store(&dir);
+
+        store.set_comment_depth(None, Some(200)).unwrap();
+        assert_eq!(store.comment_depth_for(None), MAX_COMMENT_DEPTH);
+    }
+
+    #[test]
+    fn settings_survive_reload() {
+        let dir = TempDir::new().unwrap();
+        store(&dir)
+            .set_comment_depth(Some(StoryFeed::Show), Some(3))
+            .unwrap();
+
+        let reloaded = store(&dir);
+        assert_eq!(reloaded.comment_depth_for(Some(StoryFeed::Show)), 3);
+        assert_eq!(reloaded.comment_depth_for(None), DEFAULT_COMMENT_DEPTH);
+    }
+
+    #[test]
+    fn voice_settings_survive_reload() {
+        let dir = TempDir::new().unwrap();
+        store(&dir)
+            .update_voice(|tts| {
+                tts.native_voice = Some("com.apple.voice.Samantha".to_string());
+                tts.native_rate = Some(0.6);
+                tts.neural_voice = Some("piper-en-us".to_string());
+                tts.neural_rate = Some(1.25);
+            })
+            .unwrap();
+
+        let tts = store(&dir).voice_settings();
+        assert_eq!(
+            tts.native_voice.as_deref(),
+            Some("com.apple.voice.Samantha")
+        );
+        assert_eq!(tts.native_rate, Some(0.6));
+        assert_eq!(tts.neural_voice.as_deref(), Some("piper-en-us"));
+        assert_eq!(tts.neural_rate, Some(1.25));
+    }
+
+    #[test]
+    fn files_without_voice_settings_still_load() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("settings.json"), br#"{"commentDepth":5}"#).unwrap();
+
+        let store = store(&dir);
+        assert_eq!(store.comment_depth_for(None), 5);
+        assert_eq!(store.voice_settings(), VoiceSettings::default());
+    }
+
+    #[test]
+    fn startup_warmup_is_off_until_enabled() {
+        let dir = TempDir::new().unwrap();
+        assert!(!store(&dir).get().neural_warmup_on_startup);
+
+        store(&dir)
+            .update(|s| s.neural_warmup_on_startup = true)
+            .unwrap();
+        assert!(store(&dir).get().neural_warmup_on_startup);
+    }
+
+    #[test]
+    fn corrupt_file_falls_back_to_defaults() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("settings.json"), b"not json").unwrap();
+
+        assert_eq!(store(&dir).get(), Settings::default());
+    }
+}
@@ -0,0 +1,286 @@
+//! Configurable global keyboard shortcuts.
+//!
+//! Shortcuts are described by accelerator strings such as
+//! `"Super+Shift+H"` or `"CommandOrControl+Alt+J"` and registered with the
+//! OS through `tauri-plugin-global-shortcut`, so they work while the app is
+//! in the background.
+//!
+//! | Action | Default | Effect |
+//! |--------|---------|--------|
+//! | Show window | `Super+Shift+H` | Bring the main window to the front |
+//! | Refresh | `Super+Shift+R` | Emit `tray-refresh` and bring the window to the front |
+//! | Next story | `Super+Shift+J` | Emit `tray-next-story`, leaving focus where it is |
+//! | Previous story | `Super+Shift+K` | Emit `tray-prev-story`, leaving focus where it is |
+//!
+//! [`GlobalShortcuts`] is managed as Tauri state. Reconfiguring unregisters
+//! the previous shortcuts before registering the new ones.
+
+use std::sync::{Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+use tracing::info;
+
+/// Something a global shortcut can do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutAction {
+    ShowWindow,
+    Refresh,
+    NextStory,
+    PrevStory,
+}
+
+/// Accelerator for each shortcut action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutConfig {
+    pub show_window: String,
+    pub refresh: String,
+    pub next_story: String,
+    pub prev_story: String,
+}
+
+impl Default for ShortcutConfig {
+    fn default() -> Self {
+        Self {
+            show_window: "Super+Shift+H".to_string(),
+            refresh: "Super+Shift+R".to_string(),
+            next_story: "Super+Shift+J".to_string(),
+            prev_story: "Super+Shift+K".to_string(),
+        }
+    }
+}
+
+/// Parse an accelerator such as `"CommandOrControl+Shift+J"`.
+///
+/// Parsing is the plugin's own, so names are case-insensitive and the key
+/// comes last. At least one modifier is required as well: a bare global
+/// key would swallow that key in every other application.
+pub fn parse_accelerator(accelerator: &str) -> Result<Shortcut, String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|e| format!("Invalid shortcut \"{}\": {}", accelerator, e))?;
+    if shortcut.mods.is_empty() {
+        return Err(format!(
+            "Shortcut \"{}\" needs at least one modifier",
+            accelerator
+        ));
+    }
+    Ok(shortcut)
+}
+
+/// Parse every accelerator in `config`, rejecting duplicates.
+pub fn bindings(config: &ShortcutConfig) -> Result<Vec<(Shortcut, ShortcutAction)>, String> {
+    let entries = [
+        (&config.show_window, ShortcutAction::ShowWindow),
+        (&config.refresh, ShortcutAction::Refresh),
+        (&config.next_story, ShortcutAction::NextStory),
+        (&config.prev_story, ShortcutAction::PrevStory),
+    ];
+
+    let mut bindings: Vec<(Shortcut, ShortcutAction)> = Vec::with_capacity(entries.len());
+    for (accelerator, action) in entries {
+        let shortcut = parse_accelerator(accelerator)?;
+        if bindings.iter().any(|(existing, _)| *existing == shortcut) {
+            return Err(format!(
+                "Shortcut \"{}\" is assigned more than once",
+                accelerator
+            ));
+        }
+        bindings.push((shortcut, action));
+    }
+    Ok(bindings)
+}
+
+#[derive(Default)]
+struct Registered {
+    config: ShortcutConfig,
+    bindings: Vec<(Shortcut, ShortcutAction)>,
+}
+
+/// The currently registered global shortcuts.
+#[derive(Default)]
+pub struct GlobalShortcuts {
+    registered: Mutex<Registered>,
+}
+
+impl GlobalShortcuts {
+    /// Nothing registered yet; call [`apply`](Self::apply) during setup.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The active configuration.
+    pub fn config(&self) -> ShortcutConfig {
+        self.lock().config.clone()
+    }
+
+    /// The action bound to `shortcut`, if any.
+    pub fn action_for(&self, shortcut: &Shortcut) -> Option<ShortcutAction> {
+        self.lock()
+            .bindings
+            .iter()
+            .find(|(s, _)| s == shortcut)
+            .map(|(_, action)| *action)
+    }
+
+    /// Replace the registered shortcuts with `config`.
+    ///
+    /// The whole configuration is validated first, so an invalid
+    /// accelerator leaves the current shortcuts untouched. A shortcut the
+    /// OS refuses (e.g. already taken by another app) is logged and
+    /// skipped rather than failing the rest.
+    pub fn apply(&self, app: &AppHandle, config: ShortcutConfig) -> Result<(), String> {
+        let bindings = bindings(&config)?;
+        let mut registered = self.lock();
+
+        for (shortcut, _) in &registered.bindings {
+            if let Err(e) = app.global_shortcut().unregister(*shortcut) {
+                info!("Failed to unregister shortcut {:?}: {}", shortcut, e);
+            }
+        }
+
+        let accelerators = [
+            &config.show_window,
+            &config.refresh,
+            &config.next_story,
+            &config.prev_story,
+        ];
+        for ((shortcut, action), accelerator) in bindings.iter().zip(accelerators) {
+            if let Err(e) = app.global_shortcut().register(*shortcut) {
+                info!("Failed to register {} shortcut: {}", accelerator, e);
+            } else {
+                info!("Registered global shortcut: {} ({:?})", accelerator, action);
+            }
+        }
+
+        registered.config = config;
+        registered.bindings = bindings;
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Registered> {
+        self.registered.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Carry out `action` in response to its shortcut.
+pub fn perform(app: &AppHandle, action: ShortcutAction) {
+    info!("Global shortcut: {:?}", action);
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+
+    match action {
+        ShortcutAction::ShowWindow => {}
+        ShortcutAction::Refresh => {
+            let _ = app.emit_to("main", "tray-refresh", ());
+        }
+        // Stepping through stories shouldn't pull focus from the foreground app
+        ShortcutAction::NextStory => {
+            let _ = app.emit_to("main", "tray-next-story", ());
+            return;
+        }
+        ShortcutAction::PrevStory => {
+            let _ = app.emit_to("main", "tray-prev-story", ());
+            return;
+        }
+    }
+    let _ = window.unminimize();
+    let _ = window.show();
+    let _ = window.set_focus();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tauri_plugin_global_shortcut::{Code, Modifiers};
+
+    fn primary() -> Modifiers {
+        if cfg!(target_os = "macos") {
+            Modifiers::SUPER
+        } else {
+            Modifiers::CONTROL
+        }
+    }
+
+    #[test]
+    fn parses_modifiers_and_key() {
+        assert_eq!(
+            parse_accelerator("Super+Shift+H").unwrap(),
+            Shortcut::new(Some(Modifiers::SUPER | Modifiers::SHIFT), Code::KeyH)
+        );
+        assert_eq!(
+            parse_accelerator("ctrl + alt + right").unwrap(),
+            Shortcut::new(Some(Modifiers::CONTROL | Modifiers::ALT), Code::ArrowRight)
+        );
+        assert_eq!(
+            parse_accelerator("Cmd+F5").unwrap(),
+            Shortcut::new(Some(Modifiers::SUPER), Code::F5)
+        );
+    }
+
+    #[test]
+    fn command_or_control_maps_per_platform() {
+        assert_eq!(
+            parse_accelerator("CommandOrControl+Shift+J").unwrap(),
+            Shortcut::new(Some(primary() | Modifiers::SHIFT), Code::KeyJ)
+        );
+        assert_eq!(
+            parse_accelerator("CmdOrCtrl+1").unwrap(),
+            Shortcut::new(Some(primary()), Code::Digit1)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_accelerators() {
+        assert!(parse_accelerator("").is_err());
+        assert!(parse_accelerator("Shift+").is_err());
+        assert!(parse_accelerator("Super+Shift").is_err());
+        assert!(parse_accelerator("J")
+            .unwrap_err()
+            .contains("needs at least one modifier"));
+        assert!(parse_accelerator("Super+J+K").is_err());
+        assert!(parse_accelerator("Super+Banana").is_err());
+    }
+
+    #[test]
+    fn default_config_matches_previous_shortcuts() {
+        let bindings = bindings(&ShortcutConfig::default()).unwrap();
+        let mods = Some(Modifiers::SUPER | Modifiers::SHIFT);
+
+        assert_eq!(
+            bindings,
+            vec![
+                (Shortcut::new(mods, Code::KeyH), ShortcutAction::ShowWindow),
+                (Shortcut::new(mods, Code::KeyR), ShortcutAction::Refresh),
+                (Shortcut::new(mods, Code::KeyJ), ShortcutAction::NextStory),
+                (Shortcut::new(mods, Code::KeyK), ShortcutAction::PrevStory),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_shortcuts_are_rejected() {
+        let config = ShortcutConfig {
+            next_story: "shift+super+r".to_string(),
+            ..ShortcutConfig::default()
+        };
+
+        let err = bindings(&config).unwrap_err();
+        assert!(err.contains("more than once"));
+    }
+
+    #[test]
+    fn bad_accelerator_is_reported() {
+        let config = ShortcutConfig {
+            prev_story: "Super+Nope".to_string(),
+            ..ShortcutConfig::default()
+        };
+
+        assert!(bindings(&config)
+            .unwrap_err()
+            .starts_with("Invalid shortcut \"Super+Nope\""));
+    }
+}
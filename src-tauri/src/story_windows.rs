@@ -0,0 +1,170 @@
+//! Dedicated windows for reading a single story.
+//!
+//! Each story window loads the same frontend as the main window, routed to
+//! `#item/<id>`, so it shares all managed state (the [`HnClient`] caches,
+//! settings in local storage, and the pastel theme). Windows are labelled
+//! `story-<id>`; opening a story that already has a window focuses it.
+//!
+//! At most [`MAX_STORY_WINDOWS`] story windows can be open at once.
+//!
+//! [`HnClient`]: crate::client::HnClient
+
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use tracing::info;
+
+use crate::client::HnClient;
+
+/// Maximum number of story windows open at the same time.
+pub const MAX_STORY_WINDOWS: usize = 5;
+
+const LABEL_PREFIX: &str = "story-";
+
+/// Where the frontend is served from, shared by every window.
+///
+/// Managed as Tauri state.
+pub struct FrontendUrl(pub WebviewUrl);
+
+/// Window label for a story.
+pub fn window_label(story_id: u32) -> String {
+    format!("{}{}", LABEL_PREFIX, story_id)
+}
+
+/// Whether `label` belongs to a story window.
+pub fn is_story_window(label: &str) -> bool {
+    label
+        .strip_prefix(LABEL_PREFIX)
+        .is_some_and(|id| id.parse::<u32>().is_ok())
+}
+
+/// The frontend URL routed to a story's detail view.
+pub fn story_url(base: &WebviewUrl, story_id: u32) -> WebviewUrl {
+    let route = format!("item/{}", story_id);
+    match base {
+        WebviewUrl::External(url) => {
+            let mut url = url.clone();
+            url.set_fragment(Some(&route));
+            WebviewUrl::External(url)
+        }
+        WebviewUrl::App(path) => WebviewUrl::App(format!("{}#{}", path.display(), route).into()),
+        other => other.clone(),
+    }
+}
+
+fn window_title(title: Option<&str>) -> String {
+    match title {
+        Some(title) => format!("{} | pastel-hn", title),
+        None => "pastel-hn".to_string(),
+    }
+}
+
+/// Focus the window labelled `label`, if there is one.
+fn focus_existing(app: &AppHandle, label: &str) -> bool {
+    let Some(window) = app.get_webview_window(label) else {
+        return false;
+    };
+    let _ = window.unminimize();
+    let _ = window.show();
+    let _ = window.set_focus();
+    true
+}
+
+/// Fail if [`MAX_STORY_WINDOWS`] story windows are already open.
+fn check_window_limit(app: &AppHandle) -> Result<(), String> {
+    let open = app
+        .webview_windows()
+        .keys()
+        .filter(|label| is_story_window(label))
+        .count();
+    if open >= MAX_STORY_WINDOWS {
+        return Err(format!(
+            "Too many story windows open (max {}). Close one and try again.",
+            MAX_STORY_WINDOWS
+        ));
+    }
+    Ok(())
+}
+
+/// Open `story_id` in its own window, or focus its existing window.
+///
+/// The window title comes from the (usually cached) story, and the window
+/// uses the main window's theme.
+pub async fn open(app: &AppHandle, client: &HnClient, story_id: u32) -> Result<(), String> {
+    let label = window_label(story_id);
+    if focus_existing(app, &label) {
+        return Ok(());
+    }
+    check_window_limit(app)?;
+
+    let base = app
+        .try_state::<FrontendUrl>()
+        .map(|url| url.0.clone())
+        .unwrap_or_else(|| WebviewUrl::App("/".into()));
+    let title = client
+        .fetch_item(story_id)
+        .await
+        .ok()
+        .and_then(|item| item.title);
+    let theme = app
+        .get_webview_window("main")
+        .and_then(|main| main.theme().ok());
+
+    // Other opens may have finished while the story was fetched
+    if focus_existing(app, &label) {
+        return Ok(());
+    }
+    check_window_limit(app)?;
+
+    WebviewWindowBuilder::new(app, &label, story_url(&base, story_id))
+        .title(window_title(title.as_deref()))
+        .inner_size(1100.0, 900.0)
+        .min_inner_size(640.0, 480.0)
+        .resizable(true)
+        .theme(theme)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    info!("Opened story window for {}", story_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_identify_story_windows() {
+        assert_eq!(window_label(8863), "story-8863");
+        assert!(is_story_window("story-8863"));
+        assert!(!is_story_window("main"));
+        assert!(!is_story_window("story-"));
+        assert!(!is_story_window("story-abc"));
+    }
+
+    #[test]
+    fn external_url_gets_story_fragment() {
+        let base = WebviewUrl::External("http://localhost:4321/".parse().unwrap());
+
+        match story_url(&base, 42) {
+            WebviewUrl::External(url) => {
+                assert_eq!(url.as_str(), "http://localhost:4321/#item/42")
+            }
+            other => panic!("unexpected url {:?}", other),
+        }
+    }
+
+    #[test]
+    fn app_url_gets_story_fragment() {
+        let base = WebviewUrl::App("/".into());
+
+        match story_url(&base, 42) {
+            WebviewUrl::App(path) => assert_eq!(path.to_str(), Some("/#item/42")),
+            other => panic!("unexpected url {:?}", other),
+        }
+    }
+
+    #[test]
+    fn title_falls_back_to_app_name() {
+        assert_eq!(window_title(Some("Show HN: X")), "Show HN: X | pastel-hn");
+        assert_eq!(window_title(None), "pastel-hn");
+    }
+}
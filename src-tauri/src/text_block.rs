@@ -0,0 +1,136 @@
+//! Fallback article extraction: the page's largest block of text.
+//!
+//! Readability sometimes picks the wrong node (a teaser, a cookie banner)
+//! on pages with unusual markup, leaving reader mode with a sentence or
+//! two. [`largest_text_block`] is a cruder second pass: drop scripts and
+//! page chrome, split the page at container elements (`<div>`,
+//! `<article>`, `<section>`, ...), and keep the container with the most
+//! words.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::export::xml_escape;
+use crate::html;
+
+/// Elements whose contents are never article text.
+const SKIPPED_ELEMENTS: &[&str] = &[
+    "head", "script", "style", "noscript", "template", "svg", "nav", "header", "footer", "aside",
+    "form",
+];
+
+/// The `regex` crate has no backreferences, so each skipped element gets
+/// its own alternative.
+static SKIPPED: Lazy<Regex> = Lazy::new(|| {
+    let elements = SKIPPED_ELEMENTS
+        .iter()
+        .map(|tag| format!(r"<{0}\b.*?</{0}\s*>", tag))
+        .collect::<Vec<_>>()
+        .join("|");
+    Regex::new(&format!(r"(?is)<!--.*?-->|{}", elements)).unwrap()
+});
+static CONTAINER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)</?(?:div|article|section|main|body|td)\b[^>]*>").unwrap());
+static PARAGRAPH_BREAK: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)</?(?:p|br|li|h[1-6]|blockquote|pre|tr)\b[^>]*>").unwrap());
+static TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+/// A run of text found by [`largest_text_block`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextBlock {
+    /// Plain-text paragraphs, whitespace collapsed
+    pub paragraphs: Vec<String>,
+    /// Words across all paragraphs
+    pub word_count: usize,
+}
+
+impl TextBlock {
+    /// The paragraphs as escaped `<p>` elements, for the reader view.
+    pub fn html(&self) -> String {
+        self.paragraphs
+            .iter()
+            .map(|p| format!("<p>{}</p>", xml_escape(p)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The paragraphs separated by blank lines.
+    pub fn text(&self) -> String {
+        self.paragraphs.join("\n\n")
+    }
+}
+
+/// Plain text of an HTML fragment, with whitespace collapsed.
+fn plain_text(fragment: &str) -> String {
+    let text = html::decode_entities(&TAG.replace_all(fragment, " "));
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The block of `page` with the most words, if it has any text at all.
+///
+/// Text directly inside a container counts towards it; text in nested
+/// containers counts towards those instead, so a wrapper `<div>` around
+/// the whole page doesn't win just by holding everything.
+pub fn largest_text_block(page: &str) -> Option<TextBlock> {
+    let page = SKIPPED.replace_all(page, " ");
+
+    CONTAINER
+        .split(&page)
+        .map(|block| {
+            let paragraphs: Vec<String> = PARAGRAPH_BREAK
+                .split(block)
+                .map(plain_text)
+                .filter(|p| !p.is_empty())
+                .collect();
+            let word_count = paragraphs
+                .iter()
+                .map(|p| p.split_whitespace().count())
+                .sum();
+            TextBlock {
+                paragraphs,
+                word_count,
+            }
+        })
+        .filter(|block| block.word_count > 0)
+        .max_by_key(|block| block.word_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_container_with_the_most_words() {
+        let page = r#"<html><head><style>p { color: red }</style></head><body>
+            <nav><a href="/">Home</a> <a href="/about">About us and more links</a></nav>
+            <div class="teaser"><p>Short teaser.</p></div>
+            <div class="post">
+              <h2>The &amp; heading</h2>
+              <p>First paragraph of the   actual post.</p>
+              <p>Second paragraph<br>with a line break.</p>
+              <script>var words = "lots of words inside a script tag here";</script>
+            </div>
+            <!-- a comment with several words in it -->
+            <footer>Copyright notice with a few words</footer>
+            </body></html>"#;
+
+        let block = largest_text_block(page).unwrap();
+        assert_eq!(
+            block.paragraphs,
+            [
+                "The & heading",
+                "First paragraph of the actual post.",
+                "Second paragraph",
+                "with a line break.",
+            ]
+        );
+        assert_eq!(block.word_count, 15);
+        assert!(block.html().starts_with("<p>The &amp; heading</p>\n"));
+    }
+
+    #[test]
+    fn no_text_means_no_block() {
+        assert_eq!(largest_text_block("<div><script>x()</script></div>"), None);
+        assert_eq!(largest_text_block(""), None);
+    }
+}
@@ -0,0 +1,173 @@
+//! Table of contents for reader mode.
+//!
+//! [`add_heading_anchors`] collects the `h1`-`h4` headings of an extracted
+//! article and gives each one an `id` attribute, so the frontend can render
+//! a table of contents that jumps to each section.
+
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+use crate::html;
+use crate::types::Heading;
+
+/// `h1`-`h4` elements. The regex crate has no backreferences, so each
+/// level is its own alternative with its own closing tag: level `n` puts
+/// its attributes and inner HTML in groups `2n - 1` and `2n`.
+static HEADING: Lazy<Regex> = Lazy::new(|| {
+    let levels: Vec<String> = (1..=4)
+        .map(|n| format!(r"<h{n}\b([^>]*)>(.*?)</h{n}\s*>"))
+        .collect();
+    Regex::new(&format!("(?is){}", levels.join("|"))).unwrap()
+});
+static ID_ATTR: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)(?:^|\s)id\s*=\s*["']([^"']+)["']"#).unwrap());
+/// `id` attributes on any element.
+static ELEMENT_ID: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)<[a-z][^>]*?\sid\s*=\s*["']([^"']+)["']"#).unwrap());
+
+/// Turn heading text into a URL-fragment-safe slug.
+///
+/// Lowercases, keeps letters and digits, and collapses everything else into
+/// single hyphens. Returns `"section"` for headings with no usable text.
+fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+
+    if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Collect the headings of `content` and add anchor ids to them.
+///
+/// Headings are returned in document order. Ids are slugs of the heading
+/// text, suffixed `-1`, `-2`, ... to keep them unique, including against
+/// ids already in the content. A heading that already has an `id` keeps
+/// it. Empty headings are left untouched.
+///
+/// # Returns
+///
+/// The content with ids injected, and the headings found.
+pub fn add_heading_anchors(content: &str) -> (String, Vec<Heading>) {
+    let mut headings = Vec::new();
+    let mut used: HashSet<String> = ELEMENT_ID
+        .captures_iter(content)
+        .map(|caps| caps[1].to_string())
+        .collect();
+
+    let anchored = HEADING.replace_all(content, |caps: &Captures| {
+        let n = (1..=4)
+            .find(|&n| caps.get(2 * n - 1).is_some())
+            .unwrap_or(1);
+        let (attrs, inner) = (&caps[2 * n - 1], &caps[2 * n]);
+        let level = n as u8;
+
+        let text = html::to_plain_text(inner)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        if text.is_empty() {
+            return caps[0].to_string();
+        }
+
+        if let Some(existing) = ID_ATTR.captures(attrs) {
+            let id = existing[1].to_string();
+            headings.push(Heading { level, text, id });
+            return caps[0].to_string();
+        }
+
+        let base = slugify(&text);
+        let mut id = base.clone();
+        let mut n = 1;
+        while !used.insert(id.clone()) {
+            id = format!("{}-{}", base, n);
+            n += 1;
+        }
+
+        let tag = format!(r#"<h{}{} id="{}">{}</h{}>"#, level, attrs, id, inner, level);
+        headings.push(Heading { level, text, id });
+        tag
+    });
+
+    (anchored.into_owned(), headings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(headings: &[Heading]) -> Vec<&str> {
+        headings.iter().map(|h| h.id.as_str()).collect()
+    }
+
+    #[test]
+    fn collects_headings_in_document_order() {
+        let content = "<h1>Intro</h1><p>a</p><h3 class=\"x\">Deep <em>dive</em></h3><h2>Wrap-up &amp; notes</h2><h5>Ignored</h5>";
+        let (_, headings) = add_heading_anchors(content);
+
+        let levels: Vec<u8> = headings.iter().map(|h| h.level).collect();
+        let texts: Vec<&str> = headings.iter().map(|h| h.text.as_str()).collect();
+        assert_eq!(levels, vec![1, 3, 2]);
+        assert_eq!(texts, vec!["Intro", "Deep dive", "Wrap-up & notes"]);
+        assert_eq!(ids(&headings), vec!["intro", "deep-dive", "wrap-up-notes"]);
+    }
+
+    #[test]
+    fn duplicate_titles_get_unique_slugs() {
+        let content = "<h2>Setup</h2><h2>Setup</h2><h2>Setup-1</h2><h2>Setup</h2>";
+        let (_, headings) = add_heading_anchors(content);
+
+        assert_eq!(
+            ids(&headings),
+            vec!["setup", "setup-1", "setup-1-1", "setup-2"]
+        );
+    }
+
+    #[test]
+    fn injects_ids_and_keeps_existing_ones() {
+        let content = r#"<h2 class="t">Getting Started</h2><h3 id="custom">Kept</h3><h4>  </h4>"#;
+        let (anchored, headings) = add_heading_anchors(content);
+
+        assert_eq!(
+            anchored,
+            r#"<h2 class="t" id="getting-started">Getting Started</h2><h3 id="custom">Kept</h3><h4>  </h4>"#
+        );
+        assert_eq!(ids(&headings), vec!["getting-started", "custom"]);
+    }
+
+    #[test]
+    fn closing_tag_must_match_the_level() {
+        let content = "<h2>Broken</h3><p>text</p><h3>Real</h3>";
+        let (anchored, headings) = add_heading_anchors(content);
+
+        assert_eq!(ids(&headings), vec!["real"]);
+        assert!(anchored.starts_with("<h2>Broken</h3>"));
+    }
+
+    #[test]
+    fn generated_ids_avoid_existing_ids() {
+        let content =
+            r#"<h2>Intro</h2><h2 id="intro">Intro again</h2><p id="usage">x</p><h3>Usage</h3>"#;
+        let (_, headings) = add_heading_anchors(content);
+
+        assert_eq!(ids(&headings), vec!["intro-1", "intro", "usage-1"]);
+    }
+
+    #[test]
+    fn slugify_handles_symbols_and_empty_text() {
+        assert_eq!(slugify("  What's New in 2.0?  "), "what-s-new-in-2-0");
+        assert_eq!(slugify("Ünïcode Héadings"), "ünïcode-héadings");
+        assert_eq!(slugify("!!!"), "section");
+    }
+}
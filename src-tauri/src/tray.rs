@@ -0,0 +1,188 @@
+//! "Recent" tray submenu listing the current top stories.
+//!
+//! The submenu is created empty during tray setup and filled in the
+//! background. It is rebuilt whenever the Top feed's story IDs change
+//! (from auto-refresh or a frontend-triggered background refresh).
+//!
+//! Each entry's menu id encodes the story id (`recent_story_<id>`); clicking
+//! one emits a `tray-open-story` event with that id to the main window.
+
+use tauri::menu::{MenuItem, Submenu};
+use tauri::{AppHandle, Manager, Wry};
+use tracing::{debug, warn};
+
+use crate::client::HnClient;
+use crate::types::{HNItem, StoryFeed};
+
+/// Number of stories shown in the submenu.
+pub const RECENT_STORIES_COUNT: usize = 5;
+
+/// Longest title shown before truncating with an ellipsis.
+pub const MAX_TITLE_CHARS: usize = 48;
+
+const STORY_ITEM_PREFIX: &str = "recent_story_";
+const EMPTY_ITEM_ID: &str = "recent_empty";
+
+/// Handle to the "Recent" submenu, managed as Tauri state.
+pub struct RecentStoriesMenu(pub Submenu<Wry>);
+
+/// Menu id for a story entry.
+pub fn story_item_id(story_id: u32) -> String {
+    format!("{}{}", STORY_ITEM_PREFIX, story_id)
+}
+
+/// Story id encoded in a menu id, if it is a story entry.
+pub fn parse_story_item_id(menu_id: &str) -> Option<u32> {
+    menu_id.strip_prefix(STORY_ITEM_PREFIX)?.parse().ok()
+}
+
+/// Shorten `title` to at most `max_chars` characters.
+///
+/// Cuts at the last word boundary when one is reasonably close, so titles
+/// don't end mid-word, and marks the cut with an ellipsis.
+pub fn truncate_title(title: &str, max_chars: usize) -> String {
+    let title = title.trim();
+    if title.chars().count() <= max_chars {
+        return title.to_string();
+    }
+
+    // Leave room for the ellipsis
+    let cut: String = title.chars().take(max_chars.saturating_sub(1)).collect();
+    let cut = match cut.rfind(' ') {
+        Some(space) if space >= cut.len() * 2 / 3 => &cut[..space],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut.trim_end())
+}
+
+/// Build the menu entries' (id, label) pairs for `stories`.
+fn entries(stories: &[HNItem]) -> Vec<(String, String)> {
+    stories
+        .iter()
+        .filter_map(|story| {
+            let title = story.title.as_deref()?;
+            Some((
+                story_item_id(story.id),
+                truncate_title(title, MAX_TITLE_CHARS),
+            ))
+        })
+        .take(RECENT_STORIES_COUNT)
+        .collect()
+}
+
+/// Replace the submenu's items with `stories`.
+pub fn set_recent_stories(app: &AppHandle, stories: &[HNItem]) -> tauri::Result<()> {
+    let Some(menu) = app.try_state::<RecentStoriesMenu>() else {
+        return Ok(());
+    };
+    let submenu = &menu.0;
+
+    for item in submenu.items()? {
+        submenu.remove(&item)?;
+    }
+
+    let entries = entries(stories);
+    if entries.is_empty() {
+        let empty = MenuItem::with_id(app, EMPTY_ITEM_ID, "No stories yet", false, None::<&str>)?;
+        return submenu.append(&empty);
+    }
+
+    for (id, label) in entries {
+        let item = MenuItem::with_id(app, id, label, true, None::<&str>)?;
+        submenu.append(&item)?;
+    }
+    Ok(())
+}
+
+/// Fetch the current top stories and rebuild the submenu.
+///
+/// Uses the client's caches, so calling this right after a feed refresh
+/// costs at most a few item fetches. Failures are logged and leave the
+/// previous entries in place.
+pub async fn refresh_recent_stories(app: &AppHandle, client: &HnClient) {
+    match client
+        .fetch_stories_paginated(StoryFeed::Top, 0, RECENT_STORIES_COUNT, true)
+        .await
+    {
+        Ok(page) => {
+            debug!("Updating tray recent stories ({})", page.stories.len());
+            if let Err(e) = set_recent_stories(app, &page.stories) {
+                warn!("Failed to update tray recent stories: {}", e);
+            }
+        }
+        Err(e) => warn!("Failed to fetch stories for tray: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn story_item_ids_round_trip() {
+        assert_eq!(story_item_id(8863), "recent_story_8863");
+        assert_eq!(parse_story_item_id("recent_story_8863"), Some(8863));
+        assert_eq!(parse_story_item_id("recent_story_abc"), None);
+        assert_eq!(parse_story_item_id("feed_top"), None);
+        assert_eq!(parse_story_item_id(EMPTY_ITEM_ID), None);
+    }
+
+    #[test]
+    fn short_titles_are_unchanged() {
+        assert_eq!(
+            truncate_title("  Show HN: pastel-hn ", 48),
+            "Show HN: pastel-hn"
+        );
+    }
+
+    #[test]
+    fn long_titles_cut_at_word_boundary() {
+        let title = "The surprising effectiveness of simple caching strategies";
+        let short = truncate_title(title, 30);
+
+        assert_eq!(short, "The surprising effectiveness…");
+        assert!(short.chars().count() <= 30);
+    }
+
+    #[test]
+    fn long_words_are_cut_mid_word() {
+        let short = truncate_title("Supercalifragilisticexpialidocious", 10);
+        assert_eq!(short, "Supercali…");
+    }
+
+    #[test]
+    fn truncation_respects_multibyte_characters() {
+        let short = truncate_title(&"日本語".repeat(10), 5);
+        assert_eq!(short, "日本語日…");
+    }
+
+    #[test]
+    fn entries_skip_untitled_items_and_cap_count() {
+        let stories: Vec<HNItem> = (1..=8)
+            .map(|id| HNItem {
+                id,
+                item_type: 0,
+                by: Some("pg".to_string()),
+                time: 1609459200,
+                text: None,
+                url: None,
+                score: 1,
+                title: (id != 2).then(|| format!("Story {}", id)),
+                descendants: 0,
+                kids: None,
+                parent: None,
+                dead: false,
+                deleted: false,
+                post_kind: None,
+            })
+            .collect();
+
+        let entries = entries(&stories);
+        assert_eq!(entries.len(), RECENT_STORIES_COUNT);
+        assert_eq!(
+            entries[0],
+            ("recent_story_1".to_string(), "Story 1".to_string())
+        );
+        assert_eq!(entries[1].0, "recent_story_3");
+    }
+}
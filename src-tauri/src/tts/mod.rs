@@ -43,12 +43,32 @@ pub mod neural;
 
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
 use tts::{Features, Tts, UtteranceId};
 
+use neural::{NeuralVoiceInfo, SentenceEvent, SpeakOutcome, TtsBackend};
+
 /// Global TTS instance
 static TTS_INSTANCE: OnceCell<Mutex<Tts>> = OnceCell::new();
 
+/// Features confirmed by [`verify_features`], probed once by [`init`]
+static VERIFIED_FEATURES: OnceCell<TtsFeatures> = OnceCell::new();
+
+/// Set by [`stop`] to end a [`speak_sentences`] run early
+static STOP_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// How often playback state is checked while a sentence is spoken
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long a sentence may take to start before it is treated as done
+const START_GRACE: Duration = Duration::from_millis(500);
+
+/// Approximate native speaking speed at the normal rate (0.5)
+const WORDS_PER_MINUTE: f32 = 170.0;
+
 /// Information about an available voice
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoiceInfo {
@@ -60,6 +80,22 @@ pub struct VoiceInfo {
     pub language: Option<String>,
 }
 
+/// A voice from either backend, for a single voice picker
+#[derive(Debug, Clone, Serialize)]
+pub struct UnifiedVoice {
+    /// Voice identifier, as passed to the backend's set-voice command
+    pub id: String,
+    /// Human-readable voice name
+    pub name: String,
+    /// Language code (e.g., "en-US")
+    pub language: Option<String>,
+    /// Which backend speaks with this voice
+    pub backend: TtsBackend,
+    /// Whether the voice can be used now (system voices always can; neural
+    /// voices need their model downloaded)
+    pub downloaded: bool,
+}
+
 /// TTS service status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TtsStatus {
@@ -69,14 +105,18 @@ pub struct TtsStatus {
     pub is_speaking: bool,
     /// Current speech rate (0.0 - 1.0, where 0.5 is normal)
     pub rate: f32,
-    /// Supported features on this platform
+    /// Features the platform claims to support
     pub features: TtsFeatures,
+    /// Claimed features that also worked when tried at startup; use these
+    /// to decide which controls to enable
+    #[serde(default)]
+    pub verified_features: TtsFeatures,
     /// Error message if not available
     pub message: Option<String>,
 }
 
 /// Platform-specific TTS features
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TtsFeatures {
     pub stop: bool,
     pub rate: bool,
@@ -101,15 +141,100 @@ impl From<Features> for TtsFeatures {
     }
 }
 
+/// A feature in [`TtsFeatures`], for [`verify_features`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Feature {
+    Stop,
+    Rate,
+    Pitch,
+    Volume,
+    IsSpeaking,
+    Voice,
+    UtteranceCallbacks,
+}
+
+/// The `claimed` features that also pass `probe`.
+///
+/// Unclaimed features aren't probed; the `tts` crate refuses them anyway.
+fn verify_features(
+    claimed: &TtsFeatures,
+    mut probe: impl FnMut(Feature) -> Result<(), String>,
+) -> TtsFeatures {
+    let mut check = |claimed: bool, feature: Feature| {
+        if !claimed {
+            return false;
+        }
+        match probe(feature) {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!("TTS claims {:?} but it failed: {}", feature, e);
+                false
+            }
+        }
+    };
+
+    TtsFeatures {
+        stop: check(claimed.stop, Feature::Stop),
+        rate: check(claimed.rate, Feature::Rate),
+        pitch: check(claimed.pitch, Feature::Pitch),
+        volume: check(claimed.volume, Feature::Volume),
+        is_speaking: check(claimed.is_speaking, Feature::IsSpeaking),
+        voice: check(claimed.voice, Feature::Voice),
+        utterance_callbacks: check(claimed.utterance_callbacks, Feature::UtteranceCallbacks),
+    }
+}
+
+/// Exercise `feature` without making a sound.
+///
+/// Settings are read and written back unchanged rather than spoken with, so
+/// the probe is silent and leaves the engine as it was.
+fn probe_feature(tts: &mut Tts, feature: Feature) -> Result<(), tts::Error> {
+    match feature {
+        Feature::Stop => tts.stop().map(|_| ()),
+        Feature::Rate => {
+            let rate = tts.get_rate()?;
+            tts.set_rate(rate).map(|_| ())
+        }
+        Feature::Pitch => {
+            let pitch = tts.get_pitch()?;
+            tts.set_pitch(pitch).map(|_| ())
+        }
+        Feature::Volume => {
+            let volume = tts.get_volume()?;
+            tts.set_volume(volume).map(|_| ())
+        }
+        Feature::IsSpeaking => tts.is_speaking().map(|_| ()),
+        Feature::Voice => match tts.voice()? {
+            Some(voice) => tts.set_voice(&voice),
+            None => tts.voices().map(|_| ()),
+        },
+        // No callback is registered yet when this runs
+        Feature::UtteranceCallbacks => tts.on_utterance_end(None),
+    }
+}
+
 /// Initialize the TTS engine.
 ///
+/// The first successful call also probes which claimed features actually
+/// work (see [`TtsStatus::verified_features`]).
+///
 /// Returns Ok(()) if successful, or an error message if TTS is unavailable.
 pub fn init() -> Result<(), String> {
-    TTS_INSTANCE.get_or_try_init(|| {
+    let mutex = TTS_INSTANCE.get_or_try_init(|| {
         Tts::default()
             .map(Mutex::new)
             .map_err(|e| format!("Failed to initialize TTS: {}", e))
     })?;
+
+    VERIFIED_FEATURES.get_or_try_init(|| {
+        let mut tts = mutex.lock().map_err(|e| format!("TTS lock error: {}", e))?;
+        let claimed: TtsFeatures = tts.supported_features().into();
+        let verified = verify_features(&claimed, |feature| {
+            probe_feature(&mut tts, feature).map_err(|e| e.to_string())
+        });
+        tracing::info!("Verified TTS features: {:?}", verified);
+        Ok::<_, String>(verified)
+    })?;
     Ok(())
 }
 
@@ -127,6 +252,7 @@ pub fn get_status() -> TtsStatus {
                     is_speaking,
                     rate: normalized_rate,
                     features: tts.supported_features().into(),
+                    verified_features: VERIFIED_FEATURES.get().cloned().unwrap_or_default(),
                     message: None,
                 }
             }
@@ -134,15 +260,8 @@ pub fn get_status() -> TtsStatus {
                 available: false,
                 is_speaking: false,
                 rate: 0.5,
-                features: TtsFeatures {
-                    stop: false,
-                    rate: false,
-                    pitch: false,
-                    volume: false,
-                    is_speaking: false,
-                    voice: false,
-                    utterance_callbacks: false,
-                },
+                features: TtsFeatures::default(),
+                verified_features: TtsFeatures::default(),
                 message: Some(format!("TTS lock error: {}", e)),
             },
         },
@@ -150,15 +269,8 @@ pub fn get_status() -> TtsStatus {
             available: false,
             is_speaking: false,
             rate: 0.5,
-            features: TtsFeatures {
-                stop: false,
-                rate: false,
-                pitch: false,
-                volume: false,
-                is_speaking: false,
-                voice: false,
-                utterance_callbacks: false,
-            },
+            features: TtsFeatures::default(),
+            verified_features: TtsFeatures::default(),
             message: Some("TTS not initialized".to_string()),
         },
     }
@@ -188,12 +300,159 @@ pub fn stop() -> Result<(), String> {
         .get()
         .ok_or_else(|| "TTS not initialized".to_string())?;
 
+    STOP_REQUESTED.store(true, Ordering::SeqCst);
+
     let mut tts = mutex.lock().map_err(|e| format!("TTS lock error: {}", e))?;
 
     tts.stop().map_err(|e| format!("Failed to stop: {}", e))?;
     Ok(())
 }
 
+/// Speak sentences one at a time, emitting `tts-sentence` events.
+///
+/// The events are the same [`SentenceEvent`]s the neural engine emits, so
+/// the frontend can highlight the current sentence with either backend.
+/// Where the platform reports whether it is speaking, each sentence ends
+/// when speech stops; elsewhere its end is estimated from its word count
+/// and the current rate.
+///
+/// Blocks until all sentences are spoken or [`stop`] is called.
+pub fn speak_sentences(sentences: &[String], app_handle: &AppHandle) -> Result<(), String> {
+    let mutex = TTS_INSTANCE
+        .get()
+        .ok_or_else(|| "TTS not initialized".to_string())?;
+
+    let mut speaker = {
+        let mut tts = mutex.lock().map_err(|e| format!("TTS lock error: {}", e))?;
+        let _ = tts.stop();
+        NativeSpeaker {
+            rate: normalize_rate_to_standard(tts.get_rate().unwrap_or(0.5)),
+            poll_speaking: tts.supported_features().is_speaking,
+        }
+    };
+
+    STOP_REQUESTED.store(false, Ordering::SeqCst);
+    run_sentences(&mut speaker, sentences, |event| {
+        if let Err(e) = app_handle.emit("tts-sentence", &event) {
+            tracing::warn!("Failed to emit TTS sentence event: {}", e);
+        }
+    });
+    Ok(())
+}
+
+/// Estimated time to speak `text` at a normalized `rate` (0.5 is normal).
+pub fn estimate_duration(text: &str, rate: f32) -> Duration {
+    let words = text.split_whitespace().count() as f32;
+    // Rate 0.0 is half speed and 1.0 one and a half times normal speed
+    let words_per_minute = WORDS_PER_MINUTE * (0.5 + rate.clamp(0.0, 1.0));
+    Duration::from_secs_f32(words * 60.0 / words_per_minute)
+}
+
+/// Plays one sentence at a time for [`run_sentences`].
+///
+/// Separated from the engine so the event sequence can be tested without
+/// audio.
+trait SentenceSpeaker {
+    /// Start speaking a sentence.
+    fn speak(&mut self, text: &str) -> Result<(), String>;
+    /// Block until the sentence started by [`Self::speak`] is done or
+    /// playback is stopped.
+    fn wait(&mut self, text: &str);
+    /// Whether playback has been stopped.
+    fn stopped(&self) -> bool;
+}
+
+/// Speak `sentences` in order, reporting progress through `emit`.
+///
+/// Blank sentences are skipped. Each spoken sentence gets a `Start` and an
+/// `End`; a sentence that fails to play only gets an `End`. A stop before
+/// the next sentence emits `Stopped`, and the run always ends with
+/// `Finished`, matching the neural engine.
+fn run_sentences(
+    speaker: &mut impl SentenceSpeaker,
+    sentences: &[String],
+    mut emit: impl FnMut(SentenceEvent),
+) {
+    for (index, sentence) in sentences.iter().enumerate() {
+        if speaker.stopped() {
+            emit(SentenceEvent::Stopped);
+            break;
+        }
+
+        if sentence.trim().is_empty() {
+            continue;
+        }
+
+        match speaker.speak(sentence) {
+            Ok(()) => {
+                emit(SentenceEvent::Start {
+                    index,
+                    text: sentence.clone(),
+                });
+                speaker.wait(sentence);
+            }
+            Err(e) => tracing::warn!("Failed to speak sentence {}: {}", index, e),
+        }
+
+        emit(SentenceEvent::End { index });
+    }
+
+    emit(SentenceEvent::Finished);
+}
+
+/// [`SentenceSpeaker`] backed by the global native engine.
+struct NativeSpeaker {
+    /// Normalized rate, for duration estimates
+    rate: f32,
+    /// Whether the platform reports `is_speaking`
+    poll_speaking: bool,
+}
+
+impl NativeSpeaker {
+    fn is_speaking(&self) -> bool {
+        TTS_INSTANCE
+            .get()
+            .and_then(|mutex| mutex.lock().ok())
+            .and_then(|tts| tts.is_speaking().ok())
+            .unwrap_or(false)
+    }
+}
+
+impl SentenceSpeaker for NativeSpeaker {
+    fn speak(&mut self, text: &str) -> Result<(), String> {
+        speak(text, false).map(|_| ())
+    }
+
+    fn wait(&mut self, text: &str) {
+        let estimate = estimate_duration(text, self.rate);
+        let started = Instant::now();
+        let mut heard = false;
+
+        while !self.stopped() {
+            let elapsed = started.elapsed();
+            if self.poll_speaking {
+                let speaking = self.is_speaking();
+                heard |= speaking;
+                // Speech can take a moment to begin; some backends never
+                // report it, so the estimate also bounds the wait
+                if !speaking && (heard || elapsed >= START_GRACE) {
+                    return;
+                }
+                if elapsed >= estimate * 2 + START_GRACE {
+                    return;
+                }
+            } else if elapsed >= estimate {
+                return;
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn stopped(&self) -> bool {
+        STOP_REQUESTED.load(Ordering::SeqCst)
+    }
+}
+
 /// Get list of available voices.
 pub fn get_voices() -> Result<Vec<VoiceInfo>, String> {
     let mutex = TTS_INSTANCE
@@ -216,6 +475,41 @@ pub fn get_voices() -> Result<Vec<VoiceInfo>, String> {
         .collect())
 }
 
+/// List the voices of both backends: neural voices first, then system
+/// voices.
+///
+/// System voices are left out if native TTS isn't initialized.
+pub fn list_all_voices() -> Vec<UnifiedVoice> {
+    let native = get_voices().unwrap_or_default();
+    merge_voices(neural::list_neural_voices(), native, |id| {
+        neural::is_model_ready(id).unwrap_or(false)
+    })
+}
+
+/// Combine neural and system voices into one list, tagging each with its
+/// backend.
+fn merge_voices(
+    neural: Vec<NeuralVoiceInfo>,
+    native: Vec<VoiceInfo>,
+    is_downloaded: impl Fn(&str) -> bool,
+) -> Vec<UnifiedVoice> {
+    let neural = neural.into_iter().map(|v| UnifiedVoice {
+        downloaded: is_downloaded(&v.id),
+        id: v.id,
+        name: v.name,
+        language: Some(v.language),
+        backend: TtsBackend::Neural,
+    });
+    let native = native.into_iter().map(|v| UnifiedVoice {
+        id: v.id,
+        name: v.name,
+        language: v.language,
+        backend: TtsBackend::Native,
+        downloaded: true,
+    });
+    neural.chain(native).collect()
+}
+
 /// Set the speech rate.
 ///
 /// Rate is normalized to 0.0-1.0 where 0.5 is normal speed.
@@ -256,6 +550,158 @@ pub fn set_voice(voice_id: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Speak `text` in `voice_id`, interrupting current speech, without
+/// changing the voice used afterwards.
+///
+/// Platforms pick up the voice when an utterance is queued, so the previous
+/// voice is restored as soon as `text` is, whether or not speaking it
+/// succeeded.
+fn speak_in_voice(text: &str, voice_id: &str) -> Result<Option<UtteranceId>, String> {
+    let mutex = TTS_INSTANCE
+        .get()
+        .ok_or_else(|| "TTS not initialized".to_string())?;
+
+    let mut tts = mutex.lock().map_err(|e| format!("TTS lock error: {}", e))?;
+
+    let voice = tts
+        .voices()
+        .map_err(|e| format!("Failed to get voices: {}", e))?
+        .into_iter()
+        .find(|v| v.id() == voice_id)
+        .ok_or_else(|| format!("Voice not found: {}", voice_id))?;
+    let previous = tts.voice().ok().flatten();
+
+    tts.set_voice(&voice)
+        .map_err(|e| format!("Failed to set voice: {}", e))?;
+    let _ = tts.stop();
+    let result = tts
+        .speak(text, true)
+        .map_err(|e| format!("Failed to speak: {}", e));
+
+    if let Some(previous) = previous {
+        if let Err(e) = tts.set_voice(&previous) {
+            tracing::warn!("Failed to restore voice {}: {}", previous.id(), e);
+        }
+    }
+    result
+}
+
+/// Re-apply a saved voice and rate, e.g. after [`init`].
+///
+/// A saved voice that is no longer installed is skipped, leaving the system
+/// default. Returns whether the saved voice was applied.
+pub fn restore(voice_id: Option<&str>, rate: Option<f32>) -> Result<bool, String> {
+    if let Some(rate) = rate {
+        set_rate(rate)?;
+    }
+
+    let ids: Vec<String> = get_voices()?.into_iter().map(|v| v.id).collect();
+    match available_voice(voice_id, &ids) {
+        Some(id) => {
+            set_voice(id)?;
+            Ok(true)
+        }
+        None => {
+            if let Some(id) = voice_id {
+                tracing::warn!("Saved voice {} is no longer available, using default", id);
+            }
+            Ok(false)
+        }
+    }
+}
+
+/// The best installed system voice for a language tag like `fr` or `en-GB`.
+///
+/// Returns `None` if no voice speaks the language (or TTS isn't
+/// initialized).
+pub fn voice_for_language(lang: &str) -> Option<String> {
+    let voices = get_voices().ok()?;
+    best_voice_for_language(
+        lang,
+        voices
+            .iter()
+            .filter_map(|v| Some((v.id.as_str(), v.language.as_deref()?))),
+    )
+    .map(str::to_string)
+}
+
+/// Speak an article, choosing a voice in its language.
+///
+/// With `neural`, a downloaded neural voice for `lang` is preferred; if
+/// there is none, a system voice for `lang` is used instead. Without a
+/// voice for the language on either backend, the requested backend speaks
+/// with its current voice. A language voice is used for this article only;
+/// the current voice is left as it was.
+pub async fn speak_article(
+    text: &str,
+    lang: Option<&str>,
+    neural: bool,
+) -> Result<SpeakOutcome, String> {
+    let neural_voice = if neural {
+        lang.and_then(neural::voice_for_language)
+    } else {
+        None
+    };
+    let native_voice = match neural_voice {
+        Some(_) => None,
+        None => lang.and_then(voice_for_language),
+    };
+
+    if neural && native_voice.is_none() {
+        return neural::speak_auto(text, neural_voice.as_deref(), None).await;
+    }
+
+    match &native_voice {
+        Some(voice) => speak_in_voice(text, voice)?,
+        None => speak(text, true)?,
+    };
+    Ok(SpeakOutcome {
+        backend_used: TtsBackend::Native,
+        fell_back: false,
+        reason: None,
+    })
+}
+
+/// Pick the voice for `lang` from `(id, language)` pairs.
+///
+/// Tags are compared case-insensitively, with `_` treated as `-`. A voice
+/// for the exact tag wins over one that only shares the primary language
+/// (`en-GB` for `en-US`); ties go to the earlier voice.
+pub fn best_voice_for_language<'a>(
+    lang: &str,
+    voices: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Option<&'a str> {
+    let normalize = |tag: &str| tag.trim().to_ascii_lowercase().replace('_', "-");
+    let primary = |tag: &str| tag.split('-').next().unwrap_or_default().to_string();
+
+    let wanted = normalize(lang);
+    if wanted.is_empty() {
+        return None;
+    }
+    let wanted_primary = primary(&wanted);
+
+    let mut best: Option<(&str, u8)> = None;
+    for (id, language) in voices {
+        let language = normalize(language);
+        let score = if language == wanted {
+            2
+        } else if primary(&language) == wanted_primary {
+            1
+        } else {
+            continue;
+        };
+        if best.is_none_or(|(_, best_score)| score > best_score) {
+            best = Some((id, score));
+        }
+    }
+    best.map(|(id, _)| id)
+}
+
+/// `saved` if it is one of the voice `ids`.
+pub fn available_voice<'a>(saved: Option<&'a str>, ids: &[String]) -> Option<&'a str> {
+    saved.filter(|saved| ids.iter().any(|id| id == saved))
+}
+
 /// Normalize platform-specific rate to 0.0-1.0 standard.
 fn normalize_rate_to_standard(rate: f32) -> f32 {
     // Most platforms use different ranges. This is a rough normalization.
@@ -278,10 +724,220 @@ fn normalize_rate_from_standard(rate: f32, _tts: &Tts) -> f32 {
 mod tests {
     use super::*;
 
+    #[test]
+    fn verified_features_need_a_claim_and_a_working_probe() {
+        let claimed = TtsFeatures {
+            stop: true,
+            rate: true,
+            voice: true,
+            ..TtsFeatures::default()
+        };
+
+        let mut probed = Vec::new();
+        let verified = verify_features(&claimed, |feature| {
+            probed.push(feature);
+            match feature {
+                Feature::Voice => Err("Operation not supported".to_string()),
+                _ => Ok(()),
+            }
+        });
+
+        assert_eq!(
+            verified,
+            TtsFeatures {
+                stop: true,
+                rate: true,
+                ..TtsFeatures::default()
+            }
+        );
+        // Unclaimed features aren't tried
+        assert_eq!(probed, [Feature::Stop, Feature::Rate, Feature::Voice]);
+    }
+
     #[test]
     fn test_normalize_rate() {
         assert_eq!(normalize_rate_to_standard(0.5), 0.5);
         assert_eq!(normalize_rate_to_standard(-0.5), 0.0);
         assert_eq!(normalize_rate_to_standard(1.5), 1.0);
     }
+
+    #[test]
+    fn merged_voices_are_tagged_with_their_backend() {
+        let neural = vec![NeuralVoiceInfo {
+            id: "piper-en-us".to_string(),
+            name: "Piper (US English)".to_string(),
+            language: "en".to_string(),
+            description: None,
+        }];
+        let native = vec![VoiceInfo {
+            id: "samantha".to_string(),
+            name: "Samantha".to_string(),
+            language: Some("en-US".to_string()),
+        }];
+
+        let voices = merge_voices(neural, native, |_| false);
+
+        assert_eq!(voices.len(), 2);
+        assert_eq!(voices[0].id, "piper-en-us");
+        assert_eq!(voices[0].backend, TtsBackend::Neural);
+        assert!(!voices[0].downloaded);
+        assert_eq!(voices[1].id, "samantha");
+        assert_eq!(voices[1].backend, TtsBackend::Native);
+        assert_eq!(voices[1].language.as_deref(), Some("en-US"));
+        assert!(voices[1].downloaded);
+    }
+
+    #[test]
+    fn neural_download_state_is_reported() {
+        let neural = vec![NeuralVoiceInfo {
+            id: "piper-en-us".to_string(),
+            name: "Piper (US English)".to_string(),
+            language: "en".to_string(),
+            description: None,
+        }];
+
+        let voices = merge_voices(neural, vec![], |id| id == "piper-en-us");
+
+        assert!(voices[0].downloaded);
+    }
+
+    const VOICES: [(&str, &str); 4] = [
+        ("daniel", "en-GB"),
+        ("samantha", "en_US"),
+        ("thomas", "fr-FR"),
+        ("joana", "pt-PT"),
+    ];
+
+    #[test]
+    fn exact_regional_voice_is_preferred() {
+        assert_eq!(best_voice_for_language("en-US", VOICES), Some("samantha"));
+        assert_eq!(best_voice_for_language("en_gb", VOICES), Some("daniel"));
+    }
+
+    #[test]
+    fn primary_language_matches_other_regions() {
+        assert_eq!(best_voice_for_language("en", VOICES), Some("daniel"));
+        assert_eq!(best_voice_for_language("fr-CA", VOICES), Some("thomas"));
+        assert_eq!(best_voice_for_language("PT-br", VOICES), Some("joana"));
+    }
+
+    #[test]
+    fn unmatched_language_has_no_voice() {
+        assert_eq!(best_voice_for_language("de", VOICES), None);
+        assert_eq!(best_voice_for_language("", VOICES), None);
+        assert_eq!(best_voice_for_language("en", []), None);
+    }
+
+    #[test]
+    fn saved_voice_is_used_when_installed() {
+        let ids = vec!["alex".to_string(), "samantha".to_string()];
+
+        assert_eq!(available_voice(Some("samantha"), &ids), Some("samantha"));
+    }
+
+    #[test]
+    fn missing_saved_voice_falls_back_to_default() {
+        let ids = vec!["alex".to_string()];
+
+        assert_eq!(available_voice(Some("samantha"), &ids), None);
+        assert_eq!(available_voice(None, &ids), None);
+        assert_eq!(available_voice(Some("alex"), &[]), None);
+    }
+
+    /// Records what was spoken; optionally fails or stops on a sentence.
+    #[derive(Default)]
+    struct FakeSpeaker {
+        spoken: Vec<String>,
+        fail_on: Option<&'static str>,
+        stop_after: Option<&'static str>,
+        stopped: bool,
+    }
+
+    impl SentenceSpeaker for FakeSpeaker {
+        fn speak(&mut self, text: &str) -> Result<(), String> {
+            if self.fail_on == Some(text) {
+                return Err("no audio device".to_string());
+            }
+            self.spoken.push(text.to_string());
+            Ok(())
+        }
+
+        fn wait(&mut self, text: &str) {
+            if self.stop_after == Some(text) {
+                self.stopped = true;
+            }
+        }
+
+        fn stopped(&self) -> bool {
+            self.stopped
+        }
+    }
+
+    fn describe(events: &[SentenceEvent]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| match event {
+                SentenceEvent::Start { index, text } => format!("start {} {}", index, text),
+                SentenceEvent::End { index } => format!("end {}", index),
+                SentenceEvent::Error { index, .. } => format!("error {}", index),
+                SentenceEvent::Finished => "finished".to_string(),
+                SentenceEvent::Stopped => "stopped".to_string(),
+            })
+            .collect()
+    }
+
+    fn run(speaker: &mut FakeSpeaker, sentences: &[&str]) -> Vec<String> {
+        let sentences: Vec<String> = sentences.iter().map(|s| s.to_string()).collect();
+        let mut events = Vec::new();
+        run_sentences(speaker, &sentences, |event| events.push(event));
+        describe(&events)
+    }
+
+    #[test]
+    fn sentences_emit_start_and_end_in_order() {
+        let mut speaker = FakeSpeaker::default();
+
+        let events = run(&mut speaker, &["One.", "  ", "Two."]);
+
+        assert_eq!(
+            events,
+            ["start 0 One.", "end 0", "start 2 Two.", "end 2", "finished"]
+        );
+        assert_eq!(speaker.spoken, ["One.", "Two."]);
+    }
+
+    #[test]
+    fn stop_ends_the_run_before_the_next_sentence() {
+        let mut speaker = FakeSpeaker {
+            stop_after: Some("One."),
+            ..Default::default()
+        };
+
+        let events = run(&mut speaker, &["One.", "Two."]);
+
+        assert_eq!(events, ["start 0 One.", "end 0", "stopped", "finished"]);
+        assert_eq!(speaker.spoken, ["One."]);
+    }
+
+    #[test]
+    fn failed_sentence_still_ends() {
+        let mut speaker = FakeSpeaker {
+            fail_on: Some("One."),
+            ..Default::default()
+        };
+
+        let events = run(&mut speaker, &["One.", "Two."]);
+
+        assert_eq!(events, ["end 0", "start 1 Two.", "end 1", "finished"]);
+    }
+
+    #[test]
+    fn duration_estimate_scales_with_rate() {
+        let text = "word ".repeat(170);
+
+        assert_eq!(estimate_duration(&text, 0.5), Duration::from_secs(60));
+        assert!(estimate_duration(&text, 1.0) < estimate_duration(&text, 0.5));
+        assert!(estimate_duration(&text, 0.0) > estimate_duration(&text, 0.5));
+        assert_eq!(estimate_duration("", 0.5), Duration::ZERO);
+    }
 }
@@ -65,6 +65,17 @@ impl AudioData {
         self.samples.len() as f64 / self.sample_rate as f64 / self.channels as f64
     }
 
+    /// Index of the first sample at `position_ms` into the audio
+    ///
+    /// Rounded down to a whole frame, and clamped to the end of the audio.
+    pub fn sample_offset(&self, position_ms: u64) -> usize {
+        let channels = u64::from(self.channels.max(1));
+        let frame = position_ms.saturating_mul(u64::from(self.sample_rate)) / 1000;
+        let offset = usize::try_from(frame.saturating_mul(channels)).unwrap_or(usize::MAX);
+        let end = self.samples.len() - self.samples.len() % channels as usize;
+        offset.min(end)
+    }
+
     /// Convert to WAV bytes for rodio
     #[allow(dead_code)]
     pub fn to_wav_bytes(&self) -> Result<Vec<u8>, AudioError> {
@@ -313,6 +324,34 @@ mod tests {
         assert_eq!(&bytes[0..4], b"RIFF");
     }
 
+    #[test]
+    fn test_sample_offset() {
+        let audio = AudioData::new(vec![0.0; 22050 * 3], 22050, 1);
+
+        assert_eq!(audio.sample_offset(0), 0);
+        assert_eq!(audio.sample_offset(1000), 22050);
+        assert_eq!(audio.sample_offset(1500), 33075);
+        // 22.05 samples per ms: partial samples round down
+        assert_eq!(audio.sample_offset(1), 22);
+    }
+
+    #[test]
+    fn test_sample_offset_clamps_past_the_end() {
+        let audio = AudioData::new(vec![0.0; 22050], 22050, 1);
+
+        assert_eq!(audio.sample_offset(5000), 22050);
+        assert_eq!(audio.sample_offset(u64::MAX), 22050);
+    }
+
+    #[test]
+    fn test_sample_offset_keeps_frames_whole() {
+        // Stereo: two samples per frame
+        let audio = AudioData::new(vec![0.0; 48001], 24000, 2);
+
+        assert_eq!(audio.sample_offset(500), 24000);
+        assert_eq!(audio.sample_offset(10_000), 48000);
+    }
+
     #[test]
     fn test_chunk_buffer() {
         let mut buffer = AudioChunkBuffer::new(24000, 1);
@@ -0,0 +1,157 @@
+//! In-memory cache of synthesized sentence audio.
+//!
+//! Narration is synthesized a sentence at a time. [`SentenceAudioCache`]
+//! keeps the samples, so replaying an article, skipping back a sentence, or
+//! playing sentences prepared by [`super::prepare`] doesn't run inference
+//! again. Audio depends on the model, voice, and rate as well as the text,
+//! so all four make up the key.
+//!
+//! The cache holds at most [`MAX_CACHED_SAMPLES`]; the least recently used
+//! sentences are dropped first.
+
+use std::collections::{HashMap, VecDeque};
+
+/// About 15 minutes of 22.05 kHz audio (~80 MB of samples)
+pub const MAX_CACHED_SAMPLES: usize = 22_050 * 60 * 15;
+
+/// What a sentence's audio was synthesized from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    model_id: String,
+    voice_id: String,
+    /// The rate's bits, since `f32` isn't `Hash`
+    rate_bits: u32,
+    /// Preprocessed sentence text
+    text: String,
+}
+
+impl CacheKey {
+    pub fn new(model_id: &str, voice_id: &str, rate: f32, text: &str) -> Self {
+        CacheKey {
+            model_id: model_id.to_string(),
+            voice_id: voice_id.to_string(),
+            rate_bits: rate.to_bits(),
+            text: text.to_string(),
+        }
+    }
+}
+
+/// Synthesized samples by [`CacheKey`], bounded by total sample count.
+#[derive(Debug)]
+pub struct SentenceAudioCache {
+    entries: HashMap<CacheKey, Vec<f32>>,
+    /// Keys, least recently used first
+    order: VecDeque<CacheKey>,
+    /// Samples across all entries
+    samples: usize,
+    max_samples: usize,
+}
+
+impl Default for SentenceAudioCache {
+    fn default() -> Self {
+        Self::with_capacity(MAX_CACHED_SAMPLES)
+    }
+}
+
+impl SentenceAudioCache {
+    /// An empty cache holding at most `max_samples` samples.
+    pub fn with_capacity(max_samples: usize) -> Self {
+        SentenceAudioCache {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            samples: 0,
+            max_samples,
+        }
+    }
+
+    /// Whether audio for `key` is cached.
+    pub fn contains(&self, key: &CacheKey) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// The cached audio for `key`, marking it recently used.
+    pub fn get(&mut self, key: &CacheKey) -> Option<&[f32]> {
+        if !self.entries.contains_key(key) {
+            return None;
+        }
+        self.touch(key);
+        self.entries.get(key).map(Vec::as_slice)
+    }
+
+    /// Cache `samples` for `key`, evicting the least recently used audio
+    /// to make room.
+    ///
+    /// Audio longer than the whole cache isn't stored.
+    pub fn insert(&mut self, key: CacheKey, samples: Vec<f32>) {
+        if samples.len() > self.max_samples {
+            return;
+        }
+        if let Some(old) = self.entries.remove(&key) {
+            self.samples -= old.len();
+            self.order.retain(|k| k != &key);
+        }
+
+        while self.samples + samples.len() > self.max_samples {
+            let Some(oldest) = self.order.pop_front() else {
+                break;
+            };
+            if let Some(evicted) = self.entries.remove(&oldest) {
+                self.samples -= evicted.len();
+            }
+        }
+
+        self.samples += samples.len();
+        self.order.push_back(key.clone());
+        self.entries.insert(key, samples);
+    }
+
+    fn touch(&mut self, key: &CacheKey) {
+        if let Some(pos) = self.order.iter().position(|k| k == key) {
+            if let Some(k) = self.order.remove(pos) {
+                self.order.push_back(k);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(text: &str) -> CacheKey {
+        CacheKey::new("piper-en-us", "default", 1.0, text)
+    }
+
+    #[test]
+    fn audio_is_keyed_by_voice_and_rate() {
+        let mut cache = SentenceAudioCache::default();
+        cache.insert(key("Hello."), vec![0.5; 10]);
+
+        assert_eq!(cache.get(&key("Hello.")), Some(&[0.5; 10][..]));
+        assert!(!cache.contains(&CacheKey::new("piper-en-us", "default", 1.5, "Hello.")));
+        assert!(!cache.contains(&CacheKey::new("piper-en-us", "other", 1.0, "Hello.")));
+    }
+
+    #[test]
+    fn least_recently_used_audio_is_evicted() {
+        let mut cache = SentenceAudioCache::with_capacity(30);
+        cache.insert(key("a"), vec![0.0; 10]);
+        cache.insert(key("b"), vec![0.0; 10]);
+        cache.insert(key("c"), vec![0.0; 10]);
+
+        // "a" is used again, so "b" goes first
+        assert!(cache.get(&key("a")).is_some());
+        cache.insert(key("d"), vec![0.0; 10]);
+        assert!(!cache.contains(&key("b")));
+        assert!(cache.contains(&key("a")));
+
+        // Re-inserting replaces rather than double-counting
+        cache.insert(key("d"), vec![0.0; 20]);
+        assert!(cache.contains(&key("d")));
+        assert_eq!(cache.samples, 30);
+
+        // Too long to ever fit
+        cache.insert(key("e"), vec![0.0; 31]);
+        assert!(!cache.contains(&key("e")));
+    }
+}
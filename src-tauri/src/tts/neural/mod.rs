@@ -27,11 +27,16 @@
 //! ```
 
 pub mod audio;
+pub mod cache;
 pub mod model;
+pub mod pronunciation;
 pub mod synth;
 
-pub use model::{ModelManager, NeuralModel};
-pub use synth::{NeuralTtsEngine, SentenceEvent};
+pub use model::{DownloadProgress, ModelManager, ModelValidationReport, NeuralModel};
+pub use synth::{
+    BenchmarkResult, NeuralTtsEngine, OptimizationLevel, PreparedNarration, SentenceEvent,
+    StopHandle, SynthesisError, BENCHMARK_TEXT,
+};
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -39,9 +44,20 @@ use std::sync::OnceLock;
 use tauri::{AppHandle, Emitter};
 use tokio::sync::{mpsc, RwLock};
 
+/// Error returned to the frontend when there is no audio output device
+pub const NO_OUTPUT_DEVICE_MESSAGE: &str = "No audio output device available";
+
+/// Status message when `espeak-ng`, used for phonemization, can't be run
+pub const ESPEAK_MISSING_MESSAGE: &str = "espeak-ng is not installed; neural voices need it to \
+    read text. Install the espeak-ng package (see \
+    https://github.com/espeak-ng/espeak-ng/blob/master/docs/guide.md) and restart the app";
+
 /// Global neural TTS engine instance
 static NEURAL_TTS: OnceLock<RwLock<NeuralTtsEngine>> = OnceLock::new();
 
+/// Stops the global engine without waiting for its lock
+static STOP_HANDLE: OnceLock<StopHandle> = OnceLock::new();
+
 /// Neural TTS status response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeuralTtsStatus {
@@ -49,6 +65,12 @@ pub struct NeuralTtsStatus {
     pub available: bool,
     /// Whether currently generating/speaking
     pub is_speaking: bool,
+    /// Whether the model is loaded in memory (it reloads on demand if not)
+    #[serde(default)]
+    pub loaded: bool,
+    /// Whether the `espeak-ng` binary synthesis depends on can be run
+    #[serde(default)]
+    pub espeak_available: bool,
     /// Currently selected voice
     pub current_voice: Option<String>,
     /// Speech rate multiplier (0.5 - 2.0)
@@ -57,7 +79,7 @@ pub struct NeuralTtsStatus {
     pub download_progress: Option<u8>,
     /// Available neural voices
     pub voices: Vec<NeuralVoiceInfo>,
-    /// Error message if unavailable
+    /// Error message if unavailable, or why speaking will fail
     pub message: Option<String>,
 }
 
@@ -76,10 +98,12 @@ pub struct NeuralVoiceInfo {
 /// Returns `Ok(())` if initialization succeeds (even if no model present).
 pub async fn init_neural() -> Result<(), String> {
     let engine = NeuralTtsEngine::new().map_err(|e| e.to_string())?;
+    let stop_handle = engine.stop_handle();
 
     NEURAL_TTS
         .set(RwLock::new(engine))
         .map_err(|_| "Neural TTS already initialized")?;
+    let _ = STOP_HANDLE.set(stop_handle);
 
     Ok(())
 }
@@ -111,6 +135,8 @@ pub async fn get_status() -> NeuralTtsStatus {
         None => NeuralTtsStatus {
             available: false,
             is_speaking: false,
+            loaded: false,
+            espeak_available: synth::espeak_available().await,
             current_voice: None,
             rate: 1.0,
             download_progress: None,
@@ -120,14 +146,41 @@ pub async fn get_status() -> NeuralTtsStatus {
     }
 }
 
-/// Download a neural voice model.
+/// Payload of `tts-download-progress` events.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelDownloadEvent {
+    pub model_id: String,
+    /// Overall completion (0-100)
+    pub progress: u8,
+    pub bytes_downloaded: u64,
+    pub total_bytes: u64,
+    pub bytes_per_sec: u64,
+    /// Estimated seconds remaining, if known
+    pub eta_secs: Option<u64>,
+}
+
+impl ModelDownloadEvent {
+    pub fn new(model_id: &str, progress: DownloadProgress) -> Self {
+        ModelDownloadEvent {
+            model_id: model_id.to_string(),
+            progress: progress.percent,
+            bytes_downloaded: progress.bytes_done,
+            total_bytes: progress.bytes_total,
+            bytes_per_sec: progress.bytes_per_sec,
+            eta_secs: progress.eta_secs,
+        }
+    }
+}
+
+/// Download a neural voice model, then warm it up in the background.
 ///
 /// # Arguments
 /// * `model_id` - Model identifier (e.g., "piper-en-us")
-/// * `progress_callback` - Optional callback for download progress (0-100)
+/// * `progress_callback` - Optional callback for download progress (percent, speed, ETA)
 pub async fn download_model<F>(model_id: &str, progress_callback: Option<F>) -> Result<(), String>
 where
-    F: Fn(u8) + Send + 'static,
+    F: Fn(DownloadProgress) + Send + 'static,
 {
     let model =
         NeuralModel::from_id(model_id).ok_or_else(|| format!("Unknown model: {}", model_id))?;
@@ -135,9 +188,43 @@ where
     let manager = ModelManager::new().map_err(|e| e.to_string())?;
 
     manager
-        .download_model(model, progress_callback)
+        .download_model_with_progress(model, progress_callback)
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+
+    // Warm up the new model in the background so the first sentence isn't slow
+    let model_id = model_id.to_string();
+    tokio::spawn(async move {
+        if let Err(e) = warmup(Some(&model_id)).await {
+            tracing::warn!("Neural TTS warmup after download failed: {}", e);
+        }
+    });
+
+    Ok(())
+}
+
+/// Warm up the neural TTS engine.
+///
+/// Loads `model_id` (the configured model if `None`) and runs a tiny
+/// inference, removing the latency spike on the first spoken sentence.
+/// Initializes the engine first if needed. Started automatically after a
+/// successful download, and at startup when enabled in settings.
+pub async fn warmup(model_id: Option<&str>) -> Result<(), String> {
+    if NEURAL_TTS.get().is_none() {
+        // Losing a race with another initializer is fine
+        if let Err(e) = init_neural().await {
+            if NEURAL_TTS.get().is_none() {
+                return Err(e);
+            }
+        }
+    }
+
+    let mut engine = get_engine_mut().await?;
+    match model_id {
+        Some(model_id) => engine.warmup_model(model_id).await,
+        None => engine.warmup().await,
+    }
+    .map_err(|e| e.to_string())
 }
 
 /// Check if a model is downloaded and ready.
@@ -150,6 +237,11 @@ pub fn is_model_ready(model_id: &str) -> Result<bool, String> {
     Ok(manager.is_model_ready(model))
 }
 
+/// Check every downloadable model's files against their live sizes upstream.
+pub async fn validate_models() -> ModelValidationReport {
+    model::validate_models(&reqwest::Client::new(), model::MODELS).await
+}
+
 /// Get the list of available neural voices.
 pub fn list_neural_voices() -> Vec<NeuralVoiceInfo> {
     vec![NeuralVoiceInfo {
@@ -160,10 +252,155 @@ pub fn list_neural_voices() -> Vec<NeuralVoiceInfo> {
     }]
 }
 
+/// Jump the current narration to `position_ms` from its start.
+///
+/// Positions past the end finish it. Fails if nothing is playing, or while
+/// speaking sentences (use [`skip_next`]/[`skip_prev`] there).
+pub fn seek(position_ms: u64) -> Result<(), String> {
+    synth::seek(position_ms)
+}
+
+/// Skip to the next sentence while speaking sentences.
+///
+/// The current sentence ends and the one after it starts; skipping past
+/// the last sentence finishes. Fails if sentences aren't being spoken.
+pub fn skip_next() -> Result<(), String> {
+    synth::skip(1)
+}
+
+/// Go back to the previous sentence while speaking sentences.
+///
+/// From the first sentence, it starts over. Fails if sentences aren't
+/// being spoken.
+pub fn skip_prev() -> Result<(), String> {
+    synth::skip(-1)
+}
+
+/// Set the voice used when speech doesn't name one.
+pub async fn set_voice(voice_id: &str) -> Result<(), String> {
+    if !list_neural_voices().iter().any(|v| v.id == voice_id) {
+        return Err(format!("Voice not found: {}", voice_id));
+    }
+    get_engine_mut().await?.set_voice(voice_id);
+    Ok(())
+}
+
+/// Set the rate used when speech doesn't specify one (0.5 - 2.0).
+pub async fn set_rate(rate: f32) -> Result<(), String> {
+    get_engine_mut().await?.set_rate(rate);
+    Ok(())
+}
+
+/// Set the output latency used to time sentence start events.
+///
+/// `None` derives it from the output stream's buffer size.
+pub async fn set_output_latency(latency_ms: Option<u64>) -> Result<(), String> {
+    get_engine_mut().await?.set_output_latency(latency_ms);
+    Ok(())
+}
+
+/// Set whether [`speak_sentences`] moves on after a sentence fails
+/// instead of stopping.
+pub async fn set_continue_on_error(continue_on_error: bool) -> Result<(), String> {
+    get_engine_mut()
+        .await?
+        .set_continue_on_error(continue_on_error);
+    Ok(())
+}
+
+/// Re-apply a saved voice and rate, e.g. after [`init_neural`].
+///
+/// A saved voice that is no longer registered is skipped, leaving the
+/// default voice. Returns whether the saved voice was applied.
+pub async fn restore(voice_id: Option<&str>, rate: Option<f32>) -> Result<bool, String> {
+    if let Some(rate) = rate {
+        set_rate(rate).await?;
+    }
+
+    let ids: Vec<String> = list_neural_voices().into_iter().map(|v| v.id).collect();
+    match crate::tts::available_voice(voice_id, &ids) {
+        Some(id) => {
+            set_voice(id).await?;
+            Ok(true)
+        }
+        None => {
+            if let Some(id) = voice_id {
+                tracing::warn!(
+                    "Saved neural voice {} is no longer available, using default",
+                    id
+                );
+            }
+            Ok(false)
+        }
+    }
+}
+
+/// The best downloaded neural voice for a language tag like `en-US`.
+///
+/// Returns `None` if no downloaded voice speaks the language.
+pub fn voice_for_language(lang: &str) -> Option<String> {
+    let voices: Vec<NeuralVoiceInfo> = list_neural_voices()
+        .into_iter()
+        .filter(|v| is_model_ready(&v.id).unwrap_or(false))
+        .collect();
+    crate::tts::best_voice_for_language(
+        lang,
+        voices.iter().map(|v| (v.id.as_str(), v.language.as_str())),
+    )
+    .map(str::to_string)
+}
+
+/// Which TTS backend spoke.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TtsBackend {
+    /// Piper neural voice
+    Neural,
+    /// System voice
+    Native,
+}
+
+/// Result of [`speak_auto`]: which backend spoke, and why it fell back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpeakOutcome {
+    pub backend_used: TtsBackend,
+    /// Whether neural TTS was tried and the system voice used instead
+    pub fell_back: bool,
+    /// User-facing explanation of the fallback
+    pub reason: Option<String>,
+}
+
+impl SpeakOutcome {
+    fn neural() -> Self {
+        SpeakOutcome {
+            backend_used: TtsBackend::Neural,
+            fell_back: false,
+            reason: None,
+        }
+    }
+
+    /// Outcome of falling back to the system voice after `error`.
+    fn fallback(error: &SynthesisError) -> Self {
+        let reason = match error {
+            SynthesisError::ModelNotDownloaded(_) => {
+                "Neural voice not downloaded — using system voice".to_string()
+            }
+            other => format!("Neural voice failed ({}) — using system voice", other),
+        };
+        SpeakOutcome {
+            backend_used: TtsBackend::Native,
+            fell_back: true,
+            reason: Some(reason),
+        }
+    }
+}
+
 /// Speak text using neural TTS.
 ///
-/// Attempts to load the model if downloaded but not yet loaded.
-/// Falls back to native TTS only if the model is not downloaded.
+/// Attempts to load the model if downloaded but not yet loaded, and falls
+/// back to native TTS if neural synthesis fails, except when there is no
+/// audio output device: that fails with [`NO_OUTPUT_DEVICE_MESSAGE`].
 ///
 /// # Arguments
 ///
@@ -171,6 +408,19 @@ pub fn list_neural_voices() -> Vec<NeuralVoiceInfo> {
 /// * `voice_id` - Optional voice ID (uses default if not specified)
 /// * `rate` - Speech rate from 0.5 to 2.0 (1.0 is normal)
 pub async fn speak(text: &str, voice_id: Option<&str>, rate: Option<f32>) -> Result<(), String> {
+    speak_auto(text, voice_id, rate).await.map(|_| ())
+}
+
+/// Speak text with neural TTS, falling back to native TTS, and report
+/// which backend was used.
+///
+/// Same behavior as [`speak`]; the returned [`SpeakOutcome`] lets the UI
+/// explain a fallback. Fails only if the system voice fails too.
+pub async fn speak_auto(
+    text: &str,
+    voice_id: Option<&str>,
+    rate: Option<f32>,
+) -> Result<SpeakOutcome, String> {
     let mut engine = get_engine_mut().await?;
 
     // Set rate if provided
@@ -181,27 +431,72 @@ pub async fn speak(text: &str, voice_id: Option<&str>, rate: Option<f32>) -> Res
     // Try to speak with neural TTS - it will load the model if needed
     // The speak() method in synth.rs handles loading internally
     match engine.speak(text, voice_id).await {
-        Ok(()) => Ok(()),
+        Ok(()) => Ok(SpeakOutcome::neural()),
+        // The system voice has nowhere to play either
+        Err(e) if e.is_no_output_device() => Err(NO_OUTPUT_DEVICE_MESSAGE.to_string()),
         Err(e) => {
             // If neural TTS fails (model not downloaded, inference error, etc.),
             // fall back to native TTS
             tracing::warn!("Neural TTS failed, falling back to native: {}", e);
-            crate::tts::speak(text, true).map(|_| ())
+            crate::tts::speak(text, true)?;
+            Ok(SpeakOutcome::fallback(&e))
         }
     }
 }
 
-/// Stop current neural TTS playback.
-pub async fn stop() -> Result<(), String> {
+/// Change the ONNX session options, reloading the model if it's loaded.
+///
+/// # Arguments
+/// * `intra_threads` - Threads per operator (at least 1)
+/// * `optimization_level` - Graph optimization level
+pub async fn set_session_options(
+    intra_threads: Option<usize>,
+    optimization_level: Option<OptimizationLevel>,
+) -> Result<(), String> {
+    let mut engine = get_engine_mut().await?;
+    engine
+        .set_session_options(intra_threads, optimization_level)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Benchmark neural synthesis speed and memory use.
+///
+/// Synthesizes `sample_text` (or a fixed phrase) several times after a
+/// warmup, without playing anything.
+pub async fn benchmark(sample_text: Option<&str>) -> Result<BenchmarkResult, String> {
+    let mut engine = get_engine_mut().await?;
+    engine
+        .benchmark(sample_text.unwrap_or(BENCHMARK_TEXT))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Unload the neural model to free its memory.
+///
+/// The next `speak` reloads it transparently.
+pub async fn unload() -> Result<(), String> {
     match NEURAL_TTS.get() {
         Some(lock) => {
-            let mut engine = lock.write().await;
-            engine.stop().await.map_err(|e| e.to_string())
+            lock.write().await.unload();
+            Ok(())
         }
-        None => Ok(()), // Nothing to stop
+        None => Ok(()), // Nothing loaded
     }
 }
 
+/// Stop current neural TTS playback.
+///
+/// Takes effect right away, even while text is still being synthesized
+/// (a running espeak-ng is killed): speech holds the engine lock until it
+/// ends, so this doesn't wait for it.
+pub async fn stop() -> Result<(), String> {
+    if let Some(handle) = STOP_HANDLE.get() {
+        handle.stop();
+    }
+    Ok(()) // Nothing to stop before init
+}
+
 /// Speak sentences one-by-one with progress events.
 ///
 /// This function processes each sentence individually, emitting Tauri events
@@ -220,6 +515,8 @@ pub async fn stop() -> Result<(), String> {
 /// Emits `tts-sentence` events with payloads:
 /// - `{ type: "start", index: number, text: string }` - Sentence started
 /// - `{ type: "end", index: number }` - Sentence finished
+/// - `{ type: "error", index: number, stage: "synthesis" | "playback", reason: string }` -
+///   Sentence failed; followed by `stopped` unless continuing on errors
 /// - `{ type: "finished" }` - All sentences done
 /// - `{ type: "stopped" }` - Playback was stopped
 pub async fn speak_sentences(
@@ -253,6 +550,7 @@ pub async fn speak_sentences(
     // Speak sentences with events
     let result = match engine.speak_sentences(&sentences, voice_id, tx).await {
         Ok(()) => Ok(()),
+        Err(e) if e.is_no_output_device() => Err(NO_OUTPUT_DEVICE_MESSAGE.to_string()),
         Err(e) => {
             tracing::warn!("Neural TTS failed: {}", e);
             Err(e.to_string())
@@ -265,11 +563,70 @@ pub async fn speak_sentences(
     result
 }
 
+/// Synthesize and cache narration for `text` without playing it.
+///
+/// A following [`speak_sentences`] with the returned sentences, voice, and
+/// rate plays from the cache with no synthesis delay.
+pub async fn prepare(
+    text: &str,
+    voice_id: Option<&str>,
+    rate: Option<f32>,
+) -> Result<PreparedNarration, String> {
+    let mut engine = get_engine_mut().await?;
+
+    if let Some(r) = rate {
+        engine.set_rate(r);
+    }
+
+    engine
+        .prepare(text, voice_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 /// Get the model directory path.
 pub fn get_model_dir() -> Result<PathBuf, String> {
     ModelManager::get_model_dir().map_err(|e| e.to_string())
 }
 
+/// Store models in `dir` (`None` for the platform default), moving any
+/// downloaded models there.
+///
+/// The directory is checked for writability first. Narration is blocked
+/// while models move, on the blocking thread pool; if a move fails, every
+/// model is left where it was and the directory is unchanged. Returns the
+/// directory now in use.
+pub async fn set_model_dir(dir: Option<PathBuf>) -> Result<PathBuf, String> {
+    let from = ModelManager::get_model_dir().map_err(|e| e.to_string())?;
+    let to = ModelManager::resolve_model_dir(dir.clone()).map_err(|e| e.to_string())?;
+    if to == from {
+        ModelManager::set_model_dir(dir);
+        return Ok(to);
+    }
+
+    let mut engine = match NEURAL_TTS.get() {
+        Some(lock) => Some(lock.write().await),
+        None => None,
+    };
+    {
+        let to = to.clone();
+        tokio::task::spawn_blocking(move || {
+            model::ensure_writable(&to)?;
+            model::move_models(&from, &to)
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+    }
+    ModelManager::set_model_dir(dir);
+    if let Some(engine) = engine.as_mut() {
+        engine.set_model_manager(ModelManager::new().map_err(|e| e.to_string())?);
+    }
+
+    tracing::info!("Neural TTS models now stored in {}", to.display());
+    Ok(to)
+}
+
 /// Get disk usage for neural TTS models.
 pub fn get_model_disk_usage() -> Result<u64, String> {
     let manager = ModelManager::new().map_err(|e| e.to_string())?;
@@ -325,6 +682,42 @@ mod tests {
         assert!(status.message.is_some());
     }
 
+    #[test]
+    fn test_speak_outcome_for_missing_model() {
+        let outcome = SpeakOutcome::fallback(&SynthesisError::ModelNotDownloaded(
+            "piper-en-us".to_string(),
+        ));
+
+        assert_eq!(outcome.backend_used, TtsBackend::Native);
+        assert!(outcome.fell_back);
+        assert_eq!(
+            outcome.reason.as_deref(),
+            Some("Neural voice not downloaded — using system voice")
+        );
+    }
+
+    #[test]
+    fn test_speak_outcome_for_inference_error() {
+        let outcome =
+            SpeakOutcome::fallback(&SynthesisError::InferenceError("bad tensor".to_string()));
+
+        assert_eq!(outcome.backend_used, TtsBackend::Native);
+        assert!(outcome.fell_back);
+        assert_eq!(
+            outcome.reason.as_deref(),
+            Some("Neural voice failed (ONNX inference error: bad tensor) — using system voice")
+        );
+    }
+
+    #[test]
+    fn test_speak_outcome_serialization() {
+        let json = serde_json::to_value(SpeakOutcome::neural()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "backendUsed": "neural", "fellBack": false, "reason": null })
+        );
+    }
+
     #[test]
     fn test_is_model_ready_with_temp_dir() {
         // Create a temporary directory for testing
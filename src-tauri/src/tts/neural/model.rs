@@ -6,12 +6,26 @@
 //! - macOS: `~/Library/Application Support/pastel-hn/models/`
 //! - Linux: `~/.local/share/pastel-hn/models/`
 //! - Windows: `%APPDATA%/pastel-hn/models/`
+//!
+//! Users can pick another directory instead (e.g. on a bigger volume) with
+//! [`ModelManager::set_model_dir`]; [`move_models`] brings existing
+//! downloads along.
 
 use futures::StreamExt;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
+/// Maximum number of model files downloaded at the same time
+const MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// Model directory chosen by the user, overriding the platform default
+static MODEL_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
 /// Errors that can occur during model operations
 #[derive(Debug, Error)]
 #[allow(dead_code)]
@@ -68,7 +82,7 @@ pub struct ModelFile {
     pub name: &'static str,
     /// Size in bytes
     pub size: u64,
-    /// Optional SHA256 checksum
+    /// Optional SHA256 checksum (hex), verified after download
     pub checksum: Option<&'static str>,
     /// Relative path within model directory
     pub path: &'static str,
@@ -97,13 +111,13 @@ pub const PIPER_EN_US_MODEL: NeuralModel = NeuralModel {
     base_url: "https://huggingface.co/rhasspy/piper-voices/resolve/main/en/en_US/lessac/medium",
 };
 
+/// Every downloadable model
+pub const MODELS: &[&NeuralModel] = &[&PIPER_EN_US_MODEL];
+
 impl NeuralModel {
     /// Get model by ID
     pub fn from_id(id: &str) -> Option<&'static NeuralModel> {
-        match id {
-            "piper-en-us" => Some(&PIPER_EN_US_MODEL),
-            _ => None,
-        }
+        MODELS.iter().copied().find(|model| model.id == id)
     }
 
     /// Get model directory name
@@ -118,6 +132,409 @@ impl NeuralModel {
     }
 }
 
+/// Snapshot of a model download, for progress UIs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadProgress {
+    /// Overall completion (0-100)
+    pub percent: u8,
+    /// Bytes downloaded so far, including files that were already present
+    pub bytes_done: u64,
+    /// Total size of all model files
+    pub bytes_total: u64,
+    /// Average transfer rate since the download started
+    pub bytes_per_sec: u64,
+    /// Estimated seconds remaining; `None` until a speed is known
+    pub eta_secs: Option<u64>,
+}
+
+/// Overall download progress across a model's files
+///
+/// Each file's byte count is capped at its expected size, so a file that
+/// turns out larger than declared can't push the total past 100%.
+#[derive(Debug)]
+struct AggregateProgress {
+    /// Expected size of each file
+    expected: Vec<u64>,
+    /// Bytes downloaded so far for each file
+    downloaded: Vec<u64>,
+    /// Bytes of files that were already on disk (excluded from the speed)
+    present: u64,
+}
+
+impl AggregateProgress {
+    fn new(files: &[ModelFile]) -> Self {
+        AggregateProgress {
+            expected: files.iter().map(|f| f.size).collect(),
+            downloaded: vec![0; files.len()],
+            present: 0,
+        }
+    }
+
+    /// Record the bytes downloaded so far for file `index`
+    fn update(&mut self, index: usize, bytes: u64) {
+        self.downloaded[index] = bytes.min(self.expected[index]);
+    }
+
+    /// Record that file `index` is already on disk and needs no download
+    fn mark_present(&mut self, index: usize) {
+        self.downloaded[index] = self.expected[index];
+        self.present += self.expected[index];
+    }
+
+    fn total(&self) -> u64 {
+        self.expected.iter().sum()
+    }
+
+    fn done(&self) -> u64 {
+        self.downloaded.iter().sum::<u64>().min(self.total())
+    }
+
+    fn percent(&self) -> u8 {
+        let total = self.total();
+        if total == 0 {
+            return 100;
+        }
+        (self.done() * 100 / total) as u8
+    }
+
+    /// Progress after `elapsed` time downloading
+    fn snapshot(&self, elapsed: Duration) -> DownloadProgress {
+        let bytes_total = self.total();
+        let bytes_done = self.done();
+        let transferred = bytes_done.saturating_sub(self.present);
+
+        let secs = elapsed.as_secs_f64();
+        let bytes_per_sec = if secs > 0.0 {
+            (transferred as f64 / secs) as u64
+        } else {
+            0
+        };
+
+        let remaining = bytes_total - bytes_done;
+        let eta_secs = if remaining == 0 {
+            Some(0)
+        } else if bytes_per_sec > 0 {
+            Some(remaining.div_ceil(bytes_per_sec))
+        } else {
+            None
+        };
+
+        DownloadProgress {
+            percent: self.percent(),
+            bytes_done,
+            bytes_total,
+            bytes_per_sec,
+            eta_secs,
+        }
+    }
+}
+
+/// Reports [`AggregateProgress`] snapshots to a download's callback
+///
+/// The callback lives with the progress state so concurrent downloads can
+/// report through it without requiring `F: Sync`.
+struct ProgressReporter<F> {
+    started: Instant,
+    state: Mutex<(AggregateProgress, Option<F>)>,
+}
+
+impl<F: Fn(DownloadProgress)> ProgressReporter<F> {
+    fn new(files: &[ModelFile], callback: Option<F>) -> Self {
+        ProgressReporter {
+            started: Instant::now(),
+            state: Mutex::new((AggregateProgress::new(files), callback)),
+        }
+    }
+
+    fn file_present(&self, index: usize) {
+        self.report(|progress| progress.mark_present(index));
+    }
+
+    fn file_progress(&self, index: usize, bytes: u64) {
+        self.report(|progress| progress.update(index, bytes));
+    }
+
+    fn report(&self, change: impl FnOnce(&mut AggregateProgress)) {
+        let mut guard = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let (progress, callback) = &mut *guard;
+        change(progress);
+        if let Some(callback) = callback {
+            callback(progress.snapshot(self.started.elapsed()));
+        }
+    }
+}
+
+/// Temporary name `path` is downloaded under until it's complete
+fn partial_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    path.with_file_name(name)
+}
+
+/// Stream `url` into `path`, calling `on_progress` with the bytes written so far
+///
+/// The download goes to a `.part` file, renamed to `path` only once it has
+/// `file`'s exact size (and checksum, if one is configured). An interrupted
+/// download never leaves a file at `path`, nor damages one already there.
+async fn download_file(
+    client: &reqwest::Client,
+    url: &str,
+    file: &ModelFile,
+    path: &Path,
+    on_progress: impl Fn(u64),
+) -> Result<(), ModelError> {
+    let partial = partial_path(path);
+    let result = download_to(client, url, file, &partial, on_progress).await;
+    match result {
+        Ok(()) => std::fs::rename(&partial, path).map_err(|e| {
+            let _ = std::fs::remove_file(&partial);
+            ModelError::Io(e)
+        }),
+        Err(e) => {
+            let _ = std::fs::remove_file(&partial);
+            Err(e)
+        }
+    }
+}
+
+/// Download `url` to `path`, checking it against `file`
+async fn download_to(
+    client: &reqwest::Client,
+    url: &str,
+    file: &ModelFile,
+    path: &Path,
+    on_progress: impl Fn(u64),
+) -> Result<(), ModelError> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| ModelError::DownloadFailed(e.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(ModelError::DownloadFailed(format!(
+            "HTTP {} for {}",
+            response.status(),
+            url
+        )));
+    }
+
+    let mut file_writer = std::fs::File::create(path)?;
+    let mut stream = response.bytes_stream();
+    let mut downloaded: u64 = 0;
+    let mut hasher = Sha256::new();
+
+    while let Some(chunk_result) = stream.next().await {
+        let chunk =
+            chunk_result.map_err(|e: reqwest::Error| ModelError::DownloadFailed(e.to_string()))?;
+
+        file_writer.write_all(&chunk)?;
+        hasher.update(&chunk);
+        downloaded += chunk.len() as u64;
+        on_progress(downloaded);
+    }
+    file_writer.sync_all()?;
+
+    if downloaded != file.size {
+        return Err(ModelError::DownloadFailed(format!(
+            "{} is {} bytes, expected {}",
+            url, downloaded, file.size
+        )));
+    }
+    if let Some(checksum) = file.checksum {
+        if !format!("{:x}", hasher.finalize()).eq_ignore_ascii_case(checksum) {
+            return Err(ModelError::ChecksumError);
+        }
+    }
+
+    Ok(())
+}
+
+/// A model file whose size on the server doesn't match [`ModelFile::size`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelFileMismatch {
+    pub model_id: String,
+    pub file: String,
+    pub url: String,
+    /// Size configured in the model registry
+    pub expected_size: u64,
+    /// `Content-Length` reported by the server, if it could be read
+    pub live_size: Option<u64>,
+    /// Why the live size couldn't be read
+    pub error: Option<String>,
+}
+
+/// Result of [`validate_models`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelValidationReport {
+    /// Number of files checked
+    pub files_checked: usize,
+    /// Files whose live size differs from the configured one (or is unknown)
+    pub mismatches: Vec<ModelFileMismatch>,
+}
+
+/// Live `Content-Length` of `url`, from a HEAD request
+async fn live_size(client: &reqwest::Client, url: &str) -> Result<u64, String> {
+    let response = client.head(url).send().await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status()));
+    }
+
+    // Read the header itself: for HEAD responses `content_length()` reports
+    // the (empty) body instead
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| "No Content-Length header".to_string())
+}
+
+/// Check every file of `models` against the server.
+///
+/// [`ModelManager::is_model_ready`] requires each file's exact configured
+/// size, so a file updated upstream makes a finished download look
+/// incomplete forever. This catches that drift: each file gets a HEAD
+/// request, and any whose `Content-Length` differs from [`ModelFile::size`]
+/// (or can't be read) is reported.
+pub async fn validate_models(
+    client: &reqwest::Client,
+    models: &[&NeuralModel],
+) -> ModelValidationReport {
+    let files: Vec<(&NeuralModel, &ModelFile)> = models
+        .iter()
+        .flat_map(|model| model.files.iter().map(move |file| (*model, file)))
+        .collect();
+
+    let mismatches: Vec<ModelFileMismatch> = futures::stream::iter(files.iter())
+        .map(|(model, file)| async move {
+            let url = format!("{}/{}", model.base_url, file.name);
+            let (live_size, error) = match live_size(client, &url).await {
+                Ok(size) if size == file.size => return None,
+                Ok(size) => {
+                    tracing::warn!(
+                        "Model file {} is {} bytes upstream, configured as {}",
+                        url,
+                        size,
+                        file.size
+                    );
+                    (Some(size), None)
+                }
+                Err(e) => {
+                    tracing::warn!("Could not check model file {}: {}", url, e);
+                    (None, Some(e))
+                }
+            };
+
+            Some(ModelFileMismatch {
+                model_id: model.id.to_string(),
+                file: file.name.to_string(),
+                url,
+                expected_size: file.size,
+                live_size,
+                error,
+            })
+        })
+        .buffered(MAX_CONCURRENT_DOWNLOADS)
+        .filter_map(|mismatch| async move { mismatch })
+        .collect()
+        .await;
+
+    ModelValidationReport {
+        files_checked: files.len(),
+        mismatches,
+    }
+}
+
+/// Check that models can be stored in `dir`, creating it if needed
+pub fn ensure_writable(dir: &Path) -> Result<(), ModelError> {
+    let not_writable = |e: std::io::Error| {
+        ModelError::DirectoryError(format!("{} is not writable: {}", dir.display(), e))
+    };
+
+    std::fs::create_dir_all(dir).map_err(not_writable)?;
+    let probe = dir.join(".pastel-hn-write-test");
+    std::fs::write(&probe, b"").map_err(not_writable)?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+/// Move every downloaded model from `from` to `to`.
+///
+/// A model already present in `to` is left alone, and so is its copy in
+/// `from`. Moving to another volume falls back to copying, then deleting
+/// the original. Returns the number of models moved.
+///
+/// If a model fails to move, the ones already moved are moved back, so
+/// every model stays in `from` on error. Blocks on file I/O; call from
+/// the blocking thread pool.
+pub fn move_models(from: &Path, to: &Path) -> Result<usize, ModelError> {
+    let mut moved = Vec::new();
+    for model in MODELS {
+        let source = from.join(model.dir_name());
+        let target = to.join(model.dir_name());
+        if !source.exists() {
+            continue;
+        }
+        if target.exists() {
+            tracing::warn!(
+                "Not moving model {}: {} already exists",
+                model.id,
+                target.display()
+            );
+            continue;
+        }
+
+        if let Err(e) = move_dir(&source, &target) {
+            for (source, target) in moved.iter().rev() {
+                if let Err(e) = move_dir(target, source) {
+                    tracing::warn!("Failed to move {} back: {}", target.display(), e);
+                }
+            }
+            return Err(e);
+        }
+        tracing::info!("Moved model {} to {}", model.id, target.display());
+        moved.push((source, target));
+    }
+    Ok(moved.len())
+}
+
+/// Move the directory `from` to `to`, copying across volumes
+///
+/// A failed copy leaves `from` as it was and removes the partial `to`.
+fn move_dir(from: &Path, to: &Path) -> Result<(), ModelError> {
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    if let Err(e) = copy_dir(from, to) {
+        let _ = std::fs::remove_dir_all(to);
+        return Err(e);
+    }
+    std::fs::remove_dir_all(from)?;
+    Ok(())
+}
+
+/// Recursively copy the directory `from` to `to`
+fn copy_dir(from: &Path, to: &Path) -> Result<(), ModelError> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
 /// Manages model downloads and caching
 pub struct ModelManager {
     model_dir: PathBuf,
@@ -134,8 +551,37 @@ impl ModelManager {
         Ok(ModelManager { model_dir })
     }
 
-    /// Get the platform-specific model directory
+    /// Create a ModelManager rooted at `model_dir` instead of the platform default
+    #[cfg(test)]
+    pub(crate) fn with_model_dir(model_dir: PathBuf) -> Self {
+        ModelManager { model_dir }
+    }
+
+    /// Get the model directory: the one set with [`Self::set_model_dir`],
+    /// else the platform default
     pub fn get_model_dir() -> Result<PathBuf, ModelError> {
+        Self::resolve_model_dir(MODEL_DIR.read().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+
+    /// The model directory for a configured `dir`: `dir` itself, or the
+    /// platform default for `None`
+    pub fn resolve_model_dir(dir: Option<PathBuf>) -> Result<PathBuf, ModelError> {
+        match dir {
+            Some(dir) => Ok(dir),
+            None => Self::default_model_dir(),
+        }
+    }
+
+    /// Store models in `dir` (`None` for the platform default)
+    ///
+    /// Applies to managers created afterwards. Existing downloads stay
+    /// where they are; see [`move_models`].
+    pub fn set_model_dir(dir: Option<PathBuf>) {
+        *MODEL_DIR.write().unwrap_or_else(|e| e.into_inner()) = dir;
+    }
+
+    /// Get the platform-specific model directory
+    pub fn default_model_dir() -> Result<PathBuf, ModelError> {
         let data_dir = dirs::data_dir()
             .or_else(|| dirs::home_dir().map(|h| h.join(".local/share")))
             .ok_or_else(|| {
@@ -188,87 +634,57 @@ impl ModelManager {
         }
     }
 
-    /// Download a model with progress callback
-    pub async fn download_model<F>(
+    /// Download a model, reporting detailed progress
+    ///
+    /// Files are downloaded concurrently (up to [`MAX_CONCURRENT_DOWNLOADS`]
+    /// at a time), and the callback receives a [`DownloadProgress`] across
+    /// all of them. Files already present with the expected size are skipped.
+    pub async fn download_model_with_progress<F>(
         &self,
         model: &NeuralModel,
         progress_callback: Option<F>,
     ) -> Result<(), ModelError>
     where
-        F: Fn(u8) + Send + 'static,
+        F: Fn(DownloadProgress) + Send + 'static,
     {
         // Check available disk space
-        self.check_disk_space(model.size_bytes)?;
+        self.check_disk_space(self.remaining_bytes(model))?;
 
         let model_path = self.get_model_path(model);
         std::fs::create_dir_all(&model_path)?;
 
         let total_files = model.files.len();
         let mut completed_files = 0;
-        let mut total_downloaded: u64 = 0;
-
-        for file in model.files {
-            let file_path = model_path.join(file.path);
+        let reporter = ProgressReporter::new(model.files, progress_callback);
 
-            // Skip if already exists and size matches
-            if let Ok(metadata) = std::fs::metadata(&file_path) {
-                if metadata.len() == file.size {
-                    completed_files += 1;
-                    total_downloaded += file.size;
-
-                    // Report progress
-                    if let Some(ref callback) = progress_callback {
-                        let progress =
-                            ((total_downloaded as f64 / model.size_bytes as f64) * 100.0) as u8;
-                        callback(progress);
+        let client = reqwest::Client::new();
+        let mut downloads = futures::stream::iter(model.files.iter().enumerate())
+            .map(|(index, file)| {
+                let client = &client;
+                let reporter = &reporter;
+                let file_path = model_path.join(file.path);
+                let url = format!("{}/{}", model.base_url, file.name);
+
+                async move {
+                    // Skip if already exists and size matches
+                    if let Ok(metadata) = std::fs::metadata(&file_path) {
+                        if metadata.len() == file.size {
+                            reporter.file_present(index);
+                            return Ok(());
+                        }
                     }
-                    continue;
-                }
-            }
-
-            // Download the file
-            let url = format!("{}/{}", model.base_url, file.name);
-
-            // Create a simple HTTP client
-            let client = reqwest::Client::new();
-
-            // Stream download with progress tracking
-            let response = client
-                .get(&url)
-                .send()
-                .await
-                .map_err(|e| ModelError::DownloadFailed(e.to_string()))?;
-
-            if !response.status().is_success() {
-                return Err(ModelError::DownloadFailed(format!(
-                    "HTTP {} for {}",
-                    response.status(),
-                    url
-                )));
-            }
-
-            // Create file for writing
-            let mut file_writer = std::fs::File::create(&file_path)?;
-            let mut stream = response.bytes_stream();
-            let mut file_downloaded: u64 = 0;
-
-            while let Some(chunk_result) = stream.next().await {
-                let chunk = chunk_result
-                    .map_err(|e: reqwest::Error| ModelError::DownloadFailed(e.to_string()))?;
 
-                file_writer.write_all(&chunk)?;
-                file_downloaded += chunk.len() as u64;
-
-                // Update progress
-                let current_total = total_downloaded + file_downloaded;
-                if let Some(ref callback) = progress_callback {
-                    let progress = ((current_total as f64 / model.size_bytes as f64) * 100.0) as u8;
-                    callback(progress);
+                    download_file(client, &url, file, &file_path, |bytes| {
+                        reporter.file_progress(index, bytes)
+                    })
+                    .await
                 }
-            }
+            })
+            .buffer_unordered(MAX_CONCURRENT_DOWNLOADS);
 
+        while let Some(result) = downloads.next().await {
+            result?;
             completed_files += 1;
-            total_downloaded += file_downloaded;
         }
 
         // Verify all files downloaded
@@ -331,27 +747,54 @@ impl ModelManager {
         Ok(total)
     }
 
-    /// Check if there's enough disk space
-    fn check_disk_space(&self, _required_bytes: u64) -> Result<(), ModelError> {
-        // Get available space (platform-specific)
-        #[cfg(target_os = "macos")]
-        {
-            use std::process::Command;
-            let output = Command::new("df")
-                .args(["-k", self.model_dir.to_str().unwrap_or(".")])
-                .output()?;
-
-            if output.status.success() {
-                let _stdout = String::from_utf8_lossy(&output.stdout);
-                // Parse df output to get available space
-                // This is a simplified check - in production would be more robust
-                return Ok(());
+    /// Bytes still to download for `model`: files missing or of the wrong size
+    fn remaining_bytes(&self, model: &NeuralModel) -> u64 {
+        let model_path = self.get_model_path(model);
+        model
+            .files
+            .iter()
+            .filter(|file| {
+                !std::fs::metadata(model_path.join(file.path))
+                    .is_ok_and(|metadata| metadata.len() == file.size)
+            })
+            .map(|file| file.size)
+            .sum()
+    }
+
+    /// Check there's room for `required_bytes` in the model directory
+    fn check_disk_space(&self, required_bytes: u64) -> Result<(), ModelError> {
+        self.check_disk_space_with(required_bytes, |path| fs4::available_space(path))
+    }
+
+    /// [`check_disk_space`](Self::check_disk_space) with the free-space
+    /// lookup supplied
+    ///
+    /// If free space can't be determined the download goes ahead, so an
+    /// unusual filesystem doesn't block it outright.
+    fn check_disk_space_with(
+        &self,
+        required_bytes: u64,
+        available_space: impl FnOnce(&Path) -> std::io::Result<u64>,
+    ) -> Result<(), ModelError> {
+        // The model directory may not exist yet; ask about the nearest
+        // ancestor that does, which is on the filesystem it will be created on
+        let dir = self
+            .model_dir
+            .ancestors()
+            .find(|dir| dir.exists())
+            .unwrap_or(&self.model_dir);
+
+        match available_space(dir) {
+            Ok(available) if available < required_bytes => Err(ModelError::InsufficientSpace {
+                needed: required_bytes.div_ceil(1_000_000),
+                available: available / 1_000_000,
+            }),
+            Ok(_) => Ok(()),
+            Err(e) => {
+                tracing::warn!("Could not check free space in {}: {}", dir.display(), e);
+                Ok(())
             }
         }
-
-        // For other platforms or if check fails, assume we have space
-        // In production, add proper disk space checks for Windows/Linux
-        Ok(())
     }
 
     /// Get model file path
@@ -464,6 +907,101 @@ mod tests {
         assert!(!model_path.exists());
     }
 
+    const TWO_FILES: &[ModelFile] = &[
+        ModelFile {
+            name: "model.onnx",
+            size: 9_000,
+            checksum: None,
+            path: "model.onnx",
+        },
+        ModelFile {
+            name: "model.onnx.json",
+            size: 1_000,
+            checksum: None,
+            path: "model.onnx.json",
+        },
+    ];
+
+    #[test]
+    fn test_aggregate_progress_weights_files_by_size() {
+        let mut progress = AggregateProgress::new(TWO_FILES);
+
+        // The small file finishing first is only 10% of the total
+        progress.update(1, 1_000);
+        assert_eq!(progress.percent(), 10);
+        progress.update(0, 4_500);
+        assert_eq!(progress.percent(), 55);
+        progress.update(0, 9_000);
+        assert_eq!(progress.percent(), 100);
+    }
+
+    #[test]
+    fn test_aggregate_progress_never_exceeds_100() {
+        let mut progress = AggregateProgress::new(TWO_FILES);
+
+        // Files larger than declared are capped at their expected size
+        progress.update(1, 50_000);
+        assert_eq!(progress.percent(), 10);
+        progress.update(0, 20_000);
+        assert_eq!(progress.percent(), 100);
+    }
+
+    #[test]
+    fn test_aggregate_progress_counts_skipped_files() {
+        let mut progress = AggregateProgress::new(TWO_FILES);
+        assert_eq!(progress.percent(), 0);
+
+        // An already-downloaded ONNX file counts at full size up front
+        progress.mark_present(0);
+        assert_eq!(progress.percent(), 90);
+    }
+
+    #[test]
+    fn test_download_speed_and_eta() {
+        let mut progress = AggregateProgress::new(TWO_FILES);
+
+        // Nothing to go on at the start
+        let start = progress.snapshot(Duration::ZERO);
+        assert_eq!(start.bytes_per_sec, 0);
+        assert_eq!(start.eta_secs, None);
+
+        // 1,000 bytes after 1s, then 4,000 bytes after 2s
+        progress.update(1, 1_000);
+        let first = progress.snapshot(Duration::from_secs(1));
+        assert_eq!(first.bytes_per_sec, 1_000);
+        assert_eq!(first.eta_secs, Some(9));
+
+        progress.update(0, 3_000);
+        let second = progress.snapshot(Duration::from_secs(2));
+        assert_eq!(
+            second,
+            DownloadProgress {
+                percent: 40,
+                bytes_done: 4_000,
+                bytes_total: 10_000,
+                bytes_per_sec: 2_000,
+                eta_secs: Some(3),
+            }
+        );
+
+        progress.update(0, 9_000);
+        let done = progress.snapshot(Duration::from_millis(4_500));
+        assert_eq!(done.eta_secs, Some(0));
+        assert_eq!(done.percent, 100);
+    }
+
+    #[test]
+    fn test_download_speed_excludes_present_files() {
+        let mut progress = AggregateProgress::new(TWO_FILES);
+        progress.mark_present(0);
+        progress.update(1, 500);
+
+        let snapshot = progress.snapshot(Duration::from_secs(1));
+        assert_eq!(snapshot.bytes_done, 9_500);
+        assert_eq!(snapshot.bytes_per_sec, 500);
+        assert_eq!(snapshot.eta_secs, Some(1));
+    }
+
     #[test]
     fn test_dir_size_calculation() {
         let temp_dir = TempDir::new().unwrap();
@@ -484,6 +1022,200 @@ mod tests {
         assert_eq!(size, 23);
     }
 
+    #[tokio::test]
+    async fn test_validate_models_reports_size_drift() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for (route, size) in [("/voice.onnx", 12), ("/voice.onnx.json", 9)] {
+            Mock::given(method("HEAD"))
+                .and(path(route))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("content-length", size.to_string())
+                        .set_body_bytes(vec![0; size]),
+                )
+                .mount(&server)
+                .await;
+        }
+
+        let model = NeuralModel {
+            id: "test-voice",
+            name: "Test voice",
+            size_bytes: 21,
+            files: &[
+                ModelFile {
+                    name: "voice.onnx",
+                    size: 12,
+                    checksum: None,
+                    path: "voice.onnx",
+                },
+                ModelFile {
+                    name: "voice.onnx.json",
+                    size: 7,
+                    checksum: None,
+                    path: "voice.onnx.json",
+                },
+                ModelFile {
+                    name: "missing.bin",
+                    size: 1,
+                    checksum: None,
+                    path: "missing.bin",
+                },
+            ],
+            base_url: Box::leak(server.uri().into_boxed_str()),
+        };
+
+        let report = validate_models(&reqwest::Client::new(), &[&model]).await;
+
+        assert_eq!(report.files_checked, 3);
+        assert_eq!(report.mismatches.len(), 2);
+        assert_eq!(report.mismatches[0].file, "voice.onnx.json");
+        assert_eq!(report.mismatches[0].expected_size, 7);
+        assert_eq!(report.mismatches[0].live_size, Some(9));
+        assert_eq!(report.mismatches[0].error, None);
+        assert_eq!(report.mismatches[1].file, "missing.bin");
+        assert_eq!(report.mismatches[1].live_size, None);
+        assert_eq!(
+            report.mismatches[1].error.as_deref(),
+            Some("HTTP 404 Not Found")
+        );
+    }
+
+    #[test]
+    fn test_model_path_respects_model_dir_override() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let dir = ModelManager::resolve_model_dir(Some(temp_dir.path().to_path_buf())).unwrap();
+        assert_eq!(dir, temp_dir.path());
+        assert_eq!(
+            ModelManager::resolve_model_dir(None).unwrap(),
+            ModelManager::default_model_dir().unwrap()
+        );
+
+        let path = ModelManager::with_model_dir(dir).get_model_path(&PIPER_EN_US_MODEL);
+        assert_eq!(path, temp_dir.path().join("piper-en-us"));
+    }
+
+    #[test]
+    fn test_move_models_keeps_existing_targets() {
+        let temp_dir = TempDir::new().unwrap();
+        let from = temp_dir.path().join("old");
+        let to = temp_dir.path().join("new");
+        fs::create_dir_all(from.join("piper-en-us")).unwrap();
+        fs::write(from.join("piper-en-us").join("model.onnx"), b"onnx").unwrap();
+
+        ensure_writable(&to).unwrap();
+        assert_eq!(move_models(&from, &to).unwrap(), 1);
+        assert!(!from.join("piper-en-us").exists());
+        assert_eq!(
+            fs::read(to.join("piper-en-us").join("model.onnx")).unwrap(),
+            b"onnx"
+        );
+
+        // Nothing left to move, and an existing target isn't overwritten
+        assert_eq!(move_models(&from, &to).unwrap(), 0);
+        fs::create_dir_all(from.join("piper-en-us")).unwrap();
+        assert_eq!(move_models(&from, &to).unwrap(), 0);
+        assert!(from.join("piper-en-us").exists());
+    }
+
+    #[test]
+    fn test_check_disk_space_rejects_insufficient_space() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = ModelManager::with_model_dir(temp_dir.path().join("models"));
+
+        let result = manager.check_disk_space_with(63_206_179, |path| {
+            // The missing model directory falls back to its parent
+            assert_eq!(path, temp_dir.path());
+            Ok(10_500_000)
+        });
+        match result {
+            Err(ModelError::InsufficientSpace { needed, available }) => {
+                assert_eq!(needed, 64);
+                assert_eq!(available, 10);
+            }
+            other => panic!("expected InsufficientSpace, got {:?}", other),
+        }
+
+        assert!(manager
+            .check_disk_space_with(63_206_179, |_| Ok(100_000_000))
+            .is_ok());
+        // Unknown free space doesn't block the download
+        assert!(manager
+            .check_disk_space_with(63_206_179, |_| Err(std::io::ErrorKind::Unsupported.into()))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_remaining_bytes_skips_complete_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = ModelManager::with_model_dir(temp_dir.path().to_path_buf());
+        let model = &PIPER_EN_US_MODEL;
+        assert_eq!(manager.remaining_bytes(model), model.size_bytes);
+
+        let model_path = manager.get_model_path(model);
+        fs::create_dir_all(&model_path).unwrap();
+        fs::write(
+            model_path.join("en_US-lessac-medium.onnx.json"),
+            vec![0; 4_885],
+        )
+        .unwrap();
+        assert_eq!(manager.remaining_bytes(model), 63_201_294);
+    }
+
+    #[tokio::test]
+    async fn test_download_file_is_atomic() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/voice.onnx"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![1; 10]))
+            .mount(&server)
+            .await;
+        let url = format!("{}/voice.onnx", server.uri());
+        let client = reqwest::Client::new();
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("voice.onnx");
+        let file = |size| ModelFile {
+            name: "voice.onnx",
+            size,
+            checksum: None,
+            path: "voice.onnx",
+        };
+
+        // Cut short: the previous good file survives and nothing partial is left
+        fs::write(&target, b"previous").unwrap();
+        let result = download_file(&client, &url, &file(64), &target, |_| {}).await;
+        assert!(matches!(result, Err(ModelError::DownloadFailed(_))));
+        assert_eq!(fs::read(&target).unwrap(), b"previous");
+        assert!(!partial_path(&target).exists());
+
+        fs::remove_file(&target).unwrap();
+        download_file(&client, &url, &file(12), &target, |_| {})
+            .await
+            .unwrap_err();
+        assert!(!target.exists());
+
+        // Complete: renamed into place
+        download_file(&client, &url, &file(10), &target, |_| {})
+            .await
+            .unwrap();
+        assert_eq!(fs::read(&target).unwrap(), vec![1; 10]);
+        assert!(!partial_path(&target).exists());
+
+        let wrong_checksum = ModelFile {
+            checksum: Some("00"),
+            ..file(10)
+        };
+        let result = download_file(&client, &url, &wrong_checksum, &target, |_| {}).await;
+        assert!(matches!(result, Err(ModelError::ChecksumError)));
+        assert_eq!(fs::read(&target).unwrap(), vec![1; 10]);
+    }
+
     /// Integration test: Verify Piper model URLs are valid on HuggingFace
     ///
     /// This test makes actual HTTP HEAD requests to HuggingFace to verify
@@ -492,39 +1224,14 @@ mod tests {
     #[tokio::test]
     #[ignore] // Requires network access
     async fn test_piper_huggingface_urls_are_valid() {
-        let piper = NeuralModel::from_id("piper-en-us").unwrap();
-        let client = reqwest::Client::new();
-
-        for file in piper.files {
-            let url = format!("{}/{}", piper.base_url, file.name);
-
-            let response = client
-                .head(&url)
-                .send()
-                .await
-                .unwrap_or_else(|_| panic!("Failed to HEAD request {}", url));
+        let report = validate_models(&reqwest::Client::new(), MODELS).await;
 
-            assert!(
-                response.status().is_success(),
-                "URL {} returned status {}: file '{}' may not exist on HuggingFace",
-                url,
-                response.status(),
-                file.name
-            );
-
-            // Verify Content-Length header matches exactly
-            // This is critical because is_model_ready() uses exact size comparison
-            if let Some(content_length) = response.headers().get("content-length") {
-                let actual_size: u64 = content_length.to_str().unwrap().parse().unwrap();
-
-                assert_eq!(
-                    actual_size, file.size,
-                    "File {} has size {} bytes on HuggingFace, but we configured {} bytes. \
-                     Update PIPER_EN_US_MODEL in model.rs with the correct size.",
-                    file.name, actual_size, file.size
-                );
-            }
-        }
+        // Exact sizes matter because is_model_ready() compares them
+        assert!(
+            report.mismatches.is_empty(),
+            "Model files differ from the registry; update the sizes in model.rs: {:#?}",
+            report.mismatches
+        );
     }
 
     /// Integration test: Actually download the Piper model config file
@@ -614,7 +1321,9 @@ mod tests {
         );
 
         // Download the model
-        let result = manager.download_model(piper, None::<fn(u8)>).await;
+        let result = manager
+            .download_model_with_progress(piper, None::<fn(DownloadProgress)>)
+            .await;
         assert!(result.is_ok(), "Download should succeed: {:?}", result);
 
         // Verify model IS ready after download
@@ -0,0 +1,387 @@
+//! User pronunciation dictionary for neural TTS, persisted across restarts.
+//!
+//! espeak-ng mispronounces jargon like "nginx", "kubectl", or "GIF". Users
+//! can map a word to a respelling ("engine x"), substituted into the text
+//! before phonemization, or to IPA, which is spliced into espeak-ng's output
+//! as is.
+//!
+//! Entries match whole words only, so "GIF" leaves "GIFT" alone. A word
+//! matches in any case. Where entries overlap, the longest wins, so
+//! multi-word entries like "Hacker News" work; between entries of the same
+//! length, one whose case matches exactly wins, so "US" and "us" can be
+//! spelled differently.
+//!
+//! The dictionary is saved to a JSON file in the app data directory after
+//! every change, through a [`persist::JsonStore`]. The engine reads
+//! the active copy published by [`PronunciationStore::activate`], so edits
+//! apply without waiting for in-progress narration to release the engine.
+
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::persist;
+use crate::types::ApiError;
+
+/// The dictionary applied by the engine
+static ACTIVE: RwLock<PronunciationDictionary> = RwLock::new(PronunciationDictionary {
+    entries: Vec::new(),
+});
+
+/// How to pronounce a word.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PronunciationEntry {
+    /// Word (or phrase) as it appears in text
+    pub word: String,
+    /// Respelling, or IPA if `ipa` is set
+    pub pronunciation: String,
+    /// Whether `pronunciation` is IPA rather than a respelling
+    #[serde(default)]
+    pub ipa: bool,
+}
+
+/// Text after applying the dictionary, ready for phonemization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment {
+    /// Text (with respellings substituted) to phonemize with espeak-ng
+    Text(String),
+    /// IPA to use as is
+    Ipa(String),
+}
+
+/// A set of entries, ready to apply to text.
+#[derive(Debug, Clone, Default)]
+pub struct PronunciationDictionary {
+    /// Longest word first, so overlapping entries prefer the longest
+    entries: Vec<PronunciationEntry>,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric()
+}
+
+/// Byte length of `word` at the start of `text`, in any case, if it's
+/// there.
+fn prefix_len(text: &str, word: &str) -> Option<usize> {
+    let mut chars = text.chars();
+    let mut len = 0;
+    for expected in word.chars() {
+        let c = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+        len += c.len_utf8();
+    }
+    Some(len)
+}
+
+impl PronunciationDictionary {
+    pub fn new(mut entries: Vec<PronunciationEntry>) -> Self {
+        entries.retain(|e| !e.word.is_empty());
+        entries.sort_by_key(|e| std::cmp::Reverse(e.word.chars().count()));
+        Self { entries }
+    }
+
+    /// The dictionary currently applied by the engine.
+    pub fn active() -> Self {
+        ACTIVE.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Split `text` into segments, substituting respellings and IPA.
+    pub fn apply(&self, text: &str) -> Vec<Segment> {
+        if self.entries.is_empty() {
+            return vec![Segment::Text(text.to_string())];
+        }
+
+        let mut segments = Vec::new();
+        let mut plain = String::new();
+        let mut prev = None;
+        let mut i = 0;
+
+        while let Some(c) = text[i..].chars().next() {
+            if let Some((len, entry)) = self.match_at(&text[i..], prev) {
+                if entry.ipa {
+                    if !plain.is_empty() {
+                        segments.push(Segment::Text(std::mem::take(&mut plain)));
+                    }
+                    segments.push(Segment::Ipa(entry.pronunciation.clone()));
+                } else {
+                    plain.push_str(&entry.pronunciation);
+                }
+                prev = text[..i + len].chars().next_back();
+                i += len;
+                continue;
+            }
+
+            plain.push(c);
+            prev = Some(c);
+            i += c.len_utf8();
+        }
+
+        if !plain.is_empty() {
+            segments.push(Segment::Text(plain));
+        }
+        segments
+    }
+
+    /// The entry matching at the start of `text`, and the length it covers.
+    ///
+    /// `prev` is the character before `text`, for the word-boundary check.
+    fn match_at(&self, text: &str, prev: Option<char>) -> Option<(usize, &PronunciationEntry)> {
+        // Entries are longest first, so the first match is the longest;
+        // one of the same length may still match in exact case
+        let mut best: Option<(usize, &PronunciationEntry)> = None;
+        for entry in &self.entries {
+            if best
+                .is_some_and(|(_, found)| entry.word.chars().count() < found.word.chars().count())
+            {
+                break;
+            }
+            let Some(len) = word_len(text, prev, &entry.word) else {
+                continue;
+            };
+            if text[..len] == entry.word {
+                return Some((len, entry));
+            }
+            best.get_or_insert((len, entry));
+        }
+        best
+    }
+}
+
+/// Byte length of `word` at the start of `text` as a whole word, in any
+/// case, if it's there.
+///
+/// `prev` is the character before `text`.
+fn word_len(text: &str, prev: Option<char>, word: &str) -> Option<usize> {
+    let len = prefix_len(text, word)?;
+    let first = word.chars().next()?;
+    let last = word.chars().next_back()?;
+
+    // Words must not continue on either side ("GIF" in "GIFT"), unless the
+    // entry itself ends in punctuation ("C++")
+    if is_word_char(first) && prev.is_some_and(is_word_char) {
+        return None;
+    }
+    if is_word_char(last) && text[len..].chars().next().is_some_and(is_word_char) {
+        return None;
+    }
+    Some(len)
+}
+
+/// The persisted dictionary.
+///
+/// Managed as Tauri state.
+pub struct PronunciationStore {
+    store: persist::JsonStore<Vec<PronunciationEntry>>,
+}
+
+impl PronunciationStore {
+    /// Load the dictionary stored at `path`.
+    ///
+    /// Call [`Self::activate`] to have the engine use it.
+    pub fn load(path: PathBuf) -> Self {
+        Self {
+            store: persist::JsonStore::load(path, "pronunciations"),
+        }
+    }
+
+    /// Default location: `pronunciations.json` in the app data directory.
+    pub fn default_path() -> PathBuf {
+        persist::data_dir().join("pronunciations.json")
+    }
+
+    /// All entries, sorted by word.
+    pub fn entries(&self) -> Vec<PronunciationEntry> {
+        let mut entries = self.store.lock().clone();
+        entries.sort_by(|a, b| a.word.cmp(&b.word));
+        entries
+    }
+
+    /// The entries as a dictionary.
+    pub fn dictionary(&self) -> PronunciationDictionary {
+        PronunciationDictionary::new(self.store.lock().clone())
+    }
+
+    /// Have the engine use this dictionary.
+    pub fn activate(&self) {
+        *ACTIVE.write().unwrap_or_else(|e| e.into_inner()) = self.dictionary();
+    }
+
+    /// Add an entry, replacing any for the same word (exact case).
+    ///
+    /// Returns the updated entries.
+    pub fn add(
+        &self,
+        word: &str,
+        pronunciation: &str,
+        ipa: bool,
+    ) -> Result<Vec<PronunciationEntry>, ApiError> {
+        let word = word.trim();
+        let pronunciation = pronunciation.trim();
+        if word.is_empty() || pronunciation.is_empty() {
+            return Err(ApiError::Api(
+                "Pronunciation needs a word and a pronunciation".to_string(),
+            ));
+        }
+
+        {
+            let mut entries = self.store.lock();
+            entries.retain(|e| e.word != word);
+            entries.push(PronunciationEntry {
+                word: word.to_string(),
+                pronunciation: pronunciation.to_string(),
+                ipa,
+            });
+            self.store.save(&entries)?;
+        }
+        info!("Pronunciation added for {:?}", word);
+
+        self.activate();
+        Ok(self.entries())
+    }
+
+    /// Remove the entry for `word` (exact case), if any.
+    ///
+    /// Returns the updated entries.
+    pub fn remove(&self, word: &str) -> Result<Vec<PronunciationEntry>, ApiError> {
+        {
+            let mut entries = self.store.lock();
+            entries.retain(|e| e.word != word.trim());
+            self.store.save(&entries)?;
+        }
+
+        self.activate();
+        Ok(self.entries())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(word: &str, pronunciation: &str) -> PronunciationEntry {
+        PronunciationEntry {
+            word: word.to_string(),
+            pronunciation: pronunciation.to_string(),
+            ipa: false,
+        }
+    }
+
+    fn respell(dictionary: &PronunciationDictionary, text: &str) -> String {
+        match dictionary.apply(text).as_slice() {
+            [Segment::Text(text)] => text.clone(),
+            other => panic!("expected one text segment, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn entry_replaces_whole_words_only() {
+        let dictionary =
+            PronunciationDictionary::new(vec![entry("nginx", "engine x"), entry("GIF", "jiff")]);
+
+        assert_eq!(
+            respell(&dictionary, "Serve the GIF with nginx."),
+            "Serve the jiff with engine x."
+        );
+        assert_eq!(
+            respell(&dictionary, "A GIFT for nginxconf, not xGIF"),
+            "A GIFT for nginxconf, not xGIF"
+        );
+        assert_eq!(respell(&dictionary, "nginx's GIFs"), "engine x's GIFs");
+    }
+
+    #[test]
+    fn exact_case_wins_over_case_insensitive() {
+        let dictionary = PronunciationDictionary::new(vec![
+            entry("US", "U S"),
+            entry("kubectl", "cube control"),
+        ]);
+
+        assert_eq!(
+            respell(&dictionary, "Kubectl in the US"),
+            "cube control in the U S"
+        );
+        assert_eq!(respell(&dictionary, "us"), "U S");
+
+        let dictionary = PronunciationDictionary::new(vec![entry("US", "U S"), entry("us", "uss")]);
+        assert_eq!(respell(&dictionary, "us and US"), "uss and U S");
+    }
+
+    #[test]
+    fn longest_entry_wins() {
+        let dictionary = PronunciationDictionary::new(vec![
+            entry("Hacker", "hacker"),
+            entry("Hacker News", "H N"),
+        ]);
+
+        assert_eq!(
+            respell(&dictionary, "Hacker News for a Hacker"),
+            "H N for a hacker"
+        );
+    }
+
+    #[test]
+    fn longer_entry_wins_over_exact_case() {
+        let dictionary = PronunciationDictionary::new(vec![
+            entry("hacker", "hacker"),
+            entry("Hacker News", "H N"),
+        ]);
+
+        assert_eq!(respell(&dictionary, "hacker news"), "H N");
+    }
+
+    #[test]
+    fn punctuation_entries_match_next_to_words() {
+        let dictionary = PronunciationDictionary::new(vec![entry("C++", "C plus plus")]);
+
+        assert_eq!(respell(&dictionary, "C++20 or C"), "C plus plus20 or C");
+    }
+
+    #[test]
+    fn ipa_entries_become_their_own_segment() {
+        let dictionary = PronunciationDictionary::new(vec![PronunciationEntry {
+            word: "Tauri".to_string(),
+            pronunciation: "ˈtaʊɹi".to_string(),
+            ipa: true,
+        }]);
+
+        assert_eq!(
+            dictionary.apply("Built with Tauri today"),
+            [
+                Segment::Text("Built with ".to_string()),
+                Segment::Ipa("ˈtaʊɹi".to_string()),
+                Segment::Text(" today".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn entries_survive_reload() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("pronunciations.json");
+
+        let store = PronunciationStore::load(path.clone());
+        store.add("nginx", "engine ex", false).unwrap();
+        store.add("nginx", "engine x", false).unwrap();
+        store.add("GIF", "jiff", false).unwrap();
+        store.remove("GIF").unwrap();
+
+        let reloaded = PronunciationStore::load(path);
+        assert_eq!(reloaded.entries(), [entry("nginx", "engine x")]);
+    }
+
+    #[test]
+    fn empty_entries_are_rejected() {
+        let dir = TempDir::new().unwrap();
+        let store = PronunciationStore::load(dir.path().join("pronunciations.json"));
+
+        assert!(store.add("  ", "x", false).is_err());
+        assert!(store.add("nginx", "", false).is_err());
+        assert!(store.entries().is_empty());
+    }
+}
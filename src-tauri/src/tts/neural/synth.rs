@@ -6,16 +6,21 @@
 //! - Phoneme-to-ID mapping using model config
 //! - Audio generation and playback
 //! - Sentence-by-sentence playback with progress events
+//! - Pre-synthesizing narration into the sentence audio cache
 
 use super::audio::AudioData;
+use super::cache::{CacheKey, SentenceAudioCache, MAX_CACHED_SAMPLES};
 use super::model::{ModelError, ModelManager, NeuralModel};
+use super::pronunciation::{PronunciationDictionary, Segment};
+use ort::session::builder::GraphOptimizationLevel;
 use ort::session::Session;
 use ort::value::Value;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::process::Command;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::mpsc;
 
@@ -25,6 +30,8 @@ use tokio::sync::mpsc;
 pub enum SynthesisError {
     #[error("Model not loaded: {0}")]
     ModelNotLoaded(String),
+    #[error("Model not downloaded: {0}")]
+    ModelNotDownloaded(String),
     #[error("ONNX inference error: {0}")]
     InferenceError(String),
     #[error("Invalid input text: {0}")]
@@ -39,6 +46,46 @@ pub enum SynthesisError {
     PhonemeError(String),
     #[error("Config parse error: {0}")]
     ConfigError(String),
+    #[error("Synthesis cancelled")]
+    Cancelled,
+}
+
+/// [`SynthesisError::AudioError`] message when there is no audio output device
+pub const NO_OUTPUT_DEVICE: &str = "no output device";
+
+impl SynthesisError {
+    /// Whether playback failed because no audio output device is available
+    pub fn is_no_output_device(&self) -> bool {
+        matches!(self, Self::AudioError(msg) if msg == NO_OUTPUT_DEVICE)
+    }
+}
+
+/// Whether `espeak-ng` can be run.
+///
+/// Probed once with `espeak-ng --version` on the blocking thread pool; the
+/// result is cached for the life of the process.
+pub async fn espeak_available() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    if let Some(available) = AVAILABLE.get() {
+        return *available;
+    }
+
+    let available = tokio::task::spawn_blocking(probe_espeak)
+        .await
+        .unwrap_or(false);
+    *AVAILABLE.get_or_init(|| available)
+}
+
+/// Run `espeak-ng --version`, blocking until it exits
+fn probe_espeak() -> bool {
+    let available = Command::new("espeak-ng")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success());
+    if !available {
+        tracing::warn!("espeak-ng not found; neural TTS synthesis will fail");
+    }
+    available
 }
 
 /// Events emitted during sentence-by-sentence TTS playback
@@ -57,12 +104,43 @@ pub enum SentenceEvent {
         /// Index of the sentence (0-based)
         index: usize,
     },
+    /// A sentence couldn't be synthesized or played
+    ///
+    /// Followed by `Stopped` unless [`NeuralTtsConfig::continue_on_error`]
+    /// is set, in which case playback moves on to the next sentence.
+    Error {
+        /// Index of the sentence (0-based)
+        index: usize,
+        /// Which step failed
+        stage: FailureStage,
+        /// What went wrong
+        reason: String,
+    },
     /// All sentences have finished
     Finished,
     /// Playback was stopped
     Stopped,
 }
 
+/// Where a sentence failed during sentence-by-sentence playback
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureStage {
+    /// Phonemization (espeak-ng) or ONNX inference
+    Synthesis,
+    /// Audio output
+    Playback,
+}
+
+impl std::fmt::Display for FailureStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            FailureStage::Synthesis => "synthesize",
+            FailureStage::Playback => "play",
+        })
+    }
+}
+
 /// Configuration for neural TTS
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeuralTtsConfig {
@@ -74,6 +152,21 @@ pub struct NeuralTtsConfig {
     pub model_id: String,
     /// Enable GPU acceleration
     pub use_gpu: bool,
+    /// Threads ONNX Runtime uses within an operator
+    #[serde(default = "default_intra_threads")]
+    pub intra_threads: usize,
+    /// ONNX graph optimization level
+    #[serde(default)]
+    pub optimization_level: OptimizationLevel,
+    /// Output latency in ms: how long after audio is queued it becomes
+    /// audible, which delays sentence start events. `None` derives it from
+    /// the output stream's buffer size.
+    #[serde(default)]
+    pub output_latency_ms: Option<u64>,
+    /// Whether sentence playback moves on after a sentence fails, rather
+    /// than stopping
+    #[serde(default)]
+    pub continue_on_error: bool,
 }
 
 impl Default for NeuralTtsConfig {
@@ -83,6 +176,43 @@ impl Default for NeuralTtsConfig {
             voice_id: "default".to_string(),
             model_id: "piper-en-us".to_string(),
             use_gpu: true,
+            intra_threads: default_intra_threads(),
+            optimization_level: OptimizationLevel::default(),
+            output_latency_ms: None,
+            continue_on_error: false,
+        }
+    }
+}
+
+/// One intra-op thread per available core
+fn default_intra_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// ONNX graph optimization level, mirroring ort's `GraphOptimizationLevel`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptimizationLevel {
+    /// No graph optimizations
+    Disable,
+    /// Basic optimizations (constant folding, redundant node removal)
+    Level1,
+    /// Extended optimizations (node fusions)
+    Level2,
+    /// All optimizations, including layout changes
+    #[default]
+    Level3,
+}
+
+impl From<OptimizationLevel> for GraphOptimizationLevel {
+    fn from(level: OptimizationLevel) -> Self {
+        match level {
+            OptimizationLevel::Disable => GraphOptimizationLevel::Disable,
+            OptimizationLevel::Level1 => GraphOptimizationLevel::Level1,
+            OptimizationLevel::Level2 => GraphOptimizationLevel::Level2,
+            OptimizationLevel::Level3 => GraphOptimizationLevel::Level3,
         }
     }
 }
@@ -131,6 +261,27 @@ struct InferenceConfig {
     noise_w: f32,
 }
 
+/// How often a running espeak-ng is checked for cancellation
+const ESPEAK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Stops an engine's speech without holding the engine.
+///
+/// Speech keeps the engine borrowed until it ends, including while text is
+/// still being synthesized, so stopping goes through this shared handle.
+#[derive(Debug, Clone)]
+pub struct StopHandle {
+    is_speaking: Arc<AtomicBool>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl StopHandle {
+    /// Stop playback and abandon synthesis in progress
+    pub fn stop(&self) {
+        self.cancel.store(true, Ordering::SeqCst);
+        self.is_speaking.store(false, Ordering::SeqCst);
+    }
+}
+
 /// The neural TTS synthesis engine
 pub struct NeuralTtsEngine {
     config: NeuralTtsConfig,
@@ -141,8 +292,149 @@ pub struct NeuralTtsEngine {
     loaded_model: Option<String>,
     /// Whether currently speaking (atomic for thread safety)
     is_speaking: Arc<AtomicBool>,
+    /// Set by [`StopHandle::stop`] to abandon synthesis in progress;
+    /// cleared when new speech starts
+    cancel: Arc<AtomicBool>,
     /// Loaded Piper model config
     piper_config: Option<PiperConfig>,
+    /// Whether a warmup inference has run on the loaded session
+    warmed_up: bool,
+    /// Synthesized sentences, reused by [`Self::speak_sentences`]
+    audio_cache: SentenceAudioCache,
+}
+
+/// Text synthesized by [`NeuralTtsEngine::warmup`]
+const WARMUP_TEXT: &str = "Hi.";
+
+/// Default phrase for [`NeuralTtsEngine::benchmark`]
+pub const BENCHMARK_TEXT: &str =
+    "The quick brown fox jumps over the lazy dog while the story loads in the background.";
+
+/// Number of timed synthesis runs averaged by [`NeuralTtsEngine::benchmark`]
+const BENCHMARK_ITERATIONS: u32 = 3;
+
+/// Synthesis speed and memory use on this machine
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkResult {
+    /// Seconds of audio produced per second of wall time (above 1 is faster than real time)
+    pub real_time_factor: f64,
+    /// Average audio duration per run
+    pub audio_secs: f64,
+    /// Average synthesis time per run
+    pub synthesis_secs: f64,
+    /// Number of timed runs
+    pub iterations: u32,
+    /// Approximate resident memory of the app after synthesis, if known
+    pub resident_memory_bytes: Option<u64>,
+}
+
+/// Narration synthesized ahead of playback by [`NeuralTtsEngine::prepare`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreparedNarration {
+    /// The text split into sentences, to pass to `speak_sentences`
+    pub sentences: Vec<String>,
+    pub sentence_count: usize,
+    /// Total audio length at the current rate
+    pub duration_secs: f64,
+}
+
+/// Split text into sentences, combining short ones into chunks of about
+/// 200-400 characters for steadier pacing.
+///
+/// Mirrors `splitIntoSentences` in the frontend (`tts-ui.ts`), so
+/// sentences prepared here line up with the article's highlighting.
+pub fn split_sentences(text: &str) -> Vec<String> {
+    const MIN_CHUNK_LENGTH: usize = 200;
+    const MAX_CHUNK_LENGTH: usize = 400;
+
+    // Sentence-ending punctuation followed by whitespace ends a sentence
+    let mut raw = Vec::new();
+    let mut start = 0;
+    let mut prev = None;
+    for (i, c) in text.char_indices() {
+        if c.is_whitespace() && matches!(prev, Some('.' | '!' | '?')) {
+            raw.push(&text[start..i]);
+            start = i;
+        }
+        prev = Some(c);
+    }
+    raw.push(&text[start..]);
+
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    for sentence in raw.into_iter().map(str::trim).filter(|s| !s.is_empty()) {
+        if current.is_empty() {
+            current = sentence.to_string();
+            continue;
+        }
+
+        let current_len = current.chars().count();
+        if current_len < MIN_CHUNK_LENGTH
+            && current_len + 1 + sentence.chars().count() <= MAX_CHUNK_LENGTH
+        {
+            current.push(' ');
+            current.push_str(sentence);
+        } else {
+            chunks.push(std::mem::replace(&mut current, sentence.to_string()));
+        }
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+/// Audio seconds produced per wall-clock second
+fn real_time_factor(audio_secs: f64, wall_secs: f64) -> f64 {
+    if wall_secs > 0.0 {
+        audio_secs / wall_secs
+    } else {
+        0.0
+    }
+}
+
+/// The `VmRSS` line of `/proc/self/status`, in bytes
+///
+/// Reported in kB, so it doesn't depend on the page size.
+#[cfg(target_os = "linux")]
+fn parse_vm_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let rss_kb: u64 = line["VmRSS:".len()..]
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some(rss_kb * 1024)
+}
+
+/// Approximate resident set size of this process
+fn resident_memory_bytes() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        parse_vm_rss(&status)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let output = Command::new("ps")
+            .args(["-o", "rss=", "-p", &std::process::id().to_string()])
+            .output()
+            .ok()?;
+        let rss_kb: u64 = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse()
+            .ok()?;
+        Some(rss_kb * 1024)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        None
+    }
 }
 
 impl NeuralTtsEngine {
@@ -154,10 +446,18 @@ impl NeuralTtsEngine {
             model_session: None,
             loaded_model: None,
             is_speaking: Arc::new(AtomicBool::new(false)),
+            cancel: Arc::new(AtomicBool::new(false)),
             piper_config: None,
+            warmed_up: false,
+            audio_cache: SentenceAudioCache::default(),
         })
     }
 
+    /// Use `model_manager` for downloads and loads from now on
+    pub fn set_model_manager(&mut self, model_manager: ModelManager) {
+        self.model_manager = model_manager;
+    }
+
     /// Check if neural TTS is available (model downloaded)
     pub async fn is_available(&self) -> bool {
         if let Some(model) = NeuralModel::from_id(&self.config.model_id) {
@@ -169,21 +469,32 @@ impl NeuralTtsEngine {
 
     /// Get current status
     pub async fn get_status(&self) -> super::NeuralTtsStatus {
+        self.status_with_espeak(espeak_available().await).await
+    }
+
+    /// Current status, given whether `espeak-ng` is available
+    async fn status_with_espeak(&self, espeak_available: bool) -> super::NeuralTtsStatus {
         let available = self.is_available().await;
         let is_speaking = self.is_speaking.load(Ordering::SeqCst);
 
+        let mut problems = Vec::new();
+        if !available {
+            problems.push(format!("Model '{}' not downloaded", self.config.model_id));
+        }
+        if !espeak_available {
+            problems.push(super::ESPEAK_MISSING_MESSAGE.to_string());
+        }
+
         super::NeuralTtsStatus {
             available,
             is_speaking,
+            loaded: self.model_session.is_some(),
+            espeak_available,
             current_voice: Some(self.config.voice_id.clone()),
             rate: self.config.rate,
             download_progress: None,
             voices: super::list_neural_voices(),
-            message: if available {
-                None
-            } else {
-                Some(format!("Model '{}' not downloaded", self.config.model_id))
-            },
+            message: (!problems.is_empty()).then(|| problems.join(". ")),
         }
     }
 
@@ -194,10 +505,7 @@ impl NeuralTtsEngine {
 
         // Check if model is downloaded
         if !self.model_manager.is_model_ready(model) {
-            return Err(SynthesisError::ModelNotLoaded(format!(
-                "Model {} not downloaded",
-                model_id
-            )));
+            return Err(SynthesisError::ModelNotDownloaded(model_id.to_string()));
         }
 
         // Skip if already loaded
@@ -249,11 +557,18 @@ impl NeuralTtsEngine {
 
         // Configure and create session
         let session = Session::builder()?
-            .with_optimization_level(ort::session::builder::GraphOptimizationLevel::Level3)?
-            .with_intra_threads(4)?
+            .with_optimization_level(self.config.optimization_level.into())?
+            .with_intra_threads(self.config.intra_threads)?
             .commit_from_memory(&model_bytes)?;
 
+        tracing::info!(
+            "Created ONNX session: {} intra threads, optimization {:?}",
+            self.config.intra_threads,
+            self.config.optimization_level
+        );
+
         self.model_session = Some(session);
+        self.warmed_up = false;
 
         self.loaded_model = Some(model_id.to_string());
         self.config.model_id = model_id.to_string();
@@ -261,22 +576,128 @@ impl NeuralTtsEngine {
         Ok(())
     }
 
+    /// Drop the loaded model to reclaim its memory
+    ///
+    /// The ONNX session is the bulk of it. Does nothing if no model is
+    /// loaded; `speak` and friends reload the model on next use.
+    pub fn unload(&mut self) {
+        if let Some(model_id) = self.loaded_model.take() {
+            tracing::info!("Unloading neural TTS model {}", model_id);
+        }
+        self.model_session = None;
+        self.piper_config = None;
+        self.warmed_up = false;
+    }
+
+    /// Run a tiny dummy inference so ONNX Runtime finishes its lazy
+    /// initialization now instead of on the first real sentence
+    ///
+    /// Loads the configured model if needed. Runs once per loaded session;
+    /// later calls return immediately.
+    pub async fn warmup(&mut self) -> Result<(), SynthesisError> {
+        self.warmup_model(&self.config.model_id.clone()).await
+    }
+
+    /// [`Self::warmup`] for `model_id`, loading it in place of the current
+    /// model and making it the one speech uses
+    pub async fn warmup_model(&mut self, model_id: &str) -> Result<(), SynthesisError> {
+        self.cancel.store(false, Ordering::SeqCst);
+        self.load_model(model_id).await?;
+        if self.warmed_up {
+            return Ok(());
+        }
+
+        let started = std::time::Instant::now();
+        self.generate_audio(WARMUP_TEXT).await?;
+        self.warmed_up = true;
+
+        tracing::info!("Neural TTS warmed up in {:?}", started.elapsed());
+        Ok(())
+    }
+
+    /// Measure synthesis speed on this machine
+    ///
+    /// Warms up first, then averages [`BENCHMARK_ITERATIONS`] syntheses of
+    /// `sample_text`. Nothing is played.
+    pub async fn benchmark(
+        &mut self,
+        sample_text: &str,
+    ) -> Result<BenchmarkResult, SynthesisError> {
+        self.warmup().await?;
+
+        let text = self.preprocess_text(sample_text)?;
+        let sample_rate = self
+            .piper_config
+            .as_ref()
+            .map(|c| c.audio.sample_rate)
+            .unwrap_or(22050);
+
+        let mut audio_secs = 0.0;
+        let mut wall_secs = 0.0;
+        for _ in 0..BENCHMARK_ITERATIONS {
+            let started = std::time::Instant::now();
+            let samples = self.generate_audio(&text).await?;
+            wall_secs += started.elapsed().as_secs_f64();
+            audio_secs += samples.len() as f64 / f64::from(sample_rate);
+        }
+
+        let iterations = f64::from(BENCHMARK_ITERATIONS);
+        let result = BenchmarkResult {
+            real_time_factor: real_time_factor(audio_secs, wall_secs),
+            audio_secs: audio_secs / iterations,
+            synthesis_secs: wall_secs / iterations,
+            iterations: BENCHMARK_ITERATIONS,
+            resident_memory_bytes: resident_memory_bytes(),
+        };
+
+        tracing::info!(
+            "Neural TTS benchmark: {:.2}x real time, {:.2}s per run",
+            result.real_time_factor,
+            result.synthesis_secs
+        );
+        Ok(result)
+    }
+
     /// Speak text using neural TTS
+    ///
+    /// `voice_id` overrides the voice for this call only.
     pub async fn speak(
         &mut self,
         text: &str,
         voice_id: Option<&str>,
     ) -> Result<(), SynthesisError> {
+        let previous_voice = self.override_voice(voice_id);
+        let result = self.speak_in_current_voice(text).await;
+        self.restore_voice(previous_voice);
+        result
+    }
+
+    /// Use `voice_id`, if given, until [`Self::restore_voice`] is called
+    /// with the returned previous voice
+    fn override_voice(&mut self, voice_id: Option<&str>) -> Option<String> {
+        voice_id.map(|vid| std::mem::replace(&mut self.config.voice_id, vid.to_string()))
+    }
+
+    /// Undo [`Self::override_voice`]
+    fn restore_voice(&mut self, previous: Option<String>) {
+        if let Some(previous) = previous {
+            self.config.voice_id = previous;
+        }
+    }
+
+    async fn speak_in_current_voice(&mut self, text: &str) -> Result<(), SynthesisError> {
+        // Checked on every call, so a device plugged in since the last
+        // failure is picked up
+        ensure_output_device()?;
+
+        // A stop from here on cancels this call, even during model loading
+        self.cancel.store(false, Ordering::SeqCst);
+
         // Ensure model is loaded
         if self.model_session.is_none() {
             self.load_model(&self.config.model_id.clone()).await?;
         }
 
-        // Update voice if specified
-        if let Some(vid) = voice_id {
-            self.config.voice_id = vid.to_string();
-        }
-
         // Preprocess and chunk text for long articles
         let processed_text = self.preprocess_text(text)?;
         let chunks = self.chunk_text(&processed_text);
@@ -285,42 +706,42 @@ impl NeuralTtsEngine {
         self.is_speaking.store(true, Ordering::SeqCst);
 
         // Generate audio for all chunks first
-        let mut all_audio: Vec<f32> = Vec::new();
         let sample_rate = self
             .piper_config
             .as_ref()
             .map(|c| c.audio.sample_rate)
             .unwrap_or(22050);
 
-        for chunk in chunks {
-            // Check if we should stop
-            if !self.is_speaking.load(Ordering::SeqCst) {
-                break;
+        let all_audio = match self.synthesize_chunks(&chunks).await {
+            Ok(audio) => audio,
+            // Stopped before anything played
+            Err(SynthesisError::Cancelled) => {
+                self.is_speaking.store(false, Ordering::SeqCst);
+                return Ok(());
             }
-
-            match self.generate_audio(&chunk).await {
-                Ok(audio_data) => {
-                    all_audio.extend(audio_data);
-                }
-                Err(e) => {
-                    self.is_speaking.store(false, Ordering::SeqCst);
-                    return Err(e);
-                }
+            Err(e) => {
+                self.is_speaking.store(false, Ordering::SeqCst);
+                return Err(e);
             }
-        }
+        };
 
         // Play all audio in a blocking thread (rodio requires non-async context)
         if !all_audio.is_empty() {
             let is_speaking = self.is_speaking.clone();
+            let output_latency_ms = self.config.output_latency_ms;
 
             // Spawn blocking task for audio playback
             let play_result = tokio::task::spawn_blocking(move || {
-                play_audio_blocking(all_audio, sample_rate, is_speaking, None)
+                play_audio_blocking(all_audio, sample_rate, is_speaking, output_latency_ms, None)
             })
             .await;
 
             match play_result {
                 Ok(Ok(())) => {}
+                Ok(Err(e)) if e.is_no_output_device() => {
+                    self.is_speaking.store(false, Ordering::SeqCst);
+                    return Err(e);
+                }
                 Ok(Err(e)) => {
                     tracing::warn!("Audio playback error: {}", e);
                 }
@@ -334,10 +755,94 @@ impl NeuralTtsEngine {
         Ok(())
     }
 
-    /// Stop current playback
-    pub async fn stop(&mut self) -> Result<(), SynthesisError> {
-        self.is_speaking.store(false, Ordering::SeqCst);
-        Ok(())
+    /// Synthesize every sentence of `text` into the audio cache without
+    /// playing anything
+    ///
+    /// Returns the sentences and their total duration, so the UI can show
+    /// progress before playback starts. Passing the sentences to
+    /// [`Self::speak_sentences`] with the same voice and rate then plays
+    /// them from the cache. Fails on the first sentence that can't be
+    /// synthesized, with [`SynthesisError::Cancelled`] if stopped, or with
+    /// [`SynthesisError::InvalidInput`] if the narration is too long to
+    /// stay cached until it plays.
+    pub async fn prepare(
+        &mut self,
+        text: &str,
+        voice_id: Option<&str>,
+    ) -> Result<PreparedNarration, SynthesisError> {
+        self.cancel.store(false, Ordering::SeqCst);
+        if self.model_session.is_none() {
+            self.load_model(&self.config.model_id.clone()).await?;
+        }
+
+        if let Some(vid) = voice_id {
+            self.config.voice_id = vid.to_string();
+        }
+
+        let sentences = split_sentences(text);
+        // speak_sentences skips sentences that preprocess to nothing too
+        let processed: Vec<String> = sentences
+            .iter()
+            .filter_map(|sentence| self.preprocess_text(sentence).ok())
+            .filter(|processed| !processed.is_empty())
+            .collect();
+        let samples = prepare_sentences(self, &processed, MAX_CACHED_SAMPLES).await?;
+
+        let sample_rate = self
+            .piper_config
+            .as_ref()
+            .map(|c| c.audio.sample_rate)
+            .unwrap_or(22050);
+
+        tracing::info!(
+            "Prepared {} sentences ({} samples) for narration",
+            sentences.len(),
+            samples
+        );
+        Ok(PreparedNarration {
+            sentence_count: sentences.len(),
+            duration_secs: samples as f64 / sample_rate as f64,
+            sentences,
+        })
+    }
+
+    /// Cache key for preprocessed `text` with the current model, voice,
+    /// and rate
+    fn cache_key(&self, text: &str) -> CacheKey {
+        CacheKey::new(
+            &self.config.model_id,
+            &self.config.voice_id,
+            self.config.rate,
+            text,
+        )
+    }
+
+    /// Synthesize `chunks` into one buffer
+    ///
+    /// Checks for a stop before every chunk, the first included, and fails
+    /// with [`SynthesisError::Cancelled`] once one is requested.
+    async fn synthesize_chunks(&mut self, chunks: &[String]) -> Result<Vec<f32>, SynthesisError> {
+        let mut all_audio = Vec::new();
+        for chunk in chunks {
+            if self.cancelled() {
+                return Err(SynthesisError::Cancelled);
+            }
+            all_audio.extend(self.generate_audio(chunk).await?);
+        }
+        Ok(all_audio)
+    }
+
+    /// Whether a stop has been requested since speech started
+    fn cancelled(&self) -> bool {
+        self.cancel.load(Ordering::SeqCst)
+    }
+
+    /// Handle for stopping this engine's speech from elsewhere
+    pub fn stop_handle(&self) -> StopHandle {
+        StopHandle {
+            is_speaking: self.is_speaking.clone(),
+            cancel: self.cancel.clone(),
+        }
     }
 
     /// Speak sentences one-by-one, emitting events for each sentence
@@ -345,7 +850,9 @@ impl NeuralTtsEngine {
     /// This method processes each sentence individually, generating audio
     /// and playing it before moving to the next. It sends events through
     /// the provided channel to allow the frontend to highlight the current
-    /// sentence being spoken.
+    /// sentence being spoken. A sentence that fails to synthesize or play
+    /// sends [`SentenceEvent::Error`] and stops playback, unless
+    /// [`NeuralTtsConfig::continue_on_error`] is set.
     ///
     /// # Arguments
     ///
@@ -358,6 +865,9 @@ impl NeuralTtsEngine {
         voice_id: Option<&str>,
         event_tx: mpsc::Sender<SentenceEvent>,
     ) -> Result<(), SynthesisError> {
+        ensure_output_device()?;
+        self.cancel.store(false, Ordering::SeqCst);
+
         // Ensure model is loaded
         if self.model_session.is_none() {
             self.load_model(&self.config.model_id.clone()).await?;
@@ -370,105 +880,79 @@ impl NeuralTtsEngine {
 
         // Mark as speaking
         self.is_speaking.store(true, Ordering::SeqCst);
+        PLAYBACK.skip_by.store(0, Ordering::SeqCst);
+        PLAYBACK.sentences.store(true, Ordering::SeqCst);
 
-        let sample_rate = self
-            .piper_config
-            .as_ref()
-            .map(|c| c.audio.sample_rate)
-            .unwrap_or(22050);
-
-        // Process each sentence one by one
-        for (index, sentence) in sentences.iter().enumerate() {
-            // Check if we should stop
-            if !self.is_speaking.load(Ordering::SeqCst) {
-                let _ = event_tx.send(SentenceEvent::Stopped).await;
-                break;
-            }
-
-            // Preprocess the sentence
-            let processed = match self.preprocess_text(sentence) {
-                Ok(p) if !p.is_empty() => p,
-                _ => continue, // Skip empty sentences
-            };
+        let result = speak_each(self, sentences, &event_tx).await;
 
-            // Generate audio for this sentence BEFORE emitting start event
-            // This ensures highlighting syncs with actual audio playback
-            match self.generate_audio(&processed).await {
-                Ok(audio_data) => {
-                    if !audio_data.is_empty() {
-                        let is_speaking = self.is_speaking.clone();
-
-                        // Create a oneshot channel to signal when audio starts
-                        let (start_tx, start_rx) = tokio::sync::oneshot::channel::<()>();
-
-                        // Clone data needed for the callback
-                        let event_tx_clone = event_tx.clone();
-                        let sentence_clone = sentence.clone();
-
-                        // Callback to emit start event when audio actually begins
-                        let on_start = Box::new(move || {
-                            // Use blocking send since we're in a sync context
-                            let rt = tokio::runtime::Handle::current();
-                            rt.block_on(async {
-                                let _ = event_tx_clone
-                                    .send(SentenceEvent::Start {
-                                        index,
-                                        text: sentence_clone,
-                                    })
-                                    .await;
-                            });
-                            let _ = start_tx.send(());
-                        });
+        PLAYBACK.sentences.store(false, Ordering::SeqCst);
+        self.is_speaking.store(false, Ordering::SeqCst);
 
-                        // Play audio and wait for completion
-                        let play_result = tokio::task::spawn_blocking(move || {
-                            play_audio_blocking(
-                                audio_data,
-                                sample_rate,
-                                is_speaking,
-                                Some(on_start),
-                            )
-                        })
-                        .await;
-
-                        // Wait for start signal (ensures event was sent)
-                        let _ = start_rx.await;
-
-                        match play_result {
-                            Ok(Ok(())) => {}
-                            Ok(Err(e)) => {
-                                tracing::warn!("Audio playback error: {}", e);
-                            }
-                            Err(e) => {
-                                tracing::warn!("Audio task join error: {}", e);
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to generate audio for sentence {}: {}", index, e);
-                    // Continue with next sentence instead of stopping
-                }
+        match result {
+            Ok(()) => {
+                let _ = event_tx.send(SentenceEvent::Finished).await;
+                Ok(())
+            }
+            // The device went away mid-playback
+            Err(e) => {
+                let _ = event_tx.send(SentenceEvent::Stopped).await;
+                Err(e)
             }
-
-            // Emit sentence end event
-            let _ = event_tx.send(SentenceEvent::End { index }).await;
         }
+    }
 
-        self.is_speaking.store(false, Ordering::SeqCst);
+    /// Change the ONNX session options
+    ///
+    /// `None` leaves a setting unchanged. A loaded model is reloaded right
+    /// away with the new options, so errors surface here rather than on the
+    /// next `speak`.
+    pub async fn set_session_options(
+        &mut self,
+        intra_threads: Option<usize>,
+        optimization_level: Option<OptimizationLevel>,
+    ) -> Result<(), SynthesisError> {
+        if intra_threads == Some(0) {
+            return Err(SynthesisError::ConfigError(
+                "intra_threads must be at least 1".to_string(),
+            ));
+        }
 
-        // Emit finished event
-        let _ = event_tx.send(SentenceEvent::Finished).await;
+        if let Some(threads) = intra_threads {
+            self.config.intra_threads = threads;
+        }
+        if let Some(level) = optimization_level {
+            self.config.optimization_level = level;
+        }
 
+        if let Some(model_id) = self.loaded_model.clone() {
+            self.unload();
+            self.load_model(&model_id).await?;
+        }
         Ok(())
     }
 
     /// Set speech rate
-    #[allow(dead_code)]
     pub fn set_rate(&mut self, rate: f32) {
         self.config.rate = rate.clamp(0.5, 2.0);
     }
 
+    /// Set the output latency used to time sentence start events
+    ///
+    /// `None` derives it from the output stream.
+    pub fn set_output_latency(&mut self, latency_ms: Option<u64>) {
+        self.config.output_latency_ms = latency_ms;
+    }
+
+    /// Set whether sentence playback continues past failed sentences
+    pub fn set_continue_on_error(&mut self, continue_on_error: bool) {
+        self.config.continue_on_error = continue_on_error;
+    }
+
+    /// Set the voice used when speech doesn't name one
+    pub fn set_voice(&mut self, voice_id: &str) {
+        self.config.voice_id = voice_id.to_string();
+    }
+
     /// Preprocess text for synthesis
     fn preprocess_text(&self, text: &str) -> Result<String, SynthesisError> {
         // Clean up text
@@ -481,8 +965,24 @@ impl NeuralTtsEngine {
         Ok(text.trim().to_string())
     }
 
-    /// Convert text to IPA phonemes using espeak-ng
+    /// Convert text to IPA phonemes, applying the pronunciation dictionary
+    ///
+    /// Respellings are phonemized by espeak-ng with the rest of the text;
+    /// IPA entries are used as is.
     fn text_to_phonemes(&self, text: &str) -> Result<String, SynthesisError> {
+        let mut phonemes = Vec::new();
+        for segment in PronunciationDictionary::active().apply(text) {
+            match segment {
+                Segment::Text(text) if text.trim().is_empty() => {}
+                Segment::Text(text) => phonemes.push(self.espeak_phonemes(&text)?),
+                Segment::Ipa(ipa) => phonemes.push(ipa),
+            }
+        }
+        Ok(phonemes.join(" "))
+    }
+
+    /// Convert text to IPA phonemes using espeak-ng
+    fn espeak_phonemes(&self, text: &str) -> Result<String, SynthesisError> {
         let voice = self
             .piper_config
             .as_ref()
@@ -490,9 +990,11 @@ impl NeuralTtsEngine {
             .unwrap_or("en-us");
 
         // Call espeak-ng to get IPA phonemes
-        let output = Command::new("espeak-ng")
+        let mut child = Command::new("espeak-ng")
             .args(["--ipa", "-q", "-v", voice, text])
-            .output()
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
             .map_err(|e| {
                 SynthesisError::PhonemeError(format!(
                     "Failed to run espeak-ng (is it installed?): {}",
@@ -500,6 +1002,21 @@ impl NeuralTtsEngine {
                 ))
             })?;
 
+        // Kill it if speech is stopped meanwhile. A chunk's phonemes are far
+        // smaller than a pipe buffer, so it never blocks on full output.
+        let wait_error = |e: std::io::Error| {
+            SynthesisError::PhonemeError(format!("Failed to wait for espeak-ng: {}", e))
+        };
+        while child.try_wait().map_err(wait_error)?.is_none() {
+            if self.cancelled() {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(SynthesisError::Cancelled);
+            }
+            std::thread::sleep(ESPEAK_POLL_INTERVAL);
+        }
+        let output = child.wait_with_output().map_err(wait_error)?;
+
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             return Err(SynthesisError::PhonemeError(format!(
@@ -717,67 +1234,497 @@ impl NeuralTtsEngine {
     }
 }
 
-/// Load speaker embeddings from file
-fn _load_speaker_embedding(_path: &std::path::Path) -> Result<Vec<f32>, SynthesisError> {
-    // In production, would load from a .npy or .bin file
-    // For now, return a default embedding
-    Ok(vec![0.0; 512])
+/// Cached sentence synthesis, as [`prepare_sentences`] and
+/// [`NeuralTtsEngine::speak_sentences`] use it.
+///
+/// Separated from the engine so preparation can be tested without a model.
+trait SentenceSynthesizer {
+    /// Synthesize preprocessed `text`
+    async fn generate_audio(&mut self, text: &str) -> Result<Vec<f32>, SynthesisError>;
+    /// Cache key for preprocessed `text`
+    fn cache_key(&self, text: &str) -> CacheKey;
+    fn audio_cache(&mut self) -> &mut SentenceAudioCache;
+    /// Whether a stop has been requested
+    fn cancelled(&self) -> bool;
+
+    /// Audio for preprocessed `text`, from the cache or synthesized and
+    /// cached
+    async fn sentence_audio(&mut self, text: &str) -> Result<Vec<f32>, SynthesisError> {
+        let key = self.cache_key(text);
+        if let Some(samples) = self.audio_cache().get(&key) {
+            return Ok(samples.to_vec());
+        }
+
+        let samples = self.generate_audio(text).await?;
+        self.audio_cache().insert(key, samples.clone());
+        Ok(samples)
+    }
 }
 
-/// Play audio in a blocking context using rodio
-/// This function is designed to be called from spawn_blocking
-///
-/// # Arguments
-/// * `audio_samples` - The audio samples to play
-/// * `sample_rate` - Sample rate in Hz
-/// * `is_speaking` - Atomic flag to check for stop signal
-/// * `on_start` - Optional callback to invoke when audio actually starts playing
+impl SentenceSynthesizer for NeuralTtsEngine {
+    async fn generate_audio(&mut self, text: &str) -> Result<Vec<f32>, SynthesisError> {
+        NeuralTtsEngine::generate_audio(self, text).await
+    }
+
+    fn cache_key(&self, text: &str) -> CacheKey {
+        NeuralTtsEngine::cache_key(self, text)
+    }
+
+    fn audio_cache(&mut self) -> &mut SentenceAudioCache {
+        &mut self.audio_cache
+    }
+
+    fn cancelled(&self) -> bool {
+        NeuralTtsEngine::cancelled(self)
+    }
+}
+
+/// Synthesize preprocessed `sentences` ahead of playback, returning the
+/// total number of samples.
+///
+/// A stop is checked before every sentence. Narration longer than
+/// `max_samples`, the audio cache's size, would evict its own first
+/// sentences before they play, so it fails once it passes that.
+async fn prepare_sentences(
+    synth: &mut impl SentenceSynthesizer,
+    sentences: &[String],
+    max_samples: usize,
+) -> Result<usize, SynthesisError> {
+    let mut samples = 0;
+    for sentence in sentences {
+        if synth.cancelled() {
+            return Err(SynthesisError::Cancelled);
+        }
+        samples += synth.sentence_audio(sentence).await?.len();
+        if samples > max_samples {
+            return Err(SynthesisError::InvalidInput(
+                "narration is too long to prepare ahead of playback".to_string(),
+            ));
+        }
+    }
+    Ok(samples)
+}
+
+/// Sentence playback as [`speak_each`] uses it.
+///
+/// Separated from the engine so the event sequence can be tested without
+/// a model or audio device.
+trait SentencePlayer: SentenceSynthesizer {
+    /// Clean up `text` for synthesis
+    fn preprocess_text(&self, text: &str) -> Result<String, SynthesisError>;
+    /// Play `audio`, calling `on_start` once it's audible, and wait for it
+    /// to finish or be stopped
+    async fn play(
+        &mut self,
+        audio: Vec<f32>,
+        on_start: Box<dyn FnOnce() + Send>,
+    ) -> Result<(), SynthesisError>;
+    /// Whether playback is still wanted
+    fn speaking(&self) -> bool;
+    /// Whether to go on to the next sentence after one fails
+    fn continue_on_error(&self) -> bool;
+}
+
+impl SentencePlayer for NeuralTtsEngine {
+    fn preprocess_text(&self, text: &str) -> Result<String, SynthesisError> {
+        NeuralTtsEngine::preprocess_text(self, text)
+    }
+
+    async fn play(
+        &mut self,
+        audio: Vec<f32>,
+        on_start: Box<dyn FnOnce() + Send>,
+    ) -> Result<(), SynthesisError> {
+        let sample_rate = self
+            .piper_config
+            .as_ref()
+            .map(|c| c.audio.sample_rate)
+            .unwrap_or(22050);
+        let is_speaking = self.is_speaking.clone();
+        let output_latency_ms = self.config.output_latency_ms;
+
+        tokio::task::spawn_blocking(move || {
+            play_audio_blocking(
+                audio,
+                sample_rate,
+                is_speaking,
+                output_latency_ms,
+                Some(on_start),
+            )
+        })
+        .await
+        .map_err(|e| SynthesisError::AudioError(format!("audio task failed: {}", e)))?
+    }
+
+    fn speaking(&self) -> bool {
+        self.is_speaking.load(Ordering::SeqCst)
+    }
+
+    fn continue_on_error(&self) -> bool {
+        self.config.continue_on_error
+    }
+}
+
+/// Synthesize and play `sentences` in order for
+/// [`NeuralTtsEngine::speak_sentences`], sending each one's events.
+///
+/// Skips move the index. Each sentence gets an `End`, and a `Start` once
+/// its audio begins. A stop sends `Stopped` and returns. Fails only when
+/// the output device goes away; other failures go through
+/// [`sentence_failed`].
+async fn speak_each(
+    player: &mut impl SentencePlayer,
+    sentences: &[String],
+    event_tx: &mpsc::Sender<SentenceEvent>,
+) -> Result<(), SynthesisError> {
+    let mut index = 0;
+    while index < sentences.len() {
+        let sentence = &sentences[index];
+
+        // Check if we should stop
+        if !player.speaking() || player.cancelled() {
+            let _ = event_tx.send(SentenceEvent::Stopped).await;
+            return Ok(());
+        }
+
+        // Preprocess the sentence
+        let processed = match player.preprocess_text(sentence) {
+            Ok(p) if !p.is_empty() => p,
+            _ => {
+                // Skip empty sentences
+                index = next_sentence(index, PLAYBACK.take_skip(), sentences.len());
+                continue;
+            }
+        };
+
+        // Generate (or fetch cached) audio for this sentence BEFORE
+        // emitting start event
+        // This ensures highlighting syncs with actual audio playback
+        match player.sentence_audio(&processed).await {
+            Ok(audio_data) => {
+                // Skipped while synthesizing: don't start playing it
+                if !audio_data.is_empty() && !PLAYBACK.skip_pending() {
+                    // Create a oneshot channel to signal when audio starts
+                    let (start_tx, start_rx) = tokio::sync::oneshot::channel::<()>();
+
+                    // Clone data needed for the callback
+                    let event_tx_clone = event_tx.clone();
+                    let sentence_clone = sentence.clone();
+
+                    // Callback to emit start event when audio actually begins
+                    let on_start = Box::new(move || {
+                        // Use blocking send since we're in a sync context
+                        let rt = tokio::runtime::Handle::current();
+                        rt.block_on(async {
+                            let _ = event_tx_clone
+                                .send(SentenceEvent::Start {
+                                    index,
+                                    text: sentence_clone,
+                                })
+                                .await;
+                        });
+                        let _ = start_tx.send(());
+                    });
+
+                    // Play audio and wait for completion
+                    let play_result = player.play(audio_data, on_start).await;
+
+                    // Wait for start signal (ensures event was sent)
+                    let _ = start_rx.await;
+
+                    match play_result {
+                        Ok(()) => {}
+                        Err(e) if e.is_no_output_device() => return Err(e),
+                        Err(e) => {
+                            if !sentence_failed(player, event_tx, index, FailureStage::Playback, e)
+                                .await
+                            {
+                                return Ok(());
+                            }
+                        }
+                    }
+                }
+            }
+            Err(SynthesisError::Cancelled) => {
+                let _ = event_tx.send(SentenceEvent::Stopped).await;
+                return Ok(());
+            }
+            Err(e) => {
+                if !sentence_failed(player, event_tx, index, FailureStage::Synthesis, e).await {
+                    return Ok(());
+                }
+            }
+        }
+
+        // Emit sentence end event
+        let _ = event_tx.send(SentenceEvent::End { index }).await;
+
+        index = next_sentence(index, PLAYBACK.take_skip(), sentences.len());
+    }
+    Ok(())
+}
+
+/// Report that sentence `index` failed at `stage`
+///
+/// Emits [`SentenceEvent::Error`], then `Stopped` unless the player
+/// continues on errors. Returns whether to go on to the next sentence.
+async fn sentence_failed(
+    player: &impl SentencePlayer,
+    event_tx: &mpsc::Sender<SentenceEvent>,
+    index: usize,
+    stage: FailureStage,
+    error: impl std::fmt::Display,
+) -> bool {
+    tracing::warn!("Failed to {} sentence {}: {}", stage, index, error);
+    let _ = event_tx
+        .send(SentenceEvent::Error {
+            index,
+            stage,
+            reason: error.to_string(),
+        })
+        .await;
+
+    if !player.continue_on_error() {
+        let _ = event_tx.send(SentenceEvent::Stopped).await;
+    }
+    player.continue_on_error()
+}
+
+/// Load speaker embeddings from file
+fn _load_speaker_embedding(_path: &std::path::Path) -> Result<Vec<f32>, SynthesisError> {
+    // In production, would load from a .npy or .bin file
+    // For now, return a default embedding
+    Ok(vec![0.0; 512])
+}
+
+/// Controls for the audio currently played by [`play_audio_blocking`]
+///
+/// Kept outside the engine, which a speak call holds locked for the whole
+/// narration, so playback can be controlled while it runs.
+struct PlaybackControl {
+    /// Whether audio is playing
+    playing: AtomicBool,
+    /// Pending seek, in ms from the start of the playing audio
+    seek_to: std::sync::Mutex<Option<u64>>,
+    /// Whether sentences are being spoken, so skips apply
+    sentences: AtomicBool,
+    /// Pending skip, in sentences (negative rewinds)
+    skip_by: AtomicI64,
+}
+
+static PLAYBACK: PlaybackControl = PlaybackControl {
+    playing: AtomicBool::new(false),
+    seek_to: std::sync::Mutex::new(None),
+    sentences: AtomicBool::new(false),
+    skip_by: AtomicI64::new(0),
+};
+
+impl PlaybackControl {
+    fn seek_to(&self) -> std::sync::MutexGuard<'_, Option<u64>> {
+        self.seek_to.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn skip_pending(&self) -> bool {
+        self.sentences.load(Ordering::SeqCst) && self.skip_by.load(Ordering::SeqCst) != 0
+    }
+
+    fn take_skip(&self) -> i64 {
+        self.skip_by.swap(0, Ordering::SeqCst)
+    }
+}
+
+/// Skip `by` sentences while speaking sentences (negative rewinds)
+///
+/// The current sentence stops and ends; skips requested before the loop
+/// moves on add up.
+pub fn skip(by: i64) -> Result<(), String> {
+    if !PLAYBACK.sentences.load(Ordering::SeqCst) {
+        return Err("Not speaking sentences".to_string());
+    }
+    PLAYBACK.skip_by.fetch_add(by, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Index of the sentence after `current`, given a pending `skip`
+///
+/// Without a skip the next sentence follows. Skipping clamps to the
+/// sentences: back from the first one repeats it, and forward from the
+/// last one returns `len`, ending the loop.
+fn next_sentence(current: usize, skip: i64, len: usize) -> usize {
+    let step = if skip == 0 { 1 } else { skip };
+    let target = (current as i64).saturating_add(step);
+    target.clamp(0, len as i64) as usize
+}
+
+/// Jump the playing narration to `position_ms` from its start
+///
+/// Positions past the end finish it. Fails while speaking sentences: only
+/// the current sentence's audio exists, so an offset into the whole
+/// narration can't be mapped; [`skip`] moves between sentences instead.
+pub fn seek(position_ms: u64) -> Result<(), String> {
+    check_seekable(
+        PLAYBACK.playing.load(Ordering::SeqCst),
+        PLAYBACK.sentences.load(Ordering::SeqCst),
+    )?;
+    *PLAYBACK.seek_to() = Some(position_ms);
+    Ok(())
+}
+
+/// Whether a seek can apply, given what is playing
+fn check_seekable(playing: bool, sentences: bool) -> Result<(), String> {
+    if sentences {
+        return Err("Can't seek while speaking sentences; skip sentences instead".to_string());
+    }
+    if !playing {
+        return Err("Nothing is playing".to_string());
+    }
+    Ok(())
+}
+
+/// Whether the system has a default audio output device
+fn has_output_device() -> bool {
+    use rodio::cpal::traits::HostTrait;
+    rodio::cpal::default_host()
+        .default_output_device()
+        .is_some()
+}
+
+/// Fail with [`NO_OUTPUT_DEVICE`] if there is no audio output device
+fn ensure_output_device() -> Result<(), SynthesisError> {
+    if has_output_device() {
+        Ok(())
+    } else {
+        Err(SynthesisError::AudioError(NO_OUTPUT_DEVICE.to_string()))
+    }
+}
+
+/// Error for a failure to open the output stream
+///
+/// With no output device at all (headless machines, a device unplugged
+/// mid-playback) the backend's own error is unhelpful, so it becomes
+/// [`NO_OUTPUT_DEVICE`].
+fn stream_error(device_present: bool, error: impl std::fmt::Display) -> SynthesisError {
+    if device_present {
+        SynthesisError::AudioError(format!("Failed to create audio stream: {}", error))
+    } else {
+        SynthesisError::AudioError(NO_OUTPUT_DEVICE.to_string())
+    }
+}
+
+/// Output latency assumed when it is neither configured nor reported
+const DEFAULT_OUTPUT_LATENCY: Duration = Duration::from_millis(50);
+
+/// Delay between queueing audio and it becoming audible
+///
+/// A configured latency wins. Otherwise a fixed output buffer of
+/// `buffer_frames` at `sample_rate` takes that long to drain; devices that
+/// don't report a buffer size get [`DEFAULT_OUTPUT_LATENCY`].
+fn output_latency(
+    configured_ms: Option<u64>,
+    buffer_frames: Option<u32>,
+    sample_rate: u32,
+) -> Duration {
+    if let Some(ms) = configured_ms {
+        return Duration::from_millis(ms);
+    }
+    match buffer_frames {
+        Some(frames) if sample_rate > 0 => {
+            Duration::from_secs_f64(f64::from(frames) / f64::from(sample_rate))
+        }
+        _ => DEFAULT_OUTPUT_LATENCY,
+    }
+}
+
+/// Invoke `on_start` once `latency` has passed since `queued_at`
+fn start_after(queued_at: Instant, latency: Duration, on_start: Option<Box<dyn FnOnce() + Send>>) {
+    if let Some(remaining) = latency.checked_sub(queued_at.elapsed()) {
+        std::thread::sleep(remaining);
+    }
+    if let Some(callback) = on_start {
+        callback();
+    }
+}
+
+/// Play audio in a blocking context using rodio
+/// This function is designed to be called from spawn_blocking
+///
+/// # Arguments
+/// * `audio_samples` - The audio samples to play
+/// * `sample_rate` - Sample rate in Hz
+/// * `is_speaking` - Atomic flag to check for stop signal
+/// * `latency_ms` - Configured output latency (see [`output_latency`])
+/// * `on_start` - Optional callback to invoke when audio actually starts playing
 fn play_audio_blocking(
     audio_samples: Vec<f32>,
     sample_rate: u32,
     is_speaking: Arc<AtomicBool>,
+    latency_ms: Option<u64>,
     on_start: Option<Box<dyn FnOnce() + Send>>,
-) -> Result<(), String> {
+) -> Result<(), SynthesisError> {
     use rodio::{Decoder, OutputStreamBuilder, Sink};
     use std::io::Cursor;
 
-    // Create AudioData and convert to WAV
+    // Keep the samples around so a seek can replay from any offset
     let audio = AudioData::new(audio_samples, sample_rate, 1);
-    let wav_bytes = audio.to_wav_bytes().map_err(|e| e.to_string())?;
+    let decode = |samples: &[f32]| {
+        let wav_bytes = AudioData::new(samples.to_vec(), sample_rate, 1)
+            .to_wav_bytes()
+            .map_err(|e| SynthesisError::AudioError(e.to_string()))?;
+        Decoder::new(Cursor::new(wav_bytes))
+            .map_err(|e| SynthesisError::AudioError(format!("Failed to decode audio: {}", e)))
+    };
 
     // Create output stream (must stay alive during playback)
     let mut stream = OutputStreamBuilder::open_default_stream()
-        .map_err(|e| format!("Failed to create audio stream: {}", e))?;
+        .map_err(|e| stream_error(has_output_device(), e))?;
     stream.log_on_drop(false); // Don't print message when stream is dropped
 
-    // Create sink for playback using the mixer
-    let sink = Sink::connect_new(stream.mixer());
+    let config = stream.config();
+    let buffer_frames = match config.buffer_size() {
+        rodio::cpal::BufferSize::Fixed(frames) => Some(*frames),
+        rodio::cpal::BufferSize::Default => None,
+    };
+    let latency = output_latency(latency_ms, buffer_frames, config.sample_rate());
 
-    // Decode WAV and append to sink
-    let cursor = Cursor::new(wav_bytes);
-    let source = Decoder::new(cursor).map_err(|e| format!("Failed to decode audio: {}", e))?;
+    // Create sink for playback using the mixer
+    let mut sink = Sink::connect_new(stream.mixer());
+    sink.append(decode(&audio.samples)?);
+    let queued_at = Instant::now();
 
-    sink.append(source);
+    *PLAYBACK.seek_to() = None;
+    PLAYBACK.playing.store(true, Ordering::SeqCst);
 
     // Wait for audio to actually start playing
     // The sink.append() just queues the audio - there's buffer latency
-    // before sound actually comes out of the speakers.
-    // A small delay ensures the Start event fires when audio is audible.
-    std::thread::sleep(std::time::Duration::from_millis(50));
-
-    // Signal that audio playback is starting NOW
-    if let Some(callback) = on_start {
-        callback();
-    }
+    // before sound actually comes out of the speakers, so the Start event
+    // waits for it to be audible.
+    start_after(queued_at, latency, on_start);
 
-    // Wait for playback to complete, checking for stop signal
+    // Wait for playback to complete, checking for stop and seek signals
     while !sink.empty() {
-        if !is_speaking.load(Ordering::SeqCst) {
+        if !is_speaking.load(Ordering::SeqCst) || PLAYBACK.skip_pending() {
             sink.stop();
             break;
         }
-        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        let seek_to = PLAYBACK.seek_to().take();
+        if let Some(position_ms) = seek_to {
+            // Rebuild the sink from the remaining samples
+            let offset = audio.sample_offset(position_ms);
+            sink.stop();
+            sink = Sink::connect_new(stream.mixer());
+            if offset < audio.samples.len() {
+                match decode(&audio.samples[offset..]) {
+                    Ok(source) => sink.append(source),
+                    Err(e) => tracing::warn!("Failed to seek to {}ms: {}", position_ms, e),
+                }
+            }
+            continue;
+        }
+
+        std::thread::sleep(Duration::from_millis(50));
     }
+    PLAYBACK.playing.store(false, Ordering::SeqCst);
 
     // Wait for audio to fully finish playing
     // sleep_until_end waits for the sink to be empty, but there may still be
@@ -787,7 +1734,7 @@ fn play_audio_blocking(
     // Additional delay to account for audio hardware buffer latency
     // This ensures the previous sentence's audio has fully played before
     // we signal that the next sentence can start highlighting
-    std::thread::sleep(std::time::Duration::from_millis(100));
+    std::thread::sleep(Duration::from_millis(100));
 
     Ok(())
 }
@@ -796,6 +1743,97 @@ fn play_audio_blocking(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_next_sentence_advances_without_skip() {
+        assert_eq!(next_sentence(0, 0, 3), 1);
+        assert_eq!(next_sentence(2, 0, 3), 3);
+    }
+
+    #[test]
+    fn test_next_sentence_skips_forward_and_back() {
+        assert_eq!(next_sentence(1, 1, 5), 2);
+        assert_eq!(next_sentence(1, 2, 5), 3);
+        assert_eq!(next_sentence(3, -1, 5), 2);
+    }
+
+    #[test]
+    fn test_seek_only_applies_to_a_single_narration() {
+        assert!(check_seekable(true, false).is_ok());
+        assert_eq!(
+            check_seekable(false, false).unwrap_err(),
+            "Nothing is playing"
+        );
+        // Sentence audio is generated one at a time, so there's no
+        // narration-wide offset to seek to
+        assert!(check_seekable(true, true).is_err());
+        assert!(check_seekable(false, true).is_err());
+    }
+
+    #[test]
+    fn test_next_sentence_at_boundaries() {
+        // Back from the first sentence repeats it
+        assert_eq!(next_sentence(0, -1, 3), 0);
+        assert_eq!(next_sentence(1, -5, 3), 0);
+        // Forward from the last sentence ends the loop
+        assert_eq!(next_sentence(2, 1, 3), 3);
+        assert_eq!(next_sentence(2, i64::MAX, 3), 3);
+    }
+
+    #[test]
+    fn test_missing_output_device_is_a_distinct_error() {
+        let error = stream_error(false, "ALSA: no such device");
+        assert!(error.is_no_output_device());
+        assert_eq!(
+            error.to_string(),
+            "Audio generation error: no output device"
+        );
+
+        let error = stream_error(true, "device busy");
+        assert!(!error.is_no_output_device());
+        assert!(error.to_string().contains("device busy"));
+    }
+
+    #[test]
+    fn test_output_latency_prefers_configured_value() {
+        assert_eq!(
+            output_latency(Some(250), Some(1024), 48_000),
+            Duration::from_millis(250)
+        );
+        assert_eq!(output_latency(Some(0), None, 48_000), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_output_latency_from_buffer_size() {
+        assert_eq!(
+            output_latency(None, Some(4800), 48_000),
+            Duration::from_millis(100)
+        );
+        assert_eq!(output_latency(None, None, 48_000), DEFAULT_OUTPUT_LATENCY);
+        assert_eq!(output_latency(None, Some(512), 0), DEFAULT_OUTPUT_LATENCY);
+    }
+
+    #[test]
+    fn test_start_callback_waits_for_latency() {
+        let fired_after = Arc::new(std::sync::Mutex::new(None));
+        let queued_at = Instant::now();
+
+        let slot = fired_after.clone();
+        start_after(
+            queued_at,
+            Duration::from_millis(120),
+            Some(Box::new(move || {
+                *slot.lock().unwrap() = Some(queued_at.elapsed());
+            })),
+        );
+
+        let elapsed = fired_after.lock().unwrap().expect("callback fired");
+        assert!(
+            elapsed >= Duration::from_millis(120),
+            "fired after {:?}",
+            elapsed
+        );
+    }
+
     #[test]
     fn test_neural_tts_config_default() {
         let config = NeuralTtsConfig::default();
@@ -803,6 +1841,22 @@ mod tests {
         assert_eq!(config.voice_id, "default");
         assert_eq!(config.model_id, "piper-en-us");
         assert!(config.use_gpu);
+        assert_eq!(config.output_latency_ms, None);
+    }
+
+    #[tokio::test]
+    async fn test_status_reports_espeak_availability() {
+        let engine = NeuralTtsEngine::new().unwrap();
+
+        let status = engine.status_with_espeak(false).await;
+        assert!(!status.espeak_available);
+        assert!(status
+            .message
+            .is_some_and(|m| m.contains(crate::tts::neural::ESPEAK_MISSING_MESSAGE)));
+
+        let status = engine.status_with_espeak(true).await;
+        assert!(status.espeak_available);
+        assert!(!status.message.unwrap_or_default().contains("espeak-ng"));
     }
 
     #[test]
@@ -845,6 +1899,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_split_sentences_combines_short_sentences() {
+        assert_eq!(
+            split_sentences("First one.  Second one!\nThird?"),
+            ["First one. Second one! Third?"]
+        );
+        assert!(split_sentences("  ").is_empty());
+
+        // 150 characters: two make a chunk, which then isn't extended,
+        // and nothing is combined past 400 characters
+        let medium = "word ".repeat(30).trim().to_string() + ".";
+        let long = "y".repeat(400) + ".";
+        let text = format!("{medium} {medium} {medium} Short. {long}");
+        assert_eq!(
+            split_sentences(&text),
+            [
+                format!("{medium} {medium}"),
+                format!("{medium} Short."),
+                long
+            ]
+        );
+    }
+
     #[test]
     fn test_chunk_text() {
         let engine = NeuralTtsEngine::new().unwrap();
@@ -859,6 +1936,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_session_options_default() {
+        let config = NeuralTtsConfig::default();
+        assert!(config.intra_threads >= 1);
+        assert_eq!(config.optimization_level, OptimizationLevel::Level3);
+    }
+
+    #[test]
+    fn test_session_options_deserialize_with_defaults() {
+        let json = r#"{ "rate": 1.0, "voice_id": "default", "model_id": "piper-en-us", "use_gpu": false }"#;
+        let config: NeuralTtsConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.intra_threads, default_intra_threads());
+        assert_eq!(config.optimization_level, OptimizationLevel::Level3);
+
+        let level: OptimizationLevel = serde_json::from_str(r#""level1""#).unwrap();
+        assert_eq!(level, OptimizationLevel::Level1);
+    }
+
+    #[tokio::test]
+    async fn test_set_session_options_without_loaded_model() {
+        let mut engine = NeuralTtsEngine::new().unwrap();
+
+        engine
+            .set_session_options(Some(2), Some(OptimizationLevel::Disable))
+            .await
+            .unwrap();
+        assert_eq!(engine.config.intra_threads, 2);
+        assert_eq!(engine.config.optimization_level, OptimizationLevel::Disable);
+
+        // Zero threads is rejected and leaves the config alone
+        assert!(engine.set_session_options(Some(0), None).await.is_err());
+        assert_eq!(engine.config.intra_threads, 2);
+    }
+
+    #[test]
+    fn test_real_time_factor() {
+        assert_eq!(real_time_factor(6.0, 2.0), 3.0);
+        assert_eq!(real_time_factor(1.0, 4.0), 0.25);
+        assert_eq!(real_time_factor(1.0, 0.0), 0.0);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[test]
+    fn test_resident_memory_is_reported() {
+        assert!(resident_memory_bytes().is_some_and(|bytes| bytes > 0));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_vm_rss() {
+        let status = "Name:\tpastel-hn\nVmPeak:\t  900000 kB\nVmRSS:\t  123456 kB\nThreads:\t12\n";
+        assert_eq!(parse_vm_rss(status), Some(123456 * 1024));
+        assert_eq!(parse_vm_rss("Name:\tkthreadd\n"), None);
+    }
+
+    #[tokio::test]
+    async fn test_speak_voice_override_is_scoped_to_the_call() {
+        let mut engine = NeuralTtsEngine::new().unwrap();
+        engine.set_voice("default-voice");
+        // An unknown model fails every call before anything is spoken
+        engine.config.model_id = "missing-model".to_string();
+
+        assert!(engine.speak("Hello.", Some("article-voice")).await.is_err());
+        assert_eq!(engine.config.voice_id, "default-voice");
+    }
+
+    #[tokio::test]
+    async fn test_stop_aborts_generation_before_any_audio() {
+        let mut engine = NeuralTtsEngine::new().unwrap();
+        engine.is_speaking.store(true, Ordering::SeqCst);
+
+        // As neural::stop does while speech holds the engine
+        let handle = engine.stop_handle();
+        handle.stop();
+        assert!(!engine.is_speaking.load(Ordering::SeqCst));
+
+        // No model is loaded, so reaching synthesis would fail differently
+        let chunks = ["Hello.".to_string(), "World.".to_string()];
+        let result = engine.synthesize_chunks(&chunks).await;
+        assert!(matches!(result, Err(SynthesisError::Cancelled)));
+    }
+
     #[test]
     fn test_set_rate_clamping() {
         let mut engine = NeuralTtsEngine::new().unwrap();
@@ -896,6 +2055,43 @@ mod tests {
         assert_eq!(config.phoneme_id_map.get("^"), Some(&vec![1]));
     }
 
+    #[tokio::test]
+    async fn test_unload_keeps_model_available() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let model = NeuralModel::from_id("piper-en-us").unwrap();
+        let model_path = temp_dir.path().join(model.dir_name());
+        std::fs::create_dir_all(&model_path).unwrap();
+        for file in model.files {
+            // Sparse files with the expected sizes pass the readiness check
+            let f = std::fs::File::create(model_path.join(file.path)).unwrap();
+            f.set_len(file.size).unwrap();
+        }
+
+        let mut engine = NeuralTtsEngine::new().unwrap();
+        engine.model_manager = ModelManager::with_model_dir(temp_dir.path().to_path_buf());
+        engine.loaded_model = Some("piper-en-us".to_string());
+        engine.piper_config = Some(
+            serde_json::from_str(
+                r#"{
+                    "audio": { "sample_rate": 22050 },
+                    "inference": { "noise_scale": 0.667, "length_scale": 1.0, "noise_w": 0.8 },
+                    "phoneme_id_map": {}
+                }"#,
+            )
+            .unwrap(),
+        );
+
+        engine.unload();
+
+        assert!(engine.model_session.is_none());
+        assert!(engine.piper_config.is_none());
+        assert!(engine.loaded_model.is_none());
+
+        let status = engine.get_status().await;
+        assert!(status.available);
+        assert!(!status.loaded);
+    }
+
     /// Test espeak-ng integration (requires espeak-ng installed)
     #[test]
     #[ignore] // Requires espeak-ng to be installed
@@ -931,6 +2127,17 @@ mod tests {
         );
     }
 
+    /// A stop kills espeak-ng instead of waiting for its phonemes
+    #[test]
+    #[ignore] // Requires espeak-ng to be installed
+    fn test_stop_kills_espeak() {
+        let engine = NeuralTtsEngine::new().unwrap();
+        engine.stop_handle().stop();
+
+        let result = engine.espeak_phonemes(&"A long sentence to phonemize. ".repeat(20));
+        assert!(matches!(result, Err(SynthesisError::Cancelled)));
+    }
+
     /// Integration test: Generate audio and save to file for verification
     #[tokio::test]
     #[ignore] // Requires model to be downloaded
@@ -970,6 +2177,295 @@ mod tests {
         println!("Saved audio to {}", wav_path);
     }
 
+    /// Returns `samples_per_sentence` samples per sentence and plays them
+    /// instantly; optionally fails on a sentence or stops after one.
+    #[derive(Default)]
+    struct FakeSynthesizer {
+        synthesized: Vec<String>,
+        samples_per_sentence: usize,
+        fail_on: Option<&'static str>,
+        stop_after: Option<&'static str>,
+        stopped: bool,
+        continue_on_error: bool,
+        cache: SentenceAudioCache,
+    }
+
+    impl SentenceSynthesizer for FakeSynthesizer {
+        async fn generate_audio(&mut self, text: &str) -> Result<Vec<f32>, SynthesisError> {
+            if self.fail_on == Some(text) {
+                return Err(SynthesisError::InferenceError("bad input".to_string()));
+            }
+            self.synthesized.push(text.to_string());
+            if self.stop_after == Some(text) {
+                self.stopped = true;
+            }
+            Ok(vec![0.0; self.samples_per_sentence])
+        }
+
+        fn cache_key(&self, text: &str) -> CacheKey {
+            CacheKey::new("fake-model", "fake-voice", 1.0, text)
+        }
+
+        fn audio_cache(&mut self) -> &mut SentenceAudioCache {
+            &mut self.cache
+        }
+
+        fn cancelled(&self) -> bool {
+            self.stopped
+        }
+    }
+
+    impl SentencePlayer for FakeSynthesizer {
+        fn preprocess_text(&self, text: &str) -> Result<String, SynthesisError> {
+            Ok(text.trim().to_string())
+        }
+
+        async fn play(
+            &mut self,
+            _audio: Vec<f32>,
+            on_start: Box<dyn FnOnce() + Send>,
+        ) -> Result<(), SynthesisError> {
+            // Called from the audio thread in real playback
+            tokio::task::spawn_blocking(on_start).await.unwrap();
+            Ok(())
+        }
+
+        fn speaking(&self) -> bool {
+            !self.stopped
+        }
+
+        fn continue_on_error(&self) -> bool {
+            self.continue_on_error
+        }
+    }
+
+    /// Events from speaking `texts` with `player`, as JSON
+    async fn speak_events(player: &mut FakeSynthesizer, texts: &[&str]) -> Vec<serde_json::Value> {
+        let (tx, mut rx) = mpsc::channel(32);
+        speak_each(player, &sentences(texts), &tx).await.unwrap();
+        drop(tx);
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(serde_json::to_value(event).unwrap());
+        }
+        events
+    }
+
+    #[tokio::test]
+    async fn test_synthesis_failure_emits_error_event() {
+        let mut player = FakeSynthesizer {
+            samples_per_sentence: 10,
+            fail_on: Some("Two."),
+            ..Default::default()
+        };
+        let texts = ["One.", "Two.", "Three."];
+
+        let events = speak_events(&mut player, &texts).await;
+        assert_eq!(
+            events,
+            [
+                serde_json::json!({ "type": "start", "index": 0, "text": "One." }),
+                serde_json::json!({ "type": "end", "index": 0 }),
+                serde_json::json!({
+                    "type": "error",
+                    "index": 1,
+                    "stage": "synthesis",
+                    "reason": "ONNX inference error: bad input",
+                }),
+                serde_json::json!({ "type": "stopped" }),
+            ]
+        );
+
+        // Carrying on reports the same sentence and plays the rest
+        player.continue_on_error = true;
+        let events = speak_events(&mut player, &texts).await;
+        let kinds: Vec<(&str, Option<u64>)> = events
+            .iter()
+            .map(|e| (e["type"].as_str().unwrap(), e["index"].as_u64()))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("start", Some(0)),
+                ("end", Some(0)),
+                ("error", Some(1)),
+                ("end", Some(1)),
+                ("start", Some(2)),
+                ("end", Some(2)),
+            ]
+        );
+    }
+
+    fn sentences(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_prepare_caches_every_sentence() {
+        let mut synth = FakeSynthesizer {
+            samples_per_sentence: 100,
+            ..Default::default()
+        };
+        let text = sentences(&["One.", "Two."]);
+
+        let samples = prepare_sentences(&mut synth, &text, 1000).await.unwrap();
+
+        assert_eq!(samples, 200);
+        assert_eq!(synth.synthesized, ["One.", "Two."]);
+        assert!(synth.cache.contains(&synth.cache_key("One.")));
+        assert!(synth.cache.contains(&synth.cache_key("Two.")));
+
+        // Preparing again is served from the cache
+        let samples = prepare_sentences(&mut synth, &text, 1000).await.unwrap();
+        assert_eq!(samples, 200);
+        assert_eq!(synth.synthesized.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_stops_between_sentences() {
+        let mut synth = FakeSynthesizer {
+            stop_after: Some("One."),
+            ..Default::default()
+        };
+
+        let result = prepare_sentences(&mut synth, &sentences(&["One.", "Two."]), 1000).await;
+
+        assert!(matches!(result, Err(SynthesisError::Cancelled)));
+        assert_eq!(synth.synthesized, ["One."]);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_rejects_narration_longer_than_the_cache() {
+        let mut synth = FakeSynthesizer {
+            samples_per_sentence: 600,
+            ..Default::default()
+        };
+
+        let text = sentences(&["One.", "Two.", "Three."]);
+        let result = prepare_sentences(&mut synth, &text, 1000).await;
+
+        assert!(matches!(result, Err(SynthesisError::InvalidInput(_))));
+        // Gives up as soon as it's over, not after the whole article
+        assert_eq!(synth.synthesized, ["One.", "Two."]);
+    }
+
+    /// Integration test: Prepared narration is cached for playback
+    #[tokio::test]
+    #[ignore] // Requires model to be downloaded
+    async fn test_prepare_integration() {
+        let mut engine = NeuralTtsEngine::new().unwrap();
+        let text = "Hello world. This is a test of prepared narration.";
+
+        let prepared = engine
+            .prepare(text, None)
+            .await
+            .expect("Should prepare narration");
+
+        assert_eq!(prepared.sentences, [text]);
+        assert_eq!(prepared.sentence_count, 1);
+        // About nine words: a few seconds of speech
+        assert!(
+            (1.0..15.0).contains(&prepared.duration_secs),
+            "implausible duration {}s",
+            prepared.duration_secs
+        );
+
+        let processed = engine.preprocess_text(text).unwrap();
+        assert!(engine.audio_cache.contains(&engine.cache_key(&processed)));
+    }
+
+    /// Integration test: Warmup runs an inference on the loaded model
+    #[tokio::test]
+    #[ignore] // Requires model to be downloaded
+    async fn test_warmup_integration() {
+        let mut engine = NeuralTtsEngine::new().unwrap();
+
+        engine
+            .warmup()
+            .await
+            .expect("Warmup should load the model and run inference");
+        assert!(engine.model_session.is_some());
+        assert!(engine.warmed_up);
+
+        // A second warmup is a no-op
+        engine.warmup().await.expect("Second warmup should succeed");
+
+        // Real synthesis still works afterwards
+        let audio = engine
+            .generate_audio("Hello world")
+            .await
+            .expect("Should generate audio after warmup");
+        assert!(!audio.is_empty());
+    }
+
+    /// Integration test: An unloaded model reloads transparently
+    #[tokio::test]
+    #[ignore] // Requires model to be downloaded
+    async fn test_unload_and_reload_integration() {
+        let mut engine = NeuralTtsEngine::new().unwrap();
+        engine
+            .load_model("piper-en-us")
+            .await
+            .expect("Model should load");
+        assert!(engine.get_status().await.loaded);
+
+        engine.unload();
+        assert!(engine.model_session.is_none());
+        let status = engine.get_status().await;
+        assert!(status.available && !status.loaded);
+
+        // Warmup goes through the same reload path as speak
+        engine.warmup().await.expect("Model should reload");
+        assert!(engine.model_session.is_some());
+    }
+
+    /// Integration test: Session options are applied to a rebuilt session
+    #[tokio::test]
+    #[ignore] // Requires model to be downloaded
+    async fn test_session_options_rebuild_integration() {
+        let mut engine = NeuralTtsEngine::new().unwrap();
+        engine
+            .load_model("piper-en-us")
+            .await
+            .expect("Model should load");
+
+        for (threads, level) in [
+            (1, OptimizationLevel::Disable),
+            (2, OptimizationLevel::Level1),
+            (default_intra_threads(), OptimizationLevel::Level3),
+        ] {
+            engine
+                .set_session_options(Some(threads), Some(level))
+                .await
+                .expect("Session should rebuild");
+            assert!(engine.model_session.is_some());
+
+            let audio = engine
+                .generate_audio("Hello")
+                .await
+                .expect("Rebuilt session should synthesize");
+            assert!(!audio.is_empty());
+        }
+    }
+
+    /// Integration test: Benchmark reports a positive real-time factor
+    #[tokio::test]
+    #[ignore] // Requires model to be downloaded
+    async fn test_benchmark_integration() {
+        let mut engine = NeuralTtsEngine::new().unwrap();
+
+        let result = engine
+            .benchmark(BENCHMARK_TEXT)
+            .await
+            .expect("Benchmark should run");
+
+        println!("{:?}", result);
+        assert!(result.real_time_factor > 0.0);
+        assert!(result.audio_secs > 0.0);
+        assert_eq!(result.iterations, BENCHMARK_ITERATIONS);
+    }
+
     /// Integration test: Full speak test with audio playback
     #[tokio::test]
     #[ignore] // Requires model and audio output
@@ -1043,12 +2539,14 @@ mod tests {
                 event_type: match &event {
                     SentenceEvent::Start { .. } => "Start".to_string(),
                     SentenceEvent::End { .. } => "End".to_string(),
+                    SentenceEvent::Error { .. } => "Error".to_string(),
                     SentenceEvent::Finished => "Finished".to_string(),
                     SentenceEvent::Stopped => "Stopped".to_string(),
                 },
                 index: match &event {
                     SentenceEvent::Start { index, .. } => Some(*index),
                     SentenceEvent::End { index } => Some(*index),
+                    SentenceEvent::Error { index, .. } => Some(*index),
                     _ => None,
                 },
                 timestamp: Instant::now(),
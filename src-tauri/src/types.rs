@@ -14,6 +14,7 @@
 //!
 //! - [`StoryFeed`] - Feed type enum (top, new, best, ask, show, jobs)
 //! - [`HNUser`] / [`RawHNUser`] - User profile data
+//! - [`CommentSort`] - Comment tree ordering
 //! - [`SubmissionFilter`] - Filter for user submissions
 //!
 //! # Search Types (Algolia)
@@ -25,8 +26,12 @@
 //! # Response Types
 //!
 //! - [`StoriesResponse`] - Paginated stories
+//! - [`FeedUpdate`] - Changed feed IDs from background refresh
 //! - [`SubmissionsResponse`] - Paginated user submissions
 //! - [`ArticleContent`] - Extracted article content
+//! - [`Heading`] - Article heading for a table of contents
+//! - [`CodeBlock`] - Article code block with its language
+//! - [`ArticleLink`] - Link found in an article
 //! - [`CacheStats`] - Cache statistics for UI display
 //!
 //! # Errors
@@ -41,6 +46,8 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::html;
+
 /// HN item types as returned by the Firebase API.
 ///
 /// Used for type-safe deserialization. Unknown types default to `Unknown`.
@@ -85,6 +92,16 @@ pub enum StoryFeed {
 }
 
 impl StoryFeed {
+    /// Every feed served by the Firebase API, in tab order.
+    pub const ALL: [StoryFeed; 6] = [
+        Self::Top,
+        Self::New,
+        Self::Best,
+        Self::Ask,
+        Self::Show,
+        Self::Jobs,
+    ];
+
     /// Get the Firebase API endpoint name for this feed.
     ///
     /// # Example
@@ -124,6 +141,8 @@ pub struct RawHNItem {
     #[serde(default)]
     pub descendants: u32,
     pub kids: Option<Vec<u32>>,
+    /// Option IDs (for polls)
+    pub parts: Option<Vec<u32>>,
     pub parent: Option<u32>,
     #[serde(default)]
     pub dead: bool,
@@ -131,6 +150,24 @@ pub struct RawHNItem {
     pub deleted: bool,
 }
 
+/// What kind of submission an item is, for labelling it in the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PostKind {
+    /// A story linking to an external URL
+    Link,
+    /// A story with text and no URL
+    Text,
+    /// An "Ask HN" post
+    AskHn,
+    /// A "Show HN" post, with or without a URL
+    ShowHn,
+    /// A job posting
+    Job,
+    /// A poll
+    Poll,
+}
+
 /// Processed HN item for TypeScript frontend.
 ///
 /// Key differences from [`RawHNItem`]:
@@ -167,6 +204,87 @@ pub struct HNItem {
     pub dead: bool,
     /// Whether item was deleted by author
     pub deleted: bool,
+    /// Kind of submission, derived from the type, title, and URL; `None`
+    /// for comments and poll options
+    #[serde(default)]
+    pub post_kind: Option<PostKind>,
+}
+
+impl HNItem {
+    /// Host of the item's external URL without a leading `www.`.
+    ///
+    /// Returns `None` for text posts and unparseable URLs.
+    ///
+    /// # Example
+    ///
+    /// A story linking to `https://www.example.com/post` has domain
+    /// `example.com`.
+    pub fn domain(&self) -> Option<String> {
+        let url = url::Url::parse(self.url.as_deref()?).ok()?;
+        let host = url.host_str()?;
+        Some(host.strip_prefix("www.").unwrap_or(host).to_string())
+    }
+
+    /// Whether the title starts with `prefix` ("Ask HN", "Show HN"),
+    /// case-insensitively and followed by a colon, dash, or space.
+    fn has_title_prefix(&self, prefix: &str) -> bool {
+        let Some(title) = self.title.as_deref() else {
+            return false;
+        };
+        let title = title.trim_start();
+        title
+            .get(..prefix.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+            && title[prefix.len()..]
+                .chars()
+                .next()
+                .is_some_and(|c| c == ':' || c == '-' || c == '–' || c.is_whitespace())
+    }
+
+    /// Whether this is an "Ask HN" post.
+    pub fn is_ask_hn(&self) -> bool {
+        self.has_title_prefix("Ask HN")
+    }
+
+    /// Whether this is a "Show HN" post.
+    pub fn is_show_hn(&self) -> bool {
+        self.has_title_prefix("Show HN")
+    }
+
+    /// Classify the item from its type, title prefix, and URL.
+    ///
+    /// Ask and Show HN take priority over link/text, so a Show HN with a URL
+    /// is still `ShowHn`.
+    pub fn classify(&self) -> Option<PostKind> {
+        match self.item_type {
+            0 if self.is_ask_hn() => Some(PostKind::AskHn),
+            0 if self.is_show_hn() => Some(PostKind::ShowHn),
+            0 if self.url.as_deref().is_some_and(|u| !u.is_empty()) => Some(PostKind::Link),
+            0 => Some(PostKind::Text),
+            2 => Some(PostKind::Job),
+            3 => Some(PostKind::Poll),
+            _ => None,
+        }
+    }
+
+    /// The link to open for this item.
+    ///
+    /// The item's `url` if it has one. Show HN self-posts often put the
+    /// project link in their text instead, so for those the first web link
+    /// in `text` is used. Returns `None` for other text posts, so the
+    /// discussion itself is shown.
+    pub fn primary_link(&self) -> Option<String> {
+        if let Some(url) = self.url.as_deref().filter(|u| !u.is_empty()) {
+            return Some(url.to_string());
+        }
+        if !self.is_show_hn() {
+            return None;
+        }
+
+        html::extract_links(self.text.as_deref()?)
+            .into_iter()
+            .find(|link| link.starts_with("http://") || link.starts_with("https://"))
+    }
 }
 
 impl From<RawHNItem> for HNItem {
@@ -180,7 +298,7 @@ impl From<RawHNItem> for HNItem {
             _ => 5,
         };
 
-        Self {
+        let mut item = Self {
             id: raw.id,
             item_type,
             by: raw.by,
@@ -194,7 +312,10 @@ impl From<RawHNItem> for HNItem {
             parent: raw.parent,
             dead: raw.dead,
             deleted: raw.deleted,
-        }
+            post_kind: None,
+        };
+        item.post_kind = item.classify();
+        item
     }
 }
 
@@ -211,6 +332,131 @@ pub struct CommentWithChildren {
     pub children: Vec<CommentWithChildren>,
 }
 
+impl CommentWithChildren {
+    /// Number of comments nested below this one in the fetched tree.
+    pub fn descendant_count(&self) -> usize {
+        self.children
+            .iter()
+            .map(|child| 1 + child.descendant_count())
+            .sum()
+    }
+}
+
+/// Ordering applied to each level of a fetched comment tree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommentSort {
+    /// HN's ranking (the parent's `kids` order)
+    #[default]
+    Default,
+    /// Most recent first
+    NewestFirst,
+    /// Highest score first; comments have no public score, so ties fall
+    /// back to the number of fetched replies
+    HighestScore,
+}
+
+impl CommentSort {
+    /// Sort one level of comments in place. Ties keep their original order.
+    pub fn sort(&self, comments: &mut [CommentWithChildren]) {
+        match self {
+            Self::Default => {}
+            Self::NewestFirst => comments.sort_by(|a, b| b.item.time.cmp(&a.item.time)),
+            Self::HighestScore => comments
+                .sort_by_cached_key(|c| std::cmp::Reverse((c.item.score, c.descendant_count()))),
+        }
+    }
+}
+
+/// One option of a poll, with its share of the votes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollOption {
+    /// Poll option (`pollopt`) item ID
+    pub id: u32,
+    /// Option text (HTML)
+    pub text: Option<String>,
+    /// Votes for this option
+    pub votes: u32,
+    /// Share of all votes, 0-100 (0 for a poll without votes)
+    pub percent: f64,
+}
+
+/// A poll with its options, most votes first.
+///
+/// Returned by `fetch_poll`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollWithOptions {
+    pub poll: HNItem,
+    /// Options sorted by votes, descending; ties keep the poll's order
+    pub options: Vec<PollOption>,
+    /// Votes across all options
+    pub total_votes: u32,
+}
+
+impl PollWithOptions {
+    /// Tally `options` (the poll's `pollopt` items, whose score is their
+    /// vote count).
+    pub fn tally(poll: HNItem, options: Vec<HNItem>) -> Self {
+        let votes = |item: &HNItem| item.score.max(0) as u32;
+        let total_votes: u32 = options.iter().map(votes).sum();
+
+        let mut options: Vec<PollOption> = options
+            .into_iter()
+            .map(|item| {
+                let votes = votes(&item);
+                PollOption {
+                    id: item.id,
+                    text: item.text,
+                    votes,
+                    percent: if total_votes == 0 {
+                        0.0
+                    } else {
+                        f64::from(votes) * 100.0 / f64::from(total_votes)
+                    },
+                }
+            })
+            .collect();
+        options.sort_by(|a, b| b.votes.cmp(&a.votes));
+
+        Self {
+            poll,
+            options,
+            total_votes,
+        }
+    }
+}
+
+/// A page of a comment's direct children, with their nested replies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentChildrenResponse {
+    /// Children in this page, in HN's ranking order
+    pub comments: Vec<CommentWithChildren>,
+    /// Whether more direct children follow this page
+    pub has_more: bool,
+    /// Total number of direct children
+    pub total: usize,
+}
+
+/// A comment together with the thread it lives in.
+///
+/// Returned by `fetch_comment_context` so a comment search hit can be shown
+/// in place.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentContext {
+    /// The requested comment
+    pub comment: HNItem,
+    /// Parent comments, from the top-level comment down to the direct parent
+    pub ancestors: Vec<HNItem>,
+    /// The story (or job/poll) the thread belongs to, if it was reached
+    pub story: Option<HNItem>,
+    /// Whether the walk stopped at the depth cap before reaching the story
+    pub truncated: bool,
+}
+
 /// A story with its full comment tree.
 ///
 /// Returned by `fetch_story_with_comments` for the detail view.
@@ -235,6 +481,113 @@ pub struct StoriesResponse {
     pub has_more: bool,
     /// Total stories in the feed
     pub total: usize,
+    /// Offset to pass for the next page (index after the last scanned story)
+    pub next_offset: usize,
+}
+
+/// Client-side ranking formula for re-ordering a page of stories.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum RankFormula {
+    /// HN-style gravity: `(score - 1) / (hours + 2)^g`; HN uses `g = 1.8`
+    Gravity { g: f64 },
+    /// Points per hour since submission
+    ScorePerHour,
+    /// Comments per hour since submission
+    CommentsPerHour,
+}
+
+impl RankFormula {
+    /// Rank of `item` at Unix time `now`; higher ranks first.
+    ///
+    /// Stories younger than an hour count as an hour old in the per-hour
+    /// formulas, so a brand-new story with a couple of votes doesn't jump
+    /// to the top.
+    pub fn rank_at(&self, item: &HNItem, now: u64) -> f64 {
+        let hours = now.saturating_sub(item.time) as f64 / 3600.0;
+        match *self {
+            Self::Gravity { g } => (f64::from(item.score) - 1.0) / (hours + 2.0).powf(g),
+            Self::ScorePerHour => f64::from(item.score) / hours.max(1.0),
+            Self::CommentsPerHour => f64::from(item.descendants) / hours.max(1.0),
+        }
+    }
+}
+
+/// A page of stories re-ordered by a [`RankFormula`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RankedStoriesResponse {
+    /// The page, with `stories` in ranked order
+    #[serde(flatten)]
+    pub page: StoriesResponse,
+    /// Story IDs in the feed's original order
+    pub feed_order: Vec<u32>,
+}
+
+/// Payload of the `feed-refreshed` event: a background refresh found a
+/// feed's story IDs changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedRefreshed {
+    pub feed: StoryFeed,
+    /// Whether the story IDs changed (always true for emitted events)
+    pub changed: bool,
+    /// Stories that weren't in the feed before, for a "N new stories" pill
+    pub new_count: usize,
+}
+
+/// Payload of the `feed-updated` event emitted by timer-driven refresh.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedUpdate {
+    /// The feed whose story IDs changed
+    pub feed: StoryFeed,
+    /// The new story IDs, in feed order
+    pub ids: Vec<u32>,
+    /// How stories moved compared to the previous ordering
+    pub deltas: Vec<PositionDelta>,
+    /// Stories that weren't in the feed before
+    pub new_count: usize,
+}
+
+/// When a feed's story IDs were last fetched, for freshness indicators.
+///
+/// Returned by `get_feed_ages`, one per feed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedAge {
+    pub feed: StoryFeed,
+    /// Unix time of the last fetch in milliseconds, `None` if the feed
+    /// hasn't been fetched this session
+    pub refreshed_at: Option<u64>,
+    /// Seconds since the last fetch
+    pub age_secs: Option<u64>,
+}
+
+/// How often a site appears among a feed's stories.
+///
+/// Returned by `top_domains`, most frequent first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainCount {
+    /// Host without a leading `www.` (see [`HNItem::domain`])
+    pub domain: String,
+    /// Number of stories linking to the domain
+    pub count: usize,
+    /// Sum of those stories' scores
+    pub total_score: i64,
+}
+
+/// A story whose rank in a feed changed between two refreshes.
+///
+/// Ranks are 1-based. A story new to the feed has no `old_rank`; one that
+/// dropped out has no `new_rank`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionDelta {
+    pub id: u32,
+    pub old_rank: Option<usize>,
+    pub new_rank: Option<usize>,
 }
 
 /// Raw HN user as returned by the Firebase API.
@@ -266,20 +619,46 @@ pub struct HNUser {
     pub about: Option<String>,
     /// IDs of all user submissions
     pub submitted: Option<Vec<u32>>,
+    /// Whole days since the account was created
+    pub account_age_days: u64,
+    /// Number of submissions (stories and comments), 0 if none
+    pub submission_count: usize,
 }
 
-impl From<RawHNUser> for HNUser {
-    fn from(raw: RawHNUser) -> Self {
+/// Seconds in a day, for account age calculation.
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+impl HNUser {
+    /// Convert a raw user, computing the account age relative to `now`.
+    ///
+    /// `now` is a Unix timestamp in seconds. A `created` timestamp in the
+    /// future (clock skew) yields an age of 0.
+    pub fn from_raw_at(raw: RawHNUser, now: u64) -> Self {
+        let account_age_days = now.saturating_sub(raw.created) / SECS_PER_DAY;
+        let submission_count = raw.submitted.as_ref().map_or(0, Vec::len);
+
         Self {
             id: raw.id,
             created: raw.created,
             karma: raw.karma,
             about: raw.about,
             submitted: raw.submitted,
+            account_age_days,
+            submission_count,
         }
     }
 }
 
+impl From<RawHNUser> for HNUser {
+    fn from(raw: RawHNUser) -> Self {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self::from_raw_at(raw, now)
+    }
+}
+
 /// Filter for user submission queries.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -292,16 +671,41 @@ pub enum SubmissionFilter {
     Comments,
 }
 
+impl SubmissionFilter {
+    /// Check whether an item passes this filter.
+    pub fn matches(&self, item: &HNItem) -> bool {
+        match self {
+            Self::All => true,
+            Self::Stories => item.item_type == 0 || item.item_type == 2, // Story or Job
+            Self::Comments => item.item_type == 1,
+        }
+    }
+}
+
 /// Paginated user submissions response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubmissionsResponse {
     /// Submissions for this page
     pub items: Vec<HNItem>,
-    /// Whether more submissions are available
+    /// Whether unscanned submissions remain after `next_offset`
     pub has_more: bool,
     /// Total submissions (before filtering)
     pub total: usize,
+    /// Offset to pass for the next page (index after the last scanned submission)
+    pub next_offset: usize,
+}
+
+/// Batch fetch outcome that keeps successes alongside failures.
+///
+/// Returned by `fetch_items_detailed`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResult {
+    /// Items fetched successfully, in input order
+    pub items: Vec<HNItem>,
+    /// IDs that failed (including missing items), with the reason
+    pub errors: Vec<(u32, String)>,
 }
 
 // ===== Search Types (Algolia) =====
@@ -453,6 +857,86 @@ pub struct ArticleContent {
     pub lang: Option<String>,
     /// Word count estimate
     pub word_count: usize,
+    /// Section headings (`h1`-`h4`) in document order, for a table of contents
+    #[serde(default)]
+    pub headings: Vec<Heading>,
+    /// Preformatted code blocks, for syntax highlighting and TTS skipping
+    #[serde(default)]
+    pub code_blocks: Vec<CodeBlock>,
+    /// Web links in the content, absolute and deduplicated
+    #[serde(default)]
+    pub links: Vec<ArticleLink>,
+    /// How much to trust the extraction, from 0 to 1.
+    ///
+    /// 1 when readability found a full article. Lower when it found little
+    /// text, or when the content came from the largest-text-block fallback
+    /// instead.
+    #[serde(default = "full_confidence")]
+    pub confidence: f32,
+    /// User agent the page was fetched with, when it was fetched. Differs
+    /// from the app's own when a site only served the article to a
+    /// fallback (see `ClientConfig::fallback_user_agents`).
+    #[serde(default)]
+    pub user_agent: Option<String>,
+}
+
+fn full_confidence() -> f32 {
+    1.0
+}
+
+/// A heading in an extracted article.
+///
+/// `id` matches the `id` attribute of the heading in
+/// [`ArticleContent::content`], so `#id` links jump to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Heading {
+    /// Heading level (1-4)
+    pub level: u8,
+    /// Heading text
+    pub text: String,
+    /// Anchor id, unique within the article
+    pub id: String,
+}
+
+/// A code block in an extracted article.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodeBlock {
+    /// Language name (`rust`, `python`, ...), from markup or sniffed
+    pub language: Option<String>,
+    /// Code as plain text, indentation preserved
+    pub content: String,
+}
+
+/// A link found in an extracted article.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArticleLink {
+    /// Anchor text (the URL itself when the anchor has no text)
+    pub text: String,
+    /// Absolute URL
+    pub url: String,
+}
+
+/// Average adult reading speed used for reading-time estimates.
+const WORDS_PER_MINUTE: usize = 200;
+
+impl ArticleContent {
+    /// Estimated reading time in whole minutes, rounded up (at least 1).
+    pub fn reading_time_minutes(&self) -> usize {
+        self.word_count.div_ceil(WORDS_PER_MINUTE).max(1)
+    }
+}
+
+/// A front-page story with a preview of its article, for the dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestEntry {
+    pub item: HNItem,
+    /// Host of the story URL, without `www.`
+    pub domain: Option<String>,
+    /// Estimated article reading time, if the article was extracted
+    pub reading_time_minutes: Option<usize>,
+    /// Opening of the article text, if the article was extracted
+    pub excerpt: Option<String>,
 }
 
 /// Cache statistics for settings/debug UI.
@@ -465,6 +949,8 @@ pub struct CacheStats {
     pub story_ids_count: u64,
     /// Number of cached users
     pub user_count: u64,
+    /// Number of cached extracted articles
+    pub article_count: u64,
     /// Item cache TTL in seconds
     pub item_ttl_secs: u64,
     /// Story IDs cache TTL in seconds
@@ -473,12 +959,72 @@ pub struct CacheStats {
     pub user_ttl_secs: u64,
 }
 
+/// Why an item left the item cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EvictionCause {
+    /// Its TTL ran out
+    Expired,
+    /// It was invalidated (e.g. by clearing the cache)
+    Explicit,
+    /// The cache was full
+    Size,
+}
+
+/// An item evicted from the item cache, sent in `cache-evicted` events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheEviction {
+    pub id: u32,
+    pub cause: EvictionCause,
+}
+
+/// Current cache snapshot file format version.
+pub const CACHE_SNAPSHOT_VERSION: u32 = 1;
+
+/// A cached value in a [`CacheSnapshot`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotEntry<K, V> {
+    pub key: K,
+    pub value: V,
+    /// When the entry expires (Unix milliseconds)
+    pub expires_at: u64,
+}
+
+/// The item, story ID, and user caches as written by `save_cache_snapshot`.
+///
+/// ```json
+/// { "version": 1, "savedAt": 1706119200000, "items": [{ "key": 1, "value": {...}, "expiresAt": ... }], "storyIds": [...], "users": [...] }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheSnapshot {
+    pub version: u32,
+    /// When the snapshot was written (Unix milliseconds)
+    pub saved_at: u64,
+    pub items: Vec<SnapshotEntry<u32, HNItem>>,
+    pub story_ids: Vec<SnapshotEntry<StoryFeed, Vec<u32>>>,
+    pub users: Vec<SnapshotEntry<String, HNUser>>,
+}
+
+/// Entries written or restored by a cache snapshot command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheSnapshotCounts {
+    pub items: usize,
+    pub story_ids: usize,
+    pub users: usize,
+}
+
 // ===== Error Types =====
 
 /// All possible errors from the HN API client.
 ///
-/// These errors are serialized to strings when returned from Tauri commands,
-/// making them easy to display in the frontend.
+/// These errors are serialized as `{ code, message }` objects when returned
+/// from Tauri commands, so the frontend can branch on the stable
+/// [`code`](Self::code) and display the message. Rate-limit errors add
+/// `retryAfter` (and `attempts`, once retries are exhausted).
 #[derive(Debug, Error)]
 #[allow(dead_code)]
 pub enum ApiError {
@@ -502,6 +1048,15 @@ pub enum ApiError {
     #[error("Rate limited, retry after {0} seconds")]
     RateLimited(u32),
 
+    /// Still rate limited after retrying with backoff
+    #[error("Gave up after {attempts} retries, try again in {retry_after}s")]
+    RetriesExhausted {
+        /// Retries made after the first request
+        attempts: u32,
+        /// Retry-after from the last 429 response, in seconds
+        retry_after: u32,
+    },
+
     /// Generic API error
     #[error("API error: {0}")]
     Api(String),
@@ -509,6 +1064,64 @@ pub enum ApiError {
     /// Article content extraction failed
     #[error("Failed to extract article content: {0}")]
     ArticleExtraction(String),
+
+    /// A site has no usable favicon
+    #[error("No favicon found for {0}")]
+    FaviconNotFound(String),
+
+    /// Reading or writing a local file failed
+    #[error("File operation failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An imported backup file is malformed or unsupported
+    #[error("Invalid backup file: {0}")]
+    InvalidBackup(String),
+
+    /// A URL redirected more times than allowed (likely a redirect loop)
+    #[error("Too many redirects fetching {0}")]
+    TooManyRedirects(String),
+
+    /// A request took longer than its timeout
+    #[error("Timed out fetching {0}")]
+    Timeout(String),
+
+    /// Offline mode is on and the data isn't cached
+    #[error("Offline: {0} is not cached")]
+    Offline(String),
+}
+
+impl ApiError {
+    /// Stable, machine-readable identifier for the error kind.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Request(_) => "request",
+            Self::Parse(_) => "parse",
+            Self::NotFound(_) => "not_found",
+            Self::UserNotFound(_) => "user_not_found",
+            Self::RateLimited(_) => "rate_limited",
+            Self::RetriesExhausted { .. } => "retries_exhausted",
+            Self::Api(_) => "api",
+            Self::ArticleExtraction(_) => "article_extraction",
+            Self::FaviconNotFound(_) => "favicon_not_found",
+            Self::Io(_) => "io",
+            Self::InvalidBackup(_) => "invalid_backup",
+            Self::TooManyRedirects(_) => "too_many_redirects",
+            Self::Timeout(_) => "timeout",
+            Self::Offline(_) => "offline",
+        }
+    }
+}
+
+/// Wire format of [`ApiError`].
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiErrorPayload {
+    code: &'static str,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_after: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attempts: Option<u32>,
 }
 
 // Implement Serialize for ApiError so it can be returned from Tauri commands
@@ -517,7 +1130,22 @@ impl Serialize for ApiError {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        let (retry_after, attempts) = match *self {
+            Self::RateLimited(retry_after) => (Some(retry_after), None),
+            Self::RetriesExhausted {
+                attempts,
+                retry_after,
+            } => (Some(retry_after), Some(attempts)),
+            _ => (None, None),
+        };
+
+        ApiErrorPayload {
+            code: self.code(),
+            message: self.to_string(),
+            retry_after,
+            attempts,
+        }
+        .serialize(serializer)
     }
 }
 
@@ -613,6 +1241,13 @@ mod tests {
         assert_eq!(StoryFeed::Jobs.endpoint(), "jobstories");
     }
 
+    #[test]
+    fn story_feed_all_lists_every_feed_once() {
+        let unique: std::collections::HashSet<_> = StoryFeed::ALL.iter().collect();
+        assert_eq!(unique.len(), 6);
+        assert_eq!(StoryFeed::ALL[0], StoryFeed::Top);
+    }
+
     #[test]
     fn story_feed_serialize_deserialize_roundtrip() {
         let feed = StoryFeed::Top;
@@ -636,6 +1271,7 @@ mod tests {
             title: Some("Test Story".to_string()),
             descendants: 50,
             kids: Some(vec![456, 789]),
+            parts: None,
             parent: None,
             dead: false,
             deleted: false,
@@ -670,6 +1306,7 @@ mod tests {
             title: None,
             descendants: 0,
             kids: None,
+            parts: None,
             parent: Some(123),
             dead: false,
             deleted: false,
@@ -695,6 +1332,7 @@ mod tests {
             title: Some("Hiring: Engineer".to_string()),
             descendants: 0,
             kids: None,
+            parts: None,
             parent: None,
             dead: false,
             deleted: false,
@@ -717,6 +1355,7 @@ mod tests {
             title: Some("Poll question?".to_string()),
             descendants: 10,
             kids: Some(vec![1001, 1002]),
+            parts: None,
             parent: None,
             dead: false,
             deleted: false,
@@ -739,6 +1378,7 @@ mod tests {
             title: None,
             descendants: 0,
             kids: None,
+            parts: None,
             parent: Some(1000),
             dead: false,
             deleted: false,
@@ -761,6 +1401,7 @@ mod tests {
             title: None,
             descendants: 0,
             kids: None,
+            parts: None,
             parent: None,
             dead: false,
             deleted: false,
@@ -783,6 +1424,7 @@ mod tests {
             title: None,
             descendants: 0,
             kids: None,
+            parts: None,
             parent: None,
             dead: false,
             deleted: false,
@@ -805,6 +1447,7 @@ mod tests {
             title: None,
             descendants: 0,
             kids: None,
+            parts: None,
             parent: Some(100),
             dead: true,
             deleted: true,
@@ -834,6 +1477,7 @@ mod tests {
         assert_eq!(user.karma, 12345);
         assert_eq!(user.about, Some("I am a test user.".to_string()));
         assert_eq!(user.submitted, Some(vec![100, 200, 300]));
+        assert_eq!(user.submission_count, 3);
     }
 
     #[test]
@@ -852,6 +1496,55 @@ mod tests {
         assert_eq!(user.karma, 1);
         assert_eq!(user.about, None);
         assert_eq!(user.submitted, None);
+        assert_eq!(user.submission_count, 0);
+    }
+
+    #[test]
+    fn hn_user_account_age_uses_injected_now() {
+        let raw = RawHNUser {
+            id: "veteran".to_string(),
+            created: 1577836800, // 2020-01-01
+            karma: 100,
+            about: None,
+            submitted: None,
+        };
+
+        // 2023-01-01 00:00:00 plus 23 hours: partial days round down
+        let now = 1672531200 + 23 * 60 * 60;
+        let user = HNUser::from_raw_at(raw, now);
+
+        assert_eq!(user.account_age_days, 1096);
+    }
+
+    #[test]
+    fn hn_user_account_age_clamps_future_created_to_zero() {
+        let raw = RawHNUser {
+            id: "timetraveler".to_string(),
+            created: 2000000000,
+            karma: 1,
+            about: None,
+            submitted: None,
+        };
+
+        let user = HNUser::from_raw_at(raw, 1600000000);
+
+        assert_eq!(user.account_age_days, 0);
+    }
+
+    #[test]
+    fn hn_user_computed_fields_serialize_camel_case() {
+        let raw = RawHNUser {
+            id: "tester".to_string(),
+            created: 0,
+            karma: 1,
+            about: None,
+            submitted: Some(vec![1, 2]),
+        };
+
+        let json = serde_json::to_value(HNUser::from_raw_at(raw, SECS_PER_DAY * 10)).unwrap();
+
+        assert_eq!(json["accountAgeDays"], 10);
+        assert_eq!(json["submissionCount"], 2);
     }
 
     // ===== AlgoliaHit -> SearchResult Conversion Tests =====
@@ -958,44 +1651,154 @@ mod tests {
 
     // ===== ApiError Tests =====
 
+    fn error_json(error: &ApiError) -> serde_json::Value {
+        serde_json::to_value(error).unwrap()
+    }
+
     #[test]
     fn api_error_serialize_not_found() {
         let error = ApiError::NotFound(12345);
-        let json = serde_json::to_string(&error).unwrap();
-        assert_eq!(json, r#""Item not found: 12345""#);
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({ "code": "not_found", "message": "Item not found: 12345" })
+        );
     }
 
     #[test]
     fn api_error_serialize_user_not_found() {
         let error = ApiError::UserNotFound("testuser".to_string());
-        let json = serde_json::to_string(&error).unwrap();
-        assert_eq!(json, r#""User not found: testuser""#);
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({ "code": "user_not_found", "message": "User not found: testuser" })
+        );
     }
 
     #[test]
     fn api_error_serialize_rate_limited() {
         let error = ApiError::RateLimited(60);
-        let json = serde_json::to_string(&error).unwrap();
-        assert_eq!(json, r#""Rate limited, retry after 60 seconds""#);
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({
+                "code": "rate_limited",
+                "message": "Rate limited, retry after 60 seconds",
+                "retryAfter": 60
+            })
+        );
+    }
+
+    #[test]
+    fn api_error_serialize_retries_exhausted() {
+        let error = ApiError::RetriesExhausted {
+            attempts: 3,
+            retry_after: 60,
+        };
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({
+                "code": "retries_exhausted",
+                "message": "Gave up after 3 retries, try again in 60s",
+                "retryAfter": 60,
+                "attempts": 3
+            })
+        );
     }
 
     #[test]
     fn api_error_serialize_api_error() {
         let error = ApiError::Api("Something went wrong".to_string());
-        let json = serde_json::to_string(&error).unwrap();
-        assert_eq!(json, r#""API error: Something went wrong""#);
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({ "code": "api", "message": "API error: Something went wrong" })
+        );
     }
 
     #[test]
     fn api_error_serialize_article_extraction() {
         let error = ApiError::ArticleExtraction("Could not parse content".to_string());
-        let json = serde_json::to_string(&error).unwrap();
         assert_eq!(
-            json,
-            r#""Failed to extract article content: Could not parse content""#
+            error_json(&error),
+            serde_json::json!({
+                "code": "article_extraction",
+                "message": "Failed to extract article content: Could not parse content"
+            })
+        );
+    }
+
+    #[test]
+    fn api_error_serialize_favicon_not_found() {
+        let error = ApiError::FaviconNotFound("example.com".to_string());
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({
+                "code": "favicon_not_found",
+                "message": "No favicon found for example.com"
+            })
+        );
+    }
+
+    #[test]
+    fn api_error_serialize_io() {
+        let error = ApiError::from(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "access denied",
+        ));
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({ "code": "io", "message": "File operation failed: access denied" })
         );
     }
 
+    #[test]
+    fn api_error_serialize_invalid_backup() {
+        let error = ApiError::InvalidBackup("missing field `bookmarks`".to_string());
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({
+                "code": "invalid_backup",
+                "message": "Invalid backup file: missing field `bookmarks`"
+            })
+        );
+    }
+
+    #[test]
+    fn api_error_serialize_too_many_redirects() {
+        let error = ApiError::TooManyRedirects("https://example.com/loop".to_string());
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({
+                "code": "too_many_redirects",
+                "message": "Too many redirects fetching https://example.com/loop"
+            })
+        );
+    }
+
+    #[test]
+    fn api_error_serialize_timeout() {
+        let error = ApiError::Timeout("https://example.com/slow".to_string());
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({
+                "code": "timeout",
+                "message": "Timed out fetching https://example.com/slow"
+            })
+        );
+    }
+
+    #[test]
+    fn api_error_serialize_offline() {
+        let error = ApiError::Offline("item 42".to_string());
+        assert_eq!(
+            error_json(&error),
+            serde_json::json!({ "code": "offline", "message": "Offline: item 42 is not cached" })
+        );
+    }
+
+    #[test]
+    fn api_error_display_is_the_message() {
+        let error = ApiError::RateLimited(30);
+        assert_eq!(error.to_string(), error_json(&error)["message"]);
+    }
+
     // ===== HNItem Serialization Tests =====
 
     #[test]
@@ -1014,12 +1817,14 @@ mod tests {
             parent: None,
             dead: false,
             deleted: false,
+            post_kind: Some(PostKind::Link),
         };
 
         let json = serde_json::to_string(&item).unwrap();
 
         // Check camelCase field names
         assert!(json.contains(r#""type":0"#));
+        assert!(json.contains(r#""postKind":"link""#));
         // Note: 'descendants' is already camelCase-friendly
         assert!(json.contains(r#""descendants":50"#));
     }
@@ -1089,4 +1894,288 @@ mod tests {
             r#""comment""#
         );
     }
+
+    // ===== CommentSort Tests =====
+
+    fn comment(id: u32, time: u64, children: Vec<CommentWithChildren>) -> CommentWithChildren {
+        let raw: RawHNItem = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "type": "comment",
+            "time": time
+        }))
+        .unwrap();
+        CommentWithChildren {
+            item: raw.into(),
+            children,
+        }
+    }
+
+    fn ids(comments: &[CommentWithChildren]) -> Vec<u32> {
+        comments.iter().map(|c| c.item.id).collect()
+    }
+
+    #[test]
+    fn comment_sort_default_keeps_kids_order() {
+        let mut level = vec![comment(1, 100, vec![]), comment(2, 300, vec![])];
+        CommentSort::Default.sort(&mut level);
+        assert_eq!(ids(&level), vec![1, 2]);
+    }
+
+    #[test]
+    fn comment_sort_newest_first_orders_by_time_descending() {
+        let mut level = vec![
+            comment(1, 100, vec![]),
+            comment(2, 300, vec![]),
+            comment(3, 200, vec![]),
+        ];
+        CommentSort::NewestFirst.sort(&mut level);
+        assert_eq!(ids(&level), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn comment_sort_highest_score_falls_back_to_reply_count() {
+        let mut level = vec![
+            comment(1, 100, vec![]),
+            comment(
+                2,
+                100,
+                vec![comment(20, 100, vec![comment(21, 100, vec![])])],
+            ),
+            comment(3, 100, vec![comment(30, 100, vec![])]),
+        ];
+        CommentSort::HighestScore.sort(&mut level);
+        assert_eq!(ids(&level), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn comment_sort_serializes_camel_case() {
+        assert_eq!(
+            serde_json::to_string(&CommentSort::NewestFirst).unwrap(),
+            "\"newestFirst\""
+        );
+        assert_eq!(CommentSort::default(), CommentSort::Default);
+    }
+
+    // ===== Poll Tally Tests =====
+
+    fn poll_item(id: u32, item_type: &str, score: i32) -> HNItem {
+        let raw: RawHNItem = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "type": item_type,
+            "score": score,
+            "text": format!("Option {}", id)
+        }))
+        .unwrap();
+        raw.into()
+    }
+
+    #[test]
+    fn poll_tally_computes_shares_most_votes_first() {
+        let poll = PollWithOptions::tally(
+            poll_item(1, "poll", 50),
+            vec![
+                poll_item(2, "pollopt", 10),
+                poll_item(3, "pollopt", 30),
+                poll_item(4, "pollopt", 0),
+                poll_item(5, "pollopt", 10),
+            ],
+        );
+
+        assert_eq!(poll.total_votes, 50);
+        let tally: Vec<(u32, u32, f64)> = poll
+            .options
+            .iter()
+            .map(|o| (o.id, o.votes, o.percent))
+            .collect();
+        assert_eq!(
+            tally,
+            vec![(3, 30, 60.0), (2, 10, 20.0), (5, 10, 20.0), (4, 0, 0.0)]
+        );
+        assert_eq!(poll.options[0].text.as_deref(), Some("Option 3"));
+    }
+
+    #[test]
+    fn poll_tally_without_votes_is_all_zero() {
+        let poll = PollWithOptions::tally(
+            poll_item(1, "poll", 1),
+            vec![poll_item(2, "pollopt", 0), poll_item(3, "pollopt", 0)],
+        );
+
+        assert_eq!(poll.total_votes, 0);
+        assert!(poll.options.iter().all(|o| o.percent == 0.0));
+        assert_eq!(poll.options[0].id, 2);
+
+        let empty = PollWithOptions::tally(poll_item(1, "poll", 1), vec![]);
+        assert_eq!(empty.total_votes, 0);
+        assert!(empty.options.is_empty());
+    }
+
+    // ===== HNItem::domain Tests =====
+
+    fn item_with_url(url: Option<&str>) -> HNItem {
+        let raw: RawHNItem = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "type": "story",
+            "url": url
+        }))
+        .unwrap();
+        raw.into()
+    }
+
+    #[test]
+    fn hn_item_domain_strips_www() {
+        let item = item_with_url(Some("https://www.example.com/post?id=1"));
+        assert_eq!(item.domain(), Some("example.com".to_string()));
+    }
+
+    #[test]
+    fn hn_item_domain_keeps_subdomains() {
+        let item = item_with_url(Some("https://blog.rust-lang.org/2024/01/01/post.html"));
+        assert_eq!(item.domain(), Some("blog.rust-lang.org".to_string()));
+    }
+
+    #[test]
+    fn hn_item_domain_none_for_text_posts_and_bad_urls() {
+        assert_eq!(item_with_url(None).domain(), None);
+        assert_eq!(item_with_url(Some("not a url")).domain(), None);
+    }
+
+    // ===== Ask/Show HN Tests =====
+
+    fn self_post(title: &str, url: Option<&str>, text: Option<&str>) -> HNItem {
+        let raw: RawHNItem = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "type": "story",
+            "title": title,
+            "url": url,
+            "text": text
+        }))
+        .unwrap();
+        raw.into()
+    }
+
+    #[test]
+    fn detects_ask_and_show_hn_prefixes() {
+        assert!(self_post("Ask HN: How do you back up your photos?", None, None).is_ask_hn());
+        assert!(self_post("ask hn - best keyboards?", None, None).is_ask_hn());
+        assert!(self_post("Show HN: A tiny Rust web server", None, None).is_show_hn());
+        assert!(self_post("  Show HN – pastel-hn", None, None).is_show_hn());
+
+        let show = self_post("Show HN: Something", None, None);
+        assert!(!show.is_ask_hn());
+        assert!(!self_post("Showing HN some love", None, None).is_show_hn());
+        assert!(!self_post("Why I stopped using Ask HN", None, None).is_ask_hn());
+        assert!(!self_post("Show HN", None, None).is_show_hn());
+    }
+
+    #[test]
+    fn post_kind_is_derived_on_conversion() {
+        let kind = |title: &str, url: Option<&str>, text: Option<&str>| {
+            self_post(title, url, text).post_kind
+        };
+        assert_eq!(
+            kind("Ask HN: Favourite editor?", None, Some("Curious.")),
+            Some(PostKind::AskHn)
+        );
+        assert_eq!(
+            kind("Show HN: pastel-hn", Some("https://example.com"), None),
+            Some(PostKind::ShowHn)
+        );
+        assert_eq!(
+            kind("A story", Some("https://example.com"), None),
+            Some(PostKind::Link)
+        );
+        assert_eq!(
+            kind("Thoughts on Rust", None, Some("So...")),
+            Some(PostKind::Text)
+        );
+        assert_eq!(kind("Empty url", Some(""), None), Some(PostKind::Text));
+
+        let item = |item_type: &str| {
+            let raw: RawHNItem = serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "type": item_type,
+                "title": "Acme (YC W24) is hiring",
+                "url": "https://example.com/jobs"
+            }))
+            .unwrap();
+            HNItem::from(raw).post_kind
+        };
+        assert_eq!(item("job"), Some(PostKind::Job));
+        assert_eq!(item("poll"), Some(PostKind::Poll));
+        assert_eq!(item("comment"), None);
+        assert_eq!(item("pollopt"), None);
+    }
+
+    #[test]
+    fn primary_link_prefers_url() {
+        let item = self_post(
+            "Show HN: My project",
+            Some("https://example.com"),
+            Some(r#"<a href="https:&#x2F;&#x2F;other.org">other</a>"#),
+        );
+        assert_eq!(item.primary_link(), Some("https://example.com".to_string()));
+    }
+
+    #[test]
+    fn primary_link_extracts_first_web_link_from_show_hn_text() {
+        let item = self_post(
+            "Show HN: My project",
+            None,
+            Some(concat!(
+                r#"Hi HN! Email <a href="mailto:me@example.com">me</a>.<p>"#,
+                r#"Code: <a href="https:&#x2F;&#x2F;github.com&#x2F;me&#x2F;proj" rel="nofollow">https:&#x2F;&#x2F;github.com&#x2F;me&#x2F;proj</a>"#,
+                r#" and demo <a href="https:&#x2F;&#x2F;proj.dev">proj.dev</a>"#
+            )),
+        );
+        assert_eq!(
+            item.primary_link(),
+            Some("https://github.com/me/proj".to_string())
+        );
+    }
+
+    #[test]
+    fn primary_link_none_for_ask_hn_and_linkless_text() {
+        let ask = self_post(
+            "Ask HN: Thoughts on https://example.com?",
+            None,
+            Some(r#"See <a href="https:&#x2F;&#x2F;example.com">this</a>"#),
+        );
+        assert_eq!(ask.primary_link(), None);
+
+        let show = self_post("Show HN: No links", None, Some("Just text"));
+        assert_eq!(show.primary_link(), None);
+    }
+
+    // ===== ArticleContent Reading Time Tests =====
+
+    fn article_with_words(word_count: usize) -> ArticleContent {
+        ArticleContent {
+            title: None,
+            content: String::new(),
+            text_content: String::new(),
+            byline: None,
+            excerpt: None,
+            site_name: None,
+            lang: None,
+            word_count,
+            headings: vec![],
+            code_blocks: vec![],
+            links: vec![],
+            confidence: 1.0,
+            user_agent: None,
+        }
+    }
+
+    #[test]
+    fn reading_time_rounds_up_to_whole_minutes() {
+        assert_eq!(article_with_words(200).reading_time_minutes(), 1);
+        assert_eq!(article_with_words(201).reading_time_minutes(), 2);
+        assert_eq!(article_with_words(1000).reading_time_minutes(), 5);
+    }
+
+    #[test]
+    fn reading_time_is_at_least_one_minute() {
+        assert_eq!(article_with_words(0).reading_time_minutes(), 1);
+    }
 }
@@ -5,6 +5,12 @@ vi.mock('@tauri-apps/api/core', () => ({
   invoke: vi.fn(),
 }))
 
+// Mock Tauri's event listener
+const mockListen = vi.fn()
+vi.mock('@tauri-apps/api/event', () => ({
+  listen: (...args: unknown[]) => mockListen(...args),
+}))
+
 import { invoke } from '@tauri-apps/api/core'
 import {
   backgroundRefreshFeed,
@@ -22,6 +28,8 @@ import {
   getCacheStats,
   getInFlightRequestCount,
   isFeedStale,
+  listenForNewStories,
+  mergedFeedPager,
   notifyFeedRefresh,
   onFeedRefresh,
   searchHN,
@@ -138,6 +146,37 @@ describe('api', () => {
     })
   })
 
+  describe('mergedFeedPager', () => {
+    it('starts each page at the previous nextOffset', async () => {
+      mockInvoke
+        .mockResolvedValueOnce({
+          stories: [{ id: 1 }, { id: 3 }],
+          hasMore: true,
+          total: 5,
+          nextOffset: 3,
+        })
+        .mockResolvedValueOnce({
+          stories: [{ id: 4 }],
+          hasMore: false,
+          total: 5,
+          nextOffset: 5,
+        })
+
+      const pager = mergedFeedPager(['top', 'show'], 2)
+      expect(await pager.next()).toHaveLength(2)
+      expect(await pager.next()).toHaveLength(1)
+
+      expect(mockInvoke).toHaveBeenLastCalledWith('fetch_merged', {
+        feeds: ['top', 'show'],
+        offset: 3,
+        limit: 2,
+      })
+      expect(pager.hasMore).toBe(false)
+      expect(await pager.next()).toEqual([])
+      expect(mockInvoke).toHaveBeenCalledTimes(2)
+    })
+  })
+
   describe('fetchStoryWithComments', () => {
     it('fetches story and its comments with depth', async () => {
       const mockResponse = {
@@ -222,6 +261,18 @@ describe('api', () => {
       expect(result.story.id).toBe(200)
       expect(result.comments).toHaveLength(0)
     })
+
+    it('uses the global default depth for saved stories', async () => {
+      mockInvoke.mockResolvedValueOnce({ story: { id: 300 }, comments: [] })
+
+      await fetchStoryWithComments(300, undefined, 'saved')
+
+      expect(mockInvoke).toHaveBeenCalledWith('fetch_story_with_comments', {
+        id: 300,
+        depth: undefined,
+        feed: undefined,
+      })
+    })
   })
 
   describe('searchHN', () => {
@@ -790,6 +841,42 @@ describe('api', () => {
       })
     })
 
+    describe('listenForNewStories', () => {
+      afterEach(() => {
+        delete (window as unknown as Record<string, unknown>).__TAURI__
+      })
+
+      it('does nothing outside Tauri', async () => {
+        await listenForNewStories(vi.fn())
+
+        expect(mockListen).not.toHaveBeenCalled()
+      })
+
+      it('reports new story counts from both refresh events', async () => {
+        ;(window as unknown as Record<string, unknown>).__TAURI__ = {}
+        const handlers = new Map<
+          string,
+          (event: { payload: unknown }) => void
+        >()
+        mockListen.mockImplementation(async (name, handler) => {
+          handlers.set(name, handler)
+          return vi.fn()
+        })
+        const callback = vi.fn()
+
+        await listenForNewStories(callback)
+        handlers.get('feed-refreshed')?.({
+          payload: { feed: 'top', changed: true, newCount: 2 },
+        })
+        handlers.get('feed-updated')?.({
+          payload: { feed: 'new', ids: [3, 2, 1], deltas: [], newCount: 1 },
+        })
+
+        expect(callback).toHaveBeenCalledWith('top', 2)
+        expect(callback).toHaveBeenCalledWith('new', 1)
+      })
+    })
+
     describe('triggerBackgroundRefreshIfStale', () => {
       it('returns false when feed is not stale', async () => {
         mockInvoke.mockResolvedValueOnce(false) // isFeedStale returns false
@@ -10,10 +10,20 @@
 
 import { invoke } from '@tauri-apps/api/core'
 import type {
+  AppSettings,
+  CacheSnapshotCounts,
   CacheStats,
+  CommentChildrenResponse,
+  CommentContext,
   CommentWithChildren,
+  DomainCount,
+  FeedAge,
+  FeedRefreshed,
+  FeedUpdate,
   HNItem,
   HNUser,
+  PositionDelta,
+  ReadingStats,
   StoryFeed,
   StoryWithComments,
 } from './types'
@@ -76,12 +86,22 @@ export interface StoriesResponse {
   stories: HNItem[]
   hasMore: boolean
   total: number
+  /**
+   * Offset of the next page. Can be past `offset + limit` when dead stories
+   * were skipped, so always page with this rather than counting stories
+   */
+  nextOffset: number
 }
 
 export interface SubmissionsResponse {
   items: HNItem[]
   hasMore: boolean
   total: number
+  /**
+   * Offset of the next page. Filtered pages scan more submissions than they
+   * return, so always page with this rather than counting items
+   */
+  nextOffset: number
 }
 
 export type SubmissionFilter = 'all' | 'stories' | 'comments'
@@ -101,6 +121,94 @@ export async function fetchStoriesPaginated(
   )
 }
 
+export type RankFormula =
+  | { type: 'gravity'; g: number }
+  | { type: 'scorePerHour' }
+  | { type: 'commentsPerHour' }
+
+export interface RankedStoriesResponse extends StoriesResponse {
+  /** Story IDs in the feed's original order */
+  feedOrder: number[]
+}
+
+/**
+ * Fetch a page of a feed re-ordered by a client-side ranking formula
+ */
+export async function fetchStoriesRanked(
+  feed: StoryFeed,
+  offset: number,
+  limit: number,
+  formula: RankFormula,
+): Promise<RankedStoriesResponse> {
+  const formulaKey =
+    formula.type === 'gravity' ? `gravity:${formula.g}` : formula.type
+  return deduplicatedInvoke<RankedStoriesResponse>(
+    `ranked:${feed}:${offset}:${limit}:${formulaKey}`,
+    'fetch_stories_ranked',
+    { feed, offset, limit, formula },
+  )
+}
+
+/**
+ * Fetch paginated stories from several feeds blended round-robin, with
+ * stories on more than one feed shown once. Continue from the response's
+ * `nextOffset` (or use `mergedFeedPager`)
+ */
+export async function fetchMerged(
+  feeds: StoryFeed[],
+  offset: number,
+  limit: number,
+): Promise<StoriesResponse> {
+  return deduplicatedInvoke<StoriesResponse>(
+    `merged:${feeds.join(',')}:${offset}:${limit}`,
+    'fetch_merged',
+    { feeds, offset, limit },
+  )
+}
+
+export interface FeedPager {
+  /** Whether another page may follow */
+  readonly hasMore: boolean
+  /** Fetch the next page; empty once the feed is exhausted */
+  next(): Promise<HNItem[]>
+}
+
+/**
+ * Page through a merged feed, starting each page at the previous page's
+ * `nextOffset`
+ */
+export function mergedFeedPager(
+  feeds: StoryFeed[],
+  pageSize: number,
+): FeedPager {
+  let offset = 0
+  let hasMore = true
+
+  return {
+    get hasMore() {
+      return hasMore
+    },
+    async next() {
+      if (!hasMore) return []
+      const page = await fetchMerged(feeds, offset, pageSize)
+      offset = page.nextOffset
+      hasMore = page.hasMore
+      return page.stories
+    },
+  }
+}
+
+/**
+ * Rank the sites linked from a feed's top `sample` stories, most frequent
+ * first
+ */
+export async function topDomains(
+  feed: StoryFeed,
+  sample?: number,
+): Promise<DomainCount[]> {
+  return invoke<DomainCount[]>('top_domains', { feed, sample })
+}
+
 /**
  * Fetch stories (convenience wrapper for backward compatibility)
  */
@@ -119,6 +227,24 @@ export async function fetchItem(id: number): Promise<HNItem> {
   return deduplicatedInvoke<HNItem>(`item:${id}`, 'fetch_item', { id })
 }
 
+/**
+ * Reload a single item from the network, bypassing the backend cache
+ */
+export async function reloadItem(id: number): Promise<HNItem> {
+  return deduplicatedInvoke<HNItem>(`item-fresh:${id}`, 'fetch_item', {
+    id,
+    force: true,
+  })
+}
+
+/**
+ * Open a story in its own window (focuses it if already open).
+ * Rejects when the maximum number of story windows is open.
+ */
+export async function openStoryWindow(id: number): Promise<void> {
+  await invoke('open_story_window', { id })
+}
+
 /**
  * Fetch multiple items by IDs
  * Note: IDs are sorted to normalize cache keys - [1,2,3] and [3,2,1] share same request
@@ -129,31 +255,164 @@ export async function fetchItems(ids: number[]): Promise<HNItem[]> {
   return deduplicatedInvoke<HNItem[]>(cacheKey, 'fetch_items', { ids })
 }
 
+export interface BatchResult {
+  items: HNItem[]
+  /** [id, reason] for each ID that failed, including missing items */
+  errors: [number, string][]
+}
+
+/**
+ * Fetch multiple items, keeping the successes when some fail
+ */
+export async function fetchItemsDetailed(ids: number[]): Promise<BatchResult> {
+  return invoke<BatchResult>('fetch_items_detailed', { ids })
+}
+
+export interface PollOption {
+  id: number
+  text: string | null
+  votes: number
+  /** Share of all votes, 0-100 */
+  percent: number
+}
+
+export interface PollWithOptions {
+  poll: HNItem
+  /** Most votes first */
+  options: PollOption[]
+  totalVotes: number
+}
+
+/**
+ * Fetch a poll with vote tallies for its options
+ */
+export async function fetchPoll(id: number): Promise<PollWithOptions> {
+  return deduplicatedInvoke<PollWithOptions>(`poll:${id}`, 'fetch_poll', {
+    id,
+  })
+}
+
 /**
  * Fetch a story with its comments
+ *
+ * Without a depth, the backend uses the configured default for `feed`
+ * (see `setDefaultCommentDepth`).
  */
 export async function fetchStoryWithComments(
   id: number,
-  depth = 2,
+  depth?: number,
+  feed?: StoryFeed,
 ): Promise<StoryWithComments> {
+  // Saved stories are kept locally; the backend has no setting for them
+  const settingsFeed = feed === 'saved' ? undefined : feed
   return deduplicatedInvoke<StoryWithComments>(
-    `story:${id}:${depth}`,
+    `story:${id}:${depth ?? `default:${settingsFeed ?? ''}`}`,
     'fetch_story_with_comments',
-    { id, depth },
+    { id, depth, feed: settingsFeed },
   )
 }
 
+/**
+ * Fetch several stories with budgeted comment trees in one call, e.g. to
+ * prefetch a read-later list for offline reading
+ *
+ * Each story gets at most `maxNodes` comments (default 50), loaded
+ * breadth-first. Missing or deleted stories are omitted.
+ */
+export async function fetchStoriesWithComments(
+  ids: number[],
+  depth?: number,
+  maxNodes?: number,
+): Promise<StoryWithComments[]> {
+  return invoke<StoryWithComments[]>('fetch_stories_with_comments', {
+    ids,
+    depth,
+    maxNodes,
+  })
+}
+
+/**
+ * Get the persisted backend settings
+ */
+export async function getSettings(): Promise<AppSettings> {
+  return invoke<AppSettings>('get_settings')
+}
+
+/**
+ * Set the default comment depth used when `fetchStoryWithComments` gets no
+ * depth. Pass a feed to set its override; pass no depth to clear it.
+ */
+export async function setDefaultCommentDepth(
+  depth?: number,
+  feed?: StoryFeed,
+): Promise<AppSettings> {
+  return invoke<AppSettings>('set_default_comment_depth', { feed, depth })
+}
+
 /**
  * Fetch children of a specific comment (for "load more")
+ *
+ * Pass `offset`/`limit` to page through very wide threads; use
+ * `fetchCommentChildrenPage` when the caller needs `hasMore`.
  */
 export async function fetchCommentChildren(
   id: number,
   depth = 2,
+  offset?: number,
+  limit?: number,
 ): Promise<CommentWithChildren[]> {
-  return deduplicatedInvoke<CommentWithChildren[]>(
-    `comments:${id}:${depth}`,
+  const page = await fetchCommentChildrenPage(id, depth, offset, limit)
+  return page.comments
+}
+
+/**
+ * Fetch a comment with its parent chain up to the story, e.g. to show a
+ * comment search hit in its thread
+ */
+export async function fetchCommentContext(
+  commentId: number,
+): Promise<CommentContext> {
+  return deduplicatedInvoke<CommentContext>(
+    `comment-context:${commentId}`,
+    'fetch_comment_context',
+    { commentId },
+  )
+}
+
+/**
+ * Count comments posted on a story since it was last seen. Without a
+ * baseline, the count recorded by `markCommentsSeen` is used.
+ */
+export async function newCommentsSince(
+  storyId: number,
+  lastDescendants?: number,
+): Promise<number> {
+  return invoke<number>('new_comments_since', { storyId, lastDescendants })
+}
+
+/**
+ * Remember a story's comment count as seen
+ */
+export async function markCommentsSeen(
+  storyId: number,
+  descendants: number,
+): Promise<void> {
+  return invoke<void>('mark_comments_seen', { storyId, descendants })
+}
+
+/**
+ * Fetch a page of a comment's direct children with pagination info
+ */
+export async function fetchCommentChildrenPage(
+  id: number,
+  depth = 2,
+  offset?: number,
+  limit?: number,
+): Promise<CommentChildrenResponse> {
+  return deduplicatedInvoke<CommentChildrenResponse>(
+    `comments:${id}:${depth}:${offset ?? 0}:${limit ?? 'all'}`,
     'fetch_comment_children',
-    { id, depth },
+    { id, depth, offset, limit },
   )
 }
 
@@ -201,6 +460,7 @@ export interface SearchResponse {
   nbHits: number
   page: number
   nbPages: number
+  /** Page size actually used, after the backend's default and cap */
   hitsPerPage: number
   query: string
 }
@@ -208,6 +468,23 @@ export interface SearchResponse {
 export type SearchSort = 'relevance' | 'date'
 export type SearchFilter = 'all' | 'story' | 'comment'
 
+/**
+ * A user's comments, newest first, via Algolia (30 per page).
+ *
+ * Faster than fetchUserSubmissions for prolific users, though the newest
+ * comments may not be indexed yet.
+ */
+export async function fetchUserCommentsSearch(
+  user: string,
+  page = 0,
+): Promise<SearchResponse> {
+  return deduplicatedInvoke<SearchResponse>(
+    `user-comments:${user}:${page}`,
+    'fetch_user_comments_search',
+    { user, page },
+  )
+}
+
 /**
  * Search HN using Algolia
  */
@@ -237,6 +514,28 @@ export async function searchHN(
   })
 }
 
+export interface SearchHistoryEntry {
+  query: string
+  sort: SearchSort
+  filter: SearchFilter
+  /** Unix milliseconds */
+  searchedAt: number
+}
+
+/**
+ * Recent searches, most recent first
+ */
+export async function getSearchHistory(): Promise<SearchHistoryEntry[]> {
+  return invoke<SearchHistoryEntry[]>('get_search_history')
+}
+
+/**
+ * Forget all recent searches
+ */
+export async function clearSearchHistory(): Promise<void> {
+  return invoke<void>('clear_search_history')
+}
+
 // ===== Article Content Extraction =====
 
 import type { ArticleContent } from './types'
@@ -254,6 +553,63 @@ export async function fetchArticleContent(
   )
 }
 
+/**
+ * Set the largest article download (in bytes) reader mode accepts
+ */
+export async function setMaxArticleBytes(bytes: number): Promise<void> {
+  return invoke('set_max_article_bytes', { bytes })
+}
+
+/**
+ * Start the backend timer that refreshes every feed each `intervalSecs`
+ * (minimum 30), or stop it with `null`
+ */
+export async function setAutoRefresh(
+  intervalSecs: number | null,
+): Promise<void> {
+  return invoke('set_auto_refresh', { intervalSecs })
+}
+
+/**
+ * Leave links back to an article's own site out of its `links`
+ */
+export async function setExcludeSameHostLinks(
+  enabled: boolean,
+): Promise<void> {
+  return invoke('set_exclude_same_host_links', { enabled })
+}
+
+/**
+ * Reader-mode content for a story: its linked article, or for self-posts
+ * (Ask HN, text stories), its own text
+ */
+export async function fetchReaderContent(id: number): Promise<ArticleContent> {
+  return deduplicatedInvoke<ArticleContent>(
+    `reader:${id}`,
+    'fetch_reader_content',
+    { id },
+  )
+}
+
+export interface DigestEntry {
+  item: HNItem
+  domain: string | null
+  /** Present when the article could be extracted */
+  readingTimeMinutes: number | null
+  excerpt: string | null
+}
+
+/**
+ * Top front-page stories with article previews, for the dashboard
+ */
+export async function fetchFrontPageDigest(count = 10): Promise<DigestEntry[]> {
+  return deduplicatedInvoke<DigestEntry[]>(
+    `digest:${count}`,
+    'fetch_front_page_digest',
+    { count },
+  )
+}
+
 // ===== Cache Management =====
 
 /**
@@ -277,8 +633,108 @@ export async function getCacheStats(): Promise<CacheStats> {
   return invoke('get_cache_stats')
 }
 
+/**
+ * Enable or disable `cache-evicted` events (batches of `CacheEviction`),
+ * for debugging
+ */
+export async function setCacheEvictionEvents(enabled: boolean): Promise<void> {
+  return invoke('set_cache_eviction_events', { enabled })
+}
+
+/**
+ * Write the backend's item, story ID, and user caches to a file
+ */
+export async function saveCacheSnapshot(
+  path: string,
+): Promise<CacheSnapshotCounts> {
+  return invoke<CacheSnapshotCounts>('save_cache_snapshot', { path })
+}
+
+/**
+ * Restore the backend caches from a snapshot file, skipping expired entries
+ */
+export async function loadCacheSnapshot(
+  path: string,
+): Promise<CacheSnapshotCounts> {
+  return invoke<CacheSnapshotCounts>('load_cache_snapshot', { path })
+}
+
 // ===== Background Refresh =====
 
+/**
+ * Warm the backend item cache with the first `count` items of a feed.
+ * Returns the number of items newly cached.
+ */
+export async function warmFeed(
+  feed: StoryFeed,
+  count?: number,
+): Promise<number> {
+  return invoke<number>('warm_feed', { feed, count })
+}
+
+/**
+ * Prime the backend's connections to the HN and Algolia hosts. Fire and
+ * forget; the backend already does this once at startup.
+ */
+export async function warmConnections(): Promise<void> {
+  return invoke('warm_connections')
+}
+
+/**
+ * Prefetch shallow comment trees for the on-screen stories, so opening any
+ * of them is instant. Fire and forget; a newer call cancels one still
+ * running.
+ */
+export async function prefetchComments(
+  ids: number[],
+  depth?: number,
+  maxNodes?: number,
+): Promise<void> {
+  return invoke('prefetch_comments', { ids, depth, maxNodes })
+}
+
+/**
+ * Count an article read in reader mode
+ */
+export async function recordArticleRead(wordCount: number): Promise<void> {
+  return invoke<void>('record_article_read', { wordCount })
+}
+
+/**
+ * Count an opened story
+ */
+export async function recordStoryOpened(): Promise<void> {
+  return invoke<void>('record_story_opened')
+}
+
+/**
+ * Record a reading session's duration
+ */
+export async function recordSession(durationSecs: number): Promise<void> {
+  return invoke<void>('record_session', { durationSecs })
+}
+
+/**
+ * Get reading totals, daily buckets, and the current streak
+ */
+export async function getReadingStats(): Promise<ReadingStats> {
+  return invoke<ReadingStats>('get_reading_stats')
+}
+
+/**
+ * Forget all recorded reading activity
+ */
+export async function resetReadingStats(): Promise<void> {
+  return invoke<void>('reset_reading_stats')
+}
+
+/**
+ * When each feed was last fetched, for "updated 2 minutes ago" labels
+ */
+export async function getFeedAges(): Promise<FeedAge[]> {
+  return invoke<FeedAge[]>('get_feed_ages')
+}
+
 /**
  * Check if a feed's cached data is stale (needs background refresh)
  */
@@ -296,6 +752,16 @@ export async function backgroundRefreshFeed(
   return invoke<number[] | null>('background_refresh_feed', { feed })
 }
 
+/**
+ * Get how stories moved in a feed when its IDs last changed, e.g. after
+ * `backgroundRefreshFeed` returns new IDs
+ */
+export async function feedPositionDeltas(
+  feed: StoryFeed,
+): Promise<PositionDelta[]> {
+  return invoke<PositionDelta[]>('feed_position_deltas', { feed })
+}
+
 /**
  * Callback type for when a feed is refreshed in the background
  */
@@ -326,6 +792,39 @@ export function notifyFeedRefresh(feed: StoryFeed, newIds: number[]): void {
   }
 }
 
+/**
+ * Callback for backend-announced refreshes that changed a feed
+ */
+export type NewStoriesCallback = (feed: StoryFeed, newCount: number) => void
+
+/**
+ * Listen for feeds changed by backend background refreshes: `feed-refreshed`
+ * from one-off refreshes and `feed-updated` from the refresh timer. Each
+ * change is announced by one of the two. Returns an unlisten function; does
+ * nothing outside Tauri
+ */
+export async function listenForNewStories(
+  callback: NewStoriesCallback,
+): Promise<() => void> {
+  if (typeof window === 'undefined' || !('__TAURI__' in window)) {
+    return () => {}
+  }
+
+  const { listen } = await import('@tauri-apps/api/event')
+  const unlistenRefreshed = await listen<FeedRefreshed>(
+    'feed-refreshed',
+    (event) => callback(event.payload.feed, event.payload.newCount),
+  )
+  const unlistenUpdated = await listen<FeedUpdate>('feed-updated', (event) =>
+    callback(event.payload.feed, event.payload.newCount),
+  )
+
+  return () => {
+    unlistenRefreshed()
+    unlistenUpdated()
+  }
+}
+
 /**
  * Trigger background refresh if feed is stale and notify on completion.
  * This is the main entry point for initiating background refresh.
@@ -403,10 +902,12 @@ export async function fetchStoryIds(
 
 /**
  * Fetch comments for an item (deprecated, use fetchStoryWithComments)
+ *
+ * Without a depth, the backend uses the configured default.
  */
 export async function fetchComments(
   item: HNItem,
-  depth = 2,
+  depth?: number,
 ): Promise<CommentWithChildren[]> {
   if (!item.kids || item.kids.length === 0) {
     return []
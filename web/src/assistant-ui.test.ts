@@ -2,6 +2,7 @@ import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest'
 import {
   CONTEXT_MENU_HEIGHT,
   CONTEXT_MENU_WIDTH,
+  countDescendants,
   getContextMenu,
   handleTextSelection,
   hideContextMenu,
@@ -11,6 +12,7 @@ import {
   toggleAssistant,
   updateAssistantZenMode,
 } from './assistant-ui'
+import type { CommentWithChildren, ItemType } from './types'
 
 // Mock copilot-client module
 vi.mock('./copilot-client', () => ({
@@ -578,4 +580,51 @@ describe('assistant-ui', () => {
       })
     })
   })
+
+  describe('countDescendants', () => {
+    const comment = (
+      id: number,
+      kids: number[] | null,
+      children?: CommentWithChildren[],
+    ): CommentWithChildren => ({
+      id,
+      type: 1 as ItemType,
+      by: 'user',
+      time: 0,
+      text: '',
+      url: null,
+      score: 0,
+      title: null,
+      descendants: 0,
+      kids,
+      parent: null,
+      dead: false,
+      deleted: false,
+      children,
+    })
+
+    it('counts loaded replies at every depth', () => {
+      const tree = comment(
+        1,
+        [2, 3],
+        [comment(2, [4], [comment(4, null, [])]), comment(3, null, [])],
+      )
+
+      expect(countDescendants(tree)).toBe(3)
+    })
+
+    it('counts kids for comments at the depth limit', () => {
+      // Past the depth limit the backend sends an empty children array
+      const kids = Array.from({ length: 40 }, (_, i) => i + 2)
+      const atLimit = comment(1, kids, [])
+
+      expect(countDescendants(atLimit)).toBe(40)
+    })
+
+    it('adds unloaded replies of deeper comments', () => {
+      const tree = comment(1, [2], [comment(2, [3, 4, 5], [])])
+
+      expect(countDescendants(tree)).toBe(4)
+    })
+  })
 })
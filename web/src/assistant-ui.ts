@@ -431,7 +431,8 @@ async function handleAnalyzeDiscussion(): Promise<void> {
     .map((c) => ({
       author: c.by ?? 'unknown',
       text_preview: stripHtml(c.text ?? '').slice(0, 200),
-      reply_count: c.children?.length ?? 0,
+      reply_count: c.children?.length || (c.kids?.length ?? 0),
+      descendant_count: countDescendants(c),
     }))
 
   const context: DiscussionContext = {
@@ -574,6 +575,19 @@ function setupKeyboardShortcut(): void {
 // Utility Functions - Text Processing
 // ============================================================================
 
+/**
+ * Count replies at any depth in a loaded comment tree. The backend sends an
+ * empty `children` array for comments past the depth limit, so those count
+ * their direct replies from `kids`
+ */
+export function countDescendants(comment: CommentWithChildren): number {
+  if (!comment.children?.length) return comment.kids?.length ?? 0
+  return comment.children.reduce(
+    (total, child) => total + 1 + countDescendants(child),
+    0,
+  )
+}
+
 /**
  * Strip HTML tags from a string, returning plain text
  * @param html - HTML string to strip
@@ -145,6 +145,23 @@ describe('CopilotClient', () => {
       expect(client.isAvailable()).toBe(true)
     })
 
+    it('defaults responses to the UI language', async () => {
+      vi.spyOn(navigator, 'language', 'get').mockReturnValue('fr-FR')
+      mockInvoke.mockResolvedValueOnce({
+        available: true,
+        running: true,
+        cli_installed: true,
+        cli_authenticated: true,
+        message: 'Service started',
+      })
+
+      await client.init()
+
+      expect(mockInvoke).toHaveBeenCalledWith('copilot_set_output_language', {
+        language: 'fr-FR',
+      })
+    })
+
     it('handles init failure gracefully', async () => {
       mockInvoke.mockRejectedValueOnce(new Error('Auth failed'))
 
@@ -34,6 +34,8 @@ export interface CommentSummary {
   author: string
   text_preview: string
   reply_count: number
+  /** Replies at any depth, standing in for engagement */
+  descendant_count?: number
 }
 
 /** Context about a discussion thread */
@@ -51,9 +53,34 @@ export interface ReplyContext {
   user_draft: string | null
 }
 
+/** Summary split into fields (structured summarize mode) */
+export interface StructuredSummary {
+  tldr: string
+  key_points: string[]
+  sentiment: string
+}
+
 /** Response from the AI assistant */
 export interface AssistantResponse {
   content: string
+  /** Present when a structured summary was requested and parsed */
+  structured?: StructuredSummary
+  /** For discussion analysis, how many comments were sent to the model */
+  comments_included?: number
+}
+
+/** Result of a Copilot health-check ping */
+export interface PingResult {
+  ok: boolean
+  latency_ms: number
+  error?: string
+}
+
+/** Payload of the `copilot-queue` event */
+export interface CopilotQueueEvent {
+  requestId: number
+  /** Requests ahead of this one; 0 once it starts */
+  position: number
 }
 
 /** Default unavailable status for non-Tauri environments */
@@ -137,6 +164,8 @@ export class CopilotClient {
       this.initialized = true
       this.available = status.available
       this.lastStatus = status
+      // Answer in the UI language unless a request names another
+      await this.setOutputLanguage(navigator.language || null)
       return status
     } catch (error) {
       console.error('Failed to initialize Copilot:', error)
@@ -149,6 +178,22 @@ export class CopilotClient {
     }
   }
 
+  /**
+   * Measure assistant round-trip latency; `ok` is false when slow or hung
+   */
+  async ping(): Promise<PingResult | null> {
+    if (!this.available) {
+      return null
+    }
+
+    try {
+      return await invoke<PingResult>('copilot_ping')
+    } catch (error) {
+      console.error('Copilot ping error:', error)
+      return null
+    }
+  }
+
   /**
    * Summarize an article based on story context
    */
@@ -166,11 +211,35 @@ export class CopilotClient {
     }
   }
 
+  /**
+   * Summarize an article as `{ tldr, key_points, sentiment }`.
+   * Falls back to plain `content` when the model's reply can't be parsed.
+   */
+  async summarizeStructured(
+    context: StoryContext,
+  ): Promise<AssistantResponse | null> {
+    if (!this.available) {
+      console.warn('Copilot not available')
+      return null
+    }
+
+    try {
+      return await invoke<AssistantResponse>('copilot_summarize', {
+        context,
+        structured: true,
+      })
+    } catch (error) {
+      console.error('Copilot summarize error:', error)
+      return null
+    }
+  }
+
   /**
    * Analyze a discussion thread
    */
   async analyzeDiscussion(
     context: DiscussionContext,
+    maxComments?: number,
   ): Promise<AssistantResponse | null> {
     if (!this.available) {
       return null
@@ -179,6 +248,7 @@ export class CopilotClient {
     try {
       return await invoke<AssistantResponse>('copilot_analyze_discussion', {
         context,
+        ...(maxComments !== undefined && { maxComments }),
       })
     } catch (error) {
       console.error('Copilot analyze discussion error:', error)
@@ -240,6 +310,98 @@ export class CopilotClient {
     }
   }
 
+  /**
+   * Set the language responses use when a request doesn't name one
+   * (e.g. the UI language, `navigator.language`)
+   */
+  async setOutputLanguage(language: string | null): Promise<void> {
+    if (!isTauri()) {
+      return
+    }
+
+    try {
+      await invoke<void>('copilot_set_output_language', { language })
+    } catch (error) {
+      console.error('Copilot set output language error:', error)
+    }
+  }
+
+  /**
+   * Enable or disable the local prompt/response transcript (unredacted)
+   */
+  async setTranscriptLogging(enabled: boolean): Promise<void> {
+    if (!isTauri()) {
+      return
+    }
+
+    try {
+      await invoke<void>('copilot_set_transcript_logging', { enabled })
+    } catch (error) {
+      console.error('Copilot set transcript logging error:', error)
+    }
+  }
+
+  /**
+   * Get the transcript file path
+   */
+  async getTranscriptPath(): Promise<string | null> {
+    if (!isTauri()) {
+      return null
+    }
+
+    try {
+      return await invoke<string>('copilot_get_transcript_path')
+    } catch (error) {
+      console.error('Copilot get transcript path error:', error)
+      return null
+    }
+  }
+
+  /**
+   * Delete the transcript file
+   */
+  async clearTranscript(): Promise<void> {
+    if (!isTauri()) {
+      return
+    }
+
+    try {
+      await invoke<void>('copilot_clear_transcript')
+    } catch (error) {
+      console.error('Copilot clear transcript error:', error)
+    }
+  }
+
+  /**
+   * Limit how many requests may be running or waiting at once
+   */
+  async setQueueDepth(depth: number): Promise<void> {
+    if (!isTauri()) {
+      return
+    }
+
+    try {
+      await invoke<void>('copilot_set_queue_depth', { depth })
+    } catch (error) {
+      console.error('Copilot set queue depth error:', error)
+    }
+  }
+
+  /**
+   * Set how many times a request is retried after a transient error
+   */
+  async setMaxRetries(retries: number): Promise<void> {
+    if (!isTauri()) {
+      return
+    }
+
+    try {
+      await invoke<void>('copilot_set_max_retries', { retries })
+    } catch (error) {
+      console.error('Copilot set max retries error:', error)
+    }
+  }
+
   /**
    * Shutdown the Copilot service
    */
@@ -2,20 +2,24 @@
  * Tests for deep-link.ts module.
  */
 import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest'
-import { configureDeepLinks, handleDeepLink, parseDeepLink } from './deep-link'
+import { configureDeepLinks, initDeepLinks, routeDeepLink } from './deep-link'
 
-vi.mock('@tauri-apps/plugin-deep-link', () => ({
-  getCurrent: vi.fn().mockResolvedValue(null),
-  onOpenUrl: vi.fn().mockResolvedValue(undefined),
-}))
+const mockListen = vi.fn()
+const mockInvoke = vi.fn()
 
 vi.mock('@tauri-apps/api/event', () => ({
-  listen: vi.fn().mockResolvedValue(() => {}),
+  listen: (...args: unknown[]) => mockListen(...args),
+}))
+
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: (...args: unknown[]) => mockInvoke(...args),
 }))
 
 describe('deep-link', () => {
   beforeEach(() => {
     vi.clearAllMocks()
+    mockListen.mockResolvedValue(() => {})
+    mockInvoke.mockResolvedValue(undefined)
     vi.spyOn(console, 'log').mockImplementation(() => {})
     vi.spyOn(console, 'warn').mockImplementation(() => {})
   })
@@ -24,69 +28,6 @@ describe('deep-link', () => {
     vi.restoreAllMocks()
   })
 
-  describe('parseDeepLink', () => {
-    it('should parse item URL with numeric ID', () => {
-      const result = parseDeepLink('pastelhn://item/12345')
-      expect(result).toEqual({ type: 'item', id: 12345 })
-    })
-
-    it('should parse story URL (alias for item)', () => {
-      const result = parseDeepLink('pastelhn://story/67890')
-      expect(result).toEqual({ type: 'item', id: 67890 })
-    })
-
-    it('should parse user URL', () => {
-      const result = parseDeepLink('pastelhn://user/dang')
-      expect(result).toEqual({ type: 'user', username: 'dang' })
-    })
-
-    it('should parse feed URL', () => {
-      const result = parseDeepLink('pastelhn://feed/best')
-      expect(result).toEqual({ type: 'feed', feed: 'best' })
-    })
-
-    it('should return unknown for invalid feed', () => {
-      const result = parseDeepLink('pastelhn://feed/invalid')
-      expect(result.type).toBe('unknown')
-    })
-
-    it('should parse search URL with query param', () => {
-      const result = parseDeepLink('pastelhn://search?q=rust')
-      expect(result).toEqual({ type: 'search', query: 'rust' })
-    })
-
-    it('should parse search URL with path value', () => {
-      const result = parseDeepLink('pastelhn://search/typescript')
-      expect(result).toEqual({ type: 'search', query: 'typescript' })
-    })
-
-    it('should return unknown for invalid item ID', () => {
-      const result = parseDeepLink('pastelhn://item/abc')
-      expect(result.type).toBe('unknown')
-    })
-
-    it('should return unknown for missing user', () => {
-      const result = parseDeepLink('pastelhn://user/')
-      expect(result.type).toBe('unknown')
-    })
-
-    it('should return unknown for unsupported scheme', () => {
-      const result = parseDeepLink('https://example.com')
-      expect(result.type).toBe('unknown')
-    })
-
-    it('should return unknown for malformed URL', () => {
-      const result = parseDeepLink('not a url')
-      expect(result.type).toBe('unknown')
-    })
-
-    it('should handle URL with triple slashes as unknown', () => {
-      // Triple slashes create a malformed URL structure
-      const result = parseDeepLink('pastelhn:///item/12345')
-      expect(result.type).toBe('unknown')
-    })
-  })
-
   describe('configureDeepLinks', () => {
     it('should accept callback configuration', () => {
       const callbacks = {
@@ -104,53 +45,79 @@ describe('deep-link', () => {
     })
   })
 
-  describe('handleDeepLink', () => {
-    it('should call onItem callback for item URLs', () => {
+  describe('routeDeepLink', () => {
+    it('should call onItem callback for item routes', () => {
       const onItem = vi.fn()
       configureDeepLinks({ onItem })
 
-      handleDeepLink('pastelhn://item/12345')
+      routeDeepLink({ type: 'item', id: 12345 })
 
       expect(onItem).toHaveBeenCalledWith(12345)
     })
 
-    it('should call onUser callback for user URLs', () => {
+    it('should call onUser callback for user routes', () => {
       const onUser = vi.fn()
       configureDeepLinks({ onUser })
 
-      handleDeepLink('pastelhn://user/pg')
+      routeDeepLink({ type: 'user', username: 'pg' })
 
       expect(onUser).toHaveBeenCalledWith('pg')
     })
 
-    it('should call onFeed callback for feed URLs', () => {
+    it('should call onFeed callback for feed routes', () => {
       const onFeed = vi.fn()
       configureDeepLinks({ onFeed })
 
-      handleDeepLink('pastelhn://feed/ask')
+      routeDeepLink({ type: 'feed', feed: 'ask' })
 
       expect(onFeed).toHaveBeenCalledWith('ask')
     })
 
-    it('should call onSearch callback for search URLs', () => {
+    it('should call onSearch callback for search routes', () => {
       const onSearch = vi.fn()
       configureDeepLinks({ onSearch })
 
-      handleDeepLink('pastelhn://search?q=javascript')
+      routeDeepLink({ type: 'search', query: 'javascript' })
 
       expect(onSearch).toHaveBeenCalledWith('javascript')
     })
 
-    it('should not throw for unknown URLs', () => {
+    it('should not call callbacks if not configured', () => {
       configureDeepLinks({})
 
-      expect(() => handleDeepLink('pastelhn://unknown/path')).not.toThrow()
+      expect(() => routeDeepLink({ type: 'item', id: 123 })).not.toThrow()
     })
+  })
 
-    it('should not call callbacks if not configured', () => {
-      configureDeepLinks({})
+  describe('initDeepLinks', () => {
+    afterEach(() => {
+      delete (window as unknown as Record<string, unknown>).__TAURI__
+    })
+
+    it('should not initialize outside Tauri', async () => {
+      await initDeepLinks()
+
+      expect(mockListen).not.toHaveBeenCalled()
+      expect(mockInvoke).not.toHaveBeenCalled()
+    })
+
+    it('should route launch links only after listening for them', async () => {
+      ;(window as unknown as Record<string, unknown>).__TAURI__ = {}
+      const onItem = vi.fn()
+      configureDeepLinks({ onItem })
+
+      // The backend answers by emitting the launch link's route
+      mockInvoke.mockImplementation(async () => {
+        const handler = mockListen.mock.calls.find(
+          ([event]) => event === 'deep-link-target',
+        )?.[1]
+        handler?.({ payload: { type: 'item', id: 42 } })
+      })
+
+      await initDeepLinks()
 
-      expect(() => handleDeepLink('pastelhn://item/123')).not.toThrow()
+      expect(mockInvoke).toHaveBeenCalledWith('dispatch_launch_deep_links')
+      expect(onItem).toHaveBeenCalledWith(42)
     })
   })
 })
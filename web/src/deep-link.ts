@@ -6,13 +6,13 @@
  * - pastelhn://user/dang - Open user profile for "dang"
  * - pastelhn://feed/best - Switch to "best" feed
  * - pastelhn://search?q=rust - Search for "rust"
+ *
+ * Links are parsed by the backend, which emits the routes below as
+ * `deep-link-target` events.
  */
 
+import { invoke } from '@tauri-apps/api/core'
 import { listen } from '@tauri-apps/api/event'
-import { getCurrent, onOpenUrl } from '@tauri-apps/plugin-deep-link'
-
-/** Valid feed names */
-const VALID_FEEDS = ['top', 'new', 'best', 'ask', 'show', 'jobs', 'saved']
 
 /** Deep link route types */
 export type DeepLinkRoute =
@@ -20,7 +20,12 @@ export type DeepLinkRoute =
   | { type: 'user'; username: string }
   | { type: 'feed'; feed: string }
   | { type: 'search'; query: string }
-  | { type: 'unknown'; url: string }
+
+/** Payload of the `deep-link-error` event for links that don't parse */
+export interface DeepLinkError {
+  url: string
+  message: string
+}
 
 /** Callbacks for handling deep link routes */
 export interface DeepLinkCallbacks {
@@ -32,56 +37,6 @@ export interface DeepLinkCallbacks {
 
 let callbacks: DeepLinkCallbacks = {}
 
-/**
- * Parse a deep link URL into a route object.
- */
-export function parseDeepLink(urlString: string): DeepLinkRoute {
-  try {
-    const url = new URL(urlString)
-
-    // Handle pastelhn:// scheme
-    // URL parsing: pastelhn://item/12345 -> protocol=pastelhn:, host=item, pathname=/12345
-    if (url.protocol === 'pastelhn:') {
-      const routeType = url.hostname?.toLowerCase() || url.host?.toLowerCase()
-      const routeValue = url.pathname.replace(/^\/+/, '')
-
-      switch (routeType) {
-        case 'item':
-        case 'story': {
-          const id = parseInt(routeValue, 10)
-          if (!Number.isNaN(id) && id > 0) {
-            return { type: 'item', id }
-          }
-          break
-        }
-        case 'user': {
-          if (routeValue) {
-            return { type: 'user', username: routeValue }
-          }
-          break
-        }
-        case 'feed': {
-          if (routeValue && VALID_FEEDS.includes(routeValue.toLowerCase())) {
-            return { type: 'feed', feed: routeValue.toLowerCase() }
-          }
-          break
-        }
-        case 'search': {
-          const query = url.searchParams.get('q') || routeValue
-          if (query) {
-            return { type: 'search', query }
-          }
-          break
-        }
-      }
-    }
-
-    return { type: 'unknown', url: urlString }
-  } catch {
-    return { type: 'unknown', url: urlString }
-  }
-}
-
 /**
  * Configure deep link callbacks.
  */
@@ -90,11 +45,9 @@ export function configureDeepLinks(cb: DeepLinkCallbacks): void {
 }
 
 /**
- * Handle a deep link URL by invoking the appropriate callback.
+ * Invoke the callback for a route from a `deep-link-target` event.
  */
-export function handleDeepLink(urlString: string): void {
-  const route = parseDeepLink(urlString)
-
+export function routeDeepLink(route: DeepLinkRoute): void {
   console.log('Deep link route:', route)
 
   switch (route.type) {
@@ -110,9 +63,6 @@ export function handleDeepLink(urlString: string): void {
     case 'search':
       callbacks.onSearch?.(route.query)
       break
-    case 'unknown':
-      console.warn('Unknown deep link:', route.url)
-      break
   }
 }
 
@@ -127,25 +77,21 @@ export async function initDeepLinks(): Promise<void> {
   }
 
   try {
-    // Listen for deep links while app is running (from Rust event)
-    await listen<string>('deep-link', (event) => {
-      handleDeepLink(event.payload)
+    await listen<DeepLinkRoute>('deep-link-target', (event) => {
+      routeDeepLink(event.payload)
+    })
+    await listen<DeepLinkError>('deep-link-error', (event) => {
+      const { url, message } = event.payload
+      console.warn('Invalid deep link:', url, message)
     })
 
-    // Also use the plugin's onOpenUrl for runtime deep links
-    await onOpenUrl((urls) => {
-      for (const url of urls) {
-        handleDeepLink(url)
-      }
+    // Notification clicks open their story
+    await listen<number>('open-item', (event) => {
+      callbacks.onItem?.(event.payload)
     })
 
-    // Check if app was opened via deep link
-    const currentUrls = await getCurrent()
-    if (currentUrls && currentUrls.length > 0) {
-      for (const url of currentUrls) {
-        handleDeepLink(url)
-      }
-    }
+    // Now that we're listening, route the links the app was opened with
+    await invoke('dispatch_launch_deep_links')
 
     console.log('Deep link handler initialized')
   } catch (error) {
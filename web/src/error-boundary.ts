@@ -7,6 +7,7 @@
  * - Module-level error recovery
  */
 
+import { errorMessage } from './errors'
 import { toastError } from './toast'
 
 export interface ErrorReport {
@@ -323,8 +324,7 @@ function handleGlobalError(
  */
 function handleUnhandledRejection(event: PromiseRejectionEvent): void {
   const error = event.reason
-  const message =
-    error instanceof Error ? error.message : String(error || 'Unknown error')
+  const message = error ? errorMessage(error) : 'Unknown error'
 
   const report = createErrorReport(
     `Unhandled Promise Rejection: ${message}`,
@@ -403,7 +403,7 @@ export function withErrorBoundary<T extends unknown[], R>(
     try {
       return await fn(...args)
     } catch (error) {
-      const message = error instanceof Error ? error.message : String(error)
+      const message = errorMessage(error)
       const report = createErrorReport(
         `${context}: ${message}`,
         undefined,
@@ -433,7 +433,7 @@ export function safeExecute<T>(
   try {
     return fn()
   } catch (error) {
-    const message = error instanceof Error ? error.message : String(error)
+    const message = errorMessage(error)
     const report = createErrorReport(
       `${context}: ${message}`,
       undefined,
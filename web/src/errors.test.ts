@@ -74,6 +74,35 @@ describe('parseApiError', () => {
     expect(result.message).toContain('unexpected error')
   })
 
+  it('uses the code of command errors', () => {
+    const result = parseApiError({
+      code: 'rate_limited',
+      message: 'Rate limited, retry after 45 seconds',
+      retryAfter: 45,
+    })
+
+    expect(result.type).toBe('rate_limited')
+    expect(result.retryAfter).toBe(45)
+    expect(parseApiError({ code: 'user_not_found', message: 'x' }).type).toBe(
+      'not_found',
+    )
+    expect(parseApiError({ code: 'offline', message: 'x' }).type).toBe(
+      'network',
+    )
+  })
+
+  it('mentions the retries made once they are exhausted', () => {
+    const result = parseApiError({
+      code: 'retries_exhausted',
+      message: 'Gave up after 3 retries, try again in 60s',
+      retryAfter: 60,
+      attempts: 3,
+    })
+
+    expect(result.type).toBe('rate_limited')
+    expect(result.message).toContain('3 retries')
+  })
+
   it('handles non-Error objects', () => {
     const result = parseApiError('string error')
     expect(result.type).toBe('unknown')
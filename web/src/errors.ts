@@ -16,11 +16,83 @@ export interface ParsedError {
   retryAfter?: number
 }
 
+/**
+ * Error returned by a Tauri command (the backend's ApiError)
+ */
+export interface ApiErrorPayload {
+  /** Stable error kind, e.g. 'rate_limited' or 'not_found' */
+  code: string
+  message: string
+  /** Seconds to wait, for rate-limit errors */
+  retryAfter?: number
+  /** Retries made before giving up, for 'retries_exhausted' */
+  attempts?: number
+}
+
+export function isApiErrorPayload(error: unknown): error is ApiErrorPayload {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    typeof (error as ApiErrorPayload).code === 'string' &&
+    typeof (error as ApiErrorPayload).message === 'string'
+  )
+}
+
+/**
+ * Human-readable message for any thrown value, including command errors
+ */
+export function errorMessage(error: unknown): string {
+  if (error instanceof Error) return error.message
+  if (isApiErrorPayload(error)) return error.message
+  return String(error)
+}
+
+function parseErrorCode(error: ApiErrorPayload): ParsedError | null {
+  switch (error.code) {
+    case 'rate_limited':
+    case 'retries_exhausted': {
+      const retryAfter = error.retryAfter ?? 60
+      const gaveUp = error.attempts
+        ? `Gave up after ${error.attempts} retries. `
+        : ''
+      return {
+        type: 'rate_limited',
+        message: `${gaveUp}Too many requests. Please wait ${retryAfter} seconds before trying again.`,
+        retryAfter,
+      }
+    }
+    case 'not_found':
+    case 'user_not_found':
+      return {
+        type: 'not_found',
+        message: 'The requested content was not found.',
+      }
+    case 'request':
+    case 'offline':
+      return {
+        type: 'network',
+        message: 'Network error. Check your connection and try again.',
+      }
+    case 'timeout':
+      return {
+        type: 'network',
+        message: 'The site took too long to respond. Please try again.',
+      }
+    default:
+      return null
+  }
+}
+
 /**
  * Parse error message from API to determine error type and user-friendly message
  */
 export function parseApiError(error: unknown): ParsedError {
-  const errorStr = String(error)
+  if (isApiErrorPayload(error)) {
+    const parsed = parseErrorCode(error)
+    if (parsed) return parsed
+  }
+
+  const errorStr = isApiErrorPayload(error) ? error.message : String(error)
 
   // Check for rate limiting
   const rateLimitMatch = errorStr.match(
@@ -2,19 +2,25 @@
  * Tests for global-shortcuts.ts module.
  */
 
+import { invoke } from '@tauri-apps/api/core'
 import { isRegistered } from '@tauri-apps/plugin-global-shortcut'
 import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest'
 import {
   configureGlobalShortcuts,
-  DEFAULT_GLOBAL_SHORTCUTS,
+  getDefaultGlobalShortcutConfig,
   getGlobalShortcutCallbacks,
   isShortcutRegistered,
 } from './global-shortcuts'
 
+vi.mock('@tauri-apps/api/core', () => ({
+  invoke: vi.fn(),
+}))
+
 vi.mock('@tauri-apps/plugin-global-shortcut', () => ({
   isRegistered: vi.fn().mockResolvedValue(false),
 }))
 
+const mockInvoke = vi.mocked(invoke)
 const mockIsRegistered = vi.mocked(isRegistered)
 
 describe('global-shortcuts', () => {
@@ -26,15 +32,18 @@ describe('global-shortcuts', () => {
     vi.restoreAllMocks()
   })
 
-  describe('DEFAULT_GLOBAL_SHORTCUTS', () => {
-    it('should define showWindow shortcut', () => {
-      expect(DEFAULT_GLOBAL_SHORTCUTS.showWindow).toBe(
-        'CommandOrControl+Shift+H',
-      )
-    })
+  describe('getDefaultGlobalShortcutConfig', () => {
+    it('should read the defaults from the backend', async () => {
+      const defaults = {
+        showWindow: 'Super+Shift+H',
+        refresh: 'Super+Shift+R',
+        nextStory: 'Super+Shift+J',
+        prevStory: 'Super+Shift+K',
+      }
+      mockInvoke.mockResolvedValue(defaults)
 
-    it('should define refresh shortcut', () => {
-      expect(DEFAULT_GLOBAL_SHORTCUTS.refresh).toBe('CommandOrControl+Shift+R')
+      await expect(getDefaultGlobalShortcutConfig()).resolves.toEqual(defaults)
+      expect(mockInvoke).toHaveBeenCalledWith('get_default_global_shortcuts')
     })
   })
 
@@ -3,20 +3,20 @@
  *
  * Note: Shortcuts are registered in Rust (main.rs) for reliability.
  * This module provides:
- * - Constant definitions for shortcut strings
+ * - Access to the accelerators configured in Rust
  * - Callback configuration for JS-side actions
  * - Helper to check registration status
  */
 
+import { invoke } from '@tauri-apps/api/core'
 import { isRegistered } from '@tauri-apps/plugin-global-shortcut'
 
-/** Default global shortcuts configuration (registered in Rust) */
-export const DEFAULT_GLOBAL_SHORTCUTS = {
-  showWindow: 'CommandOrControl+Shift+H',
-  refresh: 'CommandOrControl+Shift+R',
-} as const
-
-export type GlobalShortcutKey = keyof typeof DEFAULT_GLOBAL_SHORTCUTS
+/** Global shortcut actions, as named by the Rust config */
+export type GlobalShortcutKey =
+  | 'showWindow'
+  | 'refresh'
+  | 'nextStory'
+  | 'prevStory'
 
 /** Callbacks for global shortcut actions triggered via Rust events */
 export interface GlobalShortcutCallbacks {
@@ -51,3 +51,30 @@ export async function isShortcutRegistered(shortcut: string): Promise<boolean> {
     return false
   }
 }
+
+/** Accelerator for each global shortcut, as registered in Rust */
+export type GlobalShortcutConfig = Record<GlobalShortcutKey, string>
+
+/**
+ * Get the accelerators currently registered in Rust.
+ */
+export async function getGlobalShortcutConfig(): Promise<GlobalShortcutConfig> {
+  return invoke<GlobalShortcutConfig>('get_global_shortcuts')
+}
+
+/**
+ * Get the accelerators Rust registers at startup, e.g. to reset them.
+ */
+export async function getDefaultGlobalShortcutConfig(): Promise<GlobalShortcutConfig> {
+  return invoke<GlobalShortcutConfig>('get_default_global_shortcuts')
+}
+
+/**
+ * Re-register the global shortcuts with new accelerators.
+ * Rejects (keeping the old shortcuts) if any accelerator is invalid.
+ */
+export async function setGlobalShortcutConfig(
+  config: GlobalShortcutConfig,
+): Promise<void> {
+  await invoke('set_global_shortcuts', { config })
+}
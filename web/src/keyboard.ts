@@ -452,6 +452,17 @@ function handleKeydown(e: KeyboardEvent): void {
   }
 }
 
+/**
+ * Move the selection to the next/previous item (used by global shortcuts)
+ */
+export function selectNextItem(): void {
+  navigateDown()
+}
+
+export function selectPrevItem(): void {
+  navigateUp()
+}
+
 export function initKeyboard(): void {
   document.addEventListener('keydown', handleKeydown)
 }
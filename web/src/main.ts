@@ -4,7 +4,7 @@
  */
 
 import { animateDetailExit } from './animations'
-import { init } from './api'
+import { init, warmFeed } from './api'
 import {
   clearStoryContext,
   closeAssistant,
@@ -26,6 +26,8 @@ import { configureGlobalShortcuts } from './global-shortcuts'
 import {
   getSelectedIndex,
   initKeyboard,
+  selectNextItem,
+  selectPrevItem,
   setKeyboardCallbacks,
 } from './keyboard'
 import {
@@ -33,7 +35,7 @@ import {
   handleHashChange,
   setupAllNavigation,
 } from './navigation'
-import { initNotifications } from './notifications'
+import { initNotificationClicks, initNotifications } from './notifications'
 import { initOfflineDetection } from './offline'
 import { configurePullRefresh, setupPullToRefresh } from './pull-refresh'
 import {
@@ -44,6 +46,7 @@ import {
   updateHeaderShadow,
 } from './scroll-utils'
 import {
+  applyAutoRefresh,
   closeSettingsModal,
   getSettings,
   initSettings,
@@ -63,6 +66,7 @@ import {
   getCurrentStories,
   getReadStoryIdsSet,
   getVirtualScroll,
+  initNewStoriesListener,
   isStoryListLoading,
   isYouTubeFilterActive,
   renderStories as renderStoriesModule,
@@ -164,6 +168,7 @@ async function renderStoryDetail(
     container,
     getReadStoryIdsSet(),
     clickedStoryEl,
+    currentFeed,
   )
 }
 
@@ -426,6 +431,149 @@ function setupYouTubeFilterButton(): void {
   })
 }
 
+/**
+ * Whether this webview is a dedicated story window (`story-<id>`).
+ *
+ * Story windows load the same bundle as the main window.
+ */
+async function isStoryWindow(): Promise<boolean> {
+  try {
+    const { getCurrentWebviewWindow } = await import(
+      '@tauri-apps/api/webviewWindow'
+    )
+    return getCurrentWebviewWindow().label.startsWith('story-')
+  } catch {
+    // Not in Tauri environment
+    return false
+  }
+}
+
+/**
+ * Set up notifications, followed-story polling, tray and global shortcut
+ * events, deep links and the feed refresh timer.
+ *
+ * These are app-wide, so only the main window handles them; otherwise each
+ * open story window would act on them again.
+ */
+function initMainWindowEvents(): void {
+  // Start the background feed refresh timer if the user turned it on
+  applyAutoRefresh()
+
+  // Initialize native notifications (requests permission if needed)
+  initNotifications().then((available) => {
+    if (available) {
+      // Clicking a story notification opens that story
+      initNotificationClicks()
+
+      // Start background polling for followed story updates
+      startFollowedStoriesPolling()
+    }
+  })
+
+  // Configure and initialize system tray events
+  configureTrayEvents({
+    onFeedChange: (feed) => {
+      if (currentView !== 'list' || feed === currentFeed) {
+        // Navigate to list view first if needed
+        if (currentView !== 'list') {
+          navigateBackToList().then(() => {
+            currentFeed = feed
+            document.querySelectorAll('[data-feed]').forEach((btn) => {
+              btn.classList.toggle(
+                'active',
+                btn.getAttribute('data-feed') === feed,
+              )
+            })
+            renderStories(feed)
+          })
+        }
+      } else {
+        currentFeed = feed
+        document.querySelectorAll('[data-feed]').forEach((btn) => {
+          btn.classList.toggle(
+            'active',
+            btn.getAttribute('data-feed') === feed,
+          )
+        })
+        renderStories(feed)
+      }
+      toastInfo(`Switched to ${getFeedDisplayName(feed)}`)
+    },
+    onRefresh: () => {
+      if (currentView === 'list') {
+        renderStoriesModule(currentFeed, true)
+        toastSuccess('Feed refreshed')
+      } else if (currentView === 'detail') {
+        const storyId = getCurrentStoryId()
+        if (storyId) {
+          renderStoryDetail(storyId)
+          toastSuccess('Story refreshed')
+        }
+      }
+    },
+    onSearch: async () => {
+      const { showSearchModal } = await import('./search')
+      showSearchModal()
+    },
+    onOpenStory: (storyId) => {
+      renderStoryDetail(storyId)
+    },
+    onNextStory: () => {
+      if (currentView === 'list') selectNextItem()
+    },
+    onPrevStory: () => {
+      if (currentView === 'list') selectPrevItem()
+    },
+  })
+  initTrayEvents()
+  initNewStoriesListener()
+
+  // Configure global shortcuts (Cmd+Shift+H to show, Cmd+Shift+R to refresh)
+  // Note: The Rust backend handles these shortcuts; this is for any JS-only actions
+  configureGlobalShortcuts({
+    onShowWindow: () => {
+      // Window is already shown by Rust handler
+      console.log('Global shortcut: Show window triggered')
+    },
+    onRefresh: () => {
+      // Rust handler shows window, we just refresh content
+      if (currentView === 'list') {
+        renderStoriesModule(currentFeed, true)
+        toastSuccess('Feed refreshed')
+      }
+    },
+  })
+
+  // Configure deep links (pastelhn://item/123, pastelhn://user/dang, etc.)
+  configureDeepLinks({
+    onItem: (id) => {
+      renderStoryDetail(id)
+      toastInfo(`Opening story #${id}`)
+    },
+    onUser: (username) => {
+      renderUserProfile(username)
+      toastInfo(`Opening profile: ${username}`)
+    },
+    onFeed: (feed) => {
+      if (currentView !== 'list') {
+        navigateBackToList().then(() => {
+          currentFeed = feed as StoryFeed
+          renderStories(currentFeed)
+        })
+      } else {
+        currentFeed = feed as StoryFeed
+        renderStories(currentFeed)
+      }
+      toastInfo(`Switched to ${getFeedDisplayName(feed as StoryFeed)}`)
+    },
+    onSearch: async (query) => {
+      const { showSearchModal } = await import('./search')
+      showSearchModal(query)
+    },
+  })
+  initDeepLinks()
+}
+
 /**
  * Main initialization function.
  */
@@ -436,6 +584,8 @@ async function main(): Promise<void> {
   // Initialize settings (handles theme)
   initSettings()
 
+  const storyWindow = await isStoryWindow()
+
   // Ensure window decorations are visible on startup
   // Only set decorations if not already in fullscreen (preserves zen mode state from previous session)
   try {
@@ -512,106 +662,10 @@ async function main(): Promise<void> {
     // Initialize favicon lazy loading
     initFaviconLazyLoading()
 
-    // Initialize native notifications (requests permission if needed)
-    initNotifications().then((available) => {
-      if (available) {
-        // Start background polling for followed story updates
-        startFollowedStoriesPolling()
-      }
-    })
-
-    // Configure and initialize system tray events
-    configureTrayEvents({
-      onFeedChange: (feed) => {
-        if (currentView !== 'list' || feed === currentFeed) {
-          // Navigate to list view first if needed
-          if (currentView !== 'list') {
-            navigateBackToList().then(() => {
-              currentFeed = feed
-              document.querySelectorAll('[data-feed]').forEach((btn) => {
-                btn.classList.toggle(
-                  'active',
-                  btn.getAttribute('data-feed') === feed,
-                )
-              })
-              renderStories(feed)
-            })
-          }
-        } else {
-          currentFeed = feed
-          document.querySelectorAll('[data-feed]').forEach((btn) => {
-            btn.classList.toggle(
-              'active',
-              btn.getAttribute('data-feed') === feed,
-            )
-          })
-          renderStories(feed)
-        }
-        toastInfo(`Switched to ${getFeedDisplayName(feed)}`)
-      },
-      onRefresh: () => {
-        if (currentView === 'list') {
-          renderStoriesModule(currentFeed, true)
-          toastSuccess('Feed refreshed')
-        } else if (currentView === 'detail') {
-          const storyId = getCurrentStoryId()
-          if (storyId) {
-            renderStoryDetail(storyId)
-            toastSuccess('Story refreshed')
-          }
-        }
-      },
-      onSearch: async () => {
-        const { showSearchModal } = await import('./search')
-        showSearchModal()
-      },
-    })
-    initTrayEvents()
-
-    // Configure global shortcuts (Cmd+Shift+H to show, Cmd+Shift+R to refresh)
-    // Note: The Rust backend handles these shortcuts; this is for any JS-only actions
-    configureGlobalShortcuts({
-      onShowWindow: () => {
-        // Window is already shown by Rust handler
-        console.log('Global shortcut: Show window triggered')
-      },
-      onRefresh: () => {
-        // Rust handler shows window, we just refresh content
-        if (currentView === 'list') {
-          renderStoriesModule(currentFeed, true)
-          toastSuccess('Feed refreshed')
-        }
-      },
-    })
-
-    // Configure deep links (pastelhn://item/123, pastelhn://user/dang, etc.)
-    configureDeepLinks({
-      onItem: (id) => {
-        renderStoryDetail(id)
-        toastInfo(`Opening story #${id}`)
-      },
-      onUser: (username) => {
-        renderUserProfile(username)
-        toastInfo(`Opening profile: ${username}`)
-      },
-      onFeed: (feed) => {
-        if (currentView !== 'list') {
-          navigateBackToList().then(() => {
-            currentFeed = feed as StoryFeed
-            renderStories(currentFeed)
-          })
-        } else {
-          currentFeed = feed as StoryFeed
-          renderStories(currentFeed)
-        }
-        toastInfo(`Switched to ${getFeedDisplayName(feed as StoryFeed)}`)
-      },
-      onSearch: async (query) => {
-        const { showSearchModal } = await import('./search')
-        showSearchModal(query)
-      },
-    })
-    initDeepLinks()
+    // App-wide events are handled by the main window alone
+    if (!storyWindow) {
+      initMainWindowEvents()
+    }
 
     // Set up zen mode callback
     setZenModeChangeCallback((isActive) => {
@@ -702,6 +756,13 @@ async function main(): Promise<void> {
     } else {
       await renderStories(currentFeed)
     }
+
+    // Cache items beyond the first page in the background for smooth scrolling
+    if (!storyWindow) {
+      warmFeed(currentFeed).catch((error) => {
+        console.warn('Failed to warm feed cache:', error)
+      })
+    }
   } catch (error) {
     console.error('Failed to initialize:', error)
     const container = document.getElementById('stories')
@@ -153,6 +153,7 @@ describe('notifications', () => {
       expect(mockSendNotification).toHaveBeenCalledWith({
         title: expect.stringContaining('New comments on'),
         body: '1 new comment',
+        extra: { itemId: 123 },
       })
     })
 
@@ -166,6 +167,7 @@ describe('notifications', () => {
       expect(mockSendNotification).toHaveBeenCalledWith({
         title: expect.stringContaining('New comments on'),
         body: '5 new comments',
+        extra: { itemId: 123 },
       })
     })
 
@@ -202,6 +204,7 @@ describe('notifications', () => {
       expect(mockSendNotification).toHaveBeenCalledWith({
         title: 'Test Story',
         body: 'Story reached 100 points!',
+        extra: { itemId: 123 },
       })
     })
 
@@ -222,6 +225,7 @@ describe('notifications', () => {
       expect(mockSendNotification).toHaveBeenCalledWith({
         title: 'Story Update',
         body: 'Update message',
+        extra: { itemId: 123 },
       })
     })
   })
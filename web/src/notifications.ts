@@ -54,6 +54,28 @@ export function areNotificationsAvailable(): boolean {
   return notificationsAvailable
 }
 
+/**
+ * Route notification clicks to the story they are about.
+ *
+ * Clicking a story notification emits the same `open-item` event as a
+ * `pastelhn://item/<id>` deep link. Platforms whose notifications don't
+ * report clicks simply never fire the listener.
+ */
+export async function initNotificationClicks(): Promise<void> {
+  try {
+    const { onAction } = await import('@tauri-apps/plugin-notification')
+    const { emit } = await import('@tauri-apps/api/event')
+    await onAction((notification) => {
+      const itemId = notification.extra?.itemId
+      if (typeof itemId === 'number') {
+        emit('open-item', itemId)
+      }
+    })
+  } catch (error) {
+    console.debug('Notification click handling not available:', error)
+  }
+}
+
 /**
  * Send a notification.
  * @param title - Notification title
@@ -62,6 +84,7 @@ export function areNotificationsAvailable(): boolean {
 export async function showNotification(
   title: string,
   body: string,
+  itemId?: number,
 ): Promise<boolean> {
   if (!notificationsAvailable) {
     return false
@@ -69,7 +92,9 @@ export async function showNotification(
 
   try {
     const { sendNotification } = await import('@tauri-apps/plugin-notification')
-    sendNotification({ title, body })
+    sendNotification(
+      itemId === undefined ? { title, body } : { title, body, extra: { itemId } },
+    )
     return true
   } catch (error) {
     console.error('Failed to send notification:', error)
@@ -88,7 +113,7 @@ export async function notifyNewComments(
 ): Promise<boolean> {
   const title = `New comments on "${story.title?.substring(0, 50)}${(story.title?.length ?? 0) > 50 ? '...' : ''}"`
   const body = `${newCommentCount} new comment${newCommentCount === 1 ? '' : 's'}`
-  return showNotification(title, body)
+  return showNotification(title, body, story.id)
 }
 
 /**
@@ -101,5 +126,5 @@ export async function notifyStoryUpdate(
   message: string,
 ): Promise<boolean> {
   const title = story.title?.substring(0, 60) ?? 'Story Update'
-  return showNotification(title, message)
+  return showNotification(title, message, story.id)
 }
@@ -5,6 +5,13 @@ import {
   isCurrentlyOffline,
 } from './offline'
 
+// Mock @tauri-apps/api/event module
+const mockListen = vi.fn()
+
+vi.mock('@tauri-apps/api/event', () => ({
+  listen: (...args: unknown[]) => mockListen(...args),
+}))
+
 describe('offline', () => {
   beforeEach(() => {
     // Reset DOM
@@ -119,6 +126,50 @@ describe('offline', () => {
     })
   })
 
+  describe('backend connectivity events', () => {
+    afterEach(() => {
+      delete (window as unknown as Record<string, unknown>).__TAURI__
+      mockListen.mockReset()
+    })
+
+    it('does not listen outside Tauri', () => {
+      initOfflineDetection()
+
+      expect(mockListen).not.toHaveBeenCalled()
+    })
+
+    it('follows online-changed events', async () => {
+      ;(window as unknown as Record<string, unknown>).__TAURI__ = {}
+      const unlisten = vi.fn()
+      mockListen.mockResolvedValue(unlisten)
+
+      initOfflineDetection()
+      await vi.waitFor(() => {
+        expect(mockListen).toHaveBeenCalledWith(
+          'online-changed',
+          expect.any(Function),
+        )
+      })
+      const handler = mockListen.mock.calls[0][1] as (event: {
+        payload: boolean
+      }) => void
+
+      const indicator = document.getElementById('offline-indicator')
+      handler({ payload: false })
+      expect(isCurrentlyOffline()).toBe(true)
+      expect(indicator?.classList.contains('visible')).toBe(true)
+
+      handler({ payload: true })
+      expect(isCurrentlyOffline()).toBe(false)
+      expect(indicator?.classList.contains('visible')).toBe(false)
+
+      destroyOfflineDetection()
+      await vi.waitFor(() => {
+        expect(unlisten).toHaveBeenCalled()
+      })
+    })
+  })
+
   describe('destroyOfflineDetection', () => {
     it('removes the indicator element', () => {
       initOfflineDetection()
@@ -1,6 +1,8 @@
 /**
  * Offline detection and indicator module
- * Shows a visual indicator when the app is offline
+ * Shows a visual indicator when the app is offline, going by both the
+ * browser's online/offline events and the backend's `online-changed`
+ * connectivity checks
  */
 
 import { announce } from './accessibility'
@@ -8,6 +10,7 @@ import { icons } from './icons'
 
 let isOffline = false
 let indicatorElement: HTMLElement | null = null
+let unlistenBackend: (() => void) | null = null
 
 /**
  * Check if currently offline
@@ -33,6 +36,39 @@ export function initOfflineDetection(): void {
   // Listen for online/offline events
   window.addEventListener('online', handleOnline)
   window.addEventListener('offline', handleOffline)
+
+  listenForBackendConnectivity()
+}
+
+/**
+ * Follow the backend's connectivity checks, which notice an unreachable HN
+ * API that `navigator.onLine` can't
+ */
+async function listenForBackendConnectivity(): Promise<void> {
+  // Only run in Tauri environment
+  if (typeof window === 'undefined' || !('__TAURI__' in window)) {
+    return
+  }
+
+  try {
+    const { listen } = await import('@tauri-apps/api/event')
+    const unlisten = await listen<boolean>('online-changed', (event) => {
+      if (event.payload) {
+        handleOnline()
+      } else {
+        handleOffline()
+      }
+    })
+
+    // Destroyed while the listener was being registered
+    if (!indicatorElement) {
+      unlisten()
+      return
+    }
+    unlistenBackend = unlisten
+  } catch (error) {
+    console.debug('Connectivity events not available:', error)
+  }
 }
 
 /**
@@ -42,6 +78,9 @@ export function destroyOfflineDetection(): void {
   window.removeEventListener('online', handleOnline)
   window.removeEventListener('offline', handleOffline)
 
+  unlistenBackend?.()
+  unlistenBackend = null
+
   if (indicatorElement) {
     indicatorElement.remove()
     indicatorElement = null
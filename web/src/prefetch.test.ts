@@ -4,9 +4,14 @@ import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest'
 vi.mock('./api', () => ({
   fetchStoryWithComments: vi.fn(),
   fetchStoriesPaginated: vi.fn(),
+  prefetchComments: vi.fn(),
 }))
 
-import { fetchStoriesPaginated, fetchStoryWithComments } from './api'
+import {
+  fetchStoriesPaginated,
+  fetchStoryWithComments,
+  prefetchComments,
+} from './api'
 import {
   clearPrefetchCache,
   getCachedNextPage,
@@ -22,12 +27,14 @@ import {
 
 const mockFetchStoryWithComments = vi.mocked(fetchStoryWithComments)
 const mockFetchStoriesPaginated = vi.mocked(fetchStoriesPaginated)
+const mockPrefetchComments = vi.mocked(prefetchComments)
 
 describe('prefetch', () => {
   beforeEach(() => {
     vi.clearAllMocks()
     vi.useFakeTimers()
     clearPrefetchCache()
+    mockPrefetchComments.mockResolvedValue(undefined)
   })
 
   afterEach(() => {
@@ -60,7 +67,11 @@ describe('prefetch', () => {
 
       await prefetchStoryDetail(123)
 
-      expect(mockFetchStoryWithComments).toHaveBeenCalledWith(123, 1)
+      expect(mockFetchStoryWithComments).toHaveBeenCalledWith(
+        123,
+        1,
+        undefined,
+      )
       expect(isStoryCached(123)).toBe(true)
       expect(getCachedStoryDetail(123)).toEqual(mockStory)
     })
@@ -130,7 +141,7 @@ describe('prefetch', () => {
 
       mockFetchStoryWithComments.mockResolvedValue(mockStory)
 
-      onStoryHoverStart(100)
+      onStoryHoverStart(100, 'ask')
 
       // Should not fetch immediately
       expect(mockFetchStoryWithComments).not.toHaveBeenCalled()
@@ -139,7 +150,8 @@ describe('prefetch', () => {
       vi.advanceTimersByTime(200)
       await vi.runAllTimersAsync()
 
-      expect(mockFetchStoryWithComments).toHaveBeenCalledWith(100, 1)
+      // Only top-level comments, whatever depth the feed is configured for
+      expect(mockFetchStoryWithComments).toHaveBeenCalledWith(100, 1, 'ask')
     })
 
     it('cancels prefetch when hover ends before delay', () => {
@@ -231,7 +243,7 @@ describe('prefetch', () => {
 
       mockFetchStoriesPaginated.mockResolvedValueOnce(mockResponse)
 
-      await prefetchNextPage('top', 0, 30)
+      await prefetchNextPage('top', 30, 30)
 
       expect(mockFetchStoriesPaginated).toHaveBeenCalledWith('top', 30, 30)
 
@@ -277,7 +289,7 @@ describe('prefetch', () => {
       )
 
       await expect(prefetchNextPage('best', 30, 30)).resolves.toBeUndefined()
-      expect(getCachedNextPage('best', 60)).toBeNull()
+      expect(getCachedNextPage('best', 30)).toBeNull()
     })
   })
 
@@ -349,6 +361,19 @@ describe('prefetch', () => {
       )
     })
 
+    it('warms comments only for visible stories past the first 5', () => {
+      prefetchVisibleStories([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])
+
+      expect(mockPrefetchComments).toHaveBeenCalledTimes(1)
+      expect(mockPrefetchComments).toHaveBeenCalledWith([6, 7, 8, 9, 10])
+    })
+
+    it('skips the comment prefetch when only a few stories are visible', () => {
+      prefetchVisibleStories([1, 2, 3])
+
+      expect(mockPrefetchComments).not.toHaveBeenCalled()
+    })
+
     it('skips already cached stories', async () => {
       const mockStory = {
         story: {
@@ -4,14 +4,19 @@
  * Strategies:
  * 1. Prefetch next page of stories when user scrolls near bottom
  * 2. Prefetch story details (comments) on hover with delay
- * 3. Prefetch visible story details in idle time
+ * 3. Prefetch visible story details in idle time, and warm the backend's
+ *    comment cache for the rest of the first screen
  *
  * Note: Request deduplication is handled at the API layer (deduplicatedInvoke),
  * so concurrent calls for the same resource are automatically coalesced.
  */
 
 import type { StoriesResponse } from './api'
-import { fetchStoriesPaginated, fetchStoryWithComments } from './api'
+import {
+  fetchStoriesPaginated,
+  fetchStoryWithComments,
+  prefetchComments,
+} from './api'
 import type { StoryFeed, StoryWithComments } from './types'
 
 // Cache for prefetched story details
@@ -26,6 +31,12 @@ const HOVER_PREFETCH_DELAY = 150
 // Active hover timeouts
 const hoverTimeouts = new Map<number, number>()
 
+// Visible stories whose details are prefetched
+const VISIBLE_DETAIL_PREFETCH = 5
+
+// Visible stories whose comment trees are warmed in the backend cache
+const VISIBLE_COMMENT_PREFETCH = 10
+
 /**
  * Get cached story details if available
  */
@@ -41,20 +52,23 @@ export function isStoryCached(id: number): boolean {
 }
 
 /**
- * Prefetch story details (comments) for a story ID
+ * Prefetch story details for a story ID, with only top-level comments;
+ * deeper replies load lazily when the story is opened
  * Silent failure - prefetching errors shouldn't affect the user
  *
  * Note: Concurrent calls for the same story ID are deduplicated at the API layer.
  */
-export async function prefetchStoryDetail(id: number): Promise<void> {
+export async function prefetchStoryDetail(
+  id: number,
+  feed?: StoryFeed,
+): Promise<void> {
   // Skip if already cached locally
   if (storyDetailCache.has(id)) {
     return
   }
 
   try {
-    // Use depth=1 for lazy loading - only prefetch top-level comments
-    const result = await fetchStoryWithComments(id, 1)
+    const result = await fetchStoryWithComments(id, 1, feed)
     storyDetailCache.set(id, result)
     prefetchedStories.add(id)
   } catch {
@@ -65,7 +79,7 @@ export async function prefetchStoryDetail(id: number): Promise<void> {
 /**
  * Handle mouse enter on a story card - start delayed prefetch
  */
-export function onStoryHoverStart(storyId: number): void {
+export function onStoryHoverStart(storyId: number, feed?: StoryFeed): void {
   // Clear any existing timeout
   const existingTimeout = hoverTimeouts.get(storyId)
   if (existingTimeout) {
@@ -79,7 +93,7 @@ export function onStoryHoverStart(storyId: number): void {
 
   // Start delayed prefetch
   const timeout = window.setTimeout(() => {
-    prefetchStoryDetail(storyId)
+    prefetchStoryDetail(storyId, feed)
     hoverTimeouts.delete(storyId)
   }, HOVER_PREFETCH_DELAY)
 
@@ -118,16 +132,16 @@ export function getCachedNextPage(
 }
 
 /**
- * Prefetch next page of stories
+ * Prefetch next page of stories, starting at `nextOffset` (the last page's
+ * `nextOffset`)
  *
  * Note: Concurrent calls for the same page are deduplicated at the API layer.
  */
 export async function prefetchNextPage(
   feed: StoryFeed,
-  currentOffset: number,
+  nextOffset: number,
   pageSize: number,
 ): Promise<void> {
-  const nextOffset = currentOffset + pageSize
   const cacheKey = getFeedCacheKey(feed, nextOffset)
 
   // Skip if already cached locally
@@ -146,7 +160,10 @@ export async function prefetchNextPage(
 /**
  * Prefetch visible stories' details during idle time
  */
-export function prefetchVisibleStories(storyIds: number[]): void {
+export function prefetchVisibleStories(
+  storyIds: number[],
+  feed?: StoryFeed,
+): void {
   // Use requestIdleCallback if available, otherwise use setTimeout
   const scheduleIdle =
     'requestIdleCallback' in window
@@ -154,15 +171,29 @@ export function prefetchVisibleStories(storyIds: number[]): void {
           .requestIdleCallback
       : (cb: () => void) => setTimeout(cb, 50)
 
-  // Limit to first 5 visible stories to avoid excessive prefetching
-  const toPrefetch = storyIds.slice(0, 5).filter((id) => !isStoryCached(id))
+  // Stories past the detail prefetch only get their comment trees warmed in
+  // the backend, so no story is requested twice
+  const commentIds = storyIds.slice(
+    VISIBLE_DETAIL_PREFETCH,
+    VISIBLE_COMMENT_PREFETCH,
+  )
+  if (commentIds.length > 0) {
+    prefetchComments(commentIds).catch(() => {
+      // Silent failure - prefetching is best-effort
+    })
+  }
+
+  // Limit to the first few visible stories to avoid excessive prefetching
+  const toPrefetch = storyIds
+    .slice(0, VISIBLE_DETAIL_PREFETCH)
+    .filter((id) => !isStoryCached(id))
 
   if (toPrefetch.length === 0) return
 
   scheduleIdle(() => {
     // Prefetch one at a time with small delays to avoid overwhelming the backend
     toPrefetch.forEach((id, index) => {
-      setTimeout(() => prefetchStoryDetail(id), index * 100)
+      setTimeout(() => prefetchStoryDetail(id, feed), index * 100)
     })
   })
 }
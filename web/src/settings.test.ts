@@ -10,6 +10,8 @@ vi.mock('@tauri-apps/plugin-fs', () => ({
 }))
 
 import {
+  AUTO_REFRESH_INTERVAL_SECS,
+  applyAutoRefresh,
   closeSettingsModal,
   getSettings,
   initSettings,
@@ -34,15 +36,17 @@ vi.mock('./theme', () => ({
 vi.mock('./api', () => ({
   getCacheStats: vi.fn(),
   clearCache: vi.fn(),
+  setAutoRefresh: vi.fn(),
 }))
 
-import { clearCache, getCacheStats } from './api'
+import { clearCache, getCacheStats, setAutoRefresh } from './api'
 import { setHighContrast, setTheme } from './theme'
 
 const mockSetTheme = vi.mocked(setTheme)
 const mockSetHighContrast = vi.mocked(setHighContrast)
 const mockGetCacheStats = vi.mocked(getCacheStats)
 const mockClearCache = vi.mocked(clearCache)
+const mockSetAutoRefresh = vi.mocked(setAutoRefresh)
 
 describe('settings', () => {
   const mockCacheStats: CacheStats = {
@@ -63,6 +67,7 @@ describe('settings', () => {
     // Default mock returns
     mockGetCacheStats.mockResolvedValue(mockCacheStats)
     mockClearCache.mockResolvedValue(undefined)
+    mockSetAutoRefresh.mockResolvedValue(undefined)
   })
 
   afterEach(() => {
@@ -80,6 +85,7 @@ describe('settings', () => {
         fontSize: 'normal',
         density: 'normal',
         defaultFeed: 'top',
+        autoRefresh: false,
       })
     })
 
@@ -90,6 +96,7 @@ describe('settings', () => {
         fontSize: 'compact',
         density: 'comfortable',
         defaultFeed: 'new',
+        autoRefresh: true,
       }
       localStorage.setItem('hn-settings', JSON.stringify(stored))
 
@@ -121,6 +128,7 @@ describe('settings', () => {
         fontSize: 'normal',
         density: 'normal',
         defaultFeed: 'top',
+        autoRefresh: false,
       })
     })
   })
@@ -150,6 +158,69 @@ describe('settings', () => {
 
       expect(mockSetTheme).toHaveBeenCalledWith('light')
     })
+
+    it('starts and stops the refresh timer when autoRefresh changes', () => {
+      localStorage.setItem(
+        'hn-settings',
+        JSON.stringify({ autoRefresh: false }),
+      )
+      loadSettings()
+      saveSettings({ autoRefresh: true })
+      expect(mockSetAutoRefresh).toHaveBeenLastCalledWith(
+        AUTO_REFRESH_INTERVAL_SECS,
+      )
+
+      saveSettings({ autoRefresh: false })
+      expect(mockSetAutoRefresh).toHaveBeenLastCalledWith(null)
+      expect(mockSetAutoRefresh).toHaveBeenCalledTimes(2)
+    })
+
+    it('leaves the refresh timer alone for other settings', () => {
+      localStorage.setItem(
+        'hn-settings',
+        JSON.stringify({ autoRefresh: false }),
+      )
+      loadSettings()
+      saveSettings({ theme: 'dark', autoRefresh: false })
+
+      expect(mockSetAutoRefresh).not.toHaveBeenCalled()
+    })
+  })
+
+  describe('applyAutoRefresh', () => {
+    it('applies the stored autoRefresh setting', async () => {
+      localStorage.setItem(
+        'hn-settings',
+        JSON.stringify({ autoRefresh: true }),
+      )
+      loadSettings()
+
+      await applyAutoRefresh()
+
+      expect(mockSetAutoRefresh).toHaveBeenCalledWith(
+        AUTO_REFRESH_INTERVAL_SECS,
+      )
+    })
+
+    it('stops the timer when autoRefresh is off', async () => {
+      localStorage.setItem(
+        'hn-settings',
+        JSON.stringify({ autoRefresh: false }),
+      )
+      loadSettings()
+
+      await applyAutoRefresh()
+
+      expect(mockSetAutoRefresh).toHaveBeenCalledWith(null)
+    })
+
+    it('ignores backend failures', async () => {
+      mockSetAutoRefresh.mockRejectedValue(new Error('Not in Tauri'))
+      vi.spyOn(console, 'warn').mockImplementation(() => {})
+      loadSettings()
+
+      await expect(applyAutoRefresh()).resolves.toBeUndefined()
+    })
   })
 
   describe('getSettings', () => {
@@ -787,6 +858,7 @@ describe('settings', () => {
         fontSize: 'compact',
         density: 'comfortable',
         defaultFeed: 'new',
+        autoRefresh: true,
       }
 
       const result = validateSettings(validSettings)
@@ -804,6 +876,7 @@ describe('settings', () => {
           fontSize: 'normal',
           density: 'normal',
           defaultFeed: 'top',
+          autoRefresh: false,
         },
       }
 
@@ -826,6 +899,29 @@ describe('settings', () => {
       expect(result?.highContrast).toBe(false)
     })
 
+    it('defaults autoRefresh to false for old settings files', () => {
+      const result = validateSettings({
+        theme: 'dark',
+        fontSize: 'normal',
+        density: 'normal',
+        defaultFeed: 'top',
+      })
+
+      expect(result?.autoRefresh).toBe(false)
+    })
+
+    it('returns null for invalid autoRefresh value', () => {
+      expect(
+        validateSettings({
+          theme: 'dark',
+          fontSize: 'normal',
+          density: 'normal',
+          defaultFeed: 'top',
+          autoRefresh: 'yes',
+        }),
+      ).toBeNull()
+    })
+
     it('returns null for invalid highContrast value', () => {
       const invalidSettings = {
         theme: 'dark',
@@ -3,7 +3,8 @@
  *
  * This module provides:
  * - Settings management (load, save, apply)
- * - Settings modal UI with theme, font size, density, and feed options
+ * - Settings modal UI with theme, font size, density, feed and auto-refresh
+ *   options
  * - Reading history and bookmarks management
  * - Cache management
  * - Settings import/export functionality
@@ -11,7 +12,7 @@
  * @module settings
  */
 
-import { clearCache, getCacheStats } from './api'
+import { clearCache, getCacheStats, setAutoRefresh } from './api'
 import { createFocusTrap, type FocusTrapInstance } from './focus-trap'
 import { KEYBOARD_SHORTCUTS } from './keyboard'
 import {
@@ -61,6 +62,8 @@ export interface Settings {
   density: Density
   /** Default feed shown on app launch */
   defaultFeed: DefaultFeed
+  /** Refresh every feed in the background on a timer */
+  autoRefresh: boolean
 }
 
 /** localStorage key for persisting settings */
@@ -73,8 +76,12 @@ const DEFAULT_SETTINGS: Settings = {
   fontSize: 'normal',
   density: 'normal',
   defaultFeed: 'top',
+  autoRefresh: false,
 }
 
+/** Background refresh interval when `autoRefresh` is on (5 minutes) */
+export const AUTO_REFRESH_INTERVAL_SECS = 300
+
 /** Current in-memory settings state */
 let currentSettings: Settings = { ...DEFAULT_SETTINGS }
 
@@ -130,9 +137,15 @@ export function loadSettings(): Settings {
  * Save settings to localStorage
  */
 export function saveSettings(settings: Partial<Settings>): void {
+  const autoRefreshChanged =
+    settings.autoRefresh !== undefined &&
+    settings.autoRefresh !== currentSettings.autoRefresh
   currentSettings = { ...currentSettings, ...settings }
   localStorage.setItem(STORAGE_KEY, JSON.stringify(currentSettings))
   applySettings()
+  if (autoRefreshChanged) {
+    applyAutoRefresh()
+  }
 }
 
 /**
@@ -155,6 +168,7 @@ function isValidSettingKey(key: string): key is keyof Settings {
     'fontSize',
     'density',
     'defaultFeed',
+    'autoRefresh',
   ].includes(key)
 }
 
@@ -191,6 +205,22 @@ export function applySettings(): void {
   html.setAttribute('data-density', currentSettings.density)
 }
 
+/**
+ * Start or stop the backend's feed refresh timer to match the
+ * `autoRefresh` setting
+ *
+ * The timer is app-wide, so at startup only the main window applies it.
+ */
+export async function applyAutoRefresh(): Promise<void> {
+  try {
+    await setAutoRefresh(
+      currentSettings.autoRefresh ? AUTO_REFRESH_INTERVAL_SECS : null,
+    )
+  } catch (error) {
+    console.warn('Failed to update auto-refresh:', error)
+  }
+}
+
 /**
  * Initialize settings system
  */
@@ -328,6 +358,20 @@ export async function showSettingsModal(): Promise<void> {
           </div>
         </div>
         
+        <!-- Auto Refresh -->
+        <div class="settings-section">
+          <h3 class="settings-section-title">${settingsIcons.history}Auto Refresh</h3>
+          <div class="settings-options">
+            <button class="settings-option ${currentSettings.autoRefresh ? 'active' : ''}" data-setting="autoRefresh" data-value="true">
+              <span>On</span>
+            </button>
+            <button class="settings-option ${!currentSettings.autoRefresh ? 'active' : ''}" data-setting="autoRefresh" data-value="false">
+              <span>Off</span>
+            </button>
+          </div>
+          <p class="settings-hint">Refresh every feed in the background every 5 minutes</p>
+        </div>
+        
         <!-- Reading History -->
         <div class="settings-section">
           <h3 class="settings-section-title">${settingsIcons.history}Reading History</h3>
@@ -439,8 +483,8 @@ export async function showSettingsModal(): Promise<void> {
           optionBtn.classList.add('active')
         }
 
-        // Convert highContrast value from string to boolean
-        if (setting === 'highContrast') {
+        // Convert boolean settings from strings
+        if (setting === 'highContrast' || setting === 'autoRefresh') {
           updateSetting(setting, value === 'true')
         } else {
           // Save setting with type-safe helper
@@ -918,12 +962,19 @@ export function validateSettings(data: unknown): Settings | null {
     return null
   }
 
+  // Validate autoRefresh (optional for backwards compatibility)
+  const autoRefresh = settingsObj.autoRefresh ?? false
+  if (autoRefresh !== false && autoRefresh !== true) {
+    return null
+  }
+
   return {
     theme: theme as Settings['theme'],
     highContrast: validatedHighContrast,
     fontSize: fontSize as Settings['fontSize'],
     density: density as Settings['density'],
     defaultFeed: defaultFeed as Settings['defaultFeed'],
+    autoRefresh,
   }
 }
 
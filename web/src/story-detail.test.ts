@@ -4,6 +4,9 @@ import { afterEach, beforeEach, describe, expect, it, vi } from 'vitest'
 vi.mock('./api', () => ({
   fetchStoryWithComments: vi.fn(),
   fetchCommentChildren: vi.fn(),
+  markCommentsSeen: vi.fn().mockResolvedValue(undefined),
+  recordArticleRead: vi.fn().mockResolvedValue(undefined),
+  recordStoryOpened: vi.fn().mockResolvedValue(undefined),
   fetchArticleContent: vi.fn().mockResolvedValue({
     title: 'Article Title',
     content: '<p>Article content</p>',
@@ -14,6 +17,16 @@ vi.mock('./api', () => ({
     lang: 'en',
     wordCount: 500,
   }),
+  fetchReaderContent: vi.fn().mockResolvedValue({
+    title: 'Ask HN: Test Question',
+    content: '<p>This is a text post with content</p>',
+    textContent: 'This is a text post with content',
+    byline: 'author',
+    excerpt: null,
+    siteName: null,
+    lang: null,
+    wordCount: 7,
+  }),
   extractDomain: vi
     .fn()
     .mockImplementation((url) =>
@@ -129,7 +142,12 @@ import {
   applyStaggerAnimation,
 } from './animations'
 // Import after mocks
-import { fetchCommentChildren, fetchStoryWithComments } from './api'
+import {
+  fetchArticleContent,
+  fetchCommentChildren,
+  fetchReaderContent,
+  fetchStoryWithComments,
+} from './api'
 import { setStoryContext, updateAssistantZenMode } from './assistant-ui'
 import { parseApiError, renderErrorWithRetry, showErrorToast } from './errors'
 import { isCurrentlyOffline } from './offline'
@@ -161,6 +179,8 @@ import { isZenModeActive } from './zen-mode'
 // Typed mocks
 const mockFetchStoryWithComments = vi.mocked(fetchStoryWithComments)
 const mockFetchCommentChildren = vi.mocked(fetchCommentChildren)
+const mockFetchArticleContent = vi.mocked(fetchArticleContent)
+const mockFetchReaderContent = vi.mocked(fetchReaderContent)
 const mockMarkStoryAsRead = vi.mocked(markStoryAsRead)
 const mockSaveStoryCommentCount = vi.mocked(saveStoryCommentCount)
 const mockIsStoryBookmarked = vi.mocked(isStoryBookmarked)
@@ -579,6 +599,27 @@ describe('story-detail', () => {
 
       expect(container.innerHTML).toContain('This is a text post with content')
     })
+
+    it('loads reader content for text posts from the story itself', async () => {
+      mockFetchStoryWithComments.mockResolvedValue({
+        story: mockStoryWithText,
+        comments: [],
+      })
+
+      await renderStoryDetail(456, container, new Set())
+
+      expect(mockFetchReaderContent).toHaveBeenCalledWith(456)
+      expect(mockFetchArticleContent).not.toHaveBeenCalled()
+    })
+
+    it('downloads the article for link posts', async () => {
+      await renderStoryDetail(123, container, new Set())
+
+      expect(mockFetchArticleContent).toHaveBeenCalledWith(
+        'https://example.com/article',
+      )
+      expect(mockFetchReaderContent).not.toHaveBeenCalled()
+    })
   })
 
   describe('error handling', () => {
@@ -13,8 +13,12 @@ import {
   extractDomain,
   fetchArticleContent,
   fetchCommentChildren,
+  fetchReaderContent,
   fetchStoryWithComments,
   formatTimeAgo,
+  markCommentsSeen,
+  recordArticleRead,
+  recordStoryOpened,
 } from './api'
 import { setStoryContext, updateAssistantZenMode } from './assistant-ui'
 import { parseApiError, renderErrorWithRetry, showErrorToast } from './errors'
@@ -39,7 +43,7 @@ import {
   isDefaultNeuralModelReady,
   setupTtsListeners,
 } from './tts-ui'
-import { type HNItem, ItemType } from './types'
+import { type HNItem, ItemType, type StoryFeed } from './types'
 import {
   calculateReadingTime,
   countWords,
@@ -177,19 +181,45 @@ function setupStoryTabs(container: HTMLElement): void {
 }
 
 /**
- * Fetch article content from external URL and display it.
+ * Fetch reader-mode content for a story and display it.
+ *
+ * Articles are downloaded from `url`. Self-posts (no `url`) are read from
+ * their own text, which is already shown and stays on failure.
  */
 async function fetchAndDisplayArticle(
-  url: string,
+  story: HNItem,
+  url: string | null,
   container: HTMLElement,
 ): Promise<void> {
   const articleContainer = container.querySelector('.article-content')
   if (!articleContainer) return
 
+  if (!url) {
+    try {
+      const article = await fetchReaderContent(story.id)
+      if (!article.content) return
+
+      recordArticleRead(article.wordCount).catch(() => {})
+      const readingTime = article.wordCount
+        ? calculateReadingTime(article.wordCount)
+        : ''
+      articleContainer.innerHTML = `
+        <div class="article-reader">
+          ${readingTime ? `<div class="article-meta"><span class="article-reading-time">${icons.clock}${readingTime}</span></div>` : ''}
+          <div class="article-body story-detail-text">${sanitizeHtml(article.content)}</div>
+        </div>
+      `
+    } catch (error) {
+      console.error('Failed to load self-post reader content:', error)
+    }
+    return
+  }
+
   try {
     const article = await fetchArticleContent(url)
 
     if (article.content) {
+      recordArticleRead(article.wordCount).catch(() => {})
       const readingTime = article.wordCount
         ? calculateReadingTime(article.wordCount)
         : ''
@@ -415,13 +445,15 @@ function renderOfflineStoryDetail(container: HTMLElement, story: HNItem): void {
 }
 
 /**
- * Render story detail view.
+ * Render story detail view, with comments to the depth configured for
+ * `feed`.
  */
 export async function renderStoryDetail(
   storyId: number,
   container: HTMLElement,
   readStoryIds: Set<number>,
   clickedStoryEl?: HTMLElement,
+  feed?: StoryFeed,
 ): Promise<void> {
   if (isLoading) return
   isLoading = true
@@ -468,7 +500,7 @@ export async function renderStoryDetail(
   try {
     const cachedData = getCachedStoryDetail(storyId)
     const { story, comments } =
-      cachedData || (await fetchStoryWithComments(storyId, 1))
+      cachedData || (await fetchStoryWithComments(storyId, undefined, feed))
 
     currentStoryAuthor = story.by
     currentStoryData = story
@@ -492,6 +524,9 @@ export async function renderStoryDetail(
     const isYouTube = youtubeInfo !== null
     const commentCount = story.descendants || 0
     currentStoryCommentCount = commentCount
+    // Baseline for "new comments since last visit"
+    markCommentsSeen(story.id, commentCount).catch(() => {})
+    recordStoryOpened().catch(() => {})
 
     const textWordCount = story.text ? countWords(story.text) : 0
     const textReadingTime =
@@ -585,7 +620,9 @@ export async function renderStoryDetail(
           `
                 : story.text
                   ? `
-            <div class="story-detail-text">${sanitizeHtml(story.text)}</div>
+            <div class="article-content">
+              <div class="story-detail-text">${sanitizeHtml(story.text)}</div>
+            </div>
           `
                   : `
             <div class="no-content">
@@ -619,9 +656,11 @@ export async function renderStoryDetail(
       applyStaggerAnimation(commentsList as HTMLElement, ':scope > .comment')
     }
 
-    // Only fetch article content for non-YouTube external URLs
+    // Reader content for non-YouTube external URLs and for self-posts
     if (hasExternalUrl && story.url && !isYouTube) {
-      fetchAndDisplayArticle(story.url, container)
+      fetchAndDisplayArticle(story, story.url, container)
+    } else if (!hasExternalUrl && story.text) {
+      fetchAndDisplayArticle(story, null, container)
     }
 
     setStoryContext(story, comments)
@@ -93,6 +93,7 @@ import {
   getCurrentStories,
   getFeedTitle,
   isYouTubeFilterActive,
+  loadMoreStories,
   renderStories,
   setCurrentFeed,
   toggleYouTubeFilter,
@@ -305,6 +306,31 @@ describe('renderStories caching', () => {
     expect(mockFetchStoriesPaginated).not.toHaveBeenCalled()
     expect(getCurrentStories().length).toBe(30)
   })
+
+  it('continues from the nextOffset of the last page', async () => {
+    // Story 2 was dead and skipped, so the first page used three IDs
+    mockFetchStoriesPaginated.mockResolvedValueOnce({
+      stories: mockStories,
+      hasMore: true,
+      total: 10,
+      nextOffset: 3,
+    })
+    await renderStories('top', false, false)
+
+    mockFetchStoriesPaginated.mockResolvedValueOnce({
+      stories: [{ ...mockStories[0], id: 4 }],
+      hasMore: false,
+      total: 10,
+      nextOffset: 4,
+    })
+    await loadMoreStories()
+
+    expect(mockFetchStoriesPaginated).toHaveBeenLastCalledWith(
+      'top',
+      3,
+      expect.any(Number),
+    )
+  })
 })
 
 describe('YouTube filter', () => {
@@ -8,6 +8,7 @@ import { animateListEnter, applyStaggerAnimation } from './animations'
 import {
   clearStoryIdsCache,
   fetchStoriesPaginated,
+  listenForNewStories,
   onFeedRefresh,
   triggerBackgroundRefreshIfStale,
 } from './api'
@@ -209,7 +210,7 @@ function setupStoryHoverPrefetch(container: HTMLElement): void {
     if (!storyId) return
 
     card.addEventListener('mouseenter', () => {
-      onStoryHoverStart(storyId)
+      onStoryHoverStart(storyId, currentFeed)
     })
 
     card.addEventListener('mouseleave', () => {
@@ -267,7 +268,7 @@ function renderStoriesStandard(
   setupStoryHoverPrefetch(container)
 
   const storyIds = filteredStories.map((s) => s.id)
-  prefetchVisibleStories(storyIds)
+  prefetchVisibleStories(storyIds, currentFeed)
 
   setupInfiniteScroll()
   updateAssistantZenMode(isZenModeActive(), 'list')
@@ -325,7 +326,7 @@ function renderSavedStories(container: HTMLElement, stories: HNItem[]): void {
   setupStoryHoverPrefetch(container)
 
   const storyIds = filteredStories.map((s) => s.id)
-  prefetchVisibleStories(storyIds)
+  prefetchVisibleStories(storyIds, currentFeed)
 
   updateAssistantZenMode(isZenModeActive(), 'list')
 }
@@ -379,7 +380,7 @@ async function loadMoreStoriesVirtual(): Promise<void> {
   isLoadingMore = true
 
   try {
-    const { stories, hasMore } = await fetchStoriesPaginated(
+    const { stories, hasMore, nextOffset } = await fetchStoriesPaginated(
       currentFeed,
       currentOffset,
       STORIES_PER_PAGE,
@@ -387,7 +388,7 @@ async function loadMoreStoriesVirtual(): Promise<void> {
 
     if (stories.length > 0 && virtualScroll) {
       currentStories = [...currentStories, ...stories]
-      currentOffset += stories.length
+      currentOffset = nextOffset
       hasMoreStories = hasMore
 
       currentDuplicates = findDuplicates(currentStories)
@@ -433,11 +434,6 @@ function setupInfiniteScroll(): void {
     (entries) => {
       const entry = entries[0]
       if (entry.isIntersecting && hasMoreStories && !isLoadingMore) {
-        prefetchNextPage(
-          currentFeed,
-          currentOffset + STORIES_PER_PAGE,
-          STORIES_PER_PAGE,
-        )
         loadMoreStories()
       }
     },
@@ -468,7 +464,7 @@ export async function loadMoreStories(): Promise<void> {
   }
 
   try {
-    const { stories, hasMore } = await fetchStoriesPaginated(
+    const { stories, hasMore, nextOffset } = await fetchStoriesPaginated(
       currentFeed,
       currentOffset,
       STORIES_PER_PAGE,
@@ -518,14 +514,20 @@ export async function loadMoreStories(): Promise<void> {
         const storyId = Number(el.dataset.id)
         if (!storyId) return
         el.dataset.prefetchBound = 'true'
-        el.addEventListener('mouseenter', () => onStoryHoverStart(storyId))
+        el.addEventListener('mouseenter', () =>
+          onStoryHoverStart(storyId, currentFeed),
+        )
         el.addEventListener('mouseleave', () => onStoryHoverEnd(storyId))
       })
 
       currentStories = [...currentStories, ...stories]
-      currentOffset += stories.length
+      currentOffset = nextOffset
       hasMoreStories = hasMore
 
+      if (hasMore) {
+        prefetchNextPage(currentFeed, nextOffset, STORIES_PER_PAGE)
+      }
+
       setupInfiniteScroll()
       maybeEnableVirtualScroll()
     }
@@ -649,19 +651,22 @@ export async function renderStories(
   try {
     let stories: HNItem[]
     let hasMore: boolean
+    let nextOffset: number
 
     // Handle 'saved' feed specially - load from local storage
     if (feed === 'saved') {
       stories = getBookmarkedStories()
       hasMore = false
+      nextOffset = stories.length
     } else {
       const result = await fetchStoriesPaginated(feed, 0, STORIES_PER_PAGE)
       stories = result.stories
       hasMore = result.hasMore
+      nextOffset = result.nextOffset
     }
 
     currentStories = stories
-    currentOffset = stories.length
+    currentOffset = nextOffset
     hasMoreStories = hasMore
 
     // Compute duplicate stories for the current feed
@@ -790,5 +795,26 @@ function handleFeedRefresh(feed: StoryFeed, newIds: number[]): void {
   }
 }
 
+/**
+ * Handle a backend-announced refresh (the refresh timer, or a one-off
+ * background refresh). The backend counts stories new to its cached feed.
+ */
+function handleNewStories(feed: StoryFeed, newCount: number): void {
+  if (feed !== currentFeed || newCount === 0) return
+  showNewStoriesBanner(newCount)
+}
+
+/**
+ * Start showing the new stories banner for backend refresh events.
+ * Call this after Tauri is available.
+ */
+export async function initNewStoriesListener(): Promise<void> {
+  try {
+    await listenForNewStories(handleNewStories)
+  } catch (error) {
+    console.debug('Feed refresh events not available:', error)
+  }
+}
+
 // Register the background refresh callback on module load
 onFeedRefresh(handleFeedRefresh)
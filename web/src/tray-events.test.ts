@@ -41,8 +41,9 @@ describe('tray-events', () => {
 
       await initTrayEvents()
 
-      // Should set up 3 listeners: feed-change, refresh, search
-      expect(mockListen).toHaveBeenCalledTimes(3)
+      // Should set up 6 listeners: feed-change, refresh, search, open-story,
+      // next-story, prev-story
+      expect(mockListen).toHaveBeenCalledTimes(6)
       expect(mockListen).toHaveBeenCalledWith(
         'tray-feed-change',
         expect.any(Function),
@@ -55,6 +56,18 @@ describe('tray-events', () => {
         'tray-search',
         expect.any(Function),
       )
+      expect(mockListen).toHaveBeenCalledWith(
+        'tray-open-story',
+        expect.any(Function),
+      )
+      expect(mockListen).toHaveBeenCalledWith(
+        'tray-next-story',
+        expect.any(Function),
+      )
+      expect(mockListen).toHaveBeenCalledWith(
+        'tray-prev-story',
+        expect.any(Function),
+      )
 
       // Cleanup
       delete (window as unknown as Record<string, unknown>).__TAURI__
@@ -81,14 +94,78 @@ describe('tray-events', () => {
       await initTrayEvents()
       cleanupTrayEvents()
 
-      // Should call all 3 unlisten functions
-      expect(mockUnlisten).toHaveBeenCalledTimes(3)
+      // Should call all 6 unlisten functions
+      expect(mockUnlisten).toHaveBeenCalledTimes(6)
 
       delete (window as unknown as Record<string, unknown>).__TAURI__
     })
   })
 
   describe('configureTrayEvents', () => {
+    it('should call onNextStory and onPrevStory for story shortcuts', async () => {
+      ;(window as unknown as Record<string, unknown>).__TAURI__ = {}
+
+      const onNextStory = vi.fn()
+      const onPrevStory = vi.fn()
+      configureTrayEvents({
+        onFeedChange: vi.fn(),
+        onRefresh: vi.fn(),
+        onSearch: vi.fn(),
+        onNextStory,
+        onPrevStory,
+      })
+
+      const listeners: Record<string, () => void> = {}
+      mockListen.mockImplementation(
+        async (eventName: string, callback: () => void) => {
+          listeners[eventName] = callback
+          return vi.fn()
+        },
+      )
+
+      await initTrayEvents()
+
+      listeners['tray-next-story']()
+      listeners['tray-next-story']()
+      listeners['tray-prev-story']()
+      expect(onNextStory).toHaveBeenCalledTimes(2)
+      expect(onPrevStory).toHaveBeenCalledTimes(1)
+
+      delete (window as unknown as Record<string, unknown>).__TAURI__
+    })
+
+    it('should call onOpenStory with the story id', async () => {
+      ;(window as unknown as Record<string, unknown>).__TAURI__ = {}
+
+      const onOpenStory = vi.fn()
+      configureTrayEvents({
+        onFeedChange: vi.fn(),
+        onRefresh: vi.fn(),
+        onSearch: vi.fn(),
+        onOpenStory,
+      })
+
+      let storyListener: (event: { payload: number }) => void = () => {}
+      mockListen.mockImplementation(
+        async (
+          eventName: string,
+          callback: (event: { payload: number }) => void,
+        ) => {
+          if (eventName === 'tray-open-story') {
+            storyListener = callback
+          }
+          return vi.fn()
+        },
+      )
+
+      await initTrayEvents()
+
+      storyListener({ payload: 8863 })
+      expect(onOpenStory).toHaveBeenCalledWith(8863)
+
+      delete (window as unknown as Record<string, unknown>).__TAURI__
+    })
+
     it('should call onFeedChange callback with valid feed', async () => {
       ;(window as unknown as Record<string, unknown>).__TAURI__ = {}
 
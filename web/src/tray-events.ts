@@ -9,6 +9,11 @@ export interface TrayEventCallbacks {
   onFeedChange: (feed: StoryFeed) => void
   onRefresh: () => void
   onSearch: () => void
+  /** A story was picked from the tray's "Recent" submenu */
+  onOpenStory?: (storyId: number) => void
+  /** Next/previous story global shortcuts */
+  onNextStory?: () => void
+  onPrevStory?: () => void
 }
 
 let callbacks: TrayEventCallbacks | null = null
@@ -58,6 +63,25 @@ export async function initTrayEvents(): Promise<void> {
       }
     })
     unlisteners.push(unlistenSearch)
+
+    // Listen for stories picked from the tray's Recent submenu
+    const unlistenStory = await listen<number>('tray-open-story', (event) => {
+      if (callbacks?.onOpenStory && Number.isInteger(event.payload)) {
+        callbacks.onOpenStory(event.payload)
+      }
+    })
+    unlisteners.push(unlistenStory)
+
+    // Listen for next/previous story global shortcuts
+    const unlistenNext = await listen('tray-next-story', () => {
+      callbacks?.onNextStory?.()
+    })
+    unlisteners.push(unlistenNext)
+
+    const unlistenPrev = await listen('tray-prev-story', () => {
+      callbacks?.onPrevStory?.()
+    })
+    unlisteners.push(unlistenPrev)
   } catch (error) {
     // Not in Tauri environment or event API not available
     console.debug('Tray events not available:', error)
@@ -58,6 +58,12 @@ describe('tts-client', () => {
         expect(result).toBe(false)
       })
 
+      it('should return false when speaking sentences', async () => {
+        const result = await client.speakSentences(['One.', 'Two.'], () => {})
+
+        expect(result).toBe(false)
+      })
+
       it('should return false when stopping', async () => {
         const result = await client.stop()
 
@@ -13,6 +13,8 @@
  */
 
 import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
+import type { SentenceEvent, SentenceEventCallback } from './tts-neural'
 
 /** Information about an available voice */
 export interface VoiceInfo {
@@ -24,6 +26,28 @@ export interface VoiceInfo {
   language: string | null
 }
 
+/** A voice from either TTS backend */
+export interface UnifiedVoice {
+  /** Voice identifier, as passed to the backend's set-voice command */
+  id: string
+  /** Human-readable voice name */
+  name: string
+  /** Language code (e.g., "en-US") */
+  language: string | null
+  /** Which backend speaks with this voice */
+  backend: 'neural' | 'native'
+  /** Whether the voice can be used now (neural voices need a download) */
+  downloaded: boolean
+}
+
+/** Which backend spoke, and why it fell back to the system voice */
+export interface SpeakOutcome {
+  backendUsed: 'neural' | 'native'
+  fellBack: boolean
+  /** User-facing explanation of the fallback */
+  reason: string | null
+}
+
 /** TTS service status */
 export interface TtsStatus {
   /** Whether TTS is available on this system */
@@ -32,8 +56,10 @@ export interface TtsStatus {
   is_speaking: boolean
   /** Current speech rate (0.0 - 1.0, where 0.5 is normal) */
   rate: number
-  /** Supported features on this platform */
+  /** Features the platform claims to support */
   features: TtsFeatures
+  /** Claimed features that also worked when tried at startup */
+  verified_features?: TtsFeatures
   /** Error message if not available */
   message: string | null
 }
@@ -49,6 +75,18 @@ export interface TtsFeatures {
   utterance_callbacks: boolean
 }
 
+/** Voices and rates saved in the backend settings (unset = default) */
+export interface VoiceSettings {
+  /** System voice id */
+  nativeVoice: string | null
+  /** System speech rate (0.0 - 1.0, where 0.5 is normal) */
+  nativeRate: number | null
+  /** Neural voice id */
+  neuralVoice: string | null
+  /** Neural speech rate (0.5 - 2.0, where 1.0 is normal) */
+  neuralRate: number | null
+}
+
 /** Default unavailable status for non-Tauri environments */
 const UNAVAILABLE_STATUS: TtsStatus = {
   available: false,
@@ -89,6 +127,15 @@ export class TtsClient {
     return this.available
   }
 
+  /**
+   * Whether a feature works on this platform, preferring the features
+   * verified at startup over the claimed ones
+   */
+  supports(feature: keyof TtsFeatures): boolean {
+    const status = this.lastStatus
+    return (status.verified_features ?? status.features)[feature]
+  }
+
   /** Get the last known status */
   getLastStatus(): TtsStatus {
     return this.lastStatus
@@ -133,8 +180,15 @@ export class TtsClient {
       if (status.available) {
         this.voices = await this.fetchVoices()
 
-        // Load saved preferences
-        this.loadPreferences()
+        // The backend re-applies its saved voice and rate on init; older
+        // installs only have them in localStorage
+        const saved = await getVoiceSettings()
+        if (saved?.nativeVoice || saved?.nativeRate != null) {
+          this.selectedVoiceId = saved.nativeVoice
+          this.currentRate = saved.nativeRate ?? this.currentRate
+        } else {
+          this.loadPreferences()
+        }
       }
 
       return status
@@ -189,6 +243,43 @@ export class TtsClient {
     }
   }
 
+  /**
+   * Speak sentences one-by-one with progress events
+   *
+   * Emits the same sentence events as neural TTS, so the UI can highlight
+   * the current sentence. Resolves when all sentences are spoken or stopped.
+   *
+   * @param sentences - Array of sentences to speak
+   * @param onSentenceEvent - Callback for sentence events
+   */
+  async speakSentences(
+    sentences: string[],
+    onSentenceEvent: SentenceEventCallback,
+  ): Promise<boolean> {
+    if (!isTauri() || !this.available) {
+      console.warn('TTS not available')
+      return false
+    }
+
+    // Events can arrive after the command resolves, so stop listening on
+    // the final `finished` event (it also follows `stopped`)
+    const unlisten = await listen<SentenceEvent>('tts-sentence', (event) => {
+      onSentenceEvent(event.payload)
+      if (event.payload.type === 'finished') {
+        unlisten()
+      }
+    })
+
+    try {
+      await invoke('tts_speak_sentences', { sentences })
+      return true
+    } catch (error) {
+      console.error('Failed to speak sentences:', error)
+      unlisten()
+      return false
+    }
+  }
+
   /**
    * Stop any current speech
    */
@@ -305,6 +396,61 @@ export class TtsClient {
 // Singleton instance
 let ttsClient: TtsClient | null = null
 
+/**
+ * Speak an article with a voice in its language
+ * @param text - Text to speak
+ * @param lang - The article's language tag (e.g. "fr"), if known
+ * @param neural - Prefer a neural voice
+ */
+export async function speakArticle(
+  text: string,
+  lang: string | null,
+  neural = false,
+): Promise<SpeakOutcome | null> {
+  if (!isTauri()) {
+    return null
+  }
+
+  try {
+    return await invoke<SpeakOutcome>('speak_article', { text, lang, neural })
+  } catch (error) {
+    console.error('Failed to speak article:', error)
+    return null
+  }
+}
+
+/**
+ * List system and neural voices together, neural voices first
+ */
+export async function listAllVoices(): Promise<UnifiedVoice[]> {
+  if (!isTauri()) {
+    return []
+  }
+
+  try {
+    return await invoke<UnifiedVoice[]>('list_all_voices')
+  } catch (error) {
+    console.error('Failed to list voices:', error)
+    return []
+  }
+}
+
+/**
+ * Get the voices and rates saved for both TTS backends
+ */
+export async function getVoiceSettings(): Promise<VoiceSettings | null> {
+  if (!isTauri()) {
+    return null
+  }
+
+  try {
+    return await invoke<VoiceSettings>('tts_voice_settings')
+  } catch (error) {
+    console.error('Failed to get saved voice settings:', error)
+    return null
+  }
+}
+
 /**
  * Get the TTS client singleton
  */
@@ -12,6 +12,8 @@
 
 import { invoke } from '@tauri-apps/api/core'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
+import { toastError } from './toast'
+import { getVoiceSettings } from './tts-client'
 
 // Debug flag for logging
 const DEBUG = false
@@ -43,6 +45,10 @@ export interface NeuralTtsStatus {
   currentVoice: string | null
   rate: number
   downloadProgress: number | null
+  /** Whether the model is in memory (it reloads on demand when not) */
+  loaded?: boolean
+  /** Whether espeak-ng, which synthesis needs, is installed */
+  espeakAvailable?: boolean
   voices: NeuralVoiceInfo[]
   message: string | null
 }
@@ -53,6 +59,10 @@ export interface ModelDownloadProgress {
   progress: number
   bytesDownloaded: number
   totalBytes: number
+  /** Average transfer rate in bytes per second */
+  bytesPerSec?: number
+  /** Estimated seconds remaining, null until known */
+  etaSecs?: number | null
 }
 
 /** Model information */
@@ -64,6 +74,31 @@ export interface ModelInfo {
   isDownloaded: boolean
 }
 
+/** A model file whose live size differs from the registry */
+export interface ModelFileMismatch {
+  modelId: string
+  file: string
+  url: string
+  expectedSize: number
+  /** Content-Length reported upstream, null if it couldn't be read */
+  liveSize: number | null
+  error: string | null
+}
+
+/** Result of checking all model files against their live sizes */
+export interface ModelValidationReport {
+  filesChecked: number
+  mismatches: ModelFileMismatch[]
+}
+
+/** Narration synthesized ahead of playback by tts_neural_prepare */
+export interface PreparedNarration {
+  /** Sentences to pass to speakSentences to play from the cache */
+  sentences: string[]
+  sentenceCount: number
+  durationSecs: number
+}
+
 /** Neural TTS preferences */
 export interface NeuralTtsPreferences {
   enabled: boolean
@@ -77,9 +112,24 @@ export interface NeuralTtsPreferences {
 export type SentenceEvent =
   | { type: 'start'; index: number; text: string }
   | { type: 'end'; index: number }
+  | {
+      type: 'error'
+      index: number
+      stage: 'synthesis' | 'playback'
+      reason: string
+    }
   | { type: 'finished' }
   | { type: 'stopped' }
 
+/** A pronunciation dictionary entry */
+export interface PronunciationEntry {
+  /** Word or phrase as it appears in text (matched as whole words) */
+  word: string
+  /** Respelling (e.g. "engine x"), or IPA if `ipa` is set */
+  pronunciation: string
+  ipa: boolean
+}
+
 /** Callback for sentence events */
 export type SentenceEventCallback = (event: SentenceEvent) => void
 
@@ -111,6 +161,13 @@ function isTauri(): boolean {
   return typeof window !== 'undefined' && '__TAURI_INTERNALS__' in window
 }
 
+/** Backend error when there is no audio output device */
+export const NO_OUTPUT_DEVICE_MESSAGE = 'No audio output device available'
+
+function isNoOutputDeviceError(error: unknown): boolean {
+  return String(error).includes(NO_OUTPUT_DEVICE_MESSAGE)
+}
+
 /**
  * Neural TTS client class
  *
@@ -187,6 +244,15 @@ export class NeuralTtsClient {
       debug('tts_neural_init completed')
       this.initialized = true
 
+      // Reflect the voice and rate the backend restored
+      const saved = await getVoiceSettings()
+      if (saved?.neuralVoice) {
+        this.preferences.preferredVoiceId = saved.neuralVoice
+      }
+      if (saved?.neuralRate != null) {
+        this.preferences.rate = saved.neuralRate
+      }
+
       // Get current status
       debug('Fetching status...')
       this.status = await this.fetchStatus()
@@ -376,6 +442,12 @@ export class NeuralTtsClient {
         debugError('Error stack:', error.stack)
       }
 
+      // Nothing can play without an output device, native voices included
+      if (isNoOutputDeviceError(error)) {
+        toastError(NO_OUTPUT_DEVICE_MESSAGE)
+        return false
+      }
+
       // Fall back to native TTS if enabled (for cases where Rust backend also failed)
       if (this.preferences.useFallbackOnError) {
         debug('Falling back to native TTS')
@@ -413,6 +485,113 @@ export class NeuralTtsClient {
     }
   }
 
+  /**
+   * Set the output latency used to time sentence highlighting
+   * @param latencyMs - Delay before queued audio is audible, or null to
+   *   derive it from the output device
+   */
+  async setOutputLatency(latencyMs: number | null): Promise<boolean> {
+    if (!isTauri()) {
+      return false
+    }
+
+    try {
+      await invoke('tts_neural_set_output_latency', {
+        latencyMs:
+          latencyMs === null ? null : Math.max(0, Math.round(latencyMs)),
+      })
+      return true
+    } catch (error) {
+      debugError('Failed to set neural TTS output latency:', error)
+      return false
+    }
+  }
+
+  /**
+   * Set whether sentence playback continues after a sentence fails,
+   * instead of stopping
+   */
+  async setContinueOnError(enabled: boolean): Promise<boolean> {
+    if (!isTauri()) {
+      return false
+    }
+
+    try {
+      await invoke('tts_neural_set_continue_on_error', { enabled })
+      return true
+    } catch (error) {
+      debugError('Failed to set neural TTS error handling:', error)
+      return false
+    }
+  }
+
+  /**
+   * Set whether the saved neural voice is warmed up when the app starts,
+   * so the first sentence spoken has no delay
+   */
+  async setWarmupOnStartup(enabled: boolean): Promise<boolean> {
+    if (!isTauri()) {
+      return false
+    }
+
+    try {
+      await invoke('tts_neural_set_warmup_on_startup', { enabled })
+      return true
+    } catch (error) {
+      debugError('Failed to set neural TTS startup warmup:', error)
+      return false
+    }
+  }
+
+  /**
+   * Jump to a position in the current narration. Fails (returns false) while
+   * speaking sentences; use skipNext/skipPrev there
+   * @param positionMs - Offset from the start of the narration
+   */
+  async seek(positionMs: number): Promise<boolean> {
+    if (!isTauri()) {
+      return false
+    }
+
+    try {
+      await invoke('tts_neural_seek', {
+        positionMs: Math.max(0, Math.round(positionMs)),
+      })
+      return true
+    } catch (error) {
+      debugError('Failed to seek neural TTS:', error)
+      return false
+    }
+  }
+
+  /**
+   * Skip to the next sentence while speaking sentences
+   */
+  async skipNext(): Promise<boolean> {
+    return this.skip('tts_neural_skip_next')
+  }
+
+  /**
+   * Go back to the previous sentence while speaking sentences
+   */
+  async skipPrev(): Promise<boolean> {
+    return this.skip('tts_neural_skip_prev')
+  }
+
+  private async skip(command: string): Promise<boolean> {
+    if (!isTauri()) {
+      return false
+    }
+
+    try {
+      await invoke(command)
+      return true
+    } catch (error) {
+      debugError('Failed to skip sentence:', error)
+      return false
+    }
+  }
+
   /**
    * Speak sentences one-by-one with progress events
    *
@@ -475,6 +654,9 @@ export class NeuralTtsClient {
     } catch (error) {
       this.status.isSpeaking = false
       debugError('Failed to speak sentences with neural TTS:', error)
+      if (isNoOutputDeviceError(error)) {
+        toastError(NO_OUTPUT_DEVICE_MESSAGE)
+      }
 
       // Clean up listener
       await this.cleanupSentenceEventListener()
@@ -483,6 +665,36 @@ export class NeuralTtsClient {
     }
   }
 
+  /**
+   * Synthesize and cache narration for text without playing it
+   *
+   * Pass the returned sentences to speakSentences with the same voice to
+   * play them without synthesis delay.
+   *
+   * @param text - Article text to narrate
+   * @param voiceId - Optional voice ID (uses preference if not specified)
+   * @returns The prepared sentences and total duration, or null on failure
+   */
+  async prepare(
+    text: string,
+    voiceId?: string,
+  ): Promise<PreparedNarration | null> {
+    if (!isTauri()) {
+      return null
+    }
+
+    try {
+      return await invoke<PreparedNarration>('tts_neural_prepare', {
+        text,
+        voiceId: voiceId || this.preferences.preferredVoiceId,
+        rate: this.preferences.rate,
+      })
+    } catch (error) {
+      debugError('Failed to prepare narration:', error)
+      return null
+    }
+  }
+
   /**
    * Add a listener for sentence events
    * @param callback - Function to call when sentence events occur
@@ -566,6 +778,13 @@ export class NeuralTtsClient {
     const clampedRate = Math.max(0.5, Math.min(2.0, rate))
     this.preferences.rate = clampedRate
     this.savePreferences()
+
+    // Also saved by the backend, which re-applies it on init
+    if (this.initialized) {
+      invoke('tts_neural_set_rate', { rate: clampedRate }).catch((error) =>
+        debugError('Failed to save neural rate:', error),
+      )
+    }
     return true
   }
 
@@ -576,6 +795,12 @@ export class NeuralTtsClient {
   setVoice(voiceId: string): void {
     this.preferences.preferredVoiceId = voiceId
     this.savePreferences()
+
+    if (this.initialized) {
+      invoke('tts_neural_set_voice', { voiceId }).catch((error) =>
+        debugError('Failed to save neural voice:', error),
+      )
+    }
   }
 
   /**
@@ -625,6 +850,23 @@ export class NeuralTtsClient {
     }
   }
 
+  /**
+   * Move model storage to another directory, null for the default
+   * @returns The directory now in use, or null if it couldn't be changed
+   */
+  async setModelDirectory(path: string | null): Promise<string | null> {
+    if (!isTauri()) {
+      return null
+    }
+
+    try {
+      return await invoke<string>('tts_set_model_directory', { path })
+    } catch (error) {
+      debugError('Failed to set model directory:', error)
+      return null
+    }
+  }
+
   /**
    * Get total disk usage for models
    */
@@ -641,6 +883,22 @@ export class NeuralTtsClient {
     }
   }
 
+  /**
+   * Check every downloadable model's file sizes against the server
+   */
+  async validateModels(): Promise<ModelValidationReport | null> {
+    if (!isTauri()) {
+      return null
+    }
+
+    try {
+      return await invoke<ModelValidationReport>('tts_validate_models')
+    } catch (error) {
+      debugError('Failed to validate models:', error)
+      return null
+    }
+  }
+
   /**
    * Convert voice ID to model ID
    */
@@ -743,6 +1001,42 @@ export function isNeuralTtsSupported(): boolean {
   return isTauri()
 }
 
+/**
+ * List the pronunciation dictionary, sorted by word
+ */
+export async function getPronunciations(): Promise<PronunciationEntry[]> {
+  if (!isTauri()) return []
+  return invoke<PronunciationEntry[]>('tts_neural_pronunciations')
+}
+
+/**
+ * Add or replace a pronunciation dictionary entry
+ * @returns The updated dictionary
+ */
+export async function addPronunciation(
+  word: string,
+  pronunciation: string,
+  ipa = false,
+): Promise<PronunciationEntry[]> {
+  return invoke<PronunciationEntry[]>('tts_neural_add_pronunciation', {
+    word,
+    pronunciation,
+    ipa,
+  })
+}
+
+/**
+ * Remove a pronunciation dictionary entry
+ * @returns The updated dictionary
+ */
+export async function removePronunciation(
+  word: string,
+): Promise<PronunciationEntry[]> {
+  return invoke<PronunciationEntry[]>('tts_neural_remove_pronunciation', {
+    word,
+  })
+}
+
 /**
  * Format bytes to human-readable string
  */
@@ -29,6 +29,13 @@ function debugError(...args: unknown[]): void {
   }
 }
 
+/** Shown instead of narrating with a neural voice when espeak-ng is missing */
+const ESPEAK_MISSING_WARNING =
+  'Neural voices need espeak-ng, which is not installed. Install it (see https://github.com/espeak-ng/espeak-ng/blob/master/docs/guide.md) and restart the app.'
+
+/** Long enough to read the install hint */
+const ESPEAK_WARNING_DURATION = 10000
+
 import { toastError, toastInfo, toastWarning } from './toast'
 import { getTtsClient, type VoiceInfo } from './tts-client'
 import {
@@ -710,6 +717,13 @@ export async function toggleNeuralReading(text: string): Promise<boolean> {
       }
     }
 
+    // Without espeak-ng every sentence would fail, so say so before any
+    // model download or synthesis
+    if (getNeuralClient().getStatus().espeakAvailable === false) {
+      toastWarning(ESPEAK_MISSING_WARNING, ESPEAK_WARNING_DURATION)
+      return false
+    }
+
     console.log(
       '[TTS] Checking neural availability - state.neuralAvailable:',
       state.neuralAvailable,
@@ -1002,7 +1016,9 @@ export async function handleVoiceChange(voiceId: string): Promise<void> {
 
   if (parsed.type === 'native' && state.nativeAvailable) {
     const nativeClient = getNativeClient()
-    await nativeClient.setVoice(parsed.voiceId)
+    if (nativeClient.supports('voice')) {
+      await nativeClient.setVoice(parsed.voiceId)
+    }
   } else if (parsed.type === 'neural' && state.neuralAvailable) {
     const neuralClient = getNeuralClient()
     neuralClient.setVoice(parsed.voiceId)
@@ -1020,8 +1036,8 @@ export async function handleRateChange(rate: number): Promise<void> {
   const normalizedRate = rate / 100
 
   // Update both clients
-  if (state.nativeAvailable) {
-    const nativeClient = getNativeClient()
+  const nativeClient = getNativeClient()
+  if (state.nativeAvailable && nativeClient.supports('rate')) {
     await nativeClient.setRate(normalizedRate)
   }
 
@@ -1529,6 +1545,15 @@ function handleSentenceEvent(event: SentenceEvent): void {
       // Sentence finished - could add transition effect here
       break
 
+    case 'error':
+      // A 'stopped' event follows unless playback continues past errors
+      toastError(
+        event.stage === 'synthesis'
+          ? `Couldn't generate speech for sentence ${event.index + 1}: ${event.reason}`
+          : `Couldn't play sentence ${event.index + 1}: ${event.reason}`,
+      )
+      break
+
     case 'finished':
       state.isPlaying = false
       state.currentSentenceIndex = null
@@ -47,8 +47,15 @@ export interface HNItem {
   dead: boolean
   /** Whether the item is deleted */
   deleted: boolean
+  /** Kind of submission; null for comments and poll options */
+  postKind?: PostKind | null
 }
 
+/**
+ * Kind of submission, classified by the backend
+ */
+export type PostKind = 'link' | 'text' | 'askHn' | 'showHn' | 'job' | 'poll'
+
 /**
  * A comment with its child comments recursively loaded
  * Used for comment tree rendering
@@ -58,6 +65,33 @@ export interface CommentWithChildren extends HNItem {
   children?: CommentWithChildren[]
 }
 
+/**
+ * A page of a comment's direct children
+ */
+export interface CommentChildrenResponse {
+  /** Children in this page, with nested replies */
+  comments: CommentWithChildren[]
+  /** Whether more direct children follow this page */
+  hasMore: boolean
+  /** Total number of direct children */
+  total: number
+}
+
+/**
+ * A comment with the thread it lives in
+ * Returned by fetch_comment_context
+ */
+export interface CommentContext {
+  /** The requested comment */
+  comment: HNItem
+  /** Parent comments, from the top-level comment down to the direct parent */
+  ancestors: HNItem[]
+  /** The story the thread belongs to, if it was reached */
+  story: HNItem | null
+  /** Whether the parent walk stopped at the depth cap */
+  truncated: boolean
+}
+
 /**
  * A story with its comment tree
  * Returned by fetch_story_with_comments
@@ -69,6 +103,69 @@ export interface StoryWithComments {
   comments: CommentWithChildren[]
 }
 
+/**
+ * How often a site appears among a feed's top stories
+ * Returned by top_domains
+ */
+export interface DomainCount {
+  /** Host without a leading `www.` */
+  domain: string
+  /** Number of stories linking to the domain */
+  count: number
+  /** Sum of those stories' scores */
+  totalScore: number
+}
+
+/**
+ * A story whose rank in a feed changed between two refreshes (1-based
+ * ranks; null when the story is new to or dropped out of the feed)
+ */
+export interface PositionDelta {
+  id: number
+  oldRank: number | null
+  newRank: number | null
+}
+
+/**
+ * Reading activity over a period (one day, or all time)
+ */
+export interface ReadingCounts {
+  articlesRead: number
+  wordsRead: number
+  estimatedMinutes: number
+  storiesOpened: number
+  sessions: number
+  sessionSeconds: number
+}
+
+/**
+ * Reading statistics
+ * Returned by get_reading_stats
+ */
+export interface ReadingStats {
+  /** All-time totals */
+  totals: ReadingCounts
+  /** Active days (UTC `YYYY-MM-DD`), oldest first */
+  days: Array<{ date: string } & ReadingCounts>
+  /** Consecutive active days up to today or yesterday */
+  currentStreak: number
+}
+
+/**
+ * Persisted backend settings
+ * Returned by get_settings
+ */
+export interface AppSettings {
+  /** Comment depth used when none is passed */
+  commentDepth: number
+  /** Per-feed overrides of `commentDepth` */
+  feedCommentDepth: Partial<Record<StoryFeed, number>>
+  /** Neural TTS model directory, null for the platform default */
+  modelDir?: string | null
+  /** Whether the saved neural voice is warmed up at startup */
+  neuralWarmupOnStartup?: boolean
+}
+
 /**
  * Hacker News user profile
  */
@@ -119,6 +216,41 @@ export interface ArticleContent {
   lang: string | null
   /** Word count for reading time */
   wordCount: number
+  /** Section headings for a table of contents */
+  headings?: ArticleHeading[]
+  /** Code blocks, for highlighting and TTS skipping */
+  codeBlocks?: ArticleCodeBlock[]
+  /** Outbound links, for a "links in this article" panel */
+  links?: ArticleLink[]
+  /** Extraction confidence from 0 to 1; low means a fallback guess */
+  confidence?: number
+  /** User agent that fetched the page; a fallback if the default was blocked */
+  userAgent?: string | null
+}
+
+/**
+ * A heading in an extracted article; `id` is its anchor in `content`
+ */
+export interface ArticleHeading {
+  level: number
+  text: string
+  id: string
+}
+
+/**
+ * A code block in an extracted article
+ */
+export interface ArticleCodeBlock {
+  language: string | null
+  content: string
+}
+
+/**
+ * A link found in an extracted article
+ */
+export interface ArticleLink {
+  text: string
+  url: string
 }
 
 /**
@@ -139,3 +271,52 @@ export interface CacheStats {
   /** TTL for users in seconds */
   userTtlSecs: number
 }
+
+/**
+ * Payload of `feed-refreshed` events: a background refresh changed a feed
+ */
+export interface FeedRefreshed {
+  feed: StoryFeed
+  changed: boolean
+  /** Stories that weren't in the feed before */
+  newCount: number
+}
+
+/**
+ * Payload of `feed-updated` events: the refresh timer changed a feed
+ */
+export interface FeedUpdate {
+  feed: StoryFeed
+  /** The new story IDs, in feed order */
+  ids: number[]
+  deltas: PositionDelta[]
+  /** Stories that weren't in the feed before */
+  newCount: number
+}
+
+/** When a feed was last fetched, from get_feed_ages */
+export interface FeedAge {
+  feed: StoryFeed
+  /** Unix time in milliseconds, null if not fetched this session */
+  refreshedAt: number | null
+  /** Seconds since the last fetch */
+  ageSecs: number | null
+}
+
+/**
+ * An item evicted from the backend item cache; `cache-evicted` events carry
+ * a batch of these
+ */
+export interface CacheEviction {
+  id: number
+  cause: 'expired' | 'explicit' | 'size'
+}
+
+/**
+ * Entries written to or restored from a cache snapshot
+ */
+export interface CacheSnapshotCounts {
+  items: number
+  storyIds: number
+  users: number
+}
@@ -147,6 +147,7 @@ describe('user-profile', () => {
       items: mockSubmissions,
       hasMore: true,
       total: 21,
+      nextOffset: 20,
     })
   })
 
@@ -260,6 +261,7 @@ describe('user-profile', () => {
         items: mockSubmissions,
         hasMore: false,
         total: 3,
+        nextOffset: 3,
       })
 
       await renderUserProfile('newuser', container)
@@ -272,6 +274,7 @@ describe('user-profile', () => {
         items: [],
         hasMore: false,
         total: 0,
+        nextOffset: 0,
       })
 
       await renderUserProfile('testuser', container)
@@ -402,6 +405,7 @@ describe('user-profile', () => {
                   items: mockSubmissions,
                   hasMore: false,
                   total: 2,
+                  nextOffset: 2,
                 }),
               100,
             ),
@@ -445,6 +449,7 @@ describe('user-profile', () => {
         items: [],
         hasMore: false,
         total: 0,
+        nextOffset: 0,
       })
 
       const storiesTab = container.querySelector(
@@ -502,6 +507,7 @@ describe('user-profile', () => {
                   items: mockSubmissions,
                   hasMore: true,
                   total: 21,
+                  nextOffset: 20,
                 }),
               100,
             ),
@@ -519,9 +525,17 @@ describe('user-profile', () => {
       expect(loadMoreBtn.textContent).toBe('Loading...')
     })
 
-    it('updates offset after loading more', async () => {
+    it('continues from the nextOffset of the last page', async () => {
       await renderUserProfile('testuser', container)
 
+      // A filtered page scanned further than it returned
+      mockFetchUserSubmissions.mockResolvedValue({
+        items: mockSubmissions,
+        hasMore: true,
+        total: 60,
+        nextOffset: 47,
+      })
+
       const loadMoreBtn = container.querySelector(
         '.load-more-submissions-btn',
       ) as HTMLButtonElement
@@ -529,7 +543,18 @@ describe('user-profile', () => {
 
       await vi.waitFor(() => {
         const list = container.querySelector('.submissions-list') as HTMLElement
-        expect(list.dataset.offset).toBe('40')
+        expect(list.dataset.offset).toBe('47')
+      })
+
+      loadMoreBtn.click()
+
+      await vi.waitFor(() => {
+        expect(mockFetchUserSubmissions).toHaveBeenLastCalledWith(
+          'testuser',
+          47,
+          20,
+          'all',
+        )
       })
     })
 
@@ -558,6 +583,7 @@ describe('user-profile', () => {
         items: additionalSubmissions,
         hasMore: false,
         total: 21,
+        nextOffset: 21,
       })
 
       const loadMoreBtn = container.querySelector(
@@ -577,6 +603,7 @@ describe('user-profile', () => {
         items: [],
         hasMore: false,
         total: 21,
+        nextOffset: 21,
       })
 
       const loadMoreBtn = container.querySelector(
@@ -626,6 +653,7 @@ describe('user-profile', () => {
         items: mockSubmissions,
         hasMore: true,
         total: 21,
+        nextOffset: 20,
       })
 
       // Re-render with load more button
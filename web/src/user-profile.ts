@@ -61,14 +61,14 @@ function setupUserProfileTabs(container: HTMLElement, userId: string): void {
       `
 
       try {
-        const { items } = await fetchUserSubmissions(
+        const { items, nextOffset } = await fetchUserSubmissions(
           userId,
           0,
           SUBMISSIONS_PER_PAGE,
           filter,
         )
         listEl.dataset.filter = filter
-        listEl.dataset.offset = String(SUBMISSIONS_PER_PAGE)
+        listEl.dataset.offset = String(nextOffset)
 
         listEl.innerHTML =
           items.length > 0
@@ -97,7 +97,7 @@ function setupUserProfileTabs(container: HTMLElement, userId: string): void {
       loadMoreBtn.textContent = 'Loading...'
 
       try {
-        const { items, hasMore } = await fetchUserSubmissions(
+        const { items, hasMore, nextOffset } = await fetchUserSubmissions(
           userId,
           offset,
           SUBMISSIONS_PER_PAGE,
@@ -109,8 +109,10 @@ function setupUserProfileTabs(container: HTMLElement, userId: string): void {
             .map((item) => renderSubmissionItem(item))
             .join('')
           listEl.insertAdjacentHTML('beforeend', newHtml)
-          listEl.dataset.offset = String(offset + SUBMISSIONS_PER_PAGE)
         }
+        // Filtered pages can scan past `offset + limit`, so always continue
+        // from the backend's nextOffset
+        listEl.dataset.offset = String(nextOffset)
 
         if (!hasMore) {
           loadMoreBtn.parentElement?.remove()
@@ -150,7 +152,7 @@ export async function renderUserProfile(
     })
 
     // Fetch initial submissions (stories first)
-    const { items: submissions } = await fetchUserSubmissions(
+    const { items: submissions, nextOffset } = await fetchUserSubmissions(
       userId,
       0,
       SUBMISSIONS_PER_PAGE,
@@ -213,7 +215,7 @@ export async function renderUserProfile(
               <button class="tab-btn" data-filter="comments">Comments</button>
             </div>
           </div>
-          <div class="submissions-list" data-user="${escapeHtml(userId)}" data-filter="all" data-offset="${SUBMISSIONS_PER_PAGE}">
+          <div class="submissions-list" data-user="${escapeHtml(userId)}" data-filter="all" data-offset="${nextOffset}">
             ${submissionsHtml}
           </div>
           ${